@@ -1,5 +1,5 @@
 pub use plat_lexer::IntType;
-use plat_lexer::Span;
+pub use plat_lexer::Span;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Program {
@@ -7,6 +7,8 @@ pub struct Program {
     pub use_decls: Vec<UseDecl>,
     pub type_aliases: Vec<TypeAlias>,
     pub newtypes: Vec<NewtypeDecl>,
+    pub constants: Vec<ConstDecl>,
+    pub globals: Vec<GlobalVar>,
     pub test_blocks: Vec<TestBlock>,
     pub bench_blocks: Vec<BenchBlock>,
     pub functions: Vec<Function>,
@@ -23,9 +25,21 @@ pub struct ModuleDecl {
 #[derive(Debug, Clone, PartialEq)]
 pub struct UseDecl {
     pub path: Vec<String>, // e.g., ["database"] for "use database;"
+    pub items: Vec<String>, // e.g., ["add", "subtract"] for "use math::{add, subtract};" (empty imports the whole module)
     pub span: Span,
 }
 
+impl UseDecl {
+    /// Qualified paths this declaration imports: the module itself, or each named item in it
+    pub fn imported_paths(&self) -> Vec<String> {
+        let module_path = self.path.join("::");
+        if self.items.is_empty() {
+            return vec![module_path];
+        }
+        self.items.iter().map(|item| format!("{}::{}", module_path, item)).collect()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypeAlias {
     pub name: String,
@@ -38,10 +52,28 @@ pub struct TypeAlias {
 pub struct NewtypeDecl {
     pub name: String,
     pub underlying_type: Type,
+    pub methods: Vec<Function>, // Called on newtype values; `self` has the newtype, not the underlying type
+    pub is_public: bool,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstDecl {
+    pub name: String,
+    pub ty: Type,
+    pub value: Expression, // Must be a compile-time constant expression
     pub is_public: bool,
     pub span: Span,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalVar {
+    pub name: String,
+    pub ty: Type,
+    pub value: Expression, // Evaluated once at startup, before main runs
+    pub span: Span,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TestBlock {
     pub name: String, // Test block description
@@ -94,6 +126,7 @@ pub enum Type {
     Dict(Box<Type>, Box<Type>), // Key type, Value type
     Set(Box<Type>), // Element type
     Named(String, Vec<Type>), // e.g., Option<T>, Message
+    Tuple(Vec<Type>), // e.g., (Int32, String)
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -116,6 +149,11 @@ pub enum Statement {
         value: Expression,
         span: Span,
     },
+    LetPattern {
+        pattern: Pattern, // Tuple, or a variant of a single-variant enum
+        value: Expression,
+        span: Span,
+    },
     Expression(Expression),
     Return {
         value: Option<Expression>,
@@ -135,6 +173,7 @@ pub enum Statement {
     For {
         variable: String,
         variable_type: Type,
+        value_binding: Option<(String, Type)>, // Second binding for dict iteration: for (key: K, value: V in dict)
         iterable: Expression,
         body: Block,
         span: Span,
@@ -147,6 +186,30 @@ pub enum Statement {
         body: Block,
         span: Span,
     },
+    Select {
+        arms: Vec<SelectArm>,
+        default: Option<Block>, // Runs when no channel is ready; makes the select non-blocking
+        span: Span,
+    },
+}
+
+impl Statement {
+    /// Source span covering this statement
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::Let { span, .. } => *span,
+            Statement::Var { span, .. } => *span,
+            Statement::LetPattern { span, .. } => *span,
+            Statement::Expression(expr) => expr.span(),
+            Statement::Return { span, .. } => *span,
+            Statement::If { span, .. } => *span,
+            Statement::While { span, .. } => *span,
+            Statement::For { span, .. } => *span,
+            Statement::Print { span, .. } => *span,
+            Statement::Concurrent { span, .. } => *span,
+            Statement::Select { span, .. } => *span,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -212,6 +275,11 @@ pub enum Expression {
         member: String,
         span: Span,
     },
+    OptionalMemberAccess {
+        object: Box<Expression>, // Option<Class> receiver
+        member: String,
+        span: Span,
+    },
     ConstructorCall {
         class_name: String,
         args: Vec<NamedArg>,
@@ -226,6 +294,7 @@ pub enum Expression {
         start: Box<Expression>,
         end: Box<Expression>,
         inclusive: bool, // true for ..=, false for ..
+        step: Option<Box<Expression>>, // `start..end step n`
         span: Span,
     },
     If {
@@ -243,6 +312,55 @@ pub enum Expression {
         body: Box<Expression>,
         span: Span,
     },
+    Tuple {
+        elements: Vec<Expression>,
+        span: Span,
+    },
+    TupleAccess {
+        tuple: Box<Expression>,
+        index: usize,
+        span: Span,
+    },
+}
+
+impl Expression {
+    /// Source span covering this expression
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::Literal(lit) => match lit {
+                Literal::Bool(_, span) => *span,
+                Literal::Integer(_, _, span) => *span,
+                Literal::Float(_, _, span) => *span,
+                Literal::String(_, span) => *span,
+                Literal::InterpolatedString(_, span) => *span,
+                Literal::Array(_, span) => *span,
+                Literal::Dict(_, span) => *span,
+                Literal::Set(_, span) => *span,
+            },
+            Expression::Identifier { span, .. } => *span,
+            Expression::Binary { span, .. } => *span,
+            Expression::Unary { span, .. } => *span,
+            Expression::Call { span, .. } => *span,
+            Expression::Assignment { span, .. } => *span,
+            Expression::Index { span, .. } => *span,
+            Expression::MethodCall { span, .. } => *span,
+            Expression::Block(block) => block.span,
+            Expression::EnumConstructor { span, .. } => *span,
+            Expression::Match { span, .. } => *span,
+            Expression::Try { span, .. } => *span,
+            Expression::MemberAccess { span, .. } => *span,
+            Expression::OptionalMemberAccess { span, .. } => *span,
+            Expression::If { span, .. } => *span,
+            Expression::Cast { span, .. } => *span,
+            Expression::Self_ { span, .. } => *span,
+            Expression::ConstructorCall { span, .. } => *span,
+            Expression::SuperCall { span, .. } => *span,
+            Expression::Range { span, .. } => *span,
+            Expression::Spawn { span, .. } => *span,
+            Expression::Tuple { span, .. } => *span,
+            Expression::TupleAccess { span, .. } => *span,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -311,9 +429,19 @@ pub struct EnumVariant {
     pub span: Span,
 }
 
+/// One `recv binding from channel -> { ... }` arm of a `select` statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectArm {
+    pub binding: String,
+    pub channel: Expression,
+    pub body: Block,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct MatchArm {
     pub pattern: Pattern,
+    pub binding: Option<String>, // `name @ pattern` binds the whole matched value to `name`
     pub body: Expression,
     pub span: Span,
 }
@@ -331,6 +459,10 @@ pub enum Pattern {
         span: Span,
     },
     Literal(Literal),
+    Tuple {
+        bindings: Vec<(String, Type)>, // Only valid in `let (a: T, b: U) = ...`
+        span: Span,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -339,6 +471,7 @@ pub struct ClassDecl {
     pub type_params: Vec<String>,
     pub parent_class: Option<String>, // None for no inheritance, Some(name) for inheritance
     pub fields: Vec<FieldDecl>,
+    pub constants: Vec<ConstDecl>, // Associated constants, accessed as ClassName::NAME
     pub methods: Vec<Function>,
     pub is_public: bool,
     pub span: Span,
@@ -1,12 +1,12 @@
 use clap::{Parser, Subcommand};
 use colored::*;
 use globset::{Glob, GlobSet, GlobSetBuilder};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::{self, Command};
 use anyhow::{Context, Result};
-use plat_modules::ModuleResolver;
+use plat_modules::{ModuleDependencies, ModuleResolver};
 use plat_diags::{DiagnosticError, Span};
 
 #[derive(Parser)]
@@ -28,6 +28,9 @@ enum Commands {
     Run {
         /// The Plat source file to run (optional - looks for main.plat if not specified)
         file: Option<PathBuf>,
+        /// Arguments passed through to the program (after `--`)
+        #[arg(last = true)]
+        args: Vec<String>,
     },
     /// Format a Plat source file
     Fmt {
@@ -109,7 +112,7 @@ fn run() -> Result<()> {
 
     match cli.command {
         Commands::Build { file } => build_command(file),
-        Commands::Run { file } => run_command(file),
+        Commands::Run { file, args } => run_command(file, args),
         Commands::Fmt { file } => fmt_command(file),
         Commands::Test { file, filter } => test_command(file, filter),
         Commands::Bench { file } => bench_command(file),
@@ -149,10 +152,10 @@ fn build_single_file(file: PathBuf) -> Result<()> {
 
         // Discover all dependencies
         let files = vec![file.clone()];
-        let ordered_files = resolve_modules(&files, current_dir)?;
+        let (ordered_files, module_hashes) = resolve_modules(&files, current_dir)?;
 
         // Build all modules together
-        build_multi_module(&ordered_files)?;
+        build_multi_module(&ordered_files, &module_hashes, current_dir)?;
 
         println!("{} Generated executable: {}", "✓".green().bold(), output_path.display());
         return Ok(());
@@ -172,7 +175,8 @@ fn build_single_file(file: PathBuf) -> Result<()> {
 
     // Generate native code using Cranelift
     let codegen = plat_codegen::CodeGenerator::new()
-        .with_context(|| "Failed to initialize code generator")?;
+        .with_context(|| "Failed to initialize code generator")?
+        .with_debug_info(filename.as_ref(), &source);
     match codegen.generate_code(&program) {
         Ok(object_bytes) => {
             println!("  {} Linking...", "→".cyan());
@@ -216,6 +220,7 @@ fn build_single_file(file: PathBuf) -> Result<()> {
                 .arg(&output_path)
                 .arg(&object_file)
                 .arg(&runtime_lib)
+                .arg("-lm") // Cranelift lowers some float ops (e.g. nearest) to libm calls
                 .output()
                 .with_context(|| "Failed to run linker")?;
 
@@ -255,7 +260,7 @@ fn build_project() -> Result<()> {
 
     // Resolve module dependencies
     println!("  {} Resolving dependencies...", "→".cyan());
-    let ordered_files = resolve_modules(&files, &current_dir)?;
+    let (ordered_files, module_hashes) = resolve_modules(&files, &current_dir)?;
 
     println!("  {} Compilation order: {}", "→".cyan(),
         ordered_files.iter()
@@ -264,7 +269,7 @@ fn build_project() -> Result<()> {
             .join(" → "));
 
     // Build all modules together with cross-module symbol resolution
-    build_multi_module(&ordered_files)?;
+    build_multi_module(&ordered_files, &module_hashes, &current_dir)?;
 
     println!("\n{} Project built successfully", "✓".green().bold());
 
@@ -272,13 +277,26 @@ fn build_project() -> Result<()> {
 }
 
 /// Build multiple modules together with cross-module symbol resolution
-fn build_multi_module(ordered_files: &[PathBuf]) -> Result<()> {
+fn build_multi_module(ordered_files: &[PathBuf], module_hashes: &HashMap<PathBuf, u64>, root_dir: &Path) -> Result<()> {
     // Initialize stdlib cache
     let cache_dir = get_project_root()?.join("target").join("stdlib-cache");
     let stdlib_cache = plat_modules::StdlibCache::new(cache_dir);
     stdlib_cache.init()
         .with_context(|| "Failed to initialize stdlib cache")?;
 
+    // User modules are cached per project, keyed by content hash; salting with the
+    // compiler's build time keeps objects from an older compiler from being reused
+    let compiler_stamp = std::env::current_exe()
+        .and_then(|exe| fs::metadata(exe)?.modified())
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|age| age.as_nanos() as u64)
+        .unwrap_or(0);
+    let module_cache = plat_modules::ModuleCache::new(root_dir.join("target").join("module-cache"))
+        .with_salt(compiler_stamp);
+    module_cache.init()
+        .with_context(|| "Failed to initialize module cache")?;
+
     // Phase 1: Parse all modules (user and stdlib)
     println!("\n  {} Parsing all modules...", "→".cyan());
     let mut modules = Vec::new();
@@ -336,9 +354,8 @@ fn build_multi_module(ordered_files: &[PathBuf]) -> Result<()> {
 
         // Add imports for this module
         for use_decl in &program.use_decls {
-            let import_path = use_decl.path.join("::");
-            eprintln!("DEBUG: Adding import '{}' for module '{}'", import_path, module_path);
-            module_symbols.add_import(import_path);
+            eprintln!("DEBUG: Adding import '{}' for module '{}'", use_decl.imported_paths().join(", "), module_path);
+            module_symbols.add_use(use_decl);
         }
 
         let type_checker = plat_hir::TypeChecker::with_symbols(module_symbols);
@@ -353,6 +370,14 @@ fn build_multi_module(ordered_files: &[PathBuf]) -> Result<()> {
     println!("  {} Generating code for all modules...", "→".cyan());
     let mut object_files = Vec::new();
 
+    // Public classes of every module, so callers can lay out instances returned across modules
+    let public_classes: Vec<(String, plat_ast::ClassDecl)> = modules.iter()
+        .filter_map(|(_, program)| program.module_decl.as_ref().map(|m| (m.path.join("::"), program)))
+        .flat_map(|(module_path, program)| program.classes.iter()
+            .filter(|class_decl| class_decl.is_public)
+            .map(move |class_decl| (module_path.clone(), class_decl.clone())))
+        .collect();
+
     for (file_path, program) in &modules {
         let module_path = program.module_decl
             .as_ref()
@@ -372,10 +397,27 @@ fn build_multi_module(ordered_files: &[PathBuf]) -> Result<()> {
             }
         }
 
+        // Reuse the object for user modules whose source and imports are unchanged
+        let module_hash = module_hashes.get(file_path).copied();
+        if !module_path.starts_with("std::") {
+            if let Some(cached_path) = module_hash.and_then(|hash| module_cache.get(&module_path, hash)) {
+                println!("    {} Using cached {}", "→".cyan(), file_path.display());
+                object_files.push(cached_path);
+                continue;
+            }
+        }
+
         // Compile the module
-        let codegen = plat_codegen::CodeGenerator::new()
+        let mut codegen = plat_codegen::CodeGenerator::new()
             .with_context(|| "Failed to initialize code generator")?
-            .with_symbol_table(global_symbols.clone());
+            .with_symbol_table(global_symbols.clone())
+            .with_imported_classes(public_classes.iter()
+                .filter(|(class_module, _)| *class_module != module_path)
+                .cloned()
+                .collect());
+        if let Ok(source) = fs::read_to_string(file_path) {
+            codegen = codegen.with_debug_info(&file_path.to_string_lossy(), &source);
+        }
 
         let object_bytes = match codegen.generate_code(program) {
             Ok(bytes) => bytes,
@@ -394,6 +436,10 @@ fn build_multi_module(ordered_files: &[PathBuf]) -> Result<()> {
                 // Don't fail the build if caching fails, just log it
                 eprintln!("Warning: Failed to cache {}: {}", module_path, e);
             }
+        } else if let Some(hash) = module_hash {
+            if let Err(e) = module_cache.put(&module_path, &object_file, hash) {
+                eprintln!("Warning: Failed to cache {}: {}", file_path.display(), e);
+            }
         }
 
         eprintln!("DEBUG: Adding compiled object file: {:?}", object_file);
@@ -457,6 +503,7 @@ fn build_multi_module(ordered_files: &[PathBuf]) -> Result<()> {
     }
 
     link_command.arg(&runtime_lib);
+    link_command.arg("-lm"); // Cranelift lowers some float ops (e.g. nearest) to libm calls
 
     let link_result = link_command
         .output()
@@ -477,7 +524,7 @@ fn build_multi_module(ordered_files: &[PathBuf]) -> Result<()> {
     Ok(())
 }
 
-fn run_command(file: Option<PathBuf>) -> Result<()> {
+fn run_command(file: Option<PathBuf>, args: Vec<String>) -> Result<()> {
     let file_to_run = match file {
         Some(f) => f,
         None => {
@@ -503,6 +550,7 @@ fn run_command(file: Option<PathBuf>) -> Result<()> {
     println!("{} Executing {}", "→".cyan(), output_path.display());
 
     let run_result = Command::new(&output_path)
+        .args(&args)
         .output()
         .with_context(|| format!("Failed to execute binary: {}", output_path.display()))?;
 
@@ -522,6 +570,17 @@ fn run_command(file: Option<PathBuf>) -> Result<()> {
             println!("{} Process exited with code: {}", "ℹ".yellow().bold(), code);
             process::exit(code);
         }
+
+        // No exit code means the process was killed by a signal (e.g. SIGABRT from `panic`)
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if let Some(signal) = run_result.status.signal() {
+                println!("{} Process terminated by signal: {}", "ℹ".yellow().bold(), signal);
+                process::exit(128 + signal);
+            }
+        }
+        process::exit(1);
     }
 
     Ok(())
@@ -599,7 +658,7 @@ fn test_single_file(file: PathBuf, filters: Vec<String>) -> Result<()> {
 
     // Compile and run the test program
     let output_path = get_output_path(&file);
-    compile_test_program(&mut program, &output_path)?;
+    compile_test_program(&mut program, &source, &output_path)?;
 
     // Execute the tests
     let test_result = Command::new(&output_path)
@@ -866,6 +925,10 @@ fn type_to_string(ty: &plat_ast::Type) -> String {
                 format!("{}<{}>", name, params_str)
             }
         }
+        plat_ast::Type::Tuple(elements) => {
+            let elements_str = elements.iter().map(|e| type_to_string(e)).collect::<Vec<_>>().join(", ");
+            format!("({})", elements_str)
+        }
     }
 }
 
@@ -973,7 +1036,7 @@ fn generate_test_main_with_hooks(test_blocks: &[TestBlockInfo]) -> String {
 }
 
 /// Compile a test program with test mode enabled
-fn compile_test_program(program: &mut plat_ast::Program, output_path: &Path) -> Result<()> {
+fn compile_test_program(program: &mut plat_ast::Program, source: &str, output_path: &Path) -> Result<()> {
     // Type check with test mode enabled
     let type_checker = plat_hir::TypeChecker::new().with_test_mode();
     if let Err(e) = type_checker.check_program(program) {
@@ -983,7 +1046,8 @@ fn compile_test_program(program: &mut plat_ast::Program, output_path: &Path) ->
     // Generate code with test mode enabled
     let codegen = plat_codegen::CodeGenerator::new()
         .with_context(|| "Failed to initialize code generator")?
-        .with_test_mode();
+        .with_test_mode()
+        .with_source(source);
 
     let object_bytes = codegen
         .generate_code(program)
@@ -1033,6 +1097,7 @@ fn compile_test_program(program: &mut plat_ast::Program, output_path: &Path) ->
         .arg(output_path)
         .arg(&object_file)
         .arg(&runtime_lib)
+        .arg("-lm") // Cranelift lowers some float ops (e.g. nearest) to libm calls
         .output()
         .with_context(|| "Failed to run linker")?;
 
@@ -1088,14 +1153,25 @@ fn get_project_root() -> Result<PathBuf> {
     }
 }
 
-/// Parse a single .plat file and extract its module declaration and imports
-fn parse_module_info(file_path: &Path) -> Result<(String, Vec<String>)> {
+/// A module's declaration, imports, and top-level item names, as the resolver needs them
+struct ModuleInfo {
+    module_path: String,
+    /// Imported module paths, for the dependency graph
+    imports: Vec<String>,
+    /// Imported paths, with each item of a `use module::{item}` qualified by its module
+    imported_paths: Vec<String>,
+    items: Vec<String>,
+    public_items: Vec<String>,
+}
+
+/// Parse a single .plat file and extract its module declaration, imports, and top-level item names
+fn parse_module_info(file_path: &Path) -> Result<ModuleInfo> {
     let source = fs::read_to_string(file_path)
         .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
 
     let parser = plat_parser::Parser::new(&source)
         .with_context(|| "Failed to create parser")?;
-    let mut program = parser.parse()
+    let program = parser.parse()
         .with_context(|| "Failed to parse program")?;
 
     let module_path = program.module_decl
@@ -1108,7 +1184,22 @@ fn parse_module_info(file_path: &Path) -> Result<(String, Vec<String>)> {
         .map(|u| u.path.join("::"))
         .collect();
 
-    Ok((module_path, imports))
+    let imported_paths: Vec<String> = program.use_decls
+        .iter()
+        .flat_map(|u| u.imported_paths())
+        .collect();
+
+    let items: Vec<String> = program.functions.iter().map(|f| f.name.clone())
+        .chain(program.classes.iter().map(|c| c.name.clone()))
+        .chain(program.enums.iter().map(|e| e.name.clone()))
+        .collect();
+
+    let public_items: Vec<String> = program.functions.iter().filter(|f| f.is_public).map(|f| f.name.clone())
+        .chain(program.classes.iter().filter(|c| c.is_public).map(|c| c.name.clone()))
+        .chain(program.enums.iter().filter(|e| e.is_public).map(|e| e.name.clone()))
+        .collect();
+
+    Ok(ModuleInfo { module_path, imports, imported_paths, items, public_items })
 }
 
 /// Discover all .plat files in the current directory tree
@@ -1134,17 +1225,23 @@ fn discover_plat_files(root: &Path) -> Result<Vec<PathBuf>> {
     Ok(files)
 }
 
-/// Build module dependency graph and get compilation order
-fn resolve_modules(files: &[PathBuf], root_dir: &Path) -> Result<Vec<PathBuf>> {
+/// Build module dependency graph and get compilation order, along with each
+/// user module's content hash for incremental recompilation
+fn resolve_modules(files: &[PathBuf], root_dir: &Path) -> Result<(Vec<PathBuf>, HashMap<PathBuf, u64>)> {
     let stdlib_dir = get_stdlib_root();
     let mut resolver = ModuleResolver::new(root_dir.to_path_buf(), stdlib_dir);
 
     // Register all user modules
+    let mut module_dependencies = Vec::new();
     for file in files {
-        let (module_path, imports) = parse_module_info(file)?;
-        resolver.register_module(file.clone(), &module_path)
+        let info = parse_module_info(file)?;
+        let module_id = resolver.register_module(file.clone(), &info.module_path)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        resolver.register_items(&info.module_path, file, &info.items)
             .map_err(|e| anyhow::anyhow!("{}", e))?;
-        resolver.add_dependencies(&module_path, imports);
+        resolver.register_public_items(&info.module_path, &info.public_items);
+        resolver.add_dependencies(&info.module_path, info.imports);
+        module_dependencies.push(ModuleDependencies { id: module_id, imports: info.imported_paths });
     }
 
     // Discover and register stdlib modules that are imported
@@ -1159,16 +1256,23 @@ fn resolve_modules(files: &[PathBuf], root_dir: &Path) -> Result<Vec<PathBuf>> {
         }
         processed_modules.insert(file.clone());
 
-        let (_, imports) = parse_module_info(&file)?;
+        let info = parse_module_info(&file)?;
+
+        // Stdlib files are reached only through imports; record their items once here
+        if !files.contains(&file) {
+            resolver.register_items(&info.module_path, &file, &info.items)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            resolver.register_public_items(&info.module_path, &info.public_items);
+        }
 
         // For each import that starts with std::, discover and register it
-        for import in imports.iter() {
+        for import in info.imports.iter() {
             if import.starts_with("std::") {
                 // Try to discover the stdlib module
                 if let Ok(module_id) = resolver.discover_stdlib_module(&import) {
                     // Add the stdlib module's dependencies
-                    let (stdlib_module_path, stdlib_imports) = parse_module_info(&module_id.file_path)?;
-                    resolver.add_dependencies(&stdlib_module_path, stdlib_imports);
+                    let stdlib_info = parse_module_info(&module_id.file_path)?;
+                    resolver.add_dependencies(&stdlib_info.module_path, stdlib_info.imports);
 
                     // Also process this stdlib file for its imports
                     to_process.push(module_id.file_path.clone());
@@ -1177,19 +1281,30 @@ fn resolve_modules(files: &[PathBuf], root_dir: &Path) -> Result<Vec<PathBuf>> {
         }
     }
 
+    // Every item named in a `use module::{item}` must exist and be public
+    for dependencies in &module_dependencies {
+        resolver.validate_imports(dependencies)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+    }
+
     // Get compilation order
     let order = resolver.compilation_order()
         .map_err(|e| anyhow::anyhow!("{}", e))?;
 
     // Map module names back to file paths
     let mut ordered_files = Vec::new();
+    let mut module_hashes = HashMap::new();
     for module_name in order {
+        let hash = resolver.content_hash(&module_name).ok();
         if let Ok(module_id) = resolver.resolve_module(&module_name) {
             ordered_files.push(module_id.file_path.clone());
+            if let Some(hash) = hash {
+                module_hashes.insert(module_id.file_path.clone(), hash);
+            }
         }
     }
 
-    Ok(ordered_files)
+    Ok((ordered_files, module_hashes))
 }
 
 fn bench_command(file: Option<PathBuf>) -> Result<()> {
@@ -1344,6 +1459,7 @@ fn generate_bench_main(bench_functions: &[(String, String)]) -> String {
         output.push_str(&format!("    {}();\n", bench_func_name));
         output.push_str(&format!("    {} = {} + 1;\n", warmup_var, warmup_var));
         output.push_str("  }\n");
+        output.push_str("  __bench_reset();\n");
         output.push_str("\n");
 
         // Benchmark phase - use unique variable name
@@ -1353,8 +1469,7 @@ fn generate_bench_main(bench_functions: &[(String, String)]) -> String {
         output.push_str(&format!("    {}();\n", bench_func_name));
         output.push_str(&format!("    {} = {} + 1;\n", bench_var, bench_var));
         output.push_str("  }\n");
-        output.push_str(&format!("  print(value = \"  Iterations: {}\");\n", "10,000,000"));
-        output.push_str(&format!("  print(value = \"  (Timing not yet implemented)\");\n"));
+        output.push_str(&format!("  __bench_report(name = \"{}\");\n", bench_func_name));
         output.push_str("\n");
     }
 
@@ -1426,6 +1541,7 @@ fn compile_bench_program(program: &mut plat_ast::Program, output_path: &Path) ->
         .arg(output_path)
         .arg(&object_file)
         .arg(&runtime_lib)
+        .arg("-lm") // Cranelift lowers some float ops (e.g. nearest) to libm calls
         .output()
         .with_context(|| "Failed to run linker")?;
 
@@ -288,4 +288,3749 @@ fn main() -> Int32 {
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("String with spaces"));
     assert!(stdout.contains("String with special chars: !@#$%"));
-}
\ No newline at end of file
+}
+#[test]
+fn test_string_index_of() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("index_of.plat");
+
+    let source = r#"
+fn position(text: String, needle: String) -> Int32 {
+    let result: Option<Int32> = text.index_of(needle = needle);
+    return match result {
+        Option::Some(i: Int32) -> i,
+        Option::None -> -1
+    };
+}
+
+fn main() -> Int32 {
+    let found: Int32 = position(text = "héllo world", needle = "world");
+    let missing: Int32 = position(text = "héllo world", needle = "xyz");
+    let at_start: Int32 = position(text = "héllo world", needle = "hé");
+    print(value = "found=${found} missing=${missing} at_start=${at_start}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("found=6 missing=-1 at_start=0"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_string_escape_sequences() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("escapes.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    print(value = "col1\tcol2");
+    print(value = "line1\nline2");
+    print(value = "say \"hi\" \\ \${literal}");
+    let smile: String = "\u{1F600}";
+    print(value = "smile=${smile} len=${smile.length()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("col1\tcol2"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("line1\nline2"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("say \"hi\" \\ ${literal}"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("smile=\u{1F600} len=1"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_const_declarations() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("consts.plat");
+
+    let source = r#"
+const SIZE: Int32 = 4;
+const CAPACITY: Int32 = SIZE * 25 + 1;
+const BIG: Int64 = 5_000_000_000i64;
+const GREETING: String = "hello";
+
+fn main() -> Int32 {
+    var buffer: List[Int32] = [];
+    for (i: Int32 in 0..SIZE) {
+        buffer.push(value = i * CAPACITY);
+    }
+    let total: Int32 = CAPACITY - SIZE;
+    print(value = "len=${buffer.len()} total=${total} big=${BIG} greeting=${GREETING}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("len=4 total=97 big=5000000000 greeting=hello"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_class_associated_constants() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("assoc_consts.plat");
+
+    let source = r#"
+class Circle {
+    const PI: Float64 = 3.14159;
+    const SIDES: Int32 = 0;
+    let radius: Float64;
+
+    init(radius: Float64) -> Circle {
+        self.radius = radius;
+        return self;
+    }
+
+    pub fn area() -> Float64 {
+        return PI * self.radius * self.radius;
+    }
+}
+
+fn main() -> Int32 {
+    let c: Circle = Circle.init(radius = 2.0);
+    let pi: Float64 = Circle::PI;
+    print(value = "area=${c.area()} pi=${pi} sides=${Circle::SIDES}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("area=12.56636 pi=3.14159 sides=0"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_global_mutable_variable() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("globals.plat");
+
+    let source = r#"
+var counter: Int32 = 10;
+var log: List[Int32] = [];
+
+fn increment() {
+    counter = counter + 1;
+    log.push(value = counter);
+}
+
+fn main() -> Int32 {
+    increment();
+    increment();
+    print(value = "counter=${counter} entries=${log.len()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("counter=12 entries=2"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_float16_class_fields() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("float16.plat");
+
+    let source = r#"
+class Sample {
+    pub let flag: Int8;
+    pub var a: Float16;
+    pub let b: Float16;
+    pub let c: Float32;
+}
+
+fn main() -> Int32 {
+    var s: Sample = Sample.init(flag = 7i8, a = 0.1f16, b = 65504.0f16, c = 2.0f32);
+    let a: Float16 = s.a;
+    let b: Float16 = s.b;
+    print(value = "a=${a} b=${b}");
+    s.a = s.a + 1.5f16;
+    let bumped: Float16 = s.a;
+    let scaled: Float32 = s.a * s.c;
+    let flag: Int8 = s.flag;
+    print(value = "bumped=${bumped} scaled=${scaled} flag=${flag}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    // Fields are stored at half precision, so 0.1 rounds to the nearest f16
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("a=0.099975586 b=65504"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("bumped=1.5996094 scaled=3.1992188 flag=7"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_float16_rounds_only_when_stored() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("float16_rounding.plat");
+
+    let source = r#"
+class Acc {
+    pub var total: Float16;
+}
+
+fn main() -> Int32 {
+    var acc: Acc = Acc.init(total = 2048.0f16);
+    let sum: Float16 = acc.total + 1.0f16;
+    print(value = "unrounded=${sum}");
+    acc.total = sum;
+    let stored: Float16 = acc.total;
+    print(value = "stored=${stored}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    // Arithmetic runs in f32, so 2049 survives until the field store rounds it to the f16 grid
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("unrounded=2049"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("stored=2048"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_tuples() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("tuples.plat");
+
+    let source = r#"
+fn describe(code: Int32) -> (Int32, String) {
+    return (code * 2, "doubled");
+}
+
+fn main() -> Int32 {
+    let pair: (Int32, String) = (42, "answer");
+    let n: Int32 = pair.0;
+    let s: String = pair.1;
+    print(value = "n=${n} s=${s}");
+
+    let mixed: (Int8, Int64, Bool) = (3i8, 9000000000i64, true);
+    let big: Int64 = mixed.1;
+    let nested: ((Int32, Int32), String) = ((1, 2), "x");
+    print(value = "big=${big} inner=${nested.0.1}");
+
+    let result: (Int32, String) = describe(code = 21);
+    print(value = "${result.1}=${result.0}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("n=42 s=answer"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("big=9000000000 inner=2"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("doubled=42"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_optional_chaining() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("optional_chaining.plat");
+
+    let source = r#"
+class Country {
+    pub let code: String;
+}
+
+class Address {
+    pub let city: String;
+    pub let zip: Int32;
+    pub let country: Option<Country>;
+}
+
+class User {
+    pub let address: Option<Address>;
+}
+
+fn city_of(user: User) -> String {
+    return match user.address?.city {
+        Option::Some(c: String) -> c,
+        Option::None -> "unknown"
+    };
+}
+
+fn main() -> Int32 {
+    let country: Country = Country.init(code = "UK");
+    let home: User = User.init(address = Option::Some(value = Address.init(city = "London", zip = 42, country = Option::Some(value = country))));
+    let nomad: User = User.init(address = Option::None);
+    print(value = "home=${city_of(user = home)} nomad=${city_of(user = nomad)}");
+
+    let zip: Int32 = match home.address?.zip {
+        Option::Some(z: Int32) -> z,
+        Option::None -> -1
+    };
+    let code: String = match home.address?.country?.code {
+        Option::Some(c: String) -> c,
+        Option::None -> "none"
+    };
+    let missing: String = match nomad.address?.country?.code {
+        Option::Some(c: String) -> c,
+        Option::None -> "none"
+    };
+    print(value = "zip=${zip} code=${code} missing=${missing}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("home=London nomad=unknown"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("zip=42 code=UK missing=none"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_dict_get_returns_option() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("dict_get.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let counts: Dict[String, Int32] = {"apples": 3, "zero": 0};
+    let present: Int32 = match counts.get(key = "apples") {
+        Option::Some(v: Int32) -> v,
+        Option::None -> -1
+    };
+    let zero: Int32 = match counts.get(key = "zero") {
+        Option::Some(v: Int32) -> v,
+        Option::None -> -1
+    };
+    let absent: Int32 = match counts.get(key = "missing") {
+        Option::Some(v: Int32) -> v,
+        Option::None -> -1
+    };
+    print(value = "present=${present} zero=${zero} absent=${absent}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("present=3 zero=0 absent=-1"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_dict_for_loop() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("dict_for.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let scores: Dict[String, Int32] = {"alice": 10, "bob": 20, "carol": 12};
+    var total: Int32 = 0;
+    for (name: String, score: Int32 in scores) {
+        total = total + score;
+    }
+    print(value = "total=${total}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("total=42"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_set_for_loop() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("set_for.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let tags: Set[String] = Set{"red", "green", "blue"};
+    var count: Int32 = 0;
+    for (tag: String in tags) {
+        if (tag.length() > 0) {
+            count = count + 1;
+        }
+    }
+    print(value = "count=${count}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("count=3"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_array_unique() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("unique.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let nums: List[Int32] = [1, 2, 2, 3, 1];
+    let distinct: List[Int32] = nums.unique();
+    print(value = "distinct=${distinct} original=${nums}");
+
+    let words: List[String] = ["b", "a", "b", "c", "a"];
+    let distinct_words: List[String] = words.unique();
+    print(value = "words=${distinct_words}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("distinct=[1, 2, 3] original=[1, 2, 2, 3, 1]"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains(r#"words=["b", "a", "c"]"#), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_array_find_with_named_predicate() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("find.plat");
+
+    let source = r#"
+var calls: Int32 = 0;
+
+fn is_even(x: Int32) -> Bool {
+    calls = calls + 1;
+    return x % 2 == 0;
+}
+
+fn main() -> Int32 {
+    let nums: List[Int32] = [1, 3, 4, 5];
+    let found: Option<Int32> = nums.find(predicate = is_even);
+    let even: Int32 = match found {
+        Option::Some(v: Int32) -> v,
+        Option::None -> -1
+    };
+    print(value = "first even=${even} after ${calls} calls");
+
+    let odds: List[Int32] = [1, 3, 5];
+    let missing: Option<Int32> = odds.find(predicate = is_even);
+    let fallback: Int32 = match missing {
+        Option::Some(v: Int32) -> v,
+        Option::None -> -1
+    };
+    print(value = "no even=${fallback}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("first even=4 after 3 calls"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("no even=-1"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_for_loop_over_unique_strings() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("unique_loop.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let words: List[String] = ["b", "a", "b", "c", "a"];
+    for (word: String in words.unique()) {
+        print(value = "word=${word}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("word=b\nword=a\nword=c"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_array_chunks() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("chunks.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let data: List[Int32] = [1, 2, 3, 4, 5];
+    let pairs: List[List[Int32]] = data.chunks(size = 2);
+    print(value = "count=${pairs.len()}");
+    for (chunk: List[Int32] in pairs) {
+        print(value = "chunk=${chunk}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("count=3"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("chunk=[1, 2]\nchunk=[3, 4]\nchunk=[5]"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_for_loop_over_chunks_call() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("chunks_loop.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let data: List[Int32] = [1, 2, 3, 4, 5];
+    for (chunk: List[Int32] in data.chunks(size = 3)) {
+        print(value = "chunk=${chunk}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("chunk=[1, 2, 3]\nchunk=[4, 5]"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_enum_methods_match_self() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("enum_methods.plat");
+
+    let source = r##"
+enum Color {
+    Red,
+    Green,
+    Custom(String),
+
+    fn to_hex() -> String {
+        return match self {
+            Color::Red -> "#ff0000",
+            Color::Green -> "#00ff00",
+            Color::Custom(hex: String) -> hex
+        };
+    }
+
+    fn describe(prefix: String) -> String {
+        let hex: String = self.to_hex();
+        return "${prefix} ${hex}";
+    }
+}
+
+fn main() -> Int32 {
+    let red: Color = Color::Red;
+    let green: Color = Color::Green;
+    let custom: Color = Color::Custom(value = "#123456");
+    print(value = "${red.to_hex()} ${green.to_hex()} ${custom.to_hex()}");
+    let described: String = custom.describe(prefix = "color");
+    print(value = described);
+    return 0;
+}
+"##;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("#ff0000 #00ff00 #123456"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("color #123456"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_float_to_string_precision() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("float_precision.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let pi: Float64 = 3.14159;
+    let two: String = pi.to_string_precision(digits = 2);
+    let none: String = pi.to_string_precision(digits = 0);
+    let price: Float32 = 19.996f32;
+    let cents: String = price.to_string_precision(digits = 2);
+    print(value = "two=${two} none=${none} price=${cents}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("two=3.14 none=3 price=20.00"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_class_method_named_to_string_precision_is_not_the_float_method() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("class_precision.plat");
+
+    let source = r#"
+class Price {
+  var amount: Float64;
+
+  pub fn to_string_precision(digits: Int32) -> String {
+    return "price to ${digits} digits";
+  }
+
+  pub fn show() -> String {
+    return self.amount.to_string_precision(digits = 2);
+  }
+}
+
+fn main() -> Int32 {
+  let price: Price = Price.init(amount = 1.5);
+  print(value = price.to_string_precision(digits = 3));
+  print(value = "shown=${price.show()}");
+  return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("price to 3 digits"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("shown=1.50"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_float_math_methods() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("float_math.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let x: Float64 = -2.7;
+    let a: Float64 = x.abs();
+    let fl: Float64 = x.floor();
+    let ce: Float64 = x.ceil();
+    let ro: Float64 = x.round();
+    let y: Float64 = 16.0;
+    let sq: Float64 = y.sqrt();
+    print(value = "abs=${a} floor=${fl} ceil=${ce} round=${ro} sqrt=${sq}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("abs=2.7 floor=-3 ceil=-2 round=-3 sqrt=4"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_integer_math_methods() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("int_math.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let a: Int32 = (-5).abs();
+    let b: Int32 = 3.max(other = 7);
+    let c: Int32 = 10.clamp(low = 0, high = 5);
+    let d: Int32 = 3.min(other = -2);
+    let big: Int64 = -9000000000i64;
+    let e: Int64 = big.abs();
+    print(value = "a=${a} b=${b} c=${c} d=${d} e=${e}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("a=5 b=7 c=5 d=-2 e=9000000000"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_remainder_versus_rem_euclid() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("rem_euclid.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    var a: Int32 = -7;
+    let truncated: Int32 = a % 3;
+    let euclid: Int32 = (-7).rem_euclid(other = 3);
+    let negative_divisor: Int32 = a.rem_euclid(other = -3);
+    let big: Int64 = -7i64;
+    let wide: Int64 = big.rem_euclid(other = 3i64);
+    print(value = "truncated=${truncated} euclid=${euclid} negative_divisor=${negative_divisor} wide=${wide}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("truncated=-1 euclid=2 negative_divisor=2 wide=2"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_index_dicts_and_strings() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("index_kinds.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let ages: Dict[String, Int32] = {"alice": 30, "bob": 25};
+    let alice: Int32 = match ages["alice"] {
+        Option::Some(v: Int32) -> v,
+        Option::None -> -1
+    };
+    let missing: Int32 = match ages["zed"] {
+        Option::Some(v: Int32) -> v,
+        Option::None -> -1
+    };
+
+    let word: String = "héllo";
+    let second: String = match word[1] {
+        Option::Some(c: String) -> c,
+        Option::None -> "none"
+    };
+    let past_end: String = match word[10] {
+        Option::Some(c: String) -> c,
+        Option::None -> "none"
+    };
+
+    print(value = "alice=${alice} missing=${missing} second=${second} past_end=${past_end}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("alice=30 missing=-1 second=é past_end=none"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_tail_recursion_does_not_grow_stack() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("tail_calls.plat");
+
+    // A million frames would overflow the default stack without tail-call elimination
+    let source = r#"
+fn countdown(n: Int32) -> Int32 {
+    if (n == 0) {
+        return 0;
+    }
+    return countdown(n = n - 1);
+}
+
+fn sum_to(n: Int64, acc: Int64) -> Int64 {
+    if (n == 0i64) {
+        return acc;
+    }
+    return sum_to(n = n - 1i64, acc = acc + n);
+}
+
+fn main() -> Int32 {
+    let done: Int32 = countdown(n = 1000000);
+    let total: Int64 = sum_to(n = 1000000i64, acc = 0i64);
+    print(value = "done=${done} total=${total}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("done=0 total=500000500000"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_statements_after_return_are_elided() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("dead_code.plat");
+
+    let source = r#"
+fn pick(flag: Bool) -> Int32 {
+    if (flag) {
+        return 1;
+        print(value = "dead in branch");
+    }
+    return 2;
+    print(value = "dead after return");
+}
+
+fn main() -> Int32 {
+    let a: Int32 = pick(flag = true);
+    let b: Int32 = pick(flag = false);
+    print(value = "a=${a} b=${b}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("a=1 b=2"), "Unexpected output: {}", stdout);
+    assert!(!stdout.contains("dead in branch") && !stdout.contains("dead after return"), "Unreachable statement ran: {}", stdout);
+    assert_eq!(stderr.matches("Warning: unreachable code in 'pick'").count(), 2, "Missing warnings: {}", stderr);
+}
+
+#[test]
+fn test_main_result_error_printed_to_stderr() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("main_result.plat");
+
+    let source = r#"
+fn main() -> Result<Unit, String> {
+    print(value = "starting");
+    return Result::Err(field0 = "boom");
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(!output.status.success(), "Expected a nonzero exit code");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("starting"), "Unexpected output: {}", stdout);
+    assert!(stderr.lines().any(|line| line == "boom"), "Error not reported: {}", stderr);
+}
+
+#[test]
+fn test_named_arguments_bind_by_name() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("named_args.plat");
+
+    let source = r#"
+fn subtract(a: Int32, b: Int32) -> Int32 {
+    return a - b;
+}
+
+fn label(name: String, count: Int64) -> String {
+    return "${name}:${count}";
+}
+
+fn main() -> Int32 {
+    let x: Int32 = subtract(b = 1, a = 10);
+    let s: String = label(count = 3i64, name = "items");
+    print(value = "x=${x} s=${s}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("x=9 s=items:3"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_format_builtin_substitutes_placeholders() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("format.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let sum: String = format(template = "{0} + {1} = {2}", args = ["1", "2", "3"]);
+    print(value = sum);
+    let words: List[String] = ["a", "b"];
+    let partial: String = format(template = "{1}{0} {5}", args = words);
+    print(value = partial);
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("1 + 2 = 3"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("ba {5}"), "Out-of-range placeholder should stay literal: {}", stdout);
+}
+
+#[test]
+fn test_reverse_ranges_count_down() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("reverse_ranges.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    var down: String = "";
+    for (i: Int32 in 10..0) {
+        down = "${down}${i},";
+    }
+    print(value = "down=${down}");
+    var up: String = "";
+    for (i: Int32 in 0..10) {
+        up = "${up}${i},";
+    }
+    print(value = "up=${up}");
+    var incl: String = "";
+    for (i: Int32 in 3..=-1) {
+        incl = "${incl}${i},";
+    }
+    print(value = "incl=${incl}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("down=10,9,8,7,6,5,4,3,2,1,"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("up=0,1,2,3,4,5,6,7,8,9,"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("incl=3,2,1,0,-1,"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_range_step_follows_sign() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("range_step.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    var evens: String = "";
+    for (i: Int32 in 0..10 step 2) {
+        evens = "${evens}${i},";
+    }
+    print(value = "evens=${evens}");
+    var back: String = "";
+    for (i: Int32 in 5..=0 step -1) {
+        back = "${back}${i},";
+    }
+    print(value = "back=${back}");
+    let stride: Int64 = -3i64;
+    var big: String = "";
+    for (i: Int64 in 10i64..0i64 step stride) {
+        big = "${big}${i},";
+    }
+    print(value = "big=${big}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("evens=0,2,4,6,8,"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("back=5,4,3,2,1,0,"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("big=10,7,4,1,"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_failing_assert_reports_location() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("assert_location.plat");
+
+    let source = r#"test arithmetic {
+  fn test_add() {
+    assert(condition = 1 + 1 == 2);
+    if (true) {
+      assert(condition = 2 + 2 == 5, message = "math is broken");
+    }
+  }
+
+  fn test_match() {
+    let o: Option<Int32> = Option::Some(field0 = 3);
+    match o {
+      Option::Some(v: Int32) -> assert(condition = v == 4, message = "wrong payload"),
+      Option::None -> assert(condition = false, message = "missing payload")
+    };
+  }
+}
+
+fn main() -> Int32 {
+  return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("test")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(!output.status.success(), "Failing test should fail the run");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("✗ math is broken (line 5, column 7)"), "Missing assert location: {}", stderr);
+    assert!(stderr.contains("✗ wrong payload (line 12, column 33)"), "Missing assert location: {}", stderr);
+}
+
+#[test]
+fn test_panic_aborts_with_message() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("panic.plat");
+
+    let source = r#"
+fn checked_div(a: Int32, b: Int32) -> Int32 {
+    if (b == 0) {
+        panic(message = "division by zero");
+    }
+    return a / b;
+}
+
+fn pick(o: Option<Int32>) -> Int32 {
+    return match o {
+        Option::Some(v: Int32) -> v,
+        Option::None -> panic(message = "no value")
+    };
+}
+
+fn main() -> Int32 {
+    let x: Int32 = checked_div(a = 10, b = 2);
+    print(value = "x=${x}");
+    let y: Int32 = pick(o = Option::Some(field0 = 7));
+    print(value = "y=${y}");
+    let z: Int32 = checked_div(a = 1, b = 0);
+    print(value = "unreachable ${z}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(!output.status.success(), "Program should abort");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("x=5") && stdout.contains("y=7"), "Unexpected output: {}", stdout);
+    assert!(!stdout.contains("unreachable"), "Execution continued after panic: {}", stdout);
+    assert!(stderr.contains("panic: division by zero"), "Missing panic message: {}", stderr);
+}
+
+#[test]
+fn test_unwrap_returns_payload_or_aborts() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("unwrap.plat");
+
+    let source = r#"
+fn parse(n: Int32) -> Result<Int64, String> {
+    if (n > 0) {
+        return Result::Ok(field0 = 9000000000i64);
+    }
+    return Result::Err(field0 = "negative");
+}
+
+fn main() -> Int32 {
+    let a: Int32 = Option::Some(field0 = 5).unwrap();
+    print(value = "a=${a}");
+    let b: Int64 = parse(n = 1).expect(message = "parse failed");
+    print(value = "b=${b}");
+    let f: Float64 = Option::Some(field0 = 2.5).unwrap();
+    print(value = "f=${f}");
+    let none: Option<Int32> = Option::None;
+    let c: Int32 = none.unwrap();
+    print(value = "unreachable ${c}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(!output.status.success(), "None.unwrap() should abort");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("a=5"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("b=9000000000"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("f=2.5"), "Unexpected output: {}", stdout);
+    assert!(!stdout.contains("unreachable"), "Execution continued after unwrap: {}", stdout);
+    assert!(stderr.contains("panic: called unwrap() on a None value"), "Missing panic message: {}", stderr);
+}
+
+#[test]
+fn test_option_result_discriminant_checks() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("checks.plat");
+
+    let source = r#"
+fn parse(n: Int32) -> Result<Int64, String> {
+    if (n > 0) {
+        return Result::Ok(field0 = 7i64);
+    }
+    return Result::Err(field0 = "negative");
+}
+
+fn main() -> Int32 {
+    let some: Option<Int32> = Option::Some(field0 = 3);
+    let none: Option<Int32> = Option::None;
+    print(value = "some=${some.is_some()},${some.is_none()}");
+    print(value = "none=${none.is_some()},${none.is_none()}");
+    let good: Result<Int64, String> = parse(n = 1);
+    let bad: Result<Int64, String> = parse(n = -1);
+    print(value = "good=${good.is_ok()},${good.is_err()}");
+    print(value = "bad=${bad.is_ok()},${bad.is_err()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("some=1,0"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("none=0,1"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("good=1,0"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("bad=0,1"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_enum_ordinal_follows_declaration_order() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("ordinal.plat");
+
+    let source = r#"
+enum Level {
+    Low,
+    Medium,
+    High
+}
+
+fn main() -> Int32 {
+    let level: Level = Level::Medium;
+    let medium: Int32 = level.ordinal();
+    print(value = "medium=${medium}");
+    print(value = "low=${Level::Low.ordinal()} high=${Level::High.ordinal()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("medium=1"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("low=0 high=2"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_enum_from_ordinal_round_trips() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("from_ordinal.plat");
+
+    let source = r#"
+enum Color {
+    Red,
+    Green,
+    Blue
+}
+
+fn main() -> Int32 {
+    let green: Color = Color::Green;
+    let back: Color = Color::from_ordinal(n = green.ordinal()).unwrap();
+    let name: String = match back {
+        Color::Red -> "red",
+        Color::Green -> "green",
+        Color::Blue -> "blue"
+    };
+    print(value = "round trip=${name}");
+    let missing: Option<Color> = Color::from_ordinal(n = 3);
+    let negative: Option<Color> = Color::from_ordinal(n = -1);
+    print(value = "out of range=${missing.is_none()},${negative.is_none()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("round trip=green"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("out of range=1,1"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_init_auto_assigns_same_named_params() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("init_shorthand.plat");
+
+    let source = r#"
+class Account {
+  let id: Int32;
+  var owner: String;
+  var balance: Int32;
+
+  init(id: Int32, owner: String) -> Account {
+    self.balance = 100;
+    return self;
+  }
+
+  pub fn describe() -> String {
+    return "${self.owner}#${self.id}: ${self.balance}";
+  }
+}
+
+fn main() -> Int32 {
+  let account: Account = Account.init(id = 7, owner = "ada");
+  print(value = account.describe());
+  return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("ada#7: 100"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_atomic_counter_shared_across_tasks() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("atomics.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let counter: AtomicInt = atomic_init(value = 0i64);
+    concurrent {
+        let first: Task<Int32> = spawn {
+            for (i: Int32 in 0..1000) {
+                atomic_add(ptr = counter, delta = 1i64);
+            }
+            return 0;
+        };
+        let second: Task<Int32> = spawn {
+            for (i: Int32 in 0..1000) {
+                atomic_add(ptr = counter, delta = 1i64);
+            }
+            return 0;
+        };
+        first.await();
+        second.await();
+    }
+    let total: Int64 = atomic_load(ptr = counter);
+    print(value = "total=${total}");
+    let swapped: Bool = compare_and_swap(ptr = counter, expected = 2000i64, new = 5i64);
+    let missed: Bool = compare_and_swap(ptr = counter, expected = 2000i64, new = 7i64);
+    atomic_store(ptr = counter, val = atomic_load(ptr = counter) * 10i64);
+    let now: Int64 = atomic_load(ptr = counter);
+    print(value = "cas=${swapped},${missed} now=${now}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("total=2000"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("cas=1,0 now=50"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_mutex_guards_counter_across_tasks() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("mutex.plat");
+
+    // `bump` returns early, which must still release the lock
+    let source = r#"
+var counter: Int32 = 0;
+
+fn bump() -> Int32 {
+    counter = counter + 1;
+    if (counter > 0) {
+        return counter;
+    }
+    return 0;
+}
+
+fn main() -> Int32 {
+    let guard: Mutex = mutex_init();
+    concurrent {
+        let first: Task<Int32> = spawn {
+            for (i: Int32 in 0..1000) {
+                guard.with_lock(action = bump);
+            }
+            return 0;
+        };
+        let second: Task<Int32> = spawn {
+            for (i: Int32 in 0..1000) {
+                guard.with_lock(action = bump);
+            }
+            return 0;
+        };
+        first.await();
+        second.await();
+    }
+    guard.lock();
+    let total: Int32 = counter;
+    guard.unlock();
+    let last: Int32 = guard.with_lock(action = bump);
+    print(value = "counter=${total} last=${last}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("counter=2000 last=2001"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_mutex_unlock_without_holding_panics() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("mutex_unlock.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let guard: Mutex = mutex_init();
+    guard.lock();
+    guard.unlock();
+    print(value = "released");
+    guard.unlock();
+    print(value = "unreachable");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(!output.status.success(), "Unlocking an unheld mutex should fail");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("released"), "Unexpected output: {}", stdout);
+    assert!(!stdout.contains("unreachable"), "Unexpected output: {}", stdout);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("panic: unlock of a mutex not held by the current task"), "Missing panic message: {}", stderr);
+}
+
+#[test]
+fn test_select_receives_from_ready_channel() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("select.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let first: Channel<Int32> = channel_init(capacity = 4);
+    let second: Channel<Int32> = channel_init(capacity = 4);
+    second.send(value = 42);
+
+    select {
+        recv x from first -> {
+            print(value = "first got ${x}");
+        }
+        recv y from second -> {
+            print(value = "second got ${y}");
+        }
+        default -> {
+            print(value = "nothing ready");
+        }
+    }
+
+    select {
+        recv x from first -> {
+            print(value = "first got ${x}");
+        }
+        default -> {
+            print(value = "nothing ready");
+        }
+    }
+
+    concurrent {
+        let sender: Task<Int32> = spawn {
+            first.send(value = 7);
+            return 0;
+        };
+        select {
+            recv x from first -> {
+                print(value = "blocking got ${x}");
+            }
+            recv y from second -> {
+                print(value = "wrong channel ${y}");
+            }
+        }
+        sender.await();
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("second got 42\nnothing ready\nblocking got 7"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_cancelled_task_await_returns_none() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("cancel.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    concurrent {
+        let slow: Task<Int32> = spawn {
+            time_sleep(millis = 200i64);
+            return 1;
+        };
+        let quick: Task<Int32> = spawn {
+            return 2;
+        };
+        slow.cancel();
+        let slow_result: Option<Int32> = slow.try_await();
+        let quick_result: Option<Int32> = quick.try_await();
+        let slow_text: String = match slow_result {
+            Option::Some(value: Int32) -> "finished",
+            Option::None -> "cancelled",
+        };
+        let quick_value: Int32 = match quick_result {
+            Option::Some(value: Int32) -> value,
+            Option::None -> -1,
+        };
+        print(value = "slow ${slow_text}, quick ${quick_value}");
+        quick.cancel();
+        let again: Int32 = quick.await();
+        print(value = "quick still ${again}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("slow cancelled, quick 2"), "Unexpected output: {}", stdout);
+    // Cancelling an already-finished task has no effect
+    assert!(stdout.contains("quick still 2"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_await_on_cancelled_task_panics() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("await_cancelled.plat");
+
+    // try_await also takes a task expression that is not a variable
+    let source = r#"
+fn main() -> Int32 {
+    concurrent {
+        let direct: Option<Int32> = spawn { return 3; }.try_await();
+        let value: Int32 = match direct {
+            Option::Some(v: Int32) -> v,
+            Option::None -> -1,
+        };
+        print(value = "direct ${value}");
+        let slow: Task<String> = spawn {
+            time_sleep(millis = 200i64);
+            return "late";
+        };
+        slow.cancel();
+        let text: String = slow.await();
+        print(value = "unreachable ${text}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(!output.status.success(), "Awaiting a cancelled task should fail");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("direct 3"), "Unexpected output: {}", stdout);
+    assert!(!stdout.contains("unreachable"), "Unexpected output: {}", stdout);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("panic: awaited a cancelled task"), "Missing panic message: {}", stderr);
+}
+
+#[test]
+fn test_udp_send_and_receive_loopback() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("udp.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let receiver: Int32 = match udp_bind(host = "127.0.0.1", port = 47613) {
+        Result::Ok(fd: Int32) -> fd,
+        Result::Err(msg: String) -> -1,
+    };
+    let sender: Int32 = match udp_bind(host = "127.0.0.1", port = 0) {
+        Result::Ok(fd: Int32) -> fd,
+        Result::Err(msg: String) -> -1,
+    };
+    if (receiver < 0 or sender < 0) {
+        print(value = "bind failed");
+        return 1;
+    }
+
+    for (i: Int32 in 0..3) {
+        let sent: Int32 = match udp_send_to(socket = sender, data = "ping ${i}", host = "127.0.0.1", port = 47613) {
+            Result::Ok(n: Int32) -> n,
+            Result::Err(msg: String) -> -1,
+        };
+        let received: String = match udp_recv_from(socket = receiver, max_bytes = 64) {
+            Result::Ok(text: String) -> text,
+            Result::Err(msg: String) -> msg,
+        };
+        print(value = "sent ${sent} got ${received}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for i in 0..3 {
+        assert!(stdout.contains(&format!("sent 6 got ping {}", i)), "Unexpected output: {}", stdout);
+    }
+}
+
+#[test]
+fn test_file_write_append_read_roundtrip() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("files.plat");
+    let data_file = temp_dir.path().join("notes.txt");
+    let missing_file = temp_dir.path().join("missing.txt");
+
+    let source = format!(r#"
+fn main() -> Int32 {{
+    let path: String = "{}";
+    let written: Int32 = match file_write(path = path, contents = "first line\n") {{
+        Result::Ok(n: Int32) -> n,
+        Result::Err(msg: String) -> -1,
+    }};
+    let appended: Int32 = match file_append(path = path, contents = "second line\n") {{
+        Result::Ok(n: Int32) -> n,
+        Result::Err(msg: String) -> -1,
+    }};
+    let text: String = match file_read(path = path) {{
+        Result::Ok(contents: String) -> contents,
+        Result::Err(msg: String) -> msg,
+    }};
+    print(value = "wrote ${{written}}+${{appended}}");
+    print(value = text);
+    let missing: String = match file_read(path = "{}") {{
+        Result::Ok(contents: String) -> "unexpected",
+        Result::Err(msg: String) -> "missing",
+    }};
+    print(value = missing);
+    return 0;
+}}
+"#, data_file.display(), missing_file.display());
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("wrote 11+12"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("first line\nsecond line"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("missing"), "Unexpected output: {}", stdout);
+    assert_eq!(fs::read_to_string(&data_file).unwrap(), "first line\nsecond line\n");
+}
+
+#[test]
+fn test_env_reads_set_and_unset_variables() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("env.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let configured: String = match env(name = "PLAT_TEST_CONFIGURED") {
+        Option::Some(value: String) -> value,
+        Option::None -> "unset",
+    };
+    let missing: String = match env(name = "PLAT_TEST_NEVER_SET") {
+        Option::Some(value: String) -> value,
+        Option::None -> "unset",
+    };
+    print(value = "configured=${configured} missing=${missing}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .env("PLAT_TEST_CONFIGURED", "staging")
+        .env_remove("PLAT_TEST_NEVER_SET")
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("configured=staging missing=unset"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_args_prints_program_arguments() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("echo_args.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let argv: List[String] = args();
+    let count: Int32 = argv.len();
+    print(value = "count=${count}");
+    for (arg: String in args()) {
+        print(value = "arg=${arg}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .arg("--")
+        .arg("alpha")
+        .arg("beta gamma")
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("count=3"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("arg=alpha\narg=beta gamma"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_monotonic_clock_measures_elapsed_time() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("clock.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let start_nanos: Int64 = now_nanos();
+    let start_millis: Int64 = now_millis();
+    var total: Int64 = 0i64;
+    for (i: Int32 in 0..100000) {
+        total = total + 1i64;
+    }
+    time_sleep(millis = 5i64);
+    let elapsed_nanos: Int64 = now_nanos() - start_nanos;
+    let elapsed_millis: Int64 = now_millis() - start_millis;
+    if (elapsed_nanos > 0i64 and elapsed_millis >= 5i64) {
+        print(value = "elapsed ok after ${total} iterations");
+    } else {
+        print(value = "clock did not advance");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("elapsed ok after 100000 iterations"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_seed_random_replays_sequence() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("random.plat");
+
+    let source = r#"
+fn draw() -> String {
+    let a: Int32 = random_int(min = 0, max = 1000);
+    let b: Int32 = random_int(min = 0, max = 1000);
+    let c: Int64 = random_int(min = 0i64, max = 1000000i64);
+    let f: Float64 = random_float();
+    return "${a} ${b} ${c} ${f}";
+}
+
+fn main() -> Int32 {
+    seed_random(seed = 1234i64);
+    let first: String = draw();
+    seed_random(seed = 1234i64);
+    let second: String = draw();
+    if (first == second) {
+        print(value = "deterministic");
+    } else {
+        print(value = "mismatch: ${first} vs ${second}");
+    }
+
+    var in_range: Bool = true;
+    for (i: Int32 in 0..100) {
+        let roll: Int32 = random_int(min = 1, max = 7);
+        if (roll < 1 or roll > 6) {
+            in_range = false;
+        }
+    }
+    if (in_range) {
+        print(value = "rolls in range");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("deterministic"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("rolls in range"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_hash_equal_values_hash_equally() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("hash.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let literal: String = "apple";
+    let prefix: String = "app";
+    let built: String = "${prefix}le";
+    let literal_hash: Int64 = literal.hash();
+    let built_hash: Int64 = built.hash();
+    let prefix_hash: Int64 = prefix.hash();
+    if (literal_hash == built_hash) {
+        print(value = "equal strings hash equally");
+    }
+    if (literal_hash != prefix_hash) {
+        print(value = "different strings differ");
+    }
+
+    let narrow: Int32 = 42;
+    let wide: Int64 = 42i64;
+    let other: Int32 = 43;
+    let narrow_hash: Int64 = narrow.hash();
+    let wide_hash: Int64 = wide.hash();
+    let other_hash: Int64 = other.hash();
+    if (narrow_hash == wide_hash and narrow_hash != other_hash) {
+        print(value = "integer hashes ok");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("equal strings hash equally"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("different strings differ"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("integer hashes ok"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_structural_equality_for_collections_and_classes() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("equality.plat");
+
+    let source = r#"
+class Point {
+    let x: Int32;
+    let y: Int32;
+    let label: String;
+}
+
+fn main() -> Int32 {
+    let first: List[Int32] = [1, 2, 3];
+    let second: List[Int32] = [1, 2, 3];
+    let other: List[Int32] = [1, 2, 4];
+    if (first == second) { print(value = "arrays equal"); }
+    if (first != other) { print(value = "arrays differ"); }
+
+    let ages: Dict[String, Int32] = {"ann": 30, "bob": 25};
+    let same_ages: Dict[String, Int32] = {"bob": 25, "ann": 30};
+    let older: Dict[String, Int32] = {"ann": 31, "bob": 25};
+    if (ages == same_ages) { print(value = "dicts equal"); }
+    if (ages != older) { print(value = "dicts differ"); }
+
+    let colors: Set[String] = Set{"red", "blue"};
+    let same_colors: Set[String] = Set{"blue", "red"};
+    if (colors == same_colors) { print(value = "sets equal"); }
+
+    let suffix: String = "a";
+    let origin: Point = Point.init(x = 0, y = 0, label = "a");
+    let rebuilt: Point = Point.init(x = 0, y = 0, label = "${suffix}");
+    let moved: Point = Point.init(x = 1, y = 0, label = "a");
+    if (origin == rebuilt) { print(value = "points equal"); }
+    if (origin != moved) { print(value = "points differ"); }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for expected in ["arrays equal", "arrays differ", "dicts equal", "dicts differ", "sets equal", "points equal", "points differ"] {
+        assert!(stdout.contains(expected), "Missing '{}' in output: {}", expected, stdout);
+    }
+}
+
+#[test]
+fn test_string_equality_compares_contents() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("string_eq.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let suffix: String = "c";
+    if ("ab".concat(other = suffix) == "abc") {
+        print(value = "concat equals literal");
+    }
+    if ("abc" == "ab".concat(other = suffix)) {
+        print(value = "literal equals concat");
+    }
+    let padded: String = "  abc  ";
+    if (padded.trim() != "abd") {
+        print(value = "trimmed differs");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("concat equals literal"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("literal equals concat"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("trimmed differs"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_clone_does_not_alias_original() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("clone.plat");
+
+    let source = r#"
+fn sum(values: List[Int32]) -> Int32 {
+    var total: Int32 = 0;
+    for (value: Int32 in values) {
+        total = total + value;
+    }
+    return total;
+}
+
+fn main() -> Int32 {
+    let original: List[Int32] = [1, 2, 3];
+    let copy: List[Int32] = original.clone();
+    copy.push(value = 4);
+    copy.set(index = 0, value = 10);
+    let original_sum: Int32 = sum(values = original);
+    let copy_sum: Int32 = sum(values = copy);
+    print(value = "list original=${original_sum} copy=${copy_sum}");
+
+    let ages: Dict[String, String] = {"ann": "30"};
+    let ages_copy: Dict[String, String] = ages.clone();
+    ages_copy.insert(key = "bob", value = "25");
+    if (ages_copy.has_key(key = "bob") and not ages.has_key(key = "bob")) {
+        print(value = "dict clone independent");
+    }
+
+    let tags: Set[String] = Set{"a"};
+    let tags_copy: Set[String] = tags.clone();
+    tags_copy.add(value = "b");
+    let tags_len: Int32 = tags.length();
+    let tags_copy_len: Int32 = tags_copy.length();
+    print(value = "set original=${tags_len} copy=${tags_copy_len}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("list original=6 copy=19"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("dict clone independent"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("set original=1 copy=2"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_class_returned_from_other_module() {
+    let plat = get_plat_binary();
+    // Project builds look up the workspace for the runtime, so the project lives under target/
+    let project_dir = TempDir::new_in(plat.parent().unwrap()).unwrap();
+
+    fs::write(project_dir.path().join("users.plat"), r#"mod users;
+
+pub class User {
+    pub let name: String;
+    pub let age: Int32;
+}
+
+pub fn make_user(name: String, age: Int32) -> User {
+    return User.init(name = name, age = age);
+}
+"#).unwrap();
+
+    fs::write(project_dir.path().join("main.plat"), r#"use users;
+
+fn main() -> Int32 {
+    let name: String = users::make_user(name = "ann", age = 30).name;
+    let user: users::User = users::make_user(name = "bob", age = 41);
+    let age: Int32 = user.age;
+    print(value = "name=${name} age=${age}");
+    return 0;
+}
+"#).unwrap();
+
+    let build = Command::new(&plat)
+        .arg("build")
+        .current_dir(project_dir.path())
+        .output()
+        .expect("Failed to execute plat");
+    assert!(build.status.success(), "Build failed: {}", String::from_utf8_lossy(&build.stdout));
+
+    let output = Command::new(project_dir.path().join("target").join("plat").join("main"))
+        .output()
+        .expect("Failed to run built program");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("name=ann age=41"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_shadowing_in_same_scope_reported_but_nested_allowed() {
+    let temp_dir = TempDir::new().unwrap();
+    let plat = get_plat_binary();
+
+    let nested_file = temp_dir.path().join("nested.plat");
+    fs::write(&nested_file, r#"
+fn main() -> Int32 {
+    let x: Int32 = 1;
+    if (x > 0) {
+        let x: Int32 = 2;
+        print(value = "inner=${x}");
+    }
+    print(value = "outer=${x}");
+    return 0;
+}
+"#).unwrap();
+
+    let output = Command::new(&plat)
+        .arg("run")
+        .arg(&nested_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("inner=2"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("outer=1"), "Nested declaration clobbered the outer one: {}", stdout);
+    assert!(!stderr.contains("redeclared in the same scope"), "Nested shadowing warned: {}", stderr);
+
+    let same_scope_file = temp_dir.path().join("same_scope.plat");
+    fs::write(&same_scope_file, r#"
+fn main() -> Int32 {
+    let x: Int32 = 1;
+    let x: Int32 = 2;
+    print(value = "x=${x}");
+    return 0;
+}
+"#).unwrap();
+
+    let output = Command::new(&plat)
+        .arg("run")
+        .arg(&same_scope_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    let combined = format!("{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+    assert!(!output.status.success(), "Same-scope shadowing compiled: {}", combined);
+    assert!(combined.contains("already defined in this scope"), "Missing diagnostic: {}", combined);
+}
+
+#[test]
+fn test_list_option_methods_keep_high_bit_values() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("high_bit.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let wide: List[Int64] = [-5i64, -9223372036854775807i64];
+    let first: Option<Int64> = wide.get(index = 0);
+    let last: Option<Int64> = wide.get(index = 1);
+    let missing: Option<Int64> = wide.get(index = 2);
+    let first_value: Int64 = match first { Option::Some(v: Int64) -> v, Option::None -> 0i64 };
+    let last_value: Int64 = match last { Option::Some(v: Int64) -> v, Option::None -> 0i64 };
+    let missing_value: Int64 = match missing { Option::Some(v: Int64) -> v, Option::None -> 0i64 };
+    print(value = "get first=${first_value} last=${last_value} missing=${missing_value}");
+
+    let narrow: List[Int32] = [4, -3];
+    let position: Option<Int32> = narrow.index_of(value = -3);
+    let position_value: Int32 = match position { Option::Some(v: Int32) -> v, Option::None -> -1 };
+    print(value = "index_of=${position_value}");
+
+    let removed: Option<Int64> = wide.remove_at(index = 1);
+    let removed_value: Int64 = match removed { Option::Some(v: Int64) -> v, Option::None -> 0i64 };
+    print(value = "removed=${removed_value}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("get first=-5 last=-9223372036854775807 missing=0"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("index_of=1"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("removed=-9223372036854775807"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_unit_enum_method_match_over_sixteen_variants() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("opcodes.plat");
+
+    let source = r#"
+enum Opcode {
+    Nop, Load, Store, Add, Subtract, Multiply, Divide, Jump,
+    JumpIfZero, Call, Return, Push, Pop, Compare, Halt, BreakpointTrap,
+
+    fn code() -> Int32 {
+        return match self {
+            Opcode::Nop -> 0,
+            Opcode::Load -> 1,
+            Opcode::Store -> 2,
+            Opcode::Add -> 3,
+            Opcode::Subtract -> 4,
+            Opcode::Multiply -> 5,
+            Opcode::Divide -> 6,
+            Opcode::Jump -> 7,
+            Opcode::JumpIfZero -> 8,
+            Opcode::Call -> 9,
+            Opcode::Return -> 10,
+            Opcode::Push -> 11,
+            Opcode::Pop -> 12,
+            Opcode::Compare -> 13,
+            Opcode::Halt -> 14,
+            Opcode::BreakpointTrap -> 15
+        };
+    }
+}
+
+fn main() -> Int32 {
+    let ops: List[Opcode] = [Opcode::Nop, Opcode::Load, Opcode::Store, Opcode::Add, Opcode::Subtract, Opcode::Multiply, Opcode::Divide, Opcode::Jump, Opcode::JumpIfZero, Opcode::Call, Opcode::Return, Opcode::Push, Opcode::Pop, Opcode::Compare, Opcode::Halt, Opcode::BreakpointTrap];
+    for (op: Opcode in ops) {
+        let code: Int32 = op.code();
+        print(value = "code=${code}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let codes: Vec<&str> = stdout.lines().filter(|line| line.starts_with("code=")).collect();
+    let expected: Vec<String> = (0..16).map(|code| format!("code={}", code)).collect();
+    assert_eq!(codes, expected, "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_super_call_extends_parent_method() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("super_call.plat");
+
+    let source = r#"
+class Account {
+    let balance: Int32;
+
+    init(balance: Int32) -> Account {
+        self.balance = balance;
+        return self;
+    }
+
+    pub fn interest(rate: Int32) -> Int32 {
+        return self.balance * rate / 100;
+    }
+}
+
+class Savings : Account {
+    let balance: Int32;
+
+    init(balance: Int32) -> Savings {
+        self.balance = balance;
+        return self;
+    }
+
+    pub fn interest(rate: Int32) -> Int32 {
+        return super.interest(rate = rate) + 5;
+    }
+}
+
+fn main() -> Int32 {
+    let s: Savings = Savings.init(balance = 200);
+    let a: Account = Account.init(balance = 200);
+    print(value = "savings=${s.interest(rate = 10)} account=${a.interest(rate = 10)}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("savings=25 account=20"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_newtype_methods() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("newtype_methods.plat");
+
+    let source = r#"
+newtype UserId = Int64 {
+    fn to_string() -> String {
+        let raw: Int64 = cast(value = self, target = Int64);
+        return "user-${raw}";
+    }
+
+    fn next(step: Int64 = 1i64) -> UserId {
+        let raw: Int64 = cast(value = self, target = Int64);
+        return cast(value = raw + step, target = UserId);
+    }
+}
+
+fn main() -> Int32 {
+    let id: UserId = cast(value = 41i64, target = UserId);
+    let bumped: UserId = id.next(step = 10i64);
+    print(value = "${id.to_string()} ${id.next().to_string()} ${bumped.to_string()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("user-41 user-42 user-51"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_split_with_limit() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("split_limit.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let line: String = "key=value=extra";
+    let pair: List[String] = line.split(delimiter = "=", limit = 2);
+    let all: List[String] = line.split(delimiter = "=");
+    let unlimited: List[String] = line.split(delimiter = "=", limit = 0);
+    let key: String = pair[0].unwrap();
+    let rest: String = pair[1].unwrap();
+    print(value = "pair=${pair.len()} key=${key} rest=${rest} all=${all.len()} unlimited=${unlimited.len()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("pair=2 key=key rest=value=extra all=3 unlimited=3"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_string_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("lines.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let text: String = "alpha\nbeta\r\ngamma\n";
+    print(value = "count=${text.lines().len()}");
+    for (line: String in text.lines()) {
+        print(value = "[${line}]");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("count=3\n[alpha]\n[beta]\n[gamma]\n"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_string_chars() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("chars.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let word: String = "héllo";
+    var count: Int32 = 0;
+    for (c: String in word.chars()) {
+        count = count + 1;
+        print(value = "<${c}>");
+    }
+    print(value = "chars=${count} length=${word.length()} list_len=${word.chars().len()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("<h>\n<\u{e9}>\n<l>\n<l>\n<o>\n"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("chars=5 length=5 list_len=5"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_float64_to_float32_narrowing_cast() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("narrowing.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let precise: Float64 = 0.1;
+    let narrow: Float32 = cast(value = precise, target = Float32);
+    let widened: Float64 = cast(value = narrow, target = Float64);
+    let huge: Float64 = 1.0e300;
+    let overflow: Float32 = cast(value = huge, target = Float32);
+    print(value = "narrow=${narrow} widened=${widened} overflow=${overflow}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("narrow=0.1 widened=0.10000000149011612 overflow=inf"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_bool_int_casts() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("bool_casts.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let flags: List[Bool] = [true, false, true, true];
+    var count: Int64 = 0i64;
+    for (flag: Bool in flags) {
+        count = count + cast(value = flag, target = Int64);
+    }
+    let one: Int32 = cast(value = true, target = Int32);
+    let zero: Bool = cast(value = 0, target = Bool);
+    let seven: Bool = cast(value = 7i64, target = Bool);
+    print(value = "count=${count} one=${one}");
+    if (zero) { print(value = "zero is true"); } else { print(value = "zero is false"); }
+    if (seven) { print(value = "seven is true"); } else { print(value = "seven is false"); }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("count=3 one=1\nzero is false\nseven is true\n"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_list_contains() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("list_contains.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let numbers: List[Int32] = [1, 2, 3];
+    if (numbers.contains(value = 2)) { print(value = "has 2"); }
+    if (numbers.contains(value = 9)) { print(value = "has 9"); } else { print(value = "no 9"); }
+    let names: List[String] = ["ann", "bob"];
+    let who: String = "b" + "ob";
+    if (names.contains(value = who)) { print(value = "has bob"); }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("has 2\nno 9\nhas bob\n"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_virtual_dispatch_through_vtable() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("virtual.plat");
+
+    let source = r#"
+class Shape {
+    let name: String;
+
+    init(name: String) -> Shape {
+        self.name = name;
+        return self;
+    }
+
+    pub virtual fn area() -> Int32 {
+        return 0;
+    }
+
+    pub virtual fn describe() -> String {
+        return "shape";
+    }
+}
+
+class Square : Shape {
+    let name: String;
+    let side: Int32;
+
+    init(name: String, side: Int32) -> Square {
+        self.name = name;
+        self.side = side;
+        return self;
+    }
+
+    pub override fn area() -> Int32 {
+        return self.side * self.side;
+    }
+
+    pub override fn describe() -> String {
+        let base: String = super.describe();
+        return "square ${base}";
+    }
+}
+
+fn total(shape: Shape) -> Int32 {
+    return shape.area();
+}
+
+fn main() -> Int32 {
+    let plain: Shape = Shape.init(name = "blob");
+    let square: Shape = Square.init(name = "sq", side = 4);
+    print(value = "plain=${plain.area()} square=${square.area()} total=${total(shape = square)}");
+    print(value = square.describe());
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("plain=0 square=16 total=16\nsquare shape\n"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_inherited_virtual_method_fills_subclass_vtable() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("inherited_virtual.plat");
+
+    let source = r#"
+class Animal {
+    let name: String;
+
+    init(name: String) -> Animal {
+        self.name = name;
+        return self;
+    }
+
+    pub virtual fn sound() -> String {
+        return "...";
+    }
+
+    pub virtual fn legs() -> Int32 {
+        return 4;
+    }
+}
+
+class Bird : Animal {
+    let name: String;
+
+    init(name: String) -> Bird {
+        self.name = name;
+        return self;
+    }
+
+    pub override fn legs() -> Int32 {
+        return 2;
+    }
+}
+
+fn main() -> Int32 {
+    let bird: Animal = Bird.init(name = "tweety");
+    print(value = "sound=${bird.sound()} legs=${bird.legs()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("sound=... legs=2"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_tuple_return_uses_multiple_values() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("multi_return.plat");
+
+    let source = r#"
+fn div_mod(a: Int32, b: Int32) -> (Int32, Int32) {
+    return (a / b, a % b);
+}
+
+fn describe(n: Int64) -> (String, Int64, Bool) {
+    if (n > 0i64) {
+        return ("positive", n * 2i64, true);
+    }
+    return ("other", n, false);
+}
+
+fn forward(a: Int32, b: Int32) -> (Int32, Int32) {
+    return div_mod(a = a, b = b);
+}
+
+fn swap(pair: (Int32, Int32)) -> (Int32, Int32) {
+    return (pair.1, pair.0);
+}
+
+fn main() -> Int32 {
+    let q: Int32 = div_mod(a = 17, b = 5).0;
+    let r: Int32 = div_mod(a = 17, b = 5).1;
+    let pair: (Int32, Int32) = forward(a = 23, b = 4);
+    let swapped: (Int32, Int32) = swap(pair = pair);
+    let info: (String, Int64, Bool) = describe(n = 21i64);
+    print(value = "q=${q} r=${r} pair=${pair.0},${pair.1} swapped=${swapped.0},${swapped.1}");
+    let doubled: Int64 = info.1;
+    if (info.2) { print(value = "info=${info.0} doubled=${doubled}"); }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("q=3 r=2 pair=5,3 swapped=3,5\ninfo=positive doubled=42\n"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_let_destructuring() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("destructuring.plat");
+
+    let source = r#"
+enum Meters {
+    Value(Int32)
+}
+
+fn div_mod(a: Int32, b: Int32) -> (Int32, Int32) {
+    return (a / b, a % b);
+}
+
+fn main() -> Int32 {
+    let pair: (Int32, String) = (7, "seven");
+    let (number: Int32, name: String) = pair;
+    let (q: Int32, r: Int32) = div_mod(a = 17, b = 5);
+    let Meters::Value(meters: Int32) = Meters::Value(field0 = 42);
+    print(value = "number=${number} name=${name} q=${q} r=${r} meters=${meters}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("number=7 name=seven q=3 r=2 meters=42"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_discarded_value_warning() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("discarded.plat");
+
+    let source = r#"
+fn parse(text: String) -> Result<Int32, String> {
+    if (text == "") {
+        return Result::Err(field0 = "empty");
+    }
+    return Result::Ok(field0 = 1);
+}
+
+fn log(text: String) {
+    print(value = text);
+}
+
+fn main() -> Int32 {
+    var count: Int32 = 0;
+    parse(text = "7");
+    log(text = "logged");
+    count = count + 1;
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    // Only the dropped Result warns; the void call and the assignment do not
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let warnings: Vec<&str> = stderr.lines().filter(|line| line.contains("is discarded")).collect();
+    assert_eq!(warnings.len(), 1, "Unexpected warnings: {}", stderr);
+    assert!(warnings[0].contains("Result") && warnings[0].contains("'main'") && warnings[0].contains("let _ ="), "Unexpected warning: {}", warnings[0]);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("logged"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_discard_binding_runs_the_call() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("discard_binding.plat");
+
+    let source = r#"
+fn record(label: String) -> Result<Int32, String> {
+    print(value = "recorded ${label}");
+    return Result::Ok(field0 = 1);
+}
+
+fn main() -> Int32 {
+    let _ = record(label = "first");
+    let _ = record(label = "second");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("is discarded"), "Explicit discard should not warn: {}", stderr);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("recorded first\nrecorded second\n"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_enum_to_string_uses_variant_names() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("enum_to_string.plat");
+
+    let source = r#"
+enum Light {
+    Red,
+    Amber,
+    Green
+}
+
+enum Shape {
+    Rect(Int32, Int32),
+    Label(String),
+    Empty
+}
+
+fn main() -> Int32 {
+    let red: String = Light::Red.to_string();
+    let amber: String = Light::Amber.to_string();
+    let green: String = Light::Green.to_string();
+    print(value = "${red} ${amber} ${green}");
+
+    let rect: String = Shape::Rect(field0 = 3, field1 = 4).to_string();
+    let label: String = Shape::Label(field0 = "hi").to_string();
+    let empty: String = Shape::Empty.to_string();
+    let maybe: Option<Int32> = Option::Some(field0 = 9);
+    let some: String = maybe.to_string();
+    print(value = "${rect} ${label} ${empty} ${some}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Red Amber Green\nRect(3, 4) Label(hi) Empty Some(9)\n"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_print_class_uses_to_string() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("display.plat");
+
+    let source = r#"
+class Point {
+    let x: Int32;
+    let y: Int32;
+
+    pub fn to_string() -> String {
+        return "(${self.x}, ${self.y})";
+    }
+}
+
+class Shape {
+    let sides: Int32;
+
+    pub virtual fn to_string() -> String {
+        return "shape";
+    }
+}
+
+class Square : Shape {
+    let sides: Int32;
+
+    pub override fn to_string() -> String {
+        return "square";
+    }
+}
+
+class Blob {
+    let size: Int32;
+}
+
+fn main() -> Int32 {
+    let point: Point = Point.init(x = 1, y = 2);
+    print(value = point);
+    print(value = "point=${point}");
+    let shape: Shape = Square.init(sides = 4);
+    print(value = "shape=${shape}");
+    let blob: Blob = Blob.init(size = 3);
+    print(value = "blob=${blob}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("(1, 2)\npoint=(1, 2)\nshape=square\nblob=Blob@0x"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_recursive_enum_linked_list_and_tree() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("recursive_enum.plat");
+
+    let source = r#"
+enum IntList {
+    Cons(Int32, IntList),
+    Nil
+}
+
+enum Tree {
+    Node(Tree, Int32, Tree),
+    Leaf
+}
+
+fn sum(list: IntList) -> Int32 {
+    return match list {
+        IntList::Cons(head: Int32, tail: IntList) -> head + sum(list = tail),
+        IntList::Nil -> 0
+    };
+}
+
+fn total(tree: Tree) -> Int32 {
+    return match tree {
+        Tree::Node(left: Tree, value: Int32, right: Tree) -> total(tree = left) + value + total(tree = right),
+        Tree::Leaf -> 0
+    };
+}
+
+fn main() -> Int32 {
+    let list: IntList = IntList::Cons(field0 = 1, field1 = IntList::Cons(field0 = 2, field1 = IntList::Cons(field0 = 3, field1 = IntList::Nil)));
+    let list_sum: Int32 = sum(list = list);
+
+    let left: Tree = Tree::Node(field0 = Tree::Leaf, field1 = 2, field2 = Tree::Leaf);
+    let right: Tree = Tree::Node(field0 = Tree::Leaf, field1 = 9, field2 = Tree::Leaf);
+    let tree: Tree = Tree::Node(field0 = left, field1 = 5, field2 = right);
+    let tree_total: Int32 = total(tree = tree);
+
+    print(value = "sum=${list_sum} tree=${tree_total}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("sum=6 tree=16"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_array_flatten() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("flatten.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let nested: List[List[Int32]] = [[1, 2], [3], [4, 5]];
+    let flat: List[Int32] = nested.flatten();
+    print(value = "flat=${flat} len=${flat.len()}");
+    let rows: List[List[Int32]] = flat.chunks(size = 2);
+    let again: List[Int32] = rows.flatten();
+    print(value = "again=${again}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("flat=[1, 2, 3, 4, 5] len=5"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("again=[1, 2, 3, 4, 5]"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_array_zip() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("zip.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let names: List[String] = ["a", "b"];
+    let ages: List[Int32] = [1, 2, 3];
+    let pairs: List[(String, Int32)] = names.zip(other = ages);
+    print(value = "count=${pairs.len()}");
+    for (pair: (String, Int32) in pairs) {
+        print(value = "${pair.0}=${pair.1}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("count=2\na=1\nb=2"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_array_enumerate() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("enumerate.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let values: List[Int32] = [5, 6, 7];
+    var total: Int32 = 0;
+    for (index: Int32, value: Int32 in values.enumerate()) {
+        total = total + index * value;
+    }
+    print(value = "total=${total}");
+
+    let names: List[String] = ["x", "y"];
+    let pairs: List[(Int32, String)] = names.enumerate();
+    for (pair: (Int32, String) in pairs) {
+        print(value = "${pair.1}=${pair.0}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("total=20"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("x=0\ny=1"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_class_method_named_enumerate_is_not_the_list_method() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("class_enumerate.plat");
+
+    let source = r#"
+class Roster {
+  var size: Int32;
+
+  pub fn enumerate() -> Int32 {
+    return self.size * 10;
+  }
+}
+
+fn main() -> Int32 {
+  let roster: Roster = Roster.init(size = 3);
+  let listed: Int32 = roster.enumerate();
+  print(value = "listed=${listed}");
+  let values: List[Int32] = [5, 6];
+  for (index: Int32, value: Int32 in values.enumerate()) {
+    print(value = "${index}:${value}");
+  }
+  return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("listed=30"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("0:5\n1:6"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_array_view_reflects_mutations() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("view.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    var data: List[Int32] = [1, 2, 3, 4, 5];
+    let middle: List[Int32] = data.view(start = 1, end = 4);
+    print(value = "view=${middle} len=${middle.len()}");
+    data.set(index = 2, value = 30);
+    print(value = "after base write=${middle}");
+    middle.set(index = 0, value = 20);
+    print(value = "after view write=${data}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("view=[2, 3, 4] len=3"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("after base write=[2, 30, 4]"), "Unexpected output: {}", stdout);
+    assert!(stdout.contains("after view write=[1, 20, 30, 4, 5]"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_class_method_named_view_is_not_the_list_method() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("class_view.plat");
+
+    let source = r#"
+class Camera {
+  var zoom: Int32;
+
+  pub fn view(scale: Int32) -> Int32 {
+    return self.zoom * scale;
+  }
+}
+
+fn main() -> Int32 {
+  let camera: Camera = Camera.init(zoom = 4);
+  let shown: Int32 = camera.view(scale = 5);
+  print(value = "shown=${shown}");
+  return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("shown=20"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_array_view_out_of_range_panics() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("view_range.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let values: List[Int32] = [1, 2, 3];
+    let window: List[Int32] = values.view(start = 1, end = 5);
+    print(value = "len=${window.len()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(!output.status.success(), "Out-of-range view should fail");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("len="), "Unexpected output: {}", stdout);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("panic: view range 1..5 out of bounds for list of length 3"), "Missing panic message: {}", stderr);
+}
+
+#[test]
+fn test_for_loop_over_view_of_strings() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("view_loop.plat");
+
+    let source = r#"
+fn main() -> Int32 {
+    let names: List[String] = ["ann", "bob", "cy"];
+    for (name: String in names.view(start = 0, end = 2)) {
+        print(value = "name=${name}");
+    }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("name=ann\nname=bob\n"), "Unexpected output: {}", stdout);
+    assert!(!stdout.contains("name=cy"), "View included an element past its end: {}", stdout);
+}
+
+#[test]
+fn test_match_arm_whole_value_binding() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("match_at.plat");
+
+    let source = r#"
+enum Shape {
+    Circle(Int32),
+    Rect(Int32, Int32),
+    Empty
+}
+
+fn describe(shape: Shape) -> String {
+    return match shape {
+        whole @ Shape::Circle(r: Int32) -> "${whole.to_string()} has radius ${r}",
+        whole @ Shape::Rect(w: Int32, h: Int32) -> "${whole.to_string()} has area ${w * h}",
+        Shape::Empty -> "nothing"
+    };
+}
+
+fn or_zero(value: Option<Int32>) -> Option<Int32> {
+    return match value {
+        full @ Option::Some(x: Int32) -> full,
+        Option::None -> Option::Some(field0 = 0)
+    };
+}
+
+fn main() -> Int32 {
+    print(value = describe(shape = Shape::Circle(field0 = 2)));
+    print(value = describe(shape = Shape::Rect(field0 = 3, field1 = 4)));
+    let kept: Option<Int32> = or_zero(value = Option::Some(field0 = 5));
+    print(value = "kept=${kept.to_string()}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Circle(2) has radius 2\nRect(3, 4) has area 12\nkept=Some(5)"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_selective_use_imports_only_named_items() {
+    let plat = get_plat_binary();
+    // Project builds look up the workspace for the runtime, so the project lives under target/
+    let project_dir = TempDir::new_in(plat.parent().unwrap()).unwrap();
+
+    fs::write(project_dir.path().join("math.plat"), r#"mod math;
+
+pub fn add(a: Int32, b: Int32) -> Int32 {
+    return a + b;
+}
+
+pub fn subtract(a: Int32, b: Int32) -> Int32 {
+    return a - b;
+}
+
+pub fn multiply(a: Int32, b: Int32) -> Int32 {
+    return a * b;
+}
+"#).unwrap();
+
+    fs::write(project_dir.path().join("main.plat"), r#"use math::{add, subtract};
+
+fn main() -> Int32 {
+    print(value = "sum=${add(a = 2, b = 3)} diff=${subtract(a = 9, b = 4)}");
+    return 0;
+}
+"#).unwrap();
+
+    let build = Command::new(&plat)
+        .arg("build")
+        .current_dir(project_dir.path())
+        .output()
+        .expect("Failed to execute plat");
+    assert!(build.status.success(), "Build failed: {}", String::from_utf8_lossy(&build.stdout));
+
+    let output = Command::new(project_dir.path().join("target").join("plat").join("main"))
+        .output()
+        .expect("Failed to run built program");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("sum=5 diff=5"), "Unexpected output: {}", stdout);
+
+    // multiply wasn't named in the import, so it isn't in scope unqualified
+    fs::write(project_dir.path().join("main.plat"), r#"use math::{add, subtract};
+
+fn main() -> Int32 {
+    return multiply(a = 2, b = 3);
+}
+"#).unwrap();
+
+    let build = Command::new(&plat)
+        .arg("build")
+        .current_dir(project_dir.path())
+        .output()
+        .expect("Failed to execute plat");
+    let combined = format!("{}{}", String::from_utf8_lossy(&build.stdout), String::from_utf8_lossy(&build.stderr));
+    assert!(!build.status.success(), "Unimported function compiled: {}", combined);
+    assert!(combined.contains("Unknown function 'multiply'"), "Missing diagnostic: {}", combined);
+}
+
+#[test]
+fn test_tail_call_rebinds_parameter_shadowed_by_local() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("tail_shadow.plat");
+
+    // The loop variable shadows `acc`; the tail call must rebind the parameter, not the loop variable
+    let source = r#"
+fn step(n: Int32, acc: Int32) -> Int32 {
+    if (n == 0) {
+        return acc;
+    }
+    for (acc: Int32 in [100]) {
+        return step(n = n - 1, acc = acc + 1);
+    }
+    return -1;
+}
+
+fn main() -> Int32 {
+    print(value = "result=${step(n = 3, acc = 0)}");
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Plat run failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("result=101"), "Unexpected output: {}", stdout);
+}
+
+#[test]
+fn test_structural_equality_for_deeply_nested_classes_and_lists_of_classes() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_file = temp_dir.path().join("deep_equality.plat");
+
+    let source = r#"
+class Leaf { let value: Int32; }
+class Fourth { let leaf: Leaf; }
+class Third { let fourth: Fourth; }
+class Second { let third: Third; }
+class First { let second: Second; }
+
+fn chain(value: Int32) -> First {
+    return First.init(second = Second.init(third = Third.init(fourth = Fourth.init(leaf = Leaf.init(value = value)))));
+}
+
+fn main() -> Int32 {
+    let one: First = chain(value = 1);
+    let one_again: First = chain(value = 1);
+    let two: First = chain(value = 2);
+    if (one == one_again) { print(value = "deep chains equal"); }
+    if (one != two) { print(value = "deep chains differ"); }
+
+    let leaves: List[Leaf] = [Leaf.init(value = 1), Leaf.init(value = 2)];
+    let same_leaves: List[Leaf] = [Leaf.init(value = 1), Leaf.init(value = 2)];
+    let other_leaves: List[Leaf] = [Leaf.init(value = 1), Leaf.init(value = 3)];
+    if (leaves == same_leaves) { print(value = "class lists equal"); }
+    if (leaves != other_leaves) { print(value = "class lists differ"); }
+    return 0;
+}
+"#;
+
+    fs::write(&source_file, source).unwrap();
+
+    let plat = get_plat_binary();
+    let output = Command::new(plat)
+        .arg("run")
+        .arg(&source_file)
+        .output()
+        .expect("Failed to execute plat");
+
+    assert!(output.status.success(), "Program failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for expected in ["deep chains equal", "deep chains differ", "class lists equal", "class lists differ"] {
+        assert!(stdout.contains(expected), "Missing '{}' in output: {}", expected, stdout);
+    }
+}
@@ -0,0 +1,272 @@
+/// DWARF line tables for generated code
+/// Maps each statement's machine code back to its source line so debuggers can
+/// set breakpoints and symbolize backtraces.
+
+use cranelift_codegen::CompiledCode;
+use cranelift_module::FuncId;
+use cranelift_object::ObjectProduct;
+use gimli::write::{Address, AttributeValue, DwarfUnit, EndianVec, LineProgram, LineString, Range, RangeList, Sections, Writer};
+use gimli::{Encoding, Format, LineEncoding, RunTimeEndian, SectionId};
+use object::write::{Relocation, StandardSegment};
+use object::{BinaryFormat, RelocationEncoding, RelocationFlags, RelocationKind, SectionKind};
+use std::collections::HashMap;
+
+use crate::CodegenError;
+
+/// Char offset at which each source line starts.
+/// Spans are char offsets (the lexer works on a Vec<char>), so count chars, not bytes.
+pub(crate) fn line_starts(source: &str) -> Vec<usize> {
+    let mut line_starts = vec![0];
+    for (i, c) in source.chars().enumerate() {
+        if c == '\n' {
+            line_starts.push(i + 1);
+        }
+    }
+    line_starts
+}
+
+/// 1-based (line, column) for a char offset
+pub(crate) fn line_and_column(line_starts: &[usize], offset: usize) -> (u64, u64) {
+    let line_index = line_starts.partition_point(|&start| start <= offset) - 1;
+    ((line_index + 1) as u64, (offset - line_starts[line_index] + 1) as u64)
+}
+
+/// Line rows recorded for one compiled function
+struct FunctionLines {
+    func_id: FuncId,
+    name: String,
+    size: u64,
+    /// (code offset within the function, source position in chars)
+    rows: Vec<(u64, usize)>,
+}
+
+/// Collects line information while functions are compiled, then writes the DWARF sections
+pub(crate) struct DebugInfo {
+    file_name: String,
+    directory: String,
+    /// Char offset at which each source line starts
+    line_starts: Vec<usize>,
+    functions: Vec<FunctionLines>,
+}
+
+impl DebugInfo {
+    pub(crate) fn new(path: &str, source: &str) -> Self {
+        let path = std::path::Path::new(path);
+        let file_name = path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.to_string_lossy().into_owned());
+        let directory = path.parent()
+            .and_then(|dir| std::fs::canonicalize(if dir.as_os_str().is_empty() { std::path::Path::new(".") } else { dir }).ok())
+            .map(|dir| dir.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Self { file_name, directory, line_starts: line_starts(source), functions: Vec::new() }
+    }
+
+    fn line_and_column(&self, offset: usize) -> (u64, u64) {
+        line_and_column(&self.line_starts, offset)
+    }
+
+    /// Record the source locations Cranelift attached to a just-compiled function.
+    /// The prologue maps to the declaration so debuggers can skip past it to the first statement.
+    pub(crate) fn add_function(&mut self, func_id: FuncId, name: &str, declaration: usize, compiled: &CompiledCode) {
+        let mut rows: Vec<(u64, usize)> = vec![(0, declaration)];
+        for srcloc in compiled.buffer.get_srclocs_sorted() {
+            if srcloc.loc.is_default() {
+                continue;
+            }
+            let position = srcloc.loc.bits() as usize;
+            // Consecutive ranges from the same statement collapse into one row
+            match rows.last_mut() {
+                Some((_, last)) if *last == position => {}
+                // A statement starting at offset 0 replaces the declaration row
+                Some((offset, last)) if *offset == srcloc.start as u64 => *last = position,
+                _ => rows.push((srcloc.start as u64, position)),
+            }
+        }
+
+        self.functions.push(FunctionLines {
+            func_id,
+            name: name.to_string(),
+            size: compiled.buffer.total_size() as u64,
+            rows,
+        });
+    }
+
+    /// Write .debug_info/.debug_abbrev/.debug_line (and friends) into the object
+    pub(crate) fn emit(self, product: &mut ObjectProduct) -> Result<(), CodegenError> {
+        let encoding = Encoding { format: Format::Dwarf32, version: 4, address_size: 8 };
+
+        let mut line_program = LineProgram::new(
+            encoding,
+            LineEncoding::default(),
+            LineString::String(self.directory.clone().into_bytes()),
+            LineString::String(self.file_name.clone().into_bytes()),
+            None,
+        );
+        let directory_id = line_program.default_directory();
+        let file_id = line_program.add_file(LineString::String(self.file_name.clone().into_bytes()), directory_id, None);
+
+        let mut dwarf = DwarfUnit::new(encoding);
+        let mut ranges = Vec::new();
+
+        // Function addresses are symbol-relative: symbol index i is self.functions[i]
+        for (symbol, function) in self.functions.iter().enumerate() {
+            let start = Address::Symbol { symbol, addend: 0 };
+            ranges.push(Range::StartLength { begin: start, length: function.size });
+
+            line_program.begin_sequence(Some(start));
+            for &(code_offset, position) in &function.rows {
+                let (line, column) = self.line_and_column(position);
+                let row = line_program.row();
+                row.address_offset = code_offset;
+                row.file = file_id;
+                row.line = line;
+                row.column = column;
+                line_program.generate_row();
+            }
+            line_program.end_sequence(function.size);
+
+            let root = dwarf.unit.root();
+            let subprogram = dwarf.unit.add(root, gimli::DW_TAG_subprogram);
+            let entry = dwarf.unit.get_mut(subprogram);
+            entry.set(gimli::DW_AT_name, AttributeValue::String(function.name.clone().into_bytes()));
+            entry.set(gimli::DW_AT_low_pc, AttributeValue::Address(start));
+            entry.set(gimli::DW_AT_high_pc, AttributeValue::Udata(function.size));
+            entry.set(gimli::DW_AT_decl_file, AttributeValue::FileIndex(Some(file_id)));
+            entry.set(gimli::DW_AT_decl_line, AttributeValue::Udata(self.line_and_column(function.rows[0].1).0));
+        }
+
+        let range_list = dwarf.unit.ranges.add(RangeList(ranges));
+        let root = dwarf.unit.root();
+        let entry = dwarf.unit.get_mut(root);
+        entry.set(gimli::DW_AT_producer, AttributeValue::String(b"plat".to_vec()));
+        entry.set(gimli::DW_AT_name, AttributeValue::String(self.file_name.clone().into_bytes()));
+        entry.set(gimli::DW_AT_comp_dir, AttributeValue::String(self.directory.clone().into_bytes()));
+        entry.set(gimli::DW_AT_low_pc, AttributeValue::Address(Address::Constant(0)));
+        entry.set(gimli::DW_AT_ranges, AttributeValue::RangeListRef(range_list));
+        dwarf.unit.line_program = line_program;
+
+        let mut sections = Sections::new(RelocWriter::default());
+        dwarf.write(&mut sections).map_err(CodegenError::DebugInfoError)?;
+
+        // Add every non-empty section first so cross-section references can be resolved
+        let segment = product.object.segment_name(StandardSegment::Debug).to_vec();
+        let is_macho = product.object.format() == BinaryFormat::MachO;
+        let mut section_ids = HashMap::new();
+        sections.for_each(|id, writer| -> Result<(), CodegenError> {
+            if writer.inner.slice().is_empty() {
+                return Ok(());
+            }
+            let name = if is_macho { id.name().replacen('.', "__", 1) } else { id.name().to_string() };
+            let section = product.object.add_section(segment.clone(), name.into_bytes(), SectionKind::Debug);
+            product.object.set_section_data(section, writer.inner.slice().to_vec(), 1);
+            section_ids.insert(id, section);
+            Ok(())
+        })?;
+
+        sections.for_each(|id, writer| -> Result<(), CodegenError> {
+            let Some(&section) = section_ids.get(&id) else { return Ok(()) };
+            for reloc in &writer.relocs {
+                let symbol = match reloc.target {
+                    RelocTarget::Function(index) => product.function_symbol(self.functions[index].func_id),
+                    RelocTarget::Section(target) => product.object.section_symbol(section_ids[&target]),
+                };
+                product.object.add_relocation(section, Relocation {
+                    offset: reloc.offset,
+                    symbol,
+                    addend: reloc.addend,
+                    flags: RelocationFlags::Generic {
+                        kind: RelocationKind::Absolute,
+                        encoding: RelocationEncoding::Generic,
+                        size: reloc.size * 8,
+                    },
+                }).map_err(CodegenError::ObjectEmitError)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum RelocTarget {
+    Function(usize),
+    Section(SectionId),
+}
+
+#[derive(Clone)]
+struct DebugReloc {
+    offset: u64,
+    size: u8,
+    target: RelocTarget,
+    addend: i64,
+}
+
+/// gimli writer that records symbol and section references as relocations,
+/// since neither function addresses nor section offsets are known until link time
+#[derive(Clone)]
+struct RelocWriter {
+    inner: EndianVec<RunTimeEndian>,
+    relocs: Vec<DebugReloc>,
+}
+
+impl Default for RelocWriter {
+    fn default() -> Self {
+        Self { inner: EndianVec::new(RunTimeEndian::default()), relocs: Vec::new() }
+    }
+}
+
+impl Writer for RelocWriter {
+    type Endian = RunTimeEndian;
+
+    fn endian(&self) -> Self::Endian {
+        self.inner.endian()
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> gimli::write::Result<()> {
+        self.inner.write(bytes)
+    }
+
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) -> gimli::write::Result<()> {
+        self.inner.write_at(offset, bytes)
+    }
+
+    fn write_address(&mut self, address: Address, size: u8) -> gimli::write::Result<()> {
+        match address {
+            Address::Constant(value) => self.write_udata(value, size),
+            Address::Symbol { symbol, addend } => {
+                self.relocs.push(DebugReloc {
+                    offset: self.len() as u64,
+                    size,
+                    target: RelocTarget::Function(symbol),
+                    addend,
+                });
+                self.write_udata(0, size)
+            }
+        }
+    }
+
+    fn write_offset(&mut self, value: usize, section: SectionId, size: u8) -> gimli::write::Result<()> {
+        self.relocs.push(DebugReloc {
+            offset: self.len() as u64,
+            size,
+            target: RelocTarget::Section(section),
+            addend: value as i64,
+        });
+        self.write_udata(0, size)
+    }
+
+    fn write_offset_at(&mut self, offset: usize, value: usize, section: SectionId, size: u8) -> gimli::write::Result<()> {
+        self.relocs.push(DebugReloc {
+            offset: offset as u64,
+            size,
+            target: RelocTarget::Section(section),
+            addend: value as i64,
+        });
+        self.write_udata_at(offset, 0, size)
+    }
+}
@@ -0,0 +1,373 @@
+/// Small-function inlining, run on the AST before codegen
+/// A call to a leaf function whose body is a few `let`s followed by a single `return` is
+/// replaced by the returned expression, with the arguments and locals substituted in.
+
+use plat_ast::{Block, Expression, Function, InterpolationPart, Literal, NamedArg, Program, Statement};
+use plat_ast::Type as AstType;
+use std::collections::{HashMap, HashSet};
+
+/// Functions with more statements than this are always called
+const MAX_INLINE_STATEMENTS: usize = 3;
+
+/// A function body reduced to one expression over its parameters
+struct InlineCandidate {
+    params: Vec<String>,
+    body: Expression,
+}
+
+/// Replace calls to small leaf functions with their bodies throughout the program
+pub(crate) fn inline_small_functions(program: &mut Program) {
+    let candidates: HashMap<String, InlineCandidate> = program.functions.iter()
+        .filter_map(|function| inline_candidate(function).map(|candidate| (function.name.clone(), candidate)))
+        .collect();
+    if candidates.is_empty() {
+        return;
+    }
+
+    for global in &mut program.globals {
+        inline_calls_in_expression(&mut global.value, &candidates);
+    }
+
+    let functions = program.functions.iter_mut()
+        .chain(program.enums.iter_mut().flat_map(|e| e.methods.iter_mut()))
+        .chain(program.classes.iter_mut().flat_map(|c| c.methods.iter_mut()))
+        .chain(program.test_blocks.iter_mut().flat_map(|t| t.functions.iter_mut()))
+        .chain(program.bench_blocks.iter_mut().flat_map(|b| b.functions.iter_mut()));
+
+    for function in functions {
+        inline_calls_in_block(&mut function.body, &candidates);
+    }
+}
+
+/// Reduce `let a = ...; let b = ...; return expr;` to a single expression over the parameters.
+/// Leaf functions only: a body that calls anything could recurse.
+fn inline_candidate(function: &Function) -> Option<InlineCandidate> {
+    if function.name == "main" || !function.type_params.is_empty() {
+        return None;
+    }
+    let statements = &function.body.statements;
+    if statements.is_empty() || statements.len() > MAX_INLINE_STATEMENTS {
+        return None;
+    }
+
+    let params: Vec<String> = function.params.iter().map(|param| param.name.clone()).collect();
+    let param_names: HashSet<&str> = params.iter().map(String::as_str).collect();
+
+    // Locals are folded forward into later statements; they may be used more than once,
+    // so their initializers must be free of side effects and allocation
+    let mut locals: HashMap<String, Expression> = HashMap::new();
+    let (last, lets) = statements.split_last()?;
+    for statement in lets {
+        let Statement::Let { name, value, .. } = statement else { return None };
+        let mut value = value.clone();
+        substitute_identifiers(&mut value, &locals);
+        if !is_inlinable(&value, &param_names, false) {
+            return None;
+        }
+        locals.insert(name.clone(), value);
+    }
+
+    let Statement::Return { value: Some(value), .. } = last else { return None };
+    let mut body = value.clone();
+    substitute_identifiers(&mut body, &locals);
+    if !is_inlinable(&body, &param_names, true) {
+        return None;
+    }
+
+    // Keep the declared return type, which may widen the expression (e.g. `return 0` from an Int64 function)
+    if let Some(return_type @ (AstType::Int8 | AstType::Int16 | AstType::Int32 | AstType::Int64
+        | AstType::Float8 | AstType::Float16 | AstType::Float32 | AstType::Float64)) = &function.return_type
+    {
+        let span = body.span();
+        body = Expression::Cast { value: Box::new(body), target_type: return_type.clone(), span };
+    }
+
+    Some(InlineCandidate { params, body })
+}
+
+/// Whether an expression can be moved to a call site: it only reads the given names and
+/// cannot return, assign, or call user code. `allow_effects` admits method calls and allocation.
+fn is_inlinable(expr: &Expression, names: &HashSet<&str>, allow_effects: bool) -> bool {
+    let all = |exprs: &[&Expression]| exprs.iter().all(|e| is_inlinable(e, names, allow_effects));
+    match expr {
+        Expression::Literal(literal) => match literal {
+            Literal::Bool(..) | Literal::Integer(..) | Literal::Float(..) | Literal::String(..) => true,
+            Literal::InterpolatedString(parts, _) => parts.iter().all(|part| match part {
+                InterpolationPart::Text(_) => true,
+                InterpolationPart::Expression(expr) => is_inlinable(expr, names, allow_effects),
+            }),
+            Literal::Array(elements, _) | Literal::Set(elements, _) => allow_effects && elements.iter().all(|e| is_inlinable(e, names, allow_effects)),
+            Literal::Dict(pairs, _) => allow_effects && pairs.iter().all(|(k, v)| all(&[k, v])),
+        },
+        Expression::Identifier { name, .. } => names.contains(name.as_str()),
+        Expression::Binary { left, right, .. } => all(&[left, right]),
+        Expression::Unary { operand, .. } => is_inlinable(operand, names, allow_effects),
+        Expression::Index { object, index, .. } => all(&[object, index]),
+        Expression::MemberAccess { object, .. } | Expression::OptionalMemberAccess { object, .. } => {
+            is_inlinable(object, names, allow_effects)
+        }
+        Expression::TupleAccess { tuple, .. } => is_inlinable(tuple, names, allow_effects),
+        Expression::Cast { value, .. } => is_inlinable(value, names, allow_effects),
+        Expression::If { condition, then_branch, else_branch, .. } => {
+            all(&[condition, then_branch]) && else_branch.as_deref().map_or(true, |e| is_inlinable(e, names, allow_effects))
+        }
+        Expression::Range { start, end, step, .. } => {
+            all(&[start, end]) && step.as_deref().map_or(true, |s| is_inlinable(s, names, allow_effects))
+        }
+        Expression::MethodCall { object, args, .. } => {
+            allow_effects && is_inlinable(object, names, allow_effects) && args.iter().all(|arg| is_inlinable(&arg.value, names, allow_effects))
+        }
+        Expression::EnumConstructor { args, .. } | Expression::ConstructorCall { args, .. } => {
+            allow_effects && args.iter().all(|arg| is_inlinable(&arg.value, names, allow_effects))
+        }
+        Expression::Tuple { elements, .. } => allow_effects && elements.iter().all(|e| is_inlinable(e, names, allow_effects)),
+        Expression::Call { .. }
+        | Expression::Assignment { .. }
+        | Expression::Block(_)
+        | Expression::Match { .. }
+        | Expression::Try { .. }
+        | Expression::Self_ { .. }
+        | Expression::SuperCall { .. }
+        | Expression::Spawn { .. } => false,
+    }
+}
+
+/// Replace identifiers with their bound expressions. Only called on expressions that passed
+/// `is_inlinable` (or are about to be checked by it), so binding forms never appear.
+fn substitute_identifiers(expr: &mut Expression, bindings: &HashMap<String, Expression>) {
+    match expr {
+        Expression::Identifier { name, .. } => {
+            if let Some(value) = bindings.get(name) {
+                *expr = value.clone();
+            }
+        }
+        Expression::Literal(literal) => match literal {
+            Literal::InterpolatedString(parts, _) => {
+                for part in parts {
+                    if let InterpolationPart::Expression(expr) = part {
+                        substitute_identifiers(expr, bindings);
+                    }
+                }
+            }
+            Literal::Array(elements, _) | Literal::Set(elements, _) => {
+                for element in elements {
+                    substitute_identifiers(element, bindings);
+                }
+            }
+            Literal::Dict(pairs, _) => {
+                for (key, value) in pairs {
+                    substitute_identifiers(key, bindings);
+                    substitute_identifiers(value, bindings);
+                }
+            }
+            _ => {}
+        },
+        Expression::Binary { left, right, .. }
+        | Expression::Index { object: left, index: right, .. } => {
+            substitute_identifiers(left, bindings);
+            substitute_identifiers(right, bindings);
+        }
+        Expression::Range { start, end, step, .. } => {
+            substitute_identifiers(start, bindings);
+            substitute_identifiers(end, bindings);
+            if let Some(step) = step {
+                substitute_identifiers(step, bindings);
+            }
+        }
+        Expression::Unary { operand: inner, .. }
+        | Expression::MemberAccess { object: inner, .. }
+        | Expression::OptionalMemberAccess { object: inner, .. }
+        | Expression::TupleAccess { tuple: inner, .. }
+        | Expression::Cast { value: inner, .. } => substitute_identifiers(inner, bindings),
+        Expression::If { condition, then_branch, else_branch, .. } => {
+            substitute_identifiers(condition, bindings);
+            substitute_identifiers(then_branch, bindings);
+            if let Some(else_expr) = else_branch {
+                substitute_identifiers(else_expr, bindings);
+            }
+        }
+        Expression::MethodCall { object, args, .. } => {
+            substitute_identifiers(object, bindings);
+            substitute_args(args, bindings);
+        }
+        Expression::EnumConstructor { args, .. } | Expression::ConstructorCall { args, .. } => {
+            substitute_args(args, bindings);
+        }
+        Expression::Tuple { elements, .. } => {
+            for element in elements {
+                substitute_identifiers(element, bindings);
+            }
+        }
+        Expression::Call { .. }
+        | Expression::Assignment { .. }
+        | Expression::Block(_)
+        | Expression::Match { .. }
+        | Expression::Try { .. }
+        | Expression::Self_ { .. }
+        | Expression::SuperCall { .. }
+        | Expression::Spawn { .. } => {}
+    }
+}
+
+fn substitute_args(args: &mut [NamedArg], bindings: &HashMap<String, Expression>) {
+    for arg in args {
+        substitute_identifiers(&mut arg.value, bindings);
+    }
+}
+
+/// Arguments that can be duplicated or dropped without changing behavior
+fn is_simple_argument(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::Identifier { .. }
+            | Expression::Literal(Literal::Bool(..) | Literal::Integer(..) | Literal::Float(..) | Literal::String(..))
+    )
+}
+
+fn inline_calls_in_block(block: &mut Block, candidates: &HashMap<String, InlineCandidate>) {
+    for stmt in &mut block.statements {
+        inline_calls_in_statement(stmt, candidates);
+    }
+}
+
+fn inline_calls_in_statement(stmt: &mut Statement, candidates: &HashMap<String, InlineCandidate>) {
+    match stmt {
+        Statement::Let { value, .. } | Statement::Var { value, .. } | Statement::LetPattern { value, .. } => {
+            inline_calls_in_expression(value, candidates);
+        }
+        Statement::Expression(expression) | Statement::Print { value: expression, .. } => {
+            inline_calls_in_expression(expression, candidates);
+        }
+        Statement::Return { value, .. } => {
+            if let Some(expr) = value {
+                inline_calls_in_expression(expr, candidates);
+            }
+        }
+        Statement::If { condition, then_branch, else_branch, .. } => {
+            inline_calls_in_expression(condition, candidates);
+            inline_calls_in_block(then_branch, candidates);
+            if let Some(else_block) = else_branch {
+                inline_calls_in_block(else_block, candidates);
+            }
+        }
+        Statement::While { condition, body, .. } => {
+            inline_calls_in_expression(condition, candidates);
+            inline_calls_in_block(body, candidates);
+        }
+        Statement::For { iterable, body, .. } => {
+            inline_calls_in_expression(iterable, candidates);
+            inline_calls_in_block(body, candidates);
+        }
+        Statement::Concurrent { body, .. } => {
+            inline_calls_in_block(body, candidates);
+        }
+        Statement::Select { arms, default, .. } => {
+            for arm in arms {
+                inline_calls_in_expression(&mut arm.channel, candidates);
+                inline_calls_in_block(&mut arm.body, candidates);
+            }
+            if let Some(default_block) = default {
+                inline_calls_in_block(default_block, candidates);
+            }
+        }
+    }
+}
+
+fn inline_calls_in_expression(expr: &mut Expression, candidates: &HashMap<String, InlineCandidate>) {
+    // Inline innermost calls first so their results can become simple arguments
+    match expr {
+        Expression::Literal(literal) => match literal {
+            Literal::InterpolatedString(parts, _) => {
+                for part in parts {
+                    if let InterpolationPart::Expression(expr) = part {
+                        inline_calls_in_expression(expr, candidates);
+                    }
+                }
+            }
+            Literal::Array(elements, _) | Literal::Set(elements, _) => {
+                for element in elements {
+                    inline_calls_in_expression(element, candidates);
+                }
+            }
+            Literal::Dict(pairs, _) => {
+                for (key, value) in pairs {
+                    inline_calls_in_expression(key, candidates);
+                    inline_calls_in_expression(value, candidates);
+                }
+            }
+            _ => {}
+        },
+        Expression::Identifier { .. } | Expression::Self_ { .. } => {}
+        Expression::Binary { left, right, .. }
+        | Expression::Index { object: left, index: right, .. } => {
+            inline_calls_in_expression(left, candidates);
+            inline_calls_in_expression(right, candidates);
+        }
+        Expression::Range { start, end, step, .. } => {
+            inline_calls_in_expression(start, candidates);
+            inline_calls_in_expression(end, candidates);
+            if let Some(step) = step {
+                inline_calls_in_expression(step, candidates);
+            }
+        }
+        Expression::Assignment { target, value, .. } => {
+            inline_calls_in_expression(target, candidates);
+            inline_calls_in_expression(value, candidates);
+        }
+        Expression::Unary { operand: inner, .. }
+        | Expression::MemberAccess { object: inner, .. }
+        | Expression::OptionalMemberAccess { object: inner, .. }
+        | Expression::TupleAccess { tuple: inner, .. }
+        | Expression::Cast { value: inner, .. }
+        | Expression::Try { expression: inner, .. }
+        | Expression::Spawn { body: inner, .. } => inline_calls_in_expression(inner, candidates),
+        Expression::Call { args, .. }
+        | Expression::EnumConstructor { args, .. }
+        | Expression::ConstructorCall { args, .. }
+        | Expression::SuperCall { args, .. } => {
+            for arg in args {
+                inline_calls_in_expression(&mut arg.value, candidates);
+            }
+        }
+        Expression::MethodCall { object, args, .. } => {
+            inline_calls_in_expression(object, candidates);
+            for arg in args {
+                inline_calls_in_expression(&mut arg.value, candidates);
+            }
+        }
+        Expression::Block(block) => inline_calls_in_block(block, candidates),
+        Expression::Match { value, arms, .. } => {
+            inline_calls_in_expression(value, candidates);
+            for arm in arms {
+                inline_calls_in_expression(&mut arm.body, candidates);
+            }
+        }
+        Expression::If { condition, then_branch, else_branch, .. } => {
+            inline_calls_in_expression(condition, candidates);
+            inline_calls_in_expression(then_branch, candidates);
+            if let Some(else_expr) = else_branch {
+                inline_calls_in_expression(else_expr, candidates);
+            }
+        }
+        Expression::Tuple { elements, .. } => {
+            for element in elements {
+                inline_calls_in_expression(element, candidates);
+            }
+        }
+    }
+
+    let Expression::Call { function, args, .. } = expr else { return };
+    let Some(candidate) = candidates.get(function.as_str()) else { return };
+    if args.len() != candidate.params.len()
+        || !args.iter().all(|arg| candidate.params.contains(&arg.name) && is_simple_argument(&arg.value))
+    {
+        return;
+    }
+
+    let bindings: HashMap<String, Expression> = args.iter()
+        .map(|arg| (arg.name.clone(), arg.value.clone()))
+        .collect();
+    let mut body = candidate.body.clone();
+    substitute_identifiers(&mut body, &bindings);
+    *expr = body;
+}
@@ -7,17 +7,23 @@ use plat_hir::HirType;
 use cranelift_codegen::ir::types::*;
 use std::os::raw::c_char;
 use cranelift_codegen::ir::{
-    AbiParam, Value, condcodes::{IntCC, FloatCC}, StackSlotData, StackSlotKind, MemFlags,
+    AbiParam, SourceLoc, Value, condcodes::{IntCC, FloatCC}, StackSlotData, StackSlotKind, MemFlags,
+    AtomicRmwOp, Block as IrBlock, Inst,
 };
 use cranelift_codegen::isa::CallConv;
 use cranelift_codegen::settings::{self, Configurable};
 use cranelift_codegen::Context;
 use cranelift_codegen::ir::InstBuilder;
-use cranelift_frontend::{FunctionBuilder, FunctionBuilderContext, Variable};
-use cranelift_module::{Linkage, Module, ModuleError, FuncId, DataDescription};
+use cranelift_frontend::{FunctionBuilder, FunctionBuilderContext, Switch, Variable};
+use cranelift_module::{Linkage, Module, ModuleError, FuncId, FuncOrDataId, DataDescription, DataId};
 use cranelift_object::{ObjectBuilder, ObjectModule};
 use std::collections::{HashMap, HashSet};
 
+mod debug_info;
+mod inlining;
+mod propagation;
+use debug_info::DebugInfo;
+
 /// Track the original Plat types of variables for better codegen decisions
 #[derive(Debug, Clone, PartialEq)]
 pub enum VariableType {
@@ -32,12 +38,13 @@ pub enum VariableType {
     Float64,
     String,
     Array(Box<VariableType>), // Array with element type
-    Dict,
+    Dict(Box<VariableType>, Box<VariableType>), // Dict with key and value types
     Set,
     Class(String), // class name
     Enum(String), // enum name
     Task(Box<VariableType>), // Task<T> with inner type
     Channel(Box<VariableType>), // Channel<T> with element type
+    Tuple(Vec<VariableType>), // (T1, T2, ...) with element types
 }
 
 /// Metadata about a class field
@@ -56,6 +63,9 @@ struct VirtualMethod {
     name: String,
     vtable_index: usize,
     func_id: Option<FuncId>,
+    implementor: String, // Class whose `Class__method` fills the slot; subclasses inherit it until they override
+    param_types: Vec<AstType>, // Resolved through aliases; overrides must match exactly
+    return_type: Option<AstType>,
 }
 
 /// Metadata about a class definition
@@ -68,13 +78,80 @@ struct ClassMetadata {
     parent_class: Option<String>,
     virtual_methods: Vec<VirtualMethod>,
     has_vtable: bool,
+    /// Parameter names of an explicit `init`, in declaration order
+    init_params: Option<Vec<String>>,
+}
+
+/// Where a self tail call jumps back to, and the parameter variables it rebinds.
+/// Parameters are captured at function entry so locals that shadow them are never rebound.
+#[derive(Clone, Copy)]
+struct TailCallTarget<'a> {
+    block: IrBlock,
+    params: &'a HashMap<String, Variable>,
+}
+
+/// Counter for generated symbol names, plus read-only string data interned by contents
+#[derive(Debug, Default)]
+struct StringPool {
+    counter: usize,
+    interned: HashMap<String, DataId>,
+}
+
+impl StringPool {
+    /// A fresh symbol name with the given prefix
+    fn next_name(&mut self, prefix: &str) -> String {
+        let name = format!("{}{}", prefix, self.counter);
+        self.counter += 1;
+        name
+    }
+
+    /// Null-terminated data object holding `contents`, defined once per distinct string
+    fn intern(&mut self, module: &mut ObjectModule, contents: &str) -> Result<DataId, CodegenError> {
+        if let Some(&data_id) = self.interned.get(contents) {
+            return Ok(data_id);
+        }
+
+        let data_id = module.declare_data(&self.next_name("str_"), Linkage::Local, false, false)
+            .map_err(CodegenError::ModuleError)?;
+        let mut string_data = contents.as_bytes().to_vec();
+        string_data.push(0); // null terminator for C compatibility
+        let mut data_desc = DataDescription::new();
+        data_desc.define(string_data.into_boxed_slice());
+        module.define_data(data_id, &data_desc)
+            .map_err(CodegenError::ModuleError)?;
+
+        self.interned.insert(contents.to_string(), data_id);
+        Ok(data_id)
+    }
 }
 
+/// Name of the generated function that initializes module-level variables
+const GLOBALS_INIT_FUNCTION: &str = "__plat_globals_init";
+
+/// Name of the generated function that populates every class vtable; `main` calls it first
+const VTABLES_INIT_FUNCTION: &str = "__plat_vtables_init";
+
+/// Array literals with at most this many elements may be built in a stack slot
+const MAX_STACK_ARRAY_LEN: usize = 64;
+
+/// Byte size of the runtime's `RuntimeArray` header (data, length, capacity, element_size, element_type)
+const RUNTIME_ARRAY_HEADER_SIZE: u32 = 40;
+
+/// Array methods that only read or overwrite elements in place, so the receiver can live on the stack
+const STACK_ARRAY_METHODS: &[&str] = &["len", "get", "set", "index_of", "count"];
+
+/// Matches with at least this many arms, all unit enum variants, dispatch through a `Switch`
+/// (jump tables where discriminants cluster, binary search otherwise) instead of a compare chain
+const SWITCH_MATCH_MIN_ARMS: usize = 4;
+
 pub struct CodeGenerator {
     module: ObjectModule,
+    triple: target_lexicon::Triple, // Target the object module is built for
+    is_pic: bool, // Whether the object module emits position-independent code
+    opt_level: String, // Cranelift optimization level the object module is built with
     context: Context,
     functions: HashMap<String, FuncId>,
-    string_counter: usize,
+    string_pool: StringPool, // Generated-name counter and interned string literal data
     class_metadata: HashMap<String, ClassMetadata>,
     module_name: Option<String>, // Name of the current module for name mangling
     type_aliases: HashMap<String, AstType>, // Type aliases resolved from program
@@ -83,13 +160,22 @@ pub struct CodeGenerator {
     bench_mode: bool, // Whether we're in bench mode
     method_names: HashSet<String>, // Track which functions are enum/class methods (need implicit self)
     symbol_table: Option<plat_hir::ModuleSymbolTable>, // Global symbol table for cross-module function lookups
+    imported_classes: Vec<(String, ast::ClassDecl)>, // Public classes of other modules, by module path, for field layouts
+    constants: HashMap<String, Literal>, // Const values folded at compile time, inlined at use sites
+    global_types: HashMap<String, VariableType>, // Module-level `var`s, stored in writable data objects
+    debug_info: Option<DebugInfo>, // DWARF line tables, collected when a source file is attached
+    inlining: bool, // Whether calls to small leaf functions are inlined before codegen
+    assertions: bool, // Whether `assert` calls are compiled (always kept in test mode)
+    source_line_starts: Option<Vec<usize>>, // Line table of the source file, for reporting assert locations
+    source_path: Option<String>, // Path of the source file, for reporting error locations
 }
 
 impl CodeGenerator {
     /// Compute the mangled function name for export
     fn mangle_function_name(&self, simple_name: &str) -> String {
         if let Some(mod_name) = &self.module_name {
-            // Skip mangling for main function - it must remain "_main" for linking
+            // Skip mangling for main function - the C runtime startup calls it by name.
+            // The object writer adds the platform prefix itself (`_main` on Mach-O, `main` on ELF)
             if simple_name == "main" {
                 return simple_name.to_string();
             }
@@ -99,8 +185,116 @@ impl CodeGenerator {
         }
     }
 
+    /// Offset of field `index` in a heap-allocated multi-field enum variant. The layout is
+    /// `[discriminant:i32][padding:i32]` followed by one 8-byte slot per field, so any field
+    /// (including a pointer to another value of the same enum, for recursive enums) fits
+    fn enum_field_offset(index: usize) -> i32 {
+        8 + (index * 8) as i32
+    }
+
+    /// Binds the payload fields of an enum value to the names in a variant pattern
+    fn bind_enum_payload(
+        builder: &mut FunctionBuilder,
+        value_val: Value,
+        bindings: &[(String, AstType)],
+        variables: &mut HashMap<String, Variable>,
+        variable_types: &mut HashMap<String, VariableType>,
+        variable_counter: &mut u32
+    ) {
+        for (binding_idx, (binding_name, binding_type)) in bindings.iter().enumerate() {
+            if !binding_name.is_empty() {
+                // Determine the Cranelift type and VariableType based on the AST type
+                let (var_type, cranelift_type, is_string) = match binding_type {
+                    AstType::String => (VariableType::String, I64, true),
+                    AstType::Int32 => (VariableType::Int32, I32, false),
+                    AstType::Int64 => (VariableType::Int64, I64, false),
+                    AstType::Bool => (VariableType::Bool, I32, false),
+                    AstType::Float32 => (VariableType::Float32, F32, false),
+                    AstType::Float64 => (VariableType::Float64, F64, false),
+                    AstType::List(_) => (VariableType::Array(Box::new(VariableType::Int32)), I64, false),
+                    AstType::Dict(key, value) => (VariableType::Dict(Box::new(Self::ast_to_var_type_simple(key)), Box::new(Self::ast_to_var_type_simple(value))), I64, false),
+                    AstType::Set(_) => (VariableType::Set, I64, false),
+                    AstType::Named(name, _) => (VariableType::Class(name.clone()), I64, false),
+                    _ => (VariableType::Int32, I32, false), // Fallback for other types
+                };
+
+                // Use runtime detection to handle both packed and heap formats
+                // This is needed because FFI functions return heap pointers,
+                // while Plat functions return packed values
+                // Note: Int64, Float64, List, Dict, Set, and Named types cannot be packed,
+                // so they always use heap format (i64 pointers)
+                let is_always_heap = is_string || matches!(binding_type,
+                    AstType::Int64 | AstType::Float64 |
+                    AstType::List(_) | AstType::Dict(_, _) | AstType::Set(_) |
+                    AstType::Named(_, _)
+                );
+                let field_val = if bindings.len() == 1 && !is_always_heap {
+                    // Single 32-bit field: detect format at runtime
+                    let min_addr = builder.ins().iconst(I64, 0x1000);
+                    let max_pointer = builder.ins().iconst(I64, 0x7FFFFFFFFFFF);
+
+                    let above_min = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::UnsignedGreaterThan, value_val, min_addr);
+                    let below_max = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::UnsignedLessThan, value_val, max_pointer);
+                    let use_heap = builder.ins().band(above_min, below_max);
+
+                    // Create blocks for packed vs heap extraction
+                    let packed_extract = builder.create_block();
+                    let heap_extract = builder.create_block();
+                    let extract_done = builder.create_block();
+                    builder.append_block_param(extract_done, cranelift_type);
+
+                    builder.ins().brif(use_heap, heap_extract, &[], packed_extract, &[]);
+
+                    // Packed format: value in low 32 bits (for primitives)
+                    builder.switch_to_block(packed_extract);
+                    builder.seal_block(packed_extract);
+                    let packed_val = if cranelift_type == I32 {
+                        builder.ins().ireduce(I32, value_val)
+                    } else {
+                        value_val // Already I64 or other type
+                    };
+                    builder.ins().jump(extract_done, &[packed_val]);
+
+                    // Heap format: load from offset (4 or 8 depending on type)
+                    builder.switch_to_block(heap_extract);
+                    builder.seal_block(heap_extract);
+                    // 8-byte types (Int64, Float64, String) start at offset 8, 4-byte types at offset 4
+                    let offset = match binding_type {
+                        AstType::Int64 | AstType::Float64 | AstType::String => 8,
+                        _ => 4,
+                    };
+                    let heap_val = builder.ins().load(cranelift_type, MemFlags::new(), value_val, offset);
+                    builder.ins().jump(extract_done, &[heap_val]);
+
+                    // Done block
+                    builder.switch_to_block(extract_done);
+                    builder.seal_block(extract_done);
+                    builder.block_params(extract_done)[0]
+                } else {
+                    // Multi-field, string, or Int64/Float64: always use heap format
+                    // For single-field Int64/Float64: value_val is a heap pointer, load at offset 8
+                    // For multi-field: each field has its own 8-byte slot (see `enum_field_offset`)
+                    if bindings.len() == 1 {
+                        // Single field, must be heap format (Int64/Float64/String)
+                        let offset = 8; // All 8-byte types start at offset 8
+                        builder.ins().load(cranelift_type, MemFlags::new(), value_val, offset)
+                    } else {
+                        builder.ins().load(cranelift_type, MemFlags::new(), value_val, Self::enum_field_offset(binding_idx))
+                    }
+                };
+
+                let var = Variable::from_u32(*variable_counter);
+                *variable_counter += 1;
+                builder.declare_var(var, cranelift_type);
+                builder.def_var(var, field_val);
+                variables.insert(binding_name.clone(), var);
+                variable_types.insert(binding_name.clone(), var_type);
+            }
+        }
+    }
+
     /// Determine the variable type that a match expression returns
-    fn determine_match_return_type(arms: &[MatchArm], _variable_types: &HashMap<String, VariableType>) -> VariableType {
+    fn determine_match_return_type(value: &Expression, arms: &[MatchArm], variable_types: &HashMap<String, VariableType>) -> VariableType {
         if arms.is_empty() {
             return VariableType::Int32;
         }
@@ -109,6 +303,10 @@ impl CodeGenerator {
         // If so, use the type from the pattern binding
         for arm in arms {
             if let Expression::Identifier { name, .. } = &arm.body {
+                // An `@` binding holds the scrutinee itself
+                if arm.binding.as_ref() == Some(name) {
+                    return Self::infer_expression_type(value, variable_types);
+                }
                 // Check if this identifier is a pattern binding
                 if let Pattern::EnumVariant { bindings, .. } = &arm.pattern {
                     for (binding_name, binding_type) in bindings {
@@ -126,9 +324,10 @@ impl CodeGenerator {
                                 AstType::Float64 => VariableType::Float64,
                                 AstType::String => VariableType::String,
                                 AstType::List(elem) => VariableType::Array(Box::new(VariableType::Int32)), // Simplified
-                                AstType::Dict(_, _) => VariableType::Dict,
+                                AstType::Dict(key, value) => VariableType::Dict(Box::new(Self::ast_to_var_type_simple(key)), Box::new(Self::ast_to_var_type_simple(value))),
                                 AstType::Set(_) => VariableType::Set,
                                 AstType::Named(type_name, _) => VariableType::Class(type_name.clone()),
+                                AstType::Tuple(elements) => VariableType::Tuple(elements.iter().map(Self::ast_to_var_type_simple).collect()),
                             };
                         }
                     }
@@ -163,7 +362,7 @@ impl CodeGenerator {
         match &arms[0].body {
             Expression::Literal(Literal::Bool(_, _)) => VariableType::Bool,
             Expression::Literal(Literal::Array(_, _)) => VariableType::Array(Box::new(VariableType::Int32)),
-            Expression::Literal(Literal::Dict(_, _)) => VariableType::Dict,
+            Expression::Literal(Literal::Dict(pairs, _)) => Self::infer_dict_literal_type(pairs, variable_types),
             Expression::Literal(Literal::Set(_, _)) => VariableType::Set,
             Expression::EnumConstructor { enum_name, .. } => VariableType::Enum(enum_name.clone()),
             Expression::ConstructorCall { class_name, .. } => VariableType::Class(class_name.clone()),
@@ -188,7 +387,7 @@ impl CodeGenerator {
                     Expression::EnumConstructor { enum_name, .. } => VariableType::Enum(enum_name.clone()),
                     Expression::ConstructorCall { class_name, .. } => VariableType::Class(class_name.clone()),
                     Expression::Literal(Literal::Array(_, _)) => VariableType::Array(Box::new(VariableType::Int32)),
-                    Expression::Literal(Literal::Dict(_, _)) => VariableType::Dict,
+                    Expression::Literal(Literal::Dict(pairs, _)) => Self::infer_dict_literal_type(pairs, variable_types),
                     Expression::Literal(Literal::Set(_, _)) => VariableType::Set,
                     Expression::Identifier { name, .. } => {
                         // Look up the variable's type
@@ -206,9 +405,12 @@ impl CodeGenerator {
                 }
             }
             // Method call that returns an array
-            Expression::MethodCall { .. } => {
-                VariableType::Int32 // Default assumption
-            }
+            Expression::MethodCall { .. } => match Self::infer_expression_type(iterable, variable_types) {
+                VariableType::Array(element_type) => *element_type,
+                _ => VariableType::Int32, // Default assumption
+            },
+            // The args() builtin always yields a List[String]
+            Expression::Call { function, .. } if function == "args" => VariableType::String,
             // Function call that returns an array
             Expression::Call { .. } => {
                 VariableType::Int32 // Default assumption
@@ -217,6 +419,17 @@ impl CodeGenerator {
         }
     }
 
+    /// Infer the key and value types of a dict literal from its first entry
+    fn infer_dict_literal_type(pairs: &[(Expression, Expression)], variable_types: &HashMap<String, VariableType>) -> VariableType {
+        match pairs.first() {
+            Some((key, value)) => VariableType::Dict(
+                Box::new(Self::infer_expression_type(key, variable_types)),
+                Box::new(Self::infer_expression_type(value, variable_types)),
+            ),
+            None => VariableType::Dict(Box::new(VariableType::String), Box::new(VariableType::Int32)), // Default for empty dicts
+        }
+    }
+
     fn infer_expression_type(expr: &Expression, variable_types: &HashMap<String, VariableType>) -> VariableType {
         match expr {
             Expression::Literal(Literal::Bool(_, _)) => VariableType::Bool,
@@ -238,21 +451,31 @@ impl CodeGenerator {
             }
             Expression::Literal(Literal::String(_, _)) => VariableType::String,
             Expression::Literal(Literal::InterpolatedString(_, _)) => VariableType::String,
+            Expression::Literal(Literal::Dict(pairs, _)) => Self::infer_dict_literal_type(pairs, variable_types),
+            Expression::Literal(Literal::Array(_, _)) => VariableType::Array(Box::new(Self::infer_element_type(expr, variable_types))),
+            Expression::Literal(Literal::Set(_, _)) => VariableType::Set,
             Expression::Identifier { name, .. } => {
                 variable_types.get(name).cloned().unwrap_or(VariableType::Int32)
             }
+            Expression::Self_ { .. } => {
+                variable_types.get("self").cloned().unwrap_or(VariableType::Int32)
+            }
             Expression::Binary { left, op, right, .. } => {
                 // For arithmetic operations, infer from operands
                 match op {
                     BinaryOp::Add | BinaryOp::Subtract | BinaryOp::Multiply | BinaryOp::Divide | BinaryOp::Modulo => {
                         let left_type = Self::infer_expression_type(left, variable_types);
                         let right_type = Self::infer_expression_type(right, variable_types);
-                        // Priority: F64 > F32/F16/F8 > I64 > I32/I16/I8
-                        if left_type == VariableType::Float64 || right_type == VariableType::Float64 {
-                            VariableType::Float64
-                        } else if matches!(left_type, VariableType::Float32 | VariableType::Float16 | VariableType::Float8)
-                               || matches!(right_type, VariableType::Float32 | VariableType::Float16 | VariableType::Float8) {
-                            left_type.clone()
+                        // Priority: F64 > F32 > F16 > F8 > I64 > I32/I16/I8
+                        let float_rank = |ty: &VariableType| match ty {
+                            VariableType::Float64 => 4,
+                            VariableType::Float32 => 3,
+                            VariableType::Float16 => 2,
+                            VariableType::Float8 => 1,
+                            _ => 0,
+                        };
+                        if float_rank(&left_type) > 0 || float_rank(&right_type) > 0 {
+                            if float_rank(&left_type) >= float_rank(&right_type) { left_type } else { right_type }
                         } else if left_type == VariableType::Int64 || right_type == VariableType::Int64 {
                             VariableType::Int64
                         } else {
@@ -262,13 +485,65 @@ impl CodeGenerator {
                     _ => VariableType::Bool, // Comparison and logical operations return bool
                 }
             }
-            Expression::MethodCall { object, method, .. } => {
+            Expression::MethodCall { object, method, args, .. } => {
                 // For Class.init(...), infer the class type
                 if let Expression::Identifier { name, .. } = object.as_ref() {
                     if method == "init" && name.chars().next().map_or(false, |c| c.is_uppercase()) {
                         return VariableType::Class(name.clone());
                     }
                 }
+                if method == "hash" {
+                    return VariableType::Int64;
+                }
+                if method == "clone" {
+                    let object_type = Self::infer_expression_type(object, variable_types);
+                    if matches!(object_type, VariableType::Array(_) | VariableType::Dict(_, _) | VariableType::Set) {
+                        return object_type;
+                    }
+                }
+                // Array methods returning a list of the receiver's own type
+                if method == "unique" || method == "view" {
+                    if let array_type @ VariableType::Array(_) = Self::infer_expression_type(object, variable_types) {
+                        return array_type;
+                    }
+                }
+                // Array methods whose element type follows from the receiver and argument
+                if method == "flatten" {
+                    if let VariableType::Array(element_type) = Self::infer_expression_type(object, variable_types) {
+                        if let VariableType::Array(_) = element_type.as_ref() {
+                            return *element_type;
+                        }
+                    }
+                }
+                if method == "chunks" {
+                    if let array_type @ VariableType::Array(_) = Self::infer_expression_type(object, variable_types) {
+                        return VariableType::Array(Box::new(array_type));
+                    }
+                }
+                if method == "enumerate" {
+                    if let VariableType::Array(element_type) = Self::infer_expression_type(object, variable_types) {
+                        return VariableType::Array(Box::new(VariableType::Tuple(vec![VariableType::Int32, *element_type])));
+                    }
+                }
+                if method == "zip" {
+                    if let (VariableType::Array(first), Some(arg)) = (Self::infer_expression_type(object, variable_types), args.first()) {
+                        if let VariableType::Array(second) = Self::infer_expression_type(&arg.value, variable_types) {
+                            return VariableType::Array(Box::new(VariableType::Tuple(vec![*first, *second])));
+                        }
+                    }
+                }
+                // String methods that produce a new string
+                if matches!(method.as_str(), "concat" | "trim" | "trim_left" | "trim_right" | "replace" | "replace_all" | "substring" | "char_at")
+                    && Self::infer_expression_type(object, variable_types) == VariableType::String
+                {
+                    return VariableType::String;
+                }
+                // String methods that produce a List[String]
+                if matches!(method.as_str(), "split" | "lines" | "chars")
+                    && Self::infer_expression_type(object, variable_types) == VariableType::String
+                {
+                    return VariableType::Array(Box::new(VariableType::String));
+                }
                 // For other method calls, we can't infer without type info, default to Int32
                 VariableType::Int32
             }
@@ -276,6 +551,35 @@ impl CodeGenerator {
                 // Constructor calls like Point.init(...) return the class type
                 VariableType::Class(class_name.clone())
             }
+            Expression::Tuple { elements, .. } => {
+                VariableType::Tuple(elements.iter().map(|e| Self::infer_expression_type(e, variable_types)).collect())
+            }
+            Expression::TupleAccess { tuple, index, .. } => {
+                match Self::infer_expression_type(tuple, variable_types) {
+                    VariableType::Tuple(element_types) => element_types.get(*index).cloned().unwrap_or(VariableType::Int32),
+                    _ => VariableType::Int32,
+                }
+            }
+            // Newtype casts keep the value's representation; other casts produce the target type
+            Expression::Cast { value, target_type: AstType::Named(_, _), .. } => {
+                Self::infer_expression_type(value, variable_types)
+            }
+            Expression::Cast { target_type, .. } => {
+                Self::ast_type_to_variable_type_static(&HashMap::new(), target_type)
+            }
+            // Atomic builtins have fixed result types
+            Expression::Call { function, .. } if function == "atomic_add" || function == "atomic_load" => VariableType::Int64,
+            Expression::Call { function, .. } if function == "compare_and_swap" => VariableType::Bool,
+            Expression::Call { function, .. } if function == "__describe_object" => VariableType::String,
+            // A spawn yields a task of its closure's return type
+            Expression::Spawn { body, .. } => {
+                let result_type = if let Expression::Block(block) = body.as_ref() {
+                    Self::infer_block_return_type(block, variable_types)
+                } else {
+                    Self::infer_expression_type(body, variable_types)
+                };
+                VariableType::Task(Box::new(result_type))
+            }
             _ => VariableType::Int32, // Default
         }
     }
@@ -301,7 +605,7 @@ impl CodeGenerator {
                         AstType::Float64 => VariableType::Float64,
                         AstType::String => VariableType::String,
                         AstType::List(elem) => VariableType::Array(Box::new(Self::ast_to_var_type_simple(elem))),
-                        AstType::Dict(_, _) => VariableType::Dict,
+                        AstType::Dict(key, value) => VariableType::Dict(Box::new(Self::ast_to_var_type_simple(key)), Box::new(Self::ast_to_var_type_simple(value))),
                         AstType::Set(_) => VariableType::Set,
                         AstType::Named(name, _) => {
                             if name.starts_with(char::is_uppercase) {
@@ -310,9 +614,15 @@ impl CodeGenerator {
                                 VariableType::Int32 // fallback
                             }
                         }
+                        AstType::Tuple(elements) => VariableType::Tuple(elements.iter().map(Self::ast_to_var_type_simple).collect()),
                     };
                     local_types.insert(name.clone(), var_type);
                 }
+                Statement::LetPattern { pattern: Pattern::EnumVariant { bindings, .. } | Pattern::Tuple { bindings, .. }, .. } => {
+                    for (binding_name, binding_type) in bindings {
+                        local_types.insert(binding_name.clone(), Self::ast_type_to_variable_type_static(&HashMap::new(), binding_type));
+                    }
+                }
                 Statement::Return { value, .. } => {
                     if let Some(expr) = value {
                         return Self::infer_expression_type(expr, &local_types);
@@ -336,6 +646,20 @@ impl CodeGenerator {
         }
     }
 
+    /// The runtime's element type tag (`ARRAY_TYPE_*`) for list elements of this type.
+    /// The runtime compares strings by content and everything else by value.
+    fn array_element_tag(element_type: &VariableType) -> u8 {
+        use plat_runtime::ffi::array::{ARRAY_TYPE_BOOL, ARRAY_TYPE_CLASS, ARRAY_TYPE_I32, ARRAY_TYPE_I64, ARRAY_TYPE_I8, ARRAY_TYPE_STRING};
+        match element_type {
+            VariableType::Int8 => ARRAY_TYPE_I8,
+            VariableType::Int64 => ARRAY_TYPE_I64,
+            VariableType::Bool => ARRAY_TYPE_BOOL,
+            VariableType::String => ARRAY_TYPE_STRING,
+            VariableType::Class(_) => ARRAY_TYPE_CLASS,
+            _ => ARRAY_TYPE_I32,
+        }
+    }
+
     /// Get the spawn function name for a given return type
     fn get_spawn_function_name(return_type: &VariableType) -> &'static str {
         match return_type {
@@ -346,7 +670,7 @@ impl CodeGenerator {
             VariableType::Float64 => "plat_spawn_task_f64",
             VariableType::String => "plat_spawn_task_string",
             VariableType::Array(_) => "plat_spawn_task_ptr",
-            VariableType::Dict => "plat_spawn_task_ptr",
+            VariableType::Dict(_, _) => "plat_spawn_task_ptr",
             VariableType::Set => "plat_spawn_task_ptr",
             VariableType::Class(_) => "plat_spawn_task_ptr",
             VariableType::Enum(_) => "plat_spawn_task_ptr",
@@ -364,7 +688,7 @@ impl CodeGenerator {
             VariableType::Float64 => "plat_task_await_f64",
             VariableType::String => "plat_task_await_string",
             VariableType::Array(_) => "plat_task_await_ptr",
-            VariableType::Dict => "plat_task_await_ptr",
+            VariableType::Dict(_, _) => "plat_task_await_ptr",
             VariableType::Set => "plat_task_await_ptr",
             VariableType::Class(_) => "plat_task_await_ptr",
             VariableType::Enum(_) => "plat_task_await_ptr",
@@ -407,7 +731,7 @@ impl CodeGenerator {
                 Self::find_captured_variables(object, local_vars, captured);
                 Self::find_captured_variables(index, local_vars, captured);
             }
-            Expression::MemberAccess { object, .. } => {
+            Expression::MemberAccess { object, .. } | Expression::OptionalMemberAccess { object, .. } => {
                 Self::find_captured_variables(object, local_vars, captured);
             }
             Expression::Assignment { target, value, .. } => {
@@ -440,6 +764,14 @@ impl CodeGenerator {
                 // Don't recurse into spawn - it has its own scope
                 Self::find_captured_variables(body, local_vars, captured);
             }
+            Expression::Tuple { elements, .. } => {
+                for element in elements {
+                    Self::find_captured_variables(element, local_vars, captured);
+                }
+            }
+            Expression::TupleAccess { tuple, .. } => {
+                Self::find_captured_variables(tuple, local_vars, captured);
+            }
             _ => {} // Literals and other expressions don't capture
         }
     }
@@ -456,6 +788,14 @@ impl CodeGenerator {
                 // Add to local vars (type doesn't matter for capture detection)
                 local_vars.insert(name.clone(), VariableType::Int32);
             }
+            Statement::LetPattern { pattern, value, .. } => {
+                Self::find_captured_variables(value, local_vars, captured);
+                if let Pattern::EnumVariant { bindings, .. } | Pattern::Tuple { bindings, .. } = pattern {
+                    for (binding_name, _) in bindings {
+                        local_vars.insert(binding_name.clone(), VariableType::Int32);
+                    }
+                }
+            }
             Statement::Return { value, .. } => {
                 if let Some(expr) = value {
                     Self::find_captured_variables(expr, local_vars, captured);
@@ -464,9 +804,12 @@ impl CodeGenerator {
             Statement::Expression(expression) => {
                 Self::find_captured_variables(expression, local_vars, captured);
             }
-            Statement::For { variable, iterable, body, .. } => {
+            Statement::For { variable, value_binding, iterable, body, .. } => {
                 Self::find_captured_variables(iterable, local_vars, captured);
                 local_vars.insert(variable.clone(), VariableType::Int32);
+                if let Some((value_name, _)) = value_binding {
+                    local_vars.insert(value_name.clone(), VariableType::Int32);
+                }
                 for stmt in &body.statements {
                     Self::find_captured_in_statement(stmt, local_vars, captured);
                 }
@@ -488,14 +831,729 @@ impl CodeGenerator {
                     }
                 }
             }
-            Statement::Concurrent { body, .. } => {
-                for stmt in &body.statements {
-                    Self::find_captured_in_statement(stmt, local_vars, captured);
+            Statement::Concurrent { body, .. } => {
+                for stmt in &body.statements {
+                    Self::find_captured_in_statement(stmt, local_vars, captured);
+                }
+            }
+            Statement::Select { arms, default, .. } => {
+                for arm in arms {
+                    Self::find_captured_variables(&arm.channel, local_vars, captured);
+                    local_vars.insert(arm.binding.clone(), VariableType::Int32);
+                    for stmt in &arm.body.statements {
+                        Self::find_captured_in_statement(stmt, local_vars, captured);
+                    }
+                }
+                if let Some(default_block) = default {
+                    for stmt in &default_block.statements {
+                        Self::find_captured_in_statement(stmt, local_vars, captured);
+                    }
+                }
+            }
+            Statement::Print { value, .. } => {
+                Self::find_captured_variables(value, local_vars, captured);
+            }
+        }
+    }
+
+    /// Names of `let`/`var` array locals whose literal can be built on the stack: a small
+    /// `List[Int32]`/`List[Int64]` literal whose variable never escapes the function
+    fn find_stack_arrays(body: &Block) -> HashSet<String> {
+        let mut candidates = HashSet::new();
+        Self::collect_stack_array_candidates(body, &mut candidates);
+        candidates.retain(|name| !body.statements.iter().any(|stmt| Self::array_escapes_in_statement(name, stmt, false)));
+        candidates
+    }
+
+    fn collect_stack_array_candidates(block: &Block, candidates: &mut HashSet<String>) {
+        for stmt in &block.statements {
+            match stmt {
+                Statement::Let { name, ty: AstType::List(element_type), value: Expression::Literal(Literal::Array(elements, _)), .. }
+                | Statement::Var { name, ty: AstType::List(element_type), value: Expression::Literal(Literal::Array(elements, _)), .. } => {
+                    if !elements.is_empty()
+                        && elements.len() <= MAX_STACK_ARRAY_LEN
+                        && matches!(element_type.as_ref(), AstType::Int32 | AstType::Int64)
+                    {
+                        candidates.insert(name.clone());
+                    }
+                }
+                Statement::If { then_branch, else_branch, .. } => {
+                    Self::collect_stack_array_candidates(then_branch, candidates);
+                    if let Some(else_block) = else_branch {
+                        Self::collect_stack_array_candidates(else_block, candidates);
+                    }
+                }
+                Statement::While { body, .. } | Statement::For { body, .. } | Statement::Concurrent { body, .. } => {
+                    Self::collect_stack_array_candidates(body, candidates);
+                }
+                Statement::Select { arms, default, .. } => {
+                    for arm in arms {
+                        Self::collect_stack_array_candidates(&arm.body, candidates);
+                    }
+                    if let Some(default_block) = default {
+                        Self::collect_stack_array_candidates(default_block, candidates);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Conservative escape check: any use of `name` other than indexing, a whitelisted
+    /// array method, or a `for` loop over it counts as an escape. Inside `spawn` every
+    /// use escapes, since the task may outlive the stack frame.
+    fn array_escapes_in_statement(name: &str, stmt: &Statement, in_task: bool) -> bool {
+        match stmt {
+            Statement::Let { value, .. } | Statement::Var { value, .. } | Statement::LetPattern { value, .. } => Self::array_escapes(name, value, in_task),
+            Statement::Expression(expr) | Statement::Print { value: expr, .. } => Self::array_escapes(name, expr, in_task),
+            Statement::Return { value, .. } => value.as_ref().is_some_and(|expr| Self::array_escapes(name, expr, in_task)),
+            Statement::If { condition, then_branch, else_branch, .. } => {
+                Self::array_escapes(name, condition, in_task)
+                    || Self::array_escapes_in_block(name, then_branch, in_task)
+                    || else_branch.as_ref().is_some_and(|block| Self::array_escapes_in_block(name, block, in_task))
+            }
+            Statement::While { condition, body, .. } => {
+                Self::array_escapes(name, condition, in_task) || Self::array_escapes_in_block(name, body, in_task)
+            }
+            Statement::For { iterable, body, .. } => {
+                let iterates_local = !in_task && matches!(iterable, Expression::Identifier { name: n, .. } if n == name);
+                (!iterates_local && Self::array_escapes(name, iterable, in_task))
+                    || Self::array_escapes_in_block(name, body, in_task)
+            }
+            Statement::Concurrent { body, .. } => Self::array_escapes_in_block(name, body, in_task),
+            Statement::Select { arms, default, .. } => {
+                arms.iter().any(|arm| Self::array_escapes(name, &arm.channel, in_task) || Self::array_escapes_in_block(name, &arm.body, in_task))
+                    || default.as_ref().is_some_and(|block| Self::array_escapes_in_block(name, block, in_task))
+            }
+        }
+    }
+
+    fn array_escapes_in_block(name: &str, block: &Block, in_task: bool) -> bool {
+        block.statements.iter().any(|stmt| Self::array_escapes_in_statement(name, stmt, in_task))
+    }
+
+    fn array_escapes(name: &str, expr: &Expression, in_task: bool) -> bool {
+        let is_local = |expr: &Expression| !in_task && matches!(expr, Expression::Identifier { name: n, .. } if n == name);
+        match expr {
+            Expression::Identifier { name: n, .. } => n == name,
+            Expression::Index { object, index, .. } => {
+                (!is_local(object) && Self::array_escapes(name, object, in_task)) || Self::array_escapes(name, index, in_task)
+            }
+            Expression::MethodCall { object, method, args, .. } => {
+                let local_receiver = is_local(object) && STACK_ARRAY_METHODS.contains(&method.as_str());
+                (!local_receiver && Self::array_escapes(name, object, in_task)) || Self::any_array_escapes(name, args.iter().map(|arg| &arg.value), in_task)
+            }
+            Expression::Assignment { target, value, .. } => {
+                // Rebinding the variable itself drops the stack array rather than leaking it
+                let rebinds = matches!(target.as_ref(), Expression::Identifier { name: n, .. } if n == name);
+                (!rebinds && Self::array_escapes(name, target, in_task)) || Self::array_escapes(name, value, in_task)
+            }
+            Expression::Literal(Literal::InterpolatedString(parts, _)) => {
+                let part_exprs = parts.iter().filter_map(|part| match part {
+                    ast::InterpolationPart::Expression(expr) => Some(expr.as_ref()),
+                    ast::InterpolationPart::Text(_) => None,
+                });
+                Self::any_array_escapes(name, part_exprs, in_task)
+            }
+            Expression::Literal(Literal::Array(elements, _) | Literal::Set(elements, _)) => Self::any_array_escapes(name, elements.iter(), in_task),
+            Expression::Literal(Literal::Dict(pairs, _)) => Self::any_array_escapes(name, pairs.iter().flat_map(|(key, value)| [key, value]), in_task),
+            Expression::Literal(_) | Expression::Self_ { .. } => false,
+            Expression::Binary { left, right, .. } => Self::any_array_escapes(name, [left.as_ref(), right.as_ref()], in_task),
+            Expression::Unary { operand: value, .. }
+            | Expression::Try { expression: value, .. }
+            | Expression::Cast { value, .. }
+            | Expression::MemberAccess { object: value, .. }
+            | Expression::OptionalMemberAccess { object: value, .. }
+            | Expression::TupleAccess { tuple: value, .. } => Self::array_escapes(name, value, in_task),
+            Expression::Call { args, .. }
+            | Expression::EnumConstructor { args, .. }
+            | Expression::ConstructorCall { args, .. }
+            | Expression::SuperCall { args, .. } => Self::any_array_escapes(name, args.iter().map(|arg| &arg.value), in_task),
+            Expression::Tuple { elements, .. } => Self::any_array_escapes(name, elements.iter(), in_task),
+            Expression::Block(block) => Self::array_escapes_in_block(name, block, in_task),
+            Expression::Match { value, arms, .. } => {
+                Self::array_escapes(name, value, in_task) || Self::any_array_escapes(name, arms.iter().map(|arm| &arm.body), in_task)
+            }
+            Expression::Range { start, end, step, .. } => {
+                Self::any_array_escapes(name, [Some(start), Some(end), step.as_ref()].into_iter().flatten().map(|e| e.as_ref()), in_task)
+            }
+            Expression::If { condition, then_branch, else_branch, .. } => {
+                Self::any_array_escapes(name, [Some(condition), Some(then_branch), else_branch.as_ref()].into_iter().flatten().map(|e| e.as_ref()), in_task)
+            }
+            Expression::Spawn { body, .. } => Self::array_escapes(name, body, true),
+        }
+    }
+
+    fn any_array_escapes<'a>(name: &str, exprs: impl IntoIterator<Item = &'a Expression>, in_task: bool) -> bool {
+        exprs.into_iter().any(|expr| Self::array_escapes(name, expr, in_task))
+    }
+
+    /// Constant-folding pass: evaluate a `const` initializer to a literal at compile time
+    fn fold_constant(expr: &Expression, constants: &HashMap<String, Literal>) -> Result<Literal, CodegenError> {
+        match expr {
+            Expression::Literal(literal @ (Literal::Bool(..) | Literal::Integer(..) | Literal::Float(..) | Literal::String(..))) => {
+                Ok(literal.clone())
+            }
+            Expression::Identifier { name, .. } => constants.get(name).cloned()
+                .ok_or_else(|| CodegenError::UndefinedVariable(name.clone())),
+            Expression::EnumConstructor { enum_name, variant, args, .. } if args.is_empty() => {
+                let name = format!("{}::{}", enum_name, variant);
+                constants.get(&name).cloned().ok_or(CodegenError::UndefinedVariable(name))
+            }
+            Expression::Unary { op, operand, span } => {
+                match (op, Self::fold_constant(operand, constants)?) {
+                    (UnaryOp::Not, Literal::Bool(b, _)) => Ok(Literal::Bool(!b, *span)),
+                    (UnaryOp::Negate, Literal::Integer(v, int_type, _)) => {
+                        Ok(Literal::Integer(Self::wrap_constant_int(v.wrapping_neg(), int_type), int_type, *span))
+                    }
+                    (UnaryOp::Negate, Literal::Float(v, float_type, _)) => Ok(Literal::Float(-v, float_type, *span)),
+                    (op, operand) => Err(CodegenError::UnsupportedFeature(
+                        format!("Cannot apply {:?} to constant {:?}", op, operand)
+                    )),
+                }
+            }
+            Expression::Binary { left, op, right, span } => {
+                let left = Self::fold_constant(left, constants)?;
+                let right = Self::fold_constant(right, constants)?;
+                let span = *span;
+
+                match (&left, &right) {
+                    (Literal::Integer(l, l_type, _), Literal::Integer(r, r_type, _)) => {
+                        // Mixed widths only arise from upcasting, so fold at the wider type
+                        let int_type = if Self::int_type_bits(*l_type) >= Self::int_type_bits(*r_type) { *l_type } else { *r_type };
+                        let (l, r) = (*l, *r);
+                        if matches!(op, BinaryOp::Divide | BinaryOp::Modulo) && r == 0 {
+                            return Err(CodegenError::UnsupportedFeature(
+                                "Division by zero in constant expression".to_string()
+                            ));
+                        }
+                        let value = match op {
+                            BinaryOp::Add => l.wrapping_add(r),
+                            BinaryOp::Subtract => l.wrapping_sub(r),
+                            BinaryOp::Multiply => l.wrapping_mul(r),
+                            BinaryOp::Divide => l.wrapping_div(r),
+                            BinaryOp::Modulo => l.wrapping_rem(r),
+                            BinaryOp::Equal => return Ok(Literal::Bool(l == r, span)),
+                            BinaryOp::NotEqual => return Ok(Literal::Bool(l != r, span)),
+                            BinaryOp::Less => return Ok(Literal::Bool(l < r, span)),
+                            BinaryOp::LessEqual => return Ok(Literal::Bool(l <= r, span)),
+                            BinaryOp::Greater => return Ok(Literal::Bool(l > r, span)),
+                            BinaryOp::GreaterEqual => return Ok(Literal::Bool(l >= r, span)),
+                            BinaryOp::And | BinaryOp::Or => {
+                                return Err(CodegenError::UnsupportedFeature(
+                                    format!("Cannot apply {:?} to integer constants", op)
+                                ));
+                            }
+                        };
+                        Ok(Literal::Integer(Self::wrap_constant_int(value, int_type), int_type, span))
+                    }
+                    (Literal::Float(l, l_type, _), Literal::Float(r, r_type, _)) => {
+                        let float_type = if matches!(l_type, FloatType::F64) { *l_type } else { *r_type };
+                        let (l, r) = (*l, *r);
+                        let value = match op {
+                            BinaryOp::Add => l + r,
+                            BinaryOp::Subtract => l - r,
+                            BinaryOp::Multiply => l * r,
+                            BinaryOp::Divide => l / r,
+                            BinaryOp::Modulo => l % r,
+                            BinaryOp::Equal => return Ok(Literal::Bool(l == r, span)),
+                            BinaryOp::NotEqual => return Ok(Literal::Bool(l != r, span)),
+                            BinaryOp::Less => return Ok(Literal::Bool(l < r, span)),
+                            BinaryOp::LessEqual => return Ok(Literal::Bool(l <= r, span)),
+                            BinaryOp::Greater => return Ok(Literal::Bool(l > r, span)),
+                            BinaryOp::GreaterEqual => return Ok(Literal::Bool(l >= r, span)),
+                            BinaryOp::And | BinaryOp::Or => {
+                                return Err(CodegenError::UnsupportedFeature(
+                                    format!("Cannot apply {:?} to float constants", op)
+                                ));
+                            }
+                        };
+                        Ok(Literal::Float(value, float_type, span))
+                    }
+                    (Literal::Bool(l, _), Literal::Bool(r, _)) => {
+                        let value = match op {
+                            BinaryOp::And => *l && *r,
+                            BinaryOp::Or => *l || *r,
+                            BinaryOp::Equal => l == r,
+                            BinaryOp::NotEqual => l != r,
+                            _ => {
+                                return Err(CodegenError::UnsupportedFeature(
+                                    format!("Cannot apply {:?} to boolean constants", op)
+                                ));
+                            }
+                        };
+                        Ok(Literal::Bool(value, span))
+                    }
+                    (Literal::String(l, _), Literal::String(r, _)) => {
+                        match op {
+                            BinaryOp::Equal => Ok(Literal::Bool(l == r, span)),
+                            BinaryOp::NotEqual => Ok(Literal::Bool(l != r, span)),
+                            _ => Err(CodegenError::UnsupportedFeature(
+                                format!("Cannot apply {:?} to string constants", op)
+                            )),
+                        }
+                    }
+                    _ => Err(CodegenError::UnsupportedFeature(
+                        format!("Cannot apply {:?} to constants {:?} and {:?}", op, left, right)
+                    )),
+                }
+            }
+            _ => Err(CodegenError::UnsupportedFeature(
+                "Constant initializer is not a compile-time constant expression".to_string()
+            )),
+        }
+    }
+
+    fn int_type_bits(int_type: IntType) -> u32 {
+        match int_type {
+            IntType::I8 => 8,
+            IntType::I16 => 16,
+            IntType::I32 => 32,
+            IntType::I64 => 64,
+        }
+    }
+
+    /// Wrap a folded integer to the width of its type, matching runtime overflow behavior
+    fn wrap_constant_int(value: i64, int_type: IntType) -> i64 {
+        match int_type {
+            IntType::I8 => value as i8 as i64,
+            IntType::I16 => value as i16 as i64,
+            IntType::I32 => value as i32 as i64,
+            IntType::I64 => value,
+        }
+    }
+
+    /// Convert a folded constant to its declared type (e.g. an Int32 literal declared as Int64)
+    fn coerce_constant(literal: Literal, ty: &AstType) -> Literal {
+        match (literal, ty) {
+            (Literal::Integer(v, _, span), AstType::Int8) => Literal::Integer(Self::wrap_constant_int(v, IntType::I8), IntType::I8, span),
+            (Literal::Integer(v, _, span), AstType::Int16) => Literal::Integer(Self::wrap_constant_int(v, IntType::I16), IntType::I16, span),
+            (Literal::Integer(v, _, span), AstType::Int32) => Literal::Integer(Self::wrap_constant_int(v, IntType::I32), IntType::I32, span),
+            (Literal::Integer(v, _, span), AstType::Int64) => Literal::Integer(v, IntType::I64, span),
+            (Literal::Float(v, _, span), AstType::Float32) => Literal::Float(v, FloatType::F32, span),
+            (Literal::Float(v, _, span), AstType::Float64) => Literal::Float(v, FloatType::F64, span),
+            (literal, _) => literal,
+        }
+    }
+
+    /// Replace references to constants with their folded literal values
+    fn inline_constants_in_program(program: &mut Program, constants: &HashMap<String, Literal>) {
+        for global in &mut program.globals {
+            Self::inline_constants_in_expression(&mut global.value, constants);
+        }
+
+        fn inline_function(function: &mut ast::Function, constants: &HashMap<String, Literal>) {
+            for param in &mut function.params {
+                if let Some(default_value) = &mut param.default_value {
+                    CodeGenerator::inline_constants_in_expression(default_value, constants);
+                }
+            }
+            CodeGenerator::inline_constants_in_block(&mut function.body, constants);
+        }
+
+        let functions = program.functions.iter_mut()
+            .chain(program.enums.iter_mut().flat_map(|e| e.methods.iter_mut()))
+            .chain(program.test_blocks.iter_mut().flat_map(|t| t.functions.iter_mut()))
+            .chain(program.bench_blocks.iter_mut().flat_map(|b| b.functions.iter_mut()));
+        for function in functions {
+            inline_function(function, constants);
+        }
+
+        // Methods also see their class's associated constants unqualified
+        for class in &mut program.classes {
+            let prefix = format!("{}::", class.name);
+            let mut class_constants = constants.clone();
+            class_constants.extend(constants.iter().filter_map(|(name, literal)| {
+                name.strip_prefix(&prefix).map(|short| (short.to_string(), literal.clone()))
+            }));
+            for method in &mut class.methods {
+                inline_function(method, &class_constants);
+            }
+        }
+    }
+
+    /// Attach hidden `__line`/`__column` arguments to every `assert` call so test mode can
+    /// report where a failing assertion is
+    fn annotate_assert_locations(program: &mut Program, line_starts: &[usize]) {
+        let functions = program.functions.iter_mut()
+            .chain(program.classes.iter_mut().flat_map(|c| c.methods.iter_mut()))
+            .chain(program.test_blocks.iter_mut().flat_map(|t| t.functions.iter_mut()));
+        for function in functions {
+            Self::annotate_assert_locations_in_block(&mut function.body, line_starts);
+        }
+    }
+
+    fn annotate_assert_locations_in_block(block: &mut Block, line_starts: &[usize]) {
+        for stmt in &mut block.statements {
+            Self::annotate_assert_locations_in_statement(stmt, line_starts);
+        }
+    }
+
+    fn annotate_assert_locations_in_statement(stmt: &mut Statement, line_starts: &[usize]) {
+        match stmt {
+            Statement::Let { value, .. } | Statement::Var { value, .. } | Statement::LetPattern { value, .. } => {
+                Self::annotate_assert_locations_in_expression(value, line_starts);
+            }
+            Statement::Expression(expression) | Statement::Print { value: expression, .. } => {
+                Self::annotate_assert_locations_in_expression(expression, line_starts);
+            }
+            Statement::Return { value, .. } => {
+                if let Some(expr) = value {
+                    Self::annotate_assert_locations_in_expression(expr, line_starts);
+                }
+            }
+            Statement::If { condition, then_branch, else_branch, .. } => {
+                Self::annotate_assert_locations_in_expression(condition, line_starts);
+                Self::annotate_assert_locations_in_block(then_branch, line_starts);
+                if let Some(else_block) = else_branch {
+                    Self::annotate_assert_locations_in_block(else_block, line_starts);
+                }
+            }
+            Statement::While { condition, body, .. } => {
+                Self::annotate_assert_locations_in_expression(condition, line_starts);
+                Self::annotate_assert_locations_in_block(body, line_starts);
+            }
+            Statement::For { iterable, body, .. } => {
+                Self::annotate_assert_locations_in_expression(iterable, line_starts);
+                Self::annotate_assert_locations_in_block(body, line_starts);
+            }
+            Statement::Concurrent { body, .. } => {
+                Self::annotate_assert_locations_in_block(body, line_starts);
+            }
+            Statement::Select { arms, default, .. } => {
+                for arm in arms {
+                    Self::annotate_assert_locations_in_expression(&mut arm.channel, line_starts);
+                    Self::annotate_assert_locations_in_block(&mut arm.body, line_starts);
+                }
+                if let Some(default_block) = default {
+                    Self::annotate_assert_locations_in_block(default_block, line_starts);
+                }
+            }
+        }
+    }
+
+    fn annotate_assert_locations_in_expression(expr: &mut Expression, line_starts: &[usize]) {
+        if let Expression::Call { function, args, span, .. } = expr {
+            if function == "assert" {
+                let (line, column) = debug_info::line_and_column(line_starts, span.start);
+                for (name, value) in [("__line", line), ("__column", column)] {
+                    args.push(ast::NamedArg {
+                        name: name.to_string(),
+                        value: Expression::Literal(Literal::Integer(value as i64, IntType::I32, *span)),
+                        span: *span,
+                    });
+                }
+            }
+        }
+        match expr {
+            Expression::Identifier { .. } | Expression::Self_ { .. } => {}
+            Expression::Literal(literal) => match literal {
+                Literal::InterpolatedString(parts, _) => {
+                    for part in parts {
+                        if let ast::InterpolationPart::Expression(expr) = part {
+                            Self::annotate_assert_locations_in_expression(expr, line_starts);
+                        }
+                    }
+                }
+                Literal::Array(elements, _) | Literal::Set(elements, _) => {
+                    for element in elements {
+                        Self::annotate_assert_locations_in_expression(element, line_starts);
+                    }
+                }
+                Literal::Dict(pairs, _) => {
+                    for (key, value) in pairs {
+                        Self::annotate_assert_locations_in_expression(key, line_starts);
+                        Self::annotate_assert_locations_in_expression(value, line_starts);
+                    }
+                }
+                _ => {}
+            },
+            Expression::Binary { left, right, .. } => {
+                Self::annotate_assert_locations_in_expression(left, line_starts);
+                Self::annotate_assert_locations_in_expression(right, line_starts);
+            }
+            Expression::Unary { operand, .. } => {
+                Self::annotate_assert_locations_in_expression(operand, line_starts);
+            }
+            Expression::Call { args, .. }
+            | Expression::EnumConstructor { args, .. }
+            | Expression::ConstructorCall { args, .. }
+            | Expression::SuperCall { args, .. } => {
+                for arg in args {
+                    Self::annotate_assert_locations_in_expression(&mut arg.value, line_starts);
+                }
+            }
+            Expression::MethodCall { object, args, .. } => {
+                Self::annotate_assert_locations_in_expression(object, line_starts);
+                for arg in args {
+                    Self::annotate_assert_locations_in_expression(&mut arg.value, line_starts);
+                }
+            }
+            Expression::Assignment { target, value, .. } | Expression::Index { object: target, index: value, .. } => {
+                Self::annotate_assert_locations_in_expression(target, line_starts);
+                Self::annotate_assert_locations_in_expression(value, line_starts);
+            }
+            Expression::MemberAccess { object, .. } | Expression::OptionalMemberAccess { object, .. } => {
+                Self::annotate_assert_locations_in_expression(object, line_starts);
+            }
+            Expression::Block(block) => {
+                Self::annotate_assert_locations_in_block(block, line_starts);
+            }
+            Expression::Match { value, arms, .. } => {
+                Self::annotate_assert_locations_in_expression(value, line_starts);
+                for arm in arms {
+                    Self::annotate_assert_locations_in_expression(&mut arm.body, line_starts);
+                }
+            }
+            Expression::Try { expression: inner, .. }
+            | Expression::Cast { value: inner, .. }
+            | Expression::Spawn { body: inner, .. }
+            | Expression::TupleAccess { tuple: inner, .. } => {
+                Self::annotate_assert_locations_in_expression(inner, line_starts);
+            }
+            Expression::Range { start, end, step, .. } => {
+                Self::annotate_assert_locations_in_expression(start, line_starts);
+                Self::annotate_assert_locations_in_expression(end, line_starts);
+                if let Some(step) = step {
+                    Self::annotate_assert_locations_in_expression(step, line_starts);
+                }
+            }
+            Expression::If { condition, then_branch, else_branch, .. } => {
+                Self::annotate_assert_locations_in_expression(condition, line_starts);
+                Self::annotate_assert_locations_in_expression(then_branch, line_starts);
+                if let Some(else_expr) = else_branch {
+                    Self::annotate_assert_locations_in_expression(else_expr, line_starts);
+                }
+            }
+            Expression::Tuple { elements, .. } => {
+                for element in elements {
+                    Self::annotate_assert_locations_in_expression(element, line_starts);
+                }
+            }
+        }
+    }
+
+    /// Remove statement-level `assert` calls, condition and message included
+    fn strip_assertions(program: &mut Program) {
+        fn strip_block(block: &mut Block) {
+            block.statements.retain(|stmt| {
+                !matches!(stmt, Statement::Expression(Expression::Call { function, .. }) if function == "assert")
+            });
+            for stmt in &mut block.statements {
+                match stmt {
+                    Statement::If { then_branch, else_branch, .. } => {
+                        strip_block(then_branch);
+                        if let Some(else_block) = else_branch {
+                            strip_block(else_block);
+                        }
+                    }
+                    Statement::While { body, .. } | Statement::For { body, .. } | Statement::Concurrent { body, .. } => {
+                        strip_block(body);
+                    }
+                    Statement::Select { arms, default, .. } => {
+                        for arm in arms {
+                            strip_block(&mut arm.body);
+                        }
+                        if let Some(default_block) = default {
+                            strip_block(default_block);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let functions = program.functions.iter_mut()
+            .chain(program.enums.iter_mut().flat_map(|e| e.methods.iter_mut()))
+            .chain(program.classes.iter_mut().flat_map(|c| c.methods.iter_mut()));
+        for function in functions {
+            strip_block(&mut function.body);
+        }
+    }
+
+    fn inline_constants_in_block(block: &mut Block, constants: &HashMap<String, Literal>) {
+        for stmt in &mut block.statements {
+            Self::inline_constants_in_statement(stmt, constants);
+        }
+    }
+
+    fn inline_constants_in_statement(stmt: &mut Statement, constants: &HashMap<String, Literal>) {
+        match stmt {
+            Statement::Let { value, .. } | Statement::Var { value, .. } | Statement::LetPattern { value, .. } => {
+                Self::inline_constants_in_expression(value, constants);
+            }
+            Statement::Expression(expression) | Statement::Print { value: expression, .. } => {
+                Self::inline_constants_in_expression(expression, constants);
+            }
+            Statement::Return { value, .. } => {
+                if let Some(expr) = value {
+                    Self::inline_constants_in_expression(expr, constants);
+                }
+            }
+            Statement::If { condition, then_branch, else_branch, .. } => {
+                Self::inline_constants_in_expression(condition, constants);
+                Self::inline_constants_in_block(then_branch, constants);
+                if let Some(else_block) = else_branch {
+                    Self::inline_constants_in_block(else_block, constants);
+                }
+            }
+            Statement::While { condition, body, .. } => {
+                Self::inline_constants_in_expression(condition, constants);
+                Self::inline_constants_in_block(body, constants);
+            }
+            Statement::For { iterable, body, .. } => {
+                Self::inline_constants_in_expression(iterable, constants);
+                Self::inline_constants_in_block(body, constants);
+            }
+            Statement::Concurrent { body, .. } => {
+                Self::inline_constants_in_block(body, constants);
+            }
+            Statement::Select { arms, default, .. } => {
+                for arm in arms {
+                    Self::inline_constants_in_expression(&mut arm.channel, constants);
+                    Self::inline_constants_in_block(&mut arm.body, constants);
+                }
+                if let Some(default_block) = default {
+                    Self::inline_constants_in_block(default_block, constants);
+                }
+            }
+        }
+    }
+
+    fn inline_constants_in_expression(expr: &mut Expression, constants: &HashMap<String, Literal>) {
+        // `ClassName::NAME` parses like a payload-less enum variant
+        if let Expression::EnumConstructor { enum_name, variant, args, span } = expr {
+            let name = format!("{}::{}", enum_name, variant);
+            if args.is_empty() && constants.contains_key(&name) {
+                *expr = Expression::Identifier { name, span: *span };
+            }
+        }
+        match expr {
+            Expression::Identifier { name, span } => {
+                if let Some(literal) = constants.get(name) {
+                    let span = *span;
+                    *expr = Expression::Literal(match literal.clone() {
+                        Literal::Bool(b, _) => Literal::Bool(b, span),
+                        Literal::Integer(v, int_type, _) => Literal::Integer(v, int_type, span),
+                        Literal::Float(v, float_type, _) => Literal::Float(v, float_type, span),
+                        Literal::String(s, _) => Literal::String(s, span),
+                        other => other,
+                    });
+                }
+            }
+            Expression::Literal(literal) => match literal {
+                Literal::InterpolatedString(parts, _) => {
+                    for part in parts {
+                        if let ast::InterpolationPart::Expression(expr) = part {
+                            Self::inline_constants_in_expression(expr, constants);
+                        }
+                    }
+                }
+                Literal::Array(elements, _) | Literal::Set(elements, _) => {
+                    for element in elements {
+                        Self::inline_constants_in_expression(element, constants);
+                    }
+                }
+                Literal::Dict(pairs, _) => {
+                    for (key, value) in pairs {
+                        Self::inline_constants_in_expression(key, constants);
+                        Self::inline_constants_in_expression(value, constants);
+                    }
+                }
+                _ => {}
+            },
+            Expression::Binary { left, right, .. } => {
+                Self::inline_constants_in_expression(left, constants);
+                Self::inline_constants_in_expression(right, constants);
+            }
+            Expression::Unary { operand, .. } => {
+                Self::inline_constants_in_expression(operand, constants);
+            }
+            Expression::Call { args, .. }
+            | Expression::EnumConstructor { args, .. }
+            | Expression::ConstructorCall { args, .. }
+            | Expression::SuperCall { args, .. } => {
+                for arg in args {
+                    Self::inline_constants_in_expression(&mut arg.value, constants);
+                }
+            }
+            Expression::MethodCall { object, args, .. } => {
+                Self::inline_constants_in_expression(object, constants);
+                for arg in args {
+                    Self::inline_constants_in_expression(&mut arg.value, constants);
+                }
+            }
+            Expression::Assignment { target, value, .. } => {
+                // Constants can't be assigned to, but index/member targets may reference them
+                if !matches!(target.as_ref(), Expression::Identifier { .. }) {
+                    Self::inline_constants_in_expression(target, constants);
+                }
+                Self::inline_constants_in_expression(value, constants);
+            }
+            Expression::Index { object, index, .. } => {
+                Self::inline_constants_in_expression(object, constants);
+                Self::inline_constants_in_expression(index, constants);
+            }
+            Expression::MemberAccess { object, .. } | Expression::OptionalMemberAccess { object, .. } => {
+                Self::inline_constants_in_expression(object, constants);
+            }
+            Expression::Block(block) => {
+                Self::inline_constants_in_block(block, constants);
+            }
+            Expression::Match { value, arms, .. } => {
+                Self::inline_constants_in_expression(value, constants);
+                for arm in arms {
+                    Self::inline_constants_in_expression(&mut arm.body, constants);
+                }
+            }
+            Expression::Try { expression, .. } => {
+                Self::inline_constants_in_expression(expression, constants);
+            }
+            Expression::Range { start, end, step, .. } => {
+                Self::inline_constants_in_expression(start, constants);
+                Self::inline_constants_in_expression(end, constants);
+                if let Some(step) = step {
+                    Self::inline_constants_in_expression(step, constants);
+                }
+            }
+            Expression::If { condition, then_branch, else_branch, .. } => {
+                Self::inline_constants_in_expression(condition, constants);
+                Self::inline_constants_in_expression(then_branch, constants);
+                if let Some(else_expr) = else_branch {
+                    Self::inline_constants_in_expression(else_expr, constants);
                 }
             }
-            Statement::Print { value, .. } => {
-                Self::find_captured_variables(value, local_vars, captured);
+            Expression::Cast { value, .. } => {
+                Self::inline_constants_in_expression(value, constants);
+            }
+            Expression::Spawn { body, .. } => {
+                Self::inline_constants_in_expression(body, constants);
+            }
+            Expression::Tuple { elements, .. } => {
+                for element in elements {
+                    Self::inline_constants_in_expression(element, constants);
+                }
+            }
+            Expression::TupleAccess { tuple, .. } => {
+                Self::inline_constants_in_expression(tuple, constants);
+            }
+            Expression::Self_ { .. } => {}
+        }
+    }
+
+    /// Symbol name of the data object backing a module-level `var`
+    fn global_symbol_name(name: &str) -> String {
+        format!("__plat_global_{}", name)
+    }
+
+    /// Address of the data object backing a module-level `var`, if `name` refers to one
+    fn global_variable_address(builder: &mut FunctionBuilder, module: &mut ObjectModule, name: &str) -> Option<Value> {
+        match module.get_name(&Self::global_symbol_name(name)) {
+            Some(FuncOrDataId::Data(data_id)) => {
+                let global_ref = module.declare_data_in_func(data_id, builder.func);
+                Some(builder.ins().global_value(I64, global_ref))
             }
+            _ => None,
         }
     }
 
@@ -508,17 +1566,18 @@ impl CodeGenerator {
             VariableType::Int32 => I32,
             VariableType::Int64 => I64,
             VariableType::Float8 => F32,    // Using F32 for 8-bit float
-            VariableType::Float16 => F32,   // Using F32 for 16-bit float
+            VariableType::Float16 => F32,   // f16 values are computed in F32; see `load_class_field`
             VariableType::Float32 => F32,
             VariableType::Float64 => F64,
             VariableType::String => I64,    // Strings are pointers
             VariableType::Array(_) => I64,  // Arrays are pointers
-            VariableType::Dict => I64,      // Dicts are pointers
+            VariableType::Dict(_, _) => I64, // Dicts are pointers
             VariableType::Set => I64,       // Sets are pointers
             VariableType::Class(_) => I64,  // Class instances are pointers
             VariableType::Enum(_) => I64,   // Enums are 64-bit values (discriminant + data)
             VariableType::Task(_) => I64,   // Task handles are 64-bit IDs
             VariableType::Channel(_) => I64, // Channel IDs are 64-bit
+            VariableType::Tuple(_) => I64,  // Tuples are pointers to GC-allocated structs
         }
     }
 
@@ -527,6 +1586,30 @@ impl CodeGenerator {
         Self::resolve_type_alias_or_newtype(&self.type_aliases, &self.newtypes, ty)
     }
 
+    /// Follow the alias/newtype chain starting at `name`, erroring if it leads back to a visited name
+    fn check_type_alias_cycle(
+        type_aliases: &HashMap<String, AstType>,
+        newtypes: &HashMap<String, AstType>,
+        name: &str
+    ) -> Result<(), CodegenError> {
+        let mut chain = vec![name.to_string()];
+        let mut visited = HashSet::new();
+        visited.insert(name.to_string());
+
+        let mut current = name.to_string();
+        while let Some(AstType::Named(next, type_params)) = newtypes.get(&current).or_else(|| type_aliases.get(&current)) {
+            if !type_params.is_empty() {
+                break;
+            }
+            chain.push(next.clone());
+            if !visited.insert(next.clone()) {
+                return Err(CodegenError::TypeAliasCycle(chain));
+            }
+            current = next.clone();
+        }
+        Ok(())
+    }
+
     /// Resolve both type aliases and newtypes to their underlying types
     fn resolve_type_alias_or_newtype(
         type_aliases: &HashMap<String, AstType>,
@@ -579,12 +1662,13 @@ impl CodeGenerator {
             AstType::Dict(_, _) => I64,
             AstType::Set(_) => I64,
             AstType::Named(_, _) => I64, // Custom types (classes, enums) are pointers
+            AstType::Tuple(_) => I64, // Tuples are pointers
             AstType::Bool => I32, // Booleans are I32
             AstType::Int8 => I8,
             AstType::Int16 => I16,
             AstType::Int32 => I32,
             AstType::Float8 => F32, // Cranelift doesn't support 8-bit floats, use F32
-            AstType::Float16 => F32, // Cranelift doesn't support 16-bit floats, use F32
+            AstType::Float16 => F32, // Cranelift's x64 backend can't do F16 arithmetic, compute in F32
             AstType::Float32 => F32,
         }
     }
@@ -598,7 +1682,7 @@ impl CodeGenerator {
             HirType::Int32 => I32,
             HirType::Int64 => I64,
             HirType::Float8 => F32, // Cranelift doesn't support 8-bit floats, use F32
-            HirType::Float16 => F32, // Cranelift doesn't support 16-bit floats, use F32
+            HirType::Float16 => F32, // Cranelift's x64 backend can't do F16 arithmetic, compute in F32
             HirType::Float32 => F32,
             HirType::Float64 => F64,
             HirType::String => I64,
@@ -611,13 +1695,16 @@ impl CodeGenerator {
             HirType::Newtype(_) => I64, // Newtypes are represented the same as their underlying type (usually pointer)
             HirType::Task(_) => I64, // Task handles are pointers
             HirType::Channel(_) => I64, // Channels are pointers
+            HirType::AtomicInt => I64, // Atomics are pointers to an 8-byte cell
+            HirType::Mutex => I64, // Opaque runtime mutex handle
+            HirType::Tuple(_) => I64, // Tuples are pointers
             HirType::Unit => I64, // Unit type is represented as i64 0
         }
     }
 
     /// Convert an AST type to a VariableType
     fn ast_type_to_variable_type(&self, ast_type: &AstType) -> VariableType {
-        Self::ast_type_to_variable_type_static(&self.type_aliases, ast_type)
+        Self::ast_type_to_variable_type_static(&self.type_aliases, &self.resolve_type_alias(ast_type))
     }
 
     /// Static version of ast_type_to_variable_type for use in helper methods
@@ -638,8 +1725,17 @@ impl CodeGenerator {
                 let element_var_type = Self::ast_type_to_variable_type_static(type_aliases, &element_type);
                 VariableType::Array(Box::new(element_var_type))
             }
-            AstType::Dict(_, _) => VariableType::Dict,
+            AstType::Dict(key_type, value_type) => {
+                let key_var_type = Self::ast_type_to_variable_type_static(type_aliases, &key_type);
+                let value_var_type = Self::ast_type_to_variable_type_static(type_aliases, &value_type);
+                VariableType::Dict(Box::new(key_var_type), Box::new(value_var_type))
+            }
             AstType::Set(_) => VariableType::Set,
+            AstType::Tuple(element_types) => {
+                VariableType::Tuple(element_types.iter()
+                    .map(|ty| Self::ast_type_to_variable_type_static(type_aliases, ty))
+                    .collect())
+            }
             AstType::Named(type_name, type_params) => {
                 // Check if this is a Task<T> type
                 if type_name == "Task" && type_params.len() == 1 {
@@ -652,29 +1748,25 @@ impl CodeGenerator {
         }
     }
     pub fn new() -> Result<Self, CodegenError> {
-        // Create ISA for the target platform
-        let mut flag_builder = settings::builder();
-        flag_builder.set("use_colocated_libcalls", "false")?;
-        flag_builder.set("is_pic", "true")?;  // Enable position-independent code for macOS
-        let isa_builder = cranelift_codegen::isa::lookup(target_lexicon::HOST)
-            .map_err(|_| CodegenError::UnsupportedTarget)?;
-        let isa = isa_builder
-            .finish(settings::Flags::new(flag_builder))
-            .map_err(|_| CodegenError::IsaCreationFailed)?;
+        Self::new_for_target(target_lexicon::HOST)
+    }
 
-        // Create object module
-        let object_builder = ObjectBuilder::new(
-            isa,
-            "plat_program",
-            cranelift_module::default_libcall_names(),
-        ).map_err(CodegenError::ModuleError)?;
-        let module = ObjectModule::new(object_builder);
+    /// Create a code generator emitting objects for `triple` (cross-compilation)
+    pub fn new_for_target(triple: target_lexicon::Triple) -> Result<Self, CodegenError> {
+        // macOS only loads position-independent code and Linux toolchains link PIE by
+        // default; COFF objects use plain relocations instead
+        let is_pic = !matches!(triple.binary_format, target_lexicon::BinaryFormat::Coff);
+        let opt_level = "none".to_string();
+        let module = Self::create_object_module(&triple, is_pic, &opt_level)?;
 
         Ok(Self {
             module,
+            triple,
+            is_pic,
+            opt_level,
             context: Context::new(),
             functions: HashMap::new(),
-            string_counter: 0,
+            string_pool: StringPool::default(),
             class_metadata: HashMap::new(),
             module_name: None,
             type_aliases: HashMap::new(),
@@ -683,9 +1775,59 @@ impl CodeGenerator {
             bench_mode: false,
             method_names: HashSet::new(),
             symbol_table: None,
+            imported_classes: Vec::new(),
+            constants: HashMap::new(),
+            global_types: HashMap::new(),
+            debug_info: None,
+            inlining: false,
+            assertions: true,
+            source_line_starts: None,
+            source_path: None,
         })
     }
 
+    /// Emit position-independent (`true`) or position-dependent code. Position-dependent
+    /// objects skip GOT indirection but must be linked with `-no-pie` or statically
+    pub fn with_pic(mut self, is_pic: bool) -> Result<Self, CodegenError> {
+        if !is_pic && self.triple.binary_format == target_lexicon::BinaryFormat::Macho {
+            return Err(CodegenError::UnsupportedFeature(
+                "Apple targets require position-independent code".to_string()
+            ));
+        }
+        self.module = Self::create_object_module(&self.triple, is_pic, &self.opt_level)?;
+        self.is_pic = is_pic;
+        Ok(self)
+    }
+
+    /// Set Cranelift's optimization level: "none" (the default, fastest to compile),
+    /// "speed", or "speed_and_size"
+    pub fn with_opt_level(mut self, level: &str) -> Result<Self, CodegenError> {
+        self.module = Self::create_object_module(&self.triple, self.is_pic, level)?;
+        self.opt_level = level.to_string();
+        Ok(self)
+    }
+
+    fn create_object_module(triple: &target_lexicon::Triple, is_pic: bool, opt_level: &str) -> Result<ObjectModule, CodegenError> {
+        // Create ISA for the target platform
+        let mut flag_builder = settings::builder();
+        flag_builder.set("use_colocated_libcalls", "false")?;
+        flag_builder.set("is_pic", if is_pic { "true" } else { "false" })?;
+        flag_builder.set("opt_level", opt_level)?;
+        let isa_builder = cranelift_codegen::isa::lookup(triple.clone())
+            .map_err(|_| CodegenError::UnsupportedTarget)?;
+        let isa = isa_builder
+            .finish(settings::Flags::new(flag_builder))
+            .map_err(|_| CodegenError::IsaCreationFailed)?;
+
+        // Create object module
+        let object_builder = ObjectBuilder::new(
+            isa,
+            "plat_program",
+            cranelift_module::default_libcall_names(),
+        ).map_err(CodegenError::ModuleError)?;
+        Ok(ObjectModule::new(object_builder))
+    }
+
     /// Enable test mode for this code generator
     pub fn with_test_mode(mut self) -> Self {
         self.test_mode = true;
@@ -698,6 +1840,38 @@ impl CodeGenerator {
         self
     }
 
+    /// Emit DWARF line information mapping generated code back to `path`
+    pub fn with_debug_info(mut self, path: &str, source: &str) -> Self {
+        self.debug_info = Some(DebugInfo::new(path, source));
+        self.source_path = Some(path.to_string());
+        self.with_source(source)
+    }
+
+    /// Attach the program's source text so test-mode asserts can report their line and column
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.source_line_starts = Some(debug_info::line_starts(source));
+        self
+    }
+
+    /// Inline calls to small leaf functions at their call sites
+    pub fn with_inlining(mut self) -> Self {
+        self.inlining = true;
+        self
+    }
+
+    /// Compile `assert` calls (the default), or drop them entirely like `-DNDEBUG` in C.
+    /// Test mode keeps assertions regardless.
+    pub fn with_assertions(mut self, enabled: bool) -> Self {
+        self.assertions = enabled;
+        self
+    }
+
+    /// Make the field layouts of other modules' classes available under their qualified names
+    pub fn with_imported_classes(mut self, imported_classes: Vec<(String, ast::ClassDecl)>) -> Self {
+        self.imported_classes = imported_classes;
+        self
+    }
+
     /// Set the global symbol table for cross-module function lookups
     pub fn with_symbol_table(mut self, symbol_table: plat_hir::ModuleSymbolTable) -> Self {
         self.symbol_table = Some(symbol_table);
@@ -739,11 +1913,12 @@ impl CodeGenerator {
                 AstType::Dict(_, _) => (I64, 8, 8),
                 AstType::Set(_) => (I64, 8, 8),
                 AstType::Named(_, _) => (I64, 8, 8), // Custom types are pointers
+                AstType::Tuple(_) => (I64, 8, 8),
                 AstType::Int8 => (I8, 1, 1),
                 AstType::Int16 => (I16, 2, 2),
                 AstType::Int32 => (I32, 4, 4),
-                AstType::Float8 => (F32, 4, 4), // Using F32 for 8-bit float
-                AstType::Float16 => (F32, 4, 4), // Using F32 for 16-bit float
+                AstType::Float8 => (F32, 4, 4), // f8 is emulated with F32 storage
+                AstType::Float16 => (F16, 2, 2), // Stored as real half precision
                 AstType::Float32 => (F32, 4, 4),
                 AstType::Bool => (I32, 4, 4),
             };
@@ -782,18 +1957,41 @@ impl CodeGenerator {
 
         // Process this class's methods
         for method in &class_decl.methods {
+            let param_types: Vec<AstType> = method.params.iter()
+                .map(|param| self.resolve_type_alias(&param.ty))
+                .collect();
+            let return_type = method.return_type.as_ref().map(|ty| self.resolve_type_alias(ty));
+
             if method.is_virtual {
                 // New virtual method - add to vtable
                 virtual_methods.push(VirtualMethod {
                     name: method.name.clone(),
                     vtable_index: virtual_methods.len(),
                     func_id: None, // Will be filled in later
+                    implementor: class_decl.name.clone(),
+                    param_types,
+                    return_type,
                 });
             } else if method.is_override {
                 // Override existing virtual method
                 if let Some(vm) = virtual_methods.iter_mut().find(|vm| vm.name == method.name) {
+                    // The slot is called through the parent's signature, so the override must share it
+                    if vm.param_types != param_types || vm.return_type != return_type {
+                        let describe = |params: &[AstType], ret: &Option<AstType>| format!(
+                            "({}) -> {}",
+                            params.iter().map(|ty| format!("{:?}", ty)).collect::<Vec<_>>().join(", "),
+                            ret.as_ref().map_or("Unit".to_string(), |ty| format!("{:?}", ty))
+                        );
+                        return Err(CodegenError::UnsupportedFeature(format!(
+                            "Override '{}::{}' has signature {} but the overridden virtual method has {}",
+                            class_decl.name, method.name,
+                            describe(&param_types, &return_type),
+                            describe(&vm.param_types, &vm.return_type)
+                        )));
+                    }
                     // Keep the same vtable_index, update func_id later
                     vm.func_id = None;
+                    vm.implementor = class_decl.name.clone();
                 } else {
                     return Err(CodegenError::UnsupportedFeature(
                         format!("Method '{}' marked as override but no virtual method found in parent", method.name)
@@ -809,6 +2007,9 @@ impl CodeGenerator {
             parent_class: class_decl.parent_class.clone(),
             virtual_methods,
             has_vtable,
+            init_params: class_decl.methods.iter()
+                .find(|method| method.name == "init")
+                .map(|init| init.params.iter().map(|param| param.name.clone()).collect()),
         };
 
         self.class_metadata.insert(class_decl.name.clone(), metadata);
@@ -818,6 +2019,7 @@ impl CodeGenerator {
     fn generate_vtables(&mut self, program: &Program) -> Result<(), CodegenError> {
         // Generate vtable global variables for each class with virtual methods
         // Each vtable is an array of function pointers stored as a global data object
+        let mut init_func_ids = Vec::new();
 
         for class_decl in &program.classes {
             let metadata = self.class_metadata.get(&class_decl.name)
@@ -856,15 +2058,114 @@ impl CodeGenerator {
             eprintln!("DEBUG: Created vtable '{}' with {} entries", vtable_name, metadata.virtual_methods.len());
 
             // Now generate an initialization function for this vtable
-            self.generate_vtable_init_function(&class_decl.name, &metadata)?;
+            let init_func_id = self.generate_vtable_init_function(&class_decl.name, &metadata)?;
+            init_func_ids.push(init_func_id);
+        }
+
+        if !init_func_ids.is_empty() {
+            self.generate_vtables_init_function(&init_func_ids)?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate the function that runs every `ClassName_vtable_init`, so vtables are
+    /// populated before any code (including module-level initializers) dispatches through them
+    fn generate_vtables_init_function(&mut self, init_func_ids: &[FuncId]) -> Result<(), CodegenError> {
+        let mut sig = self.module.make_signature();
+        sig.call_conv = CallConv::SystemV;
+
+        let func_id = self.module.declare_function(VTABLES_INIT_FUNCTION, Linkage::Local, &sig)
+            .map_err(CodegenError::ModuleError)?;
+        self.functions.insert(VTABLES_INIT_FUNCTION.to_string(), func_id);
+
+        self.context.func.signature = sig;
+        let mut func_ctx = FunctionBuilderContext::new();
+        let mut builder = FunctionBuilder::new(&mut self.context.func, &mut func_ctx);
+
+        let entry_block = builder.create_block();
+        builder.switch_to_block(entry_block);
+        builder.seal_block(entry_block);
+
+        for &init_func_id in init_func_ids {
+            let init_ref = self.module.declare_func_in_func(init_func_id, builder.func);
+            builder.ins().call(init_ref, &[]);
         }
 
+        builder.ins().return_(&[]);
+        builder.finalize();
+
+        self.module.define_function(func_id, &mut self.context)
+            .map_err(CodegenError::ModuleError)?;
+        self.module.clear_context(&mut self.context);
+        Ok(())
+    }
+
+    /// Internal symbol for a bench function's body; its public name belongs to the timing wrapper
+    fn bench_body_name(mangled_name: &str) -> String {
+        format!("{}__bench_body", mangled_name)
+    }
+
+    /// Generate a bench function's public entry point: it forwards to the body between
+    /// `plat_bench_start()` and `plat_bench_end(name, start)` so the runtime can time every call
+    fn generate_bench_wrapper(&mut self, wrapper_name: &str, body_name: &str, bench_name: &str) -> Result<(), CodegenError> {
+        let wrapper_id = self.functions[wrapper_name];
+        let body_id = self.functions[body_name];
+        let name_id = self.string_pool.intern(&mut self.module, bench_name)?;
+
+        let start_sig = {
+            let mut sig = self.module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.returns.push(AbiParam::new(I64)); // start timestamp
+            sig
+        };
+        let start_id = self.module.declare_function("plat_bench_start", Linkage::Import, &start_sig)
+            .map_err(CodegenError::ModuleError)?;
+        let end_sig = {
+            let mut sig = self.module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.params.push(AbiParam::new(I64)); // bench name
+            sig.params.push(AbiParam::new(I64)); // start timestamp
+            sig
+        };
+        let end_id = self.module.declare_function("plat_bench_end", Linkage::Import, &end_sig)
+            .map_err(CodegenError::ModuleError)?;
+
+        self.context.func.signature = self.module.declarations().get_function_decl(wrapper_id).signature.clone();
+        let mut func_ctx = FunctionBuilderContext::new();
+        let mut builder = FunctionBuilder::new(&mut self.context.func, &mut func_ctx);
+
+        let entry_block = builder.create_block();
+        builder.append_block_params_for_function_params(entry_block);
+        builder.switch_to_block(entry_block);
+        builder.seal_block(entry_block);
+        let args = builder.block_params(entry_block).to_vec();
+
+        let start_ref = self.module.declare_func_in_func(start_id, builder.func);
+        let start_call = builder.ins().call(start_ref, &[]);
+        let start = builder.inst_results(start_call)[0];
+
+        let body_ref = self.module.declare_func_in_func(body_id, builder.func);
+        let call = builder.ins().call(body_ref, &args);
+        let results = builder.inst_results(call).to_vec();
+
+        let name_ref = self.module.declare_data_in_func(name_id, builder.func);
+        let name_ptr = builder.ins().symbol_value(I64, name_ref);
+        let end_ref = self.module.declare_func_in_func(end_id, builder.func);
+        builder.ins().call(end_ref, &[name_ptr, start]);
+
+        builder.ins().return_(&results);
+        builder.finalize();
+
+        self.module.define_function(wrapper_id, &mut self.context)
+            .map_err(CodegenError::ModuleError)?;
+        self.module.clear_context(&mut self.context);
         Ok(())
     }
 
-    fn generate_vtable_init_function(&mut self, class_name: &str, metadata: &ClassMetadata) -> Result<(), CodegenError> {
+    fn generate_vtable_init_function(&mut self, class_name: &str, metadata: &ClassMetadata) -> Result<FuncId, CodegenError> {
         // Generate a function like: void ClassName_vtable_init()
-        // This function will be called at program startup to initialize the vtable
+        // This function is called at program startup (via VTABLES_INIT_FUNCTION) to initialize the vtable
 
         let init_func_name = format!("{}_vtable_init", class_name);
         let vtable_name = format!("{}_vtable", class_name);
@@ -903,22 +2204,28 @@ impl CodeGenerator {
 
         // For each virtual method, store its function pointer in the vtable
         for (i, vmethod) in metadata.virtual_methods.iter().enumerate() {
-            let method_name = format!("{}__{}", class_name, vmethod.name);
+            // Inherited slots point at the nearest ancestor that implements the method
+            let method_name = format!("{}__{}", vmethod.implementor, vmethod.name);
+
+            // A slot left zero would crash on dispatch, so a missing implementation is an error
+            let Some(&func_id) = self.functions.get(&method_name) else {
+                return Err(CodegenError::UnsupportedFeature(format!(
+                    "Class '{}' has no implementation of virtual method '{}' for its vtable (expected '{}' in this module)",
+                    class_name, vmethod.name, method_name
+                )));
+            };
 
-            // Get the function ID for this method
-            if let Some(&func_id) = self.functions.get(&method_name) {
-                // Get function reference
-                let func_ref = self.module.declare_func_in_func(func_id, &mut builder.func);
+            // Get function reference
+            let func_ref = self.module.declare_func_in_func(func_id, &mut builder.func);
 
-                // Get function address as a pointer
-                let func_addr = builder.ins().func_addr(I64, func_ref);
+            // Get function address as a pointer
+            let func_addr = builder.ins().func_addr(I64, func_ref);
 
-                // Calculate offset in vtable (i * 8 bytes)
-                let offset = (i * 8) as i32;
+            // Calculate offset in vtable (i * 8 bytes)
+            let offset = (i * 8) as i32;
 
-                // Store function pointer at vtable[i]
-                builder.ins().store(MemFlags::new(), func_addr, vtable_addr, offset);
-            }
+            // Store function pointer at vtable[i]
+            builder.ins().store(MemFlags::new(), func_addr, vtable_addr, offset);
         }
 
         // Return from init function
@@ -932,7 +2239,7 @@ impl CodeGenerator {
         self.module.clear_context(&mut self.context);
 
         eprintln!("DEBUG: Generated vtable init function '{}'", init_func_name);
-        Ok(())
+        Ok(init_func_id)
     }
 
     #[allow(dead_code)]
@@ -956,6 +2263,233 @@ impl CodeGenerator {
         Ok((field.offset, field.cranelift_type))
     }
 
+    /// Load a class field, widening `f16` storage to the f32 value representation
+    fn load_class_field(builder: &mut FunctionBuilder, module: &mut ObjectModule, field_type: Type, object_ptr: Value, offset: i32) -> Result<Value, CodegenError> {
+        if field_type != F16 {
+            return Ok(builder.ins().load(field_type, MemFlags::new(), object_ptr, offset));
+        }
+
+        let bits = builder.ins().load(I16, MemFlags::new(), object_ptr, offset);
+        let bits = builder.ins().uextend(I32, bits);
+
+        let mut sig = module.make_signature();
+        sig.call_conv = CallConv::SystemV;
+        sig.params.push(AbiParam::new(I32));
+        sig.returns.push(AbiParam::new(F32));
+        let func_id = module.declare_function("plat_f16_to_f32", Linkage::Import, &sig)
+            .map_err(CodegenError::ModuleError)?;
+        let func_ref = module.declare_func_in_func(func_id, builder.func);
+        let call = builder.ins().call(func_ref, &[bits]);
+        Ok(builder.inst_results(call)[0])
+    }
+
+    /// Name of the generated function comparing two instances of `class_name` field by field
+    fn class_equality_function_name(class_name: &str) -> String {
+        format!("__plat_eq_{}", class_name)
+    }
+
+    /// Whether `==` on values of this type compares contents rather than values
+    fn compares_structurally(var_type: &VariableType, functions: &HashMap<String, FuncId>) -> bool {
+        match var_type {
+            VariableType::Array(_) | VariableType::Dict(_, _) | VariableType::Set => true,
+            VariableType::Class(class_name) => functions.contains_key(&Self::class_equality_function_name(class_name)),
+            _ => false,
+        }
+    }
+
+    /// Compare two values of a heap type by contents, producing an i32 boolean.
+    /// Returns None for types that compare by value (numbers, bools, enums, handles).
+    /// Class instances, including list elements, go through their class's generated equality function.
+    fn generate_structural_equality(
+        builder: &mut FunctionBuilder,
+        module: &mut ObjectModule,
+        functions: &HashMap<String, FuncId>,
+        left: Value,
+        right: Value,
+        var_type: &VariableType,
+    ) -> Result<Option<Value>, CodegenError> {
+        let class_equality = |class_name: &str| functions.get(&Self::class_equality_function_name(class_name)).copied();
+
+        // Type tags understood by plat_deep_eq
+        let type_tag = match var_type {
+            VariableType::String => 0,
+            VariableType::Array(element_type) => {
+                if let Some(eq_id) = match element_type.as_ref() {
+                    VariableType::Class(class_name) => class_equality(class_name),
+                    _ => None,
+                } {
+                    // Lists of class instances compare element-wise through the class's equality function
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // left array
+                        sig.params.push(AbiParam::new(I64)); // right array
+                        sig.params.push(AbiParam::new(I64)); // element equality function
+                        sig.returns.push(AbiParam::new(I32)); // 1 if equal
+                        sig
+                    };
+                    let func_id = module.declare_function("plat_array_eq_with", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+                    let eq_ref = module.declare_func_in_func(eq_id, builder.func);
+                    let eq_addr = builder.ins().func_addr(I64, eq_ref);
+                    let call = builder.ins().call(func_ref, &[left, right, eq_addr]);
+                    return Ok(Some(builder.inst_results(call)[0]));
+                }
+                1
+            }
+            VariableType::Dict(_, _) => 2,
+            VariableType::Set => 3,
+            VariableType::Class(class_name) => {
+                // Named types without an equality function (enums) compare by value
+                let Some(eq_id) = class_equality(class_name) else {
+                    return Ok(None);
+                };
+                let eq_ref = module.declare_func_in_func(eq_id, builder.func);
+                let call = builder.ins().call(eq_ref, &[left, right]);
+                return Ok(Some(builder.inst_results(call)[0]));
+            }
+            _ => return Ok(None),
+        };
+
+        let func_sig = {
+            let mut sig = module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.params.push(AbiParam::new(I64)); // left pointer
+            sig.params.push(AbiParam::new(I64)); // right pointer
+            sig.params.push(AbiParam::new(I32)); // type tag
+            sig.returns.push(AbiParam::new(I32)); // 1 if equal
+            sig
+        };
+
+        let func_id = module.declare_function("plat_deep_eq", Linkage::Import, &func_sig)
+            .map_err(CodegenError::ModuleError)?;
+        let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+        let tag_val = builder.ins().iconst(I32, type_tag);
+        let call = builder.ins().call(func_ref, &[left, right, tag_val]);
+        Ok(Some(builder.inst_results(call)[0]))
+    }
+
+    /// Generate `__plat_eq_<Class>` for every class: equal when both instances have the same
+    /// dynamic class and every field is equal. All are declared before any is defined, so
+    /// classes nested at any depth (or recursively) call each other.
+    fn generate_class_equality_functions(&mut self) -> Result<(), CodegenError> {
+        let mut sig = self.module.make_signature();
+        sig.call_conv = CallConv::SystemV;
+        sig.params.push(AbiParam::new(I64)); // left instance
+        sig.params.push(AbiParam::new(I64)); // right instance
+        sig.returns.push(AbiParam::new(I32)); // 1 if equal
+
+        let mut class_names: Vec<String> = self.class_metadata.keys().cloned().collect();
+        class_names.sort();
+
+        let mut func_ids = Vec::new();
+        for class_name in &class_names {
+            let func_name = Self::class_equality_function_name(class_name);
+            let func_id = self.module.declare_function(&func_name, Linkage::Local, &sig)
+                .map_err(CodegenError::ModuleError)?;
+            self.functions.insert(func_name, func_id);
+            func_ids.push(func_id);
+        }
+
+        for (class_name, func_id) in class_names.iter().zip(func_ids) {
+            let metadata = self.class_metadata[class_name].clone();
+
+            self.context.func.signature = sig.clone();
+            let mut func_ctx = FunctionBuilderContext::new();
+            let mut builder = FunctionBuilder::new(&mut self.context.func, &mut func_ctx);
+
+            let entry_block = builder.create_block();
+            let fields_block = builder.create_block();
+            let equal_block = builder.create_block();
+            let differ_block = builder.create_block();
+
+            builder.append_block_params_for_function_params(entry_block);
+            builder.switch_to_block(entry_block);
+            let left = builder.block_params(entry_block)[0];
+            let right = builder.block_params(entry_block)[1];
+
+            // The same instance is equal to itself; otherwise neither may be null
+            let same = builder.ins().icmp(IntCC::Equal, left, right);
+            let check_null_block = builder.create_block();
+            builder.ins().brif(same, equal_block, &[], check_null_block, &[]);
+            builder.switch_to_block(check_null_block);
+            let left_null = builder.ins().icmp_imm(IntCC::Equal, left, 0);
+            let right_null = builder.ins().icmp_imm(IntCC::Equal, right, 0);
+            let any_null = builder.ins().bor(left_null, right_null);
+            builder.ins().brif(any_null, differ_block, &[], fields_block, &[]);
+            builder.switch_to_block(fields_block);
+
+            // Same vtable means same dynamic class
+            if metadata.has_vtable {
+                let left_vtable = builder.ins().load(I64, MemFlags::new(), left, 0);
+                let right_vtable = builder.ins().load(I64, MemFlags::new(), right, 0);
+                let same_class = builder.ins().icmp(IntCC::Equal, left_vtable, right_vtable);
+                let next_block = builder.create_block();
+                builder.ins().brif(same_class, next_block, &[], differ_block, &[]);
+                builder.switch_to_block(next_block);
+            }
+
+            // Stop at the first field that differs
+            for field in &metadata.fields {
+                let left_field = Self::load_class_field(&mut builder, &mut self.module, field.cranelift_type, left, field.offset)?;
+                let right_field = Self::load_class_field(&mut builder, &mut self.module, field.cranelift_type, right, field.offset)?;
+                let field_type = Self::ast_type_to_variable_type_static(&self.type_aliases, &field.ty);
+
+                let field_equal = match Self::generate_structural_equality(&mut builder, &mut self.module, &self.functions, left_field, right_field, &field_type)? {
+                    Some(equal) => builder.ins().icmp_imm(IntCC::NotEqual, equal, 0),
+                    None if builder.func.dfg.value_type(left_field).is_float() => {
+                        builder.ins().fcmp(FloatCC::Equal, left_field, right_field)
+                    }
+                    None => builder.ins().icmp(IntCC::Equal, left_field, right_field),
+                };
+                let next_block = builder.create_block();
+                builder.ins().brif(field_equal, next_block, &[], differ_block, &[]);
+                builder.switch_to_block(next_block);
+            }
+            builder.ins().jump(equal_block, &[]);
+
+            builder.switch_to_block(equal_block);
+            let one = builder.ins().iconst(I32, 1);
+            builder.ins().return_(&[one]);
+
+            builder.switch_to_block(differ_block);
+            let zero = builder.ins().iconst(I32, 0);
+            builder.ins().return_(&[zero]);
+
+            builder.seal_all_blocks();
+            builder.finalize();
+
+            self.module.define_function(func_id, &mut self.context)
+                .map_err(CodegenError::ModuleError)?;
+            self.module.clear_context(&mut self.context);
+        }
+
+        Ok(())
+    }
+
+    /// Store a class field, narrowing f32 values into `f16` storage
+    fn store_class_field(builder: &mut FunctionBuilder, module: &mut ObjectModule, field_type: Type, value: Value, object_ptr: Value, offset: i32) -> Result<(), CodegenError> {
+        if field_type != F16 {
+            builder.ins().store(MemFlags::new(), value, object_ptr, offset);
+            return Ok(());
+        }
+
+        let mut sig = module.make_signature();
+        sig.call_conv = CallConv::SystemV;
+        sig.params.push(AbiParam::new(F32));
+        sig.returns.push(AbiParam::new(I32));
+        let func_id = module.declare_function("plat_f32_to_f16", Linkage::Import, &sig)
+            .map_err(CodegenError::ModuleError)?;
+        let func_ref = module.declare_func_in_func(func_id, builder.func);
+        let call = builder.ins().call(func_ref, &[value]);
+        let bits = builder.inst_results(call)[0];
+        let bits = builder.ins().ireduce(I16, bits);
+        builder.ins().store(MemFlags::new(), bits, object_ptr, offset);
+        Ok(())
+    }
+
     #[allow(dead_code)]
     fn get_class_size(&self, class_name: &str) -> Result<i32, CodegenError> {
         let metadata = self.class_metadata.get(class_name)
@@ -966,12 +2500,41 @@ impl CodeGenerator {
         Ok(metadata.size)
     }
 
-    pub fn generate_code(mut self, program: &Program) -> Result<Vec<u8>, CodegenError> {
+    pub fn generate_code(self, program: &Program) -> Result<Vec<u8>, CodegenError> {
+        // Errors carry the span of the failing expression; report it as file:line:col when
+        // the source is attached
+        let source = self.source_line_starts.clone().map(|line_starts| {
+            (self.source_path.clone().unwrap_or_else(|| "<source>".to_string()), line_starts)
+        });
+        self.generate_program(program).map_err(|error| match &source {
+            Some((file, line_starts)) => error.locate(file, line_starts),
+            None => error,
+        })
+    }
+
+    fn generate_program(mut self, program: &Program) -> Result<Vec<u8>, CodegenError> {
         // Extract module name for function name mangling
         if let Some(mod_decl) = &program.module_decl {
             self.module_name = Some(mod_decl.path.join("::"));
         }
 
+        // Calls bind named arguments by the callee's parameter names, so single-file builds
+        // need a symbol table too (multi-module builds pass one covering every module)
+        if self.symbol_table.is_none() {
+            let module_path = self.module_name.clone().unwrap_or_default();
+            let mut symbol_table = plat_hir::ModuleSymbolTable::new(module_path.clone());
+            if plat_hir::TypeChecker::new().collect_symbols_from_program(program, &module_path, &mut symbol_table).is_ok() {
+                self.symbol_table = Some(symbol_table);
+            }
+        }
+
+        // Calls to items imported by name resolve through the symbol table
+        if let Some(symbol_table) = &mut self.symbol_table {
+            for use_decl in program.use_decls.iter().filter(|use_decl| !use_decl.items.is_empty()) {
+                symbol_table.add_use(use_decl);
+            }
+        }
+
         // Process type aliases
         for type_alias in &program.type_aliases {
             self.type_aliases.insert(type_alias.name.clone(), type_alias.ty.clone());
@@ -981,6 +2544,81 @@ impl CodeGenerator {
         for newtype in &program.newtypes {
             self.newtypes.insert(newtype.name.clone(), newtype.underlying_type.clone());
         }
+        // Resolution recurses through the chain, so reject cycles before anything resolves
+        for type_alias in &program.type_aliases {
+            Self::check_type_alias_cycle(&self.type_aliases, &self.newtypes, &type_alias.name)?;
+        }
+        for newtype in &program.newtypes {
+            Self::check_type_alias_cycle(&self.type_aliases, &self.newtypes, &newtype.name)?;
+        }
+
+        // Fold constants in declaration order and inline them at every use site
+        for constant in &program.constants {
+            let value = Self::fold_constant(&constant.value, &self.constants)?;
+            let declared_type = self.resolve_type_alias(&constant.ty);
+            self.constants.insert(constant.name.clone(), Self::coerce_constant(value, &declared_type));
+        }
+        // Associated constants are keyed as ClassName::NAME and may refer to earlier siblings unqualified
+        for class in &program.classes {
+            let mut scope = self.constants.clone();
+            for constant in &class.constants {
+                let value = Self::fold_constant(&constant.value, &scope)?;
+                let value = Self::coerce_constant(value, &self.resolve_type_alias(&constant.ty));
+                let qualified = format!("{}::{}", class.name, constant.name);
+                scope.insert(constant.name.clone(), value.clone());
+                scope.insert(qualified.clone(), value.clone());
+                self.constants.insert(qualified, value);
+            }
+        }
+        let assert_line_starts = self.source_line_starts.as_ref().filter(|_| self.test_mode);
+        let mut rewritten = program.clone();
+        Self::inline_constants_in_program(&mut rewritten, &self.constants);
+        // Immutable locals that fold to literals are substituted too, so their uses fold in turn
+        propagation::propagate_let_constants(&mut rewritten);
+        if self.inlining {
+            inlining::inline_small_functions(&mut rewritten);
+        }
+        if let Some(line_starts) = assert_line_starts {
+            Self::annotate_assert_locations(&mut rewritten, line_starts);
+        }
+        if !self.assertions && !self.test_mode {
+            Self::strip_assertions(&mut rewritten);
+        }
+        let program = &rewritten;
+
+        // Module-level variables live in zero-initialized writable data objects,
+        // filled in by a generated init function that main calls before its body
+        let globals_init = if program.globals.is_empty() {
+            None
+        } else {
+            for global in &program.globals {
+                let global_data_id = self.module.declare_data(
+                    &Self::global_symbol_name(&global.name),
+                    Linkage::Local,
+                    true,  // writable
+                    false, // not thread-local
+                ).map_err(CodegenError::ModuleError)?;
+
+                let mut data_desc = DataDescription::new();
+                data_desc.define_zeroinit(8); // Every Plat value fits in 8 bytes
+                self.module.define_data(global_data_id, &data_desc)
+                    .map_err(CodegenError::ModuleError)?;
+
+                let var_type = self.ast_type_to_variable_type(&global.ty);
+                self.global_types.insert(global.name.clone(), var_type);
+            }
+
+            Some(self.declare_globals_init_function(program)?)
+        };
+
+        // Lay out imported classes under `module::Class` so cross-module results can be read
+        for (module_path, class_decl) in std::mem::take(&mut self.imported_classes) {
+            let qualify = |name: &str| if name.contains("::") { name.to_string() } else { format!("{}::{}", module_path, name) };
+            let mut qualified_decl = class_decl;
+            qualified_decl.name = qualify(&qualified_decl.name);
+            qualified_decl.parent_class = qualified_decl.parent_class.as_deref().map(qualify);
+            self.build_class_metadata(&qualified_decl)?;
+        }
 
         // Build class metadata first (before declaring functions)
         for class_decl in &program.classes {
@@ -1021,11 +2659,14 @@ impl CodeGenerator {
             }
         }
 
-        // Declare bench functions (only in bench mode)
+        // Declare bench functions (only in bench mode). Each body gets an internal name so
+        // the public name can belong to a timing wrapper that every call goes through.
         if self.bench_mode {
             for bench_block in &program.bench_blocks {
                 for function in &bench_block.functions {
-                    self.declare_function(function)?;
+                    let mangled_name = self.mangle_function_name(&function.name);
+                    self.declare_function_with_name(&Self::bench_body_name(&mangled_name), function)?;
+                    self.declare_function_with_name(&mangled_name, function)?;
                 }
             }
         }
@@ -1033,6 +2674,9 @@ impl CodeGenerator {
         // Generate vtables for classes with virtual methods
         self.generate_vtables(program)?;
 
+        // `==` on class instances compares fields through one generated function per class
+        self.generate_class_equality_functions()?;
+
         // Second pass: generate code for all functions
         for function in &program.functions {
             self.generate_function(function)?;
@@ -1054,6 +2698,11 @@ impl CodeGenerator {
             }
         }
 
+        // Generate the module-level variable initializer
+        if let Some(init_function) = &globals_init {
+            self.generate_function_with_name(GLOBALS_INIT_FUNCTION, init_function)?;
+        }
+
         // Generate code for test functions (only in test mode)
         if self.test_mode {
             for test_block in &program.test_blocks {
@@ -1063,18 +2712,58 @@ impl CodeGenerator {
             }
         }
 
-        // Generate code for bench functions (only in bench mode)
+        // Generate code for bench functions and their timing wrappers (only in bench mode)
         if self.bench_mode {
             for bench_block in &program.bench_blocks {
                 for function in &bench_block.functions {
-                    self.generate_function(function)?;
+                    let mangled_name = self.mangle_function_name(&function.name);
+                    let body_name = Self::bench_body_name(&mangled_name);
+                    self.generate_function_with_name(&body_name, function)?;
+                    self.generate_bench_wrapper(&mangled_name, &body_name, &function.name)?;
                 }
             }
         }
 
-        // Finalize the module and return object code
-        let object_product = self.module.finish();
-        Ok(object_product.emit().map_err(CodegenError::ObjectEmitError)?)
+        // Finalize the module and return object code
+        let debug_info = self.debug_info.take();
+        let mut object_product = self.module.finish();
+        if let Some(debug_info) = debug_info {
+            debug_info.emit(&mut object_product)?;
+        }
+        Ok(object_product.emit().map_err(CodegenError::ObjectEmitError)?)
+    }
+
+    /// Declare the function that evaluates module-level variable initializers in order.
+    /// Its body is synthesized as one assignment per variable so it goes through normal codegen.
+    fn declare_globals_init_function(&mut self, program: &Program) -> Result<ast::Function, CodegenError> {
+        let mut sig = self.module.make_signature();
+        sig.call_conv = CallConv::SystemV;
+
+        let func_id = self.module.declare_function(GLOBALS_INIT_FUNCTION, Linkage::Local, &sig)
+            .map_err(CodegenError::ModuleError)?;
+        self.functions.insert(GLOBALS_INIT_FUNCTION.to_string(), func_id);
+
+        let statements = program.globals.iter()
+            .map(|global| Statement::Expression(Expression::Assignment {
+                target: Box::new(Expression::Identifier { name: global.name.clone(), span: global.span }),
+                value: Box::new(global.value.clone()),
+                span: global.span,
+            }))
+            .collect();
+
+        let span = program.globals[0].span;
+        Ok(ast::Function {
+            name: GLOBALS_INIT_FUNCTION.to_string(),
+            type_params: Vec::new(),
+            params: Vec::new(),
+            return_type: None,
+            body: Block { statements, span },
+            is_mutable: false,
+            is_virtual: false,
+            is_override: false,
+            is_public: false,
+            span,
+        })
     }
 
     fn declare_function(&mut self, function: &ast::Function) -> Result<(), CodegenError> {
@@ -1107,9 +2796,14 @@ impl CodeGenerator {
         // Add return type
         if let Some(return_type) = &function.return_type {
             // Special handling for main returning Result/Option
-            if (function.name == "main" || name == "main") && Self::is_result_or_option_with_int_return(return_type) {
+            if (function.name == "main" || name == "main") && Self::is_result_or_option_exit_code_return(return_type) {
                 // Main with Result<Int*, E> or Option<Int*> returns i32 exit code
                 sig.returns.push(AbiParam::new(I32));
+            } else if let AstType::Tuple(element_types) = self.resolve_type_alias(return_type) {
+                // Tuples come back as one return value per element instead of a GC-allocated struct
+                for element_type in &element_types {
+                    sig.returns.push(AbiParam::new(self.ast_type_to_cranelift(element_type)));
+                }
             } else {
                 let ret_type = self.ast_type_to_cranelift(return_type);
                 sig.returns.push(AbiParam::new(ret_type));
@@ -1163,10 +2857,11 @@ impl CodeGenerator {
         builder.append_block_params_for_function_params(entry_block);
         builder.switch_to_block(entry_block);
         builder.seal_block(entry_block);
+        builder.set_srcloc(SourceLoc::new(function.span.start as u32));
 
         // Create local variables map for this function
         let mut variables = HashMap::new();
-        let mut variable_types = HashMap::new(); // Track original variable types
+        let mut variable_types = self.global_types.clone(); // Track original variable types (module-level vars are visible everywhere)
         let mut variable_counter = 0u32;
 
         // Add function parameters as variables
@@ -1214,7 +2909,9 @@ impl CodeGenerator {
         // Generate function body - we need to avoid borrowing conflicts
         // Extract the functions HashMap and type_aliases to avoid borrowing self while builder exists
         let functions_copy = self.functions.clone();
-        let type_aliases_copy = self.type_aliases.clone();
+        // Newtypes share their underlying representation, so statements resolve them like aliases
+        let mut type_aliases_copy = self.type_aliases.clone();
+        type_aliases_copy.extend(self.newtypes.clone());
         let symbol_table = self.symbol_table.as_ref();
 
         // Initialize runtime for main function
@@ -1229,27 +2926,53 @@ impl CodeGenerator {
 
             // Call runtime init
             builder.ins().call(init_func_ref, &[]);
-        }
 
-        let mut has_return = false;
-        for statement in &function.body.statements {
-            has_return |= Self::generate_statement_helper(
-                &mut builder,
-                statement,
-                &mut variables,
-                &mut variable_types,
-                &mut variable_counter,
-                &functions_copy,
-                &mut self.module,
-                &mut self.string_counter,
-                &self.class_metadata,
-                &type_aliases_copy,
-                name,
-                &function.return_type,
-                self.test_mode, symbol_table
-            )?;
+            // Populate vtables before anything can dispatch through them
+            if let Some(&vtables_init_id) = self.functions.get(VTABLES_INIT_FUNCTION) {
+                let vtables_init_ref = self.module.declare_func_in_func(vtables_init_id, builder.func);
+                builder.ins().call(vtables_init_ref, &[]);
+            }
+
+            // Initialize module-level variables
+            if let Some(&globals_init_id) = self.functions.get(GLOBALS_INIT_FUNCTION) {
+                let globals_init_ref = self.module.declare_func_in_func(globals_init_id, builder.func);
+                builder.ins().call(globals_init_ref, &[]);
+            }
         }
 
+        // Self tail calls jump back here with rebound parameters instead of growing the stack
+        let param_vars: HashMap<String, Variable> = function.params.iter()
+            .filter_map(|param| variables.get(&param.name).map(|&var| (param.name.clone(), var)))
+            .collect();
+        let body_block = builder.create_block();
+        builder.ins().jump(body_block, &[]);
+        builder.switch_to_block(body_block);
+
+        // Small array locals that never leave this function are built on the stack
+        let stack_arrays = Self::find_stack_arrays(&function.body);
+
+        Self::warn_redeclared(name, &function.body.statements);
+        let has_return = Self::generate_block_statements(
+            &mut builder,
+            &function.body.statements,
+            &mut variables,
+            &mut variable_types,
+            &mut variable_counter,
+            &functions_copy,
+            &mut self.module,
+            &mut self.string_pool,
+            &self.class_metadata,
+            &type_aliases_copy,
+            name,
+            &function.return_type,
+            Some(TailCallTarget { block: body_block, params: &param_vars }),
+            &stack_arrays,
+            self.test_mode, symbol_table
+        )?;
+
+        // All tail-call jumps have been emitted
+        builder.seal_block(body_block);
+
         // If no explicit return, add default return
         if !has_return {
             if function.return_type.is_some() || function.name == "main" {
@@ -1275,6 +2998,10 @@ impl CodeGenerator {
                 CodegenError::ModuleError(e)
             })?;
 
+        if let (Some(debug_info), Some(compiled)) = (&mut self.debug_info, self.context.compiled_code()) {
+            debug_info.add_function(func_id, name, function.span.start, compiled);
+        }
+
         // Clear for next function
         self.context.clear();
 
@@ -1289,17 +3016,27 @@ impl CodeGenerator {
         variable_counter: &mut u32,
         functions: &HashMap<String, FuncId>,
         module: &mut ObjectModule,
-        string_counter: &mut usize,
+        string_pool: &mut StringPool,
         class_metadata: &HashMap<String, ClassMetadata>,
         type_aliases: &HashMap<String, AstType>,
         function_name: &str,
         function_return_type: &Option<AstType>,
+        tail_call: Option<TailCallTarget>,
+        stack_arrays: &HashSet<String>,
         test_mode: bool,
         symbol_table: Option<&plat_hir::ModuleSymbolTable>
     ) -> Result<bool, CodegenError> {
+        // Tag everything this statement emits with its source position for the DWARF line table
+        builder.set_srcloc(SourceLoc::new(statement.span().start as u32));
+
         match statement {
             Statement::Let { name, ty, value, .. } => {
-                let val = Self::generate_expression_with_expected_type(builder, value, Some(ty), variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let val = match (value, ty) {
+                    (Expression::Literal(Literal::Array(elements, _)), AstType::List(element_type)) if stack_arrays.contains(name) => {
+                        Self::generate_stack_array_literal(builder, elements, element_type, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?
+                    }
+                    _ => Self::generate_expression_with_expected_type(builder, value, Some(ty), variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?,
+                };
                 let var = Variable::from_u32(*variable_counter);
                 *variable_counter += 1;
 
@@ -1313,8 +3050,48 @@ impl CodeGenerator {
                 variable_types.insert(name.clone(), plat_type);
                 Ok(false)
             }
+            Statement::LetPattern { pattern, value, .. } => {
+                match pattern {
+                    Pattern::Tuple { bindings, .. } => {
+                        let plat_types: Vec<VariableType> = bindings.iter()
+                            .map(|(_, ty)| Self::ast_type_to_variable_type_static(type_aliases, ty))
+                            .collect();
+                        let cranelift_types: Vec<Type> = plat_types.iter().map(Self::variable_type_to_cranelift_type).collect();
+
+                        // Multi-value calls and tuple literals bind straight to variables without a heap tuple
+                        let values = Self::generate_tuple_values(builder, value, &cranelift_types, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        for (((name, _), plat_type), (val, cranelift_type)) in bindings.iter().zip(plat_types).zip(values.into_iter().zip(cranelift_types)) {
+                            let var = Variable::from_u32(*variable_counter);
+                            *variable_counter += 1;
+                            builder.declare_var(var, cranelift_type);
+                            builder.def_var(var, val);
+                            variables.insert(name.clone(), var);
+                            variable_types.insert(name.clone(), plat_type);
+                        }
+                    }
+                    Pattern::EnumVariant { bindings, .. } => {
+                        // The type checker only accepts variants of single-variant enums, so no discriminant test is needed
+                        let value_val = Self::generate_expression_helper(builder, value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        Self::bind_enum_payload(builder, value_val, bindings, variables, variable_types, variable_counter);
+                    }
+                    Pattern::Identifier { name, .. } if name == "_" => {
+                        // Evaluated for its side effects; the result is never given a variable
+                        Self::generate_expression_helper(builder, value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    }
+                    _ => return Err(CodegenError::UnsupportedFeature(
+                        "Only tuple, enum variant, and `_` patterns can be destructured by let".to_string()
+                    )),
+                }
+                Ok(false)
+            }
             Statement::Var { name, ty, value, .. } => {
-                let val = Self::generate_expression_with_expected_type(builder, value, Some(ty), variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let val = match (value, ty) {
+                    (Expression::Literal(Literal::Array(elements, _)), AstType::List(element_type)) if stack_arrays.contains(name) => {
+                        Self::generate_stack_array_literal(builder, elements, element_type, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?
+                    }
+                    _ => Self::generate_expression_with_expected_type(builder, value, Some(ty), variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?,
+                };
                 let var = Variable::from_u32(*variable_counter);
                 *variable_counter += 1;
 
@@ -1329,11 +3106,43 @@ impl CodeGenerator {
                 Ok(false)
             }
             Statement::Return { value, .. } => {
+                // `return f(...)` inside f: rebind the parameters and loop instead of calling.
+                // Cranelift's return_call needs the tail calling convention, but Plat functions
+                // use SystemV so the runtime can call them.
+                if let (Some(target), Some(Expression::Call { function, args, .. })) = (tail_call, value) {
+                    let is_self_call = function_name != "main"
+                        && args.len() == builder.func.signature.params.len()
+                        && Self::lookup_function_id(function, functions).is_some_and(|id| functions.get(function_name) == Some(&id));
+                    let param_vars: Option<Vec<Variable>> = args.iter()
+                        .map(|arg| target.params.get(&arg.name).copied())
+                        .collect();
+                    if let (true, Some(param_vars)) = (is_self_call, param_vars) {
+                        // Evaluate every argument before rebinding, since they may read the old parameters
+                        let mut arg_values = Vec::new();
+                        for arg in args {
+                            arg_values.push(Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?);
+                        }
+                        for (var, val) in param_vars.into_iter().zip(arg_values) {
+                            builder.def_var(var, val);
+                        }
+                        builder.ins().jump(target.block, &[]);
+                        return Ok(true);
+                    }
+                }
+
+                // Tuple returns hand back one value per element
+                let return_types: Vec<Type> = builder.func.signature.returns.iter().map(|param| param.value_type).collect();
+                if let (Some(expr), true) = (value, return_types.len() > 1) {
+                    let values = Self::generate_tuple_values(builder, expr, &return_types, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    builder.ins().return_(&values);
+                    return Ok(true);
+                }
+
                 if let Some(expr) = value {
-                    let val = Self::generate_expression_helper(builder, expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let val = Self::generate_expression_helper(builder, expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     // Special handling for main returning Result/Option
-                    if function_name == "main" && function_return_type.as_ref().map_or(false, |ty| Self::is_result_or_option_with_int_return(ty)) {
+                    if function_name == "main" && function_return_type.as_ref().map_or(false, |ty| Self::is_result_or_option_exit_code_return(ty)) {
                         // Extract exit code from Result/Option
                         // Enum layout: discriminant in high 32 bits, value in low 32 bits (for i32)
                         let return_type = function_return_type.as_ref().unwrap();
@@ -1343,21 +3152,15 @@ impl CodeGenerator {
                             return Err(CodegenError::UnsupportedFeature("Invalid return type".to_string()));
                         };
 
-                        // Extract discriminant from high 32 bits
-                        let disc_64 = builder.ins().ushr_imm(val, 32);
-                        let disc = builder.ins().ireduce(I32, disc_64);
+                        // Ok/Some values may be packed or boxed depending on the payload
+                        let disc = Self::enum_discriminant(builder, val);
 
-                        // Compute expected discriminants
+                        // Compute expected discriminant
                         let success_disc = if type_name == "Result" {
                             Self::variant_discriminant("Result", "Ok") as i64
                         } else {
                             Self::variant_discriminant("Option", "Some") as i64
                         };
-                        let error_disc = if type_name == "Result" {
-                            Self::variant_discriminant("Result", "Err") as i64
-                        } else {
-                            Self::variant_discriminant("Option", "None") as i64
-                        };
 
                         // Create blocks
                         let success_block = builder.create_block();
@@ -1368,15 +3171,35 @@ impl CodeGenerator {
                         let is_success = builder.ins().icmp(IntCC::Equal, disc, expected_success);
                         builder.ins().brif(is_success, success_block, &[], error_block, &[]);
 
-                        // Success block: extract value from low 32 bits
+                        // Success block: Unit payloads exit cleanly, integers are the exit code (low 32 bits)
                         builder.switch_to_block(success_block);
                         builder.seal_block(success_block);
-                        let exit_code = builder.ins().ireduce(I32, val);
+                        let returns_unit = matches!(return_type, AstType::Named(_, params)
+                            if matches!(params.first(), Some(AstType::Named(payload, _)) if payload == "Unit"));
+                        let exit_code = if returns_unit {
+                            builder.ins().iconst(I32, 0)
+                        } else {
+                            builder.ins().ireduce(I32, val)
+                        };
                         builder.ins().return_(&[exit_code]);
 
-                        // Error block: return error code (1)
+                        // Error block: report a String error on stderr, then return error code (1)
                         builder.switch_to_block(error_block);
                         builder.seal_block(error_block);
+                        if let AstType::Named(_, params) = return_type {
+                            if type_name == "Result" && matches!(params.get(1), Some(AstType::String)) {
+                                // String payloads are boxed: discriminant at offset 0, pointer at offset 8
+                                let message = builder.ins().load(I64, MemFlags::new(), val, 8);
+
+                                let mut eprint_sig = module.make_signature();
+                                eprint_sig.call_conv = CallConv::SystemV;
+                                eprint_sig.params.push(AbiParam::new(I64));
+                                let eprint_id = module.declare_function("plat_eprint", Linkage::Import, &eprint_sig)
+                                    .map_err(CodegenError::ModuleError)?;
+                                let eprint_ref = module.declare_func_in_func(eprint_id, builder.func);
+                                builder.ins().call(eprint_ref, &[message]);
+                            }
+                        }
                         let error_code = builder.ins().iconst(I32, 1);
                         builder.ins().return_(&[error_code]);
                     } else {
@@ -1410,12 +3233,12 @@ impl CodeGenerator {
                 Ok(true)
             }
             Statement::Expression(expr) => {
-                Self::generate_expression_helper(builder, expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                Self::generate_expression_helper(builder, expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
                 Ok(false)
             }
             Statement::Print { value, .. } => {
                 // Generate the value to print
-                let val = Self::generate_expression_helper(builder, value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let val = Self::generate_expression_helper(builder, value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                 // Call the print runtime function
                 // For now, we need to declare the print function if it's not already declared
@@ -1442,7 +3265,7 @@ impl CodeGenerator {
             }
             Statement::If { condition, then_branch, else_branch, .. } => {
                 // Evaluate condition
-                let condition_val = Self::generate_expression_helper(builder, condition, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let condition_val = Self::generate_expression_helper(builder, condition, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                 // Convert condition to boolean (non-zero = true)
                 let _zero = builder.ins().iconst(I32, 0);
@@ -1459,14 +3282,13 @@ impl CodeGenerator {
                 // Generate then branch
                 builder.switch_to_block(then_block);
                 builder.seal_block(then_block);
-                let mut then_has_return = false;
-                for stmt in &then_branch.statements {
-                    then_has_return |= Self::generate_statement_helper(
-                        builder, stmt, variables, variable_types, variable_counter,
-                        functions, module, string_counter, class_metadata, type_aliases,
-                        function_name, function_return_type, test_mode, symbol_table
-            )?;
-                }
+                let scope = Self::enter_scope(function_name, &then_branch.statements, variables, variable_types);
+                let then_has_return = Self::generate_block_statements(
+                    builder, &then_branch.statements, variables, variable_types, variable_counter,
+                    functions, module, string_pool, class_metadata, type_aliases,
+                    function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+                )?;
+                Self::exit_scope(scope, variables, variable_types);
                 if !then_has_return {
                     builder.ins().jump(merge_block, &[]);
                 }
@@ -1476,13 +3298,13 @@ impl CodeGenerator {
                 builder.seal_block(else_block);
                 let mut else_has_return = false;
                 if let Some(else_block_ast) = else_branch {
-                    for stmt in &else_block_ast.statements {
-                        else_has_return |= Self::generate_statement_helper(
-                            builder, stmt, variables, variable_types, variable_counter,
-                            functions, module, string_counter, class_metadata, type_aliases,
-                            function_name, function_return_type, test_mode, symbol_table
-            )?;
-                    }
+                    let scope = Self::enter_scope(function_name, &else_block_ast.statements, variables, variable_types);
+                    else_has_return = Self::generate_block_statements(
+                        builder, &else_block_ast.statements, variables, variable_types, variable_counter,
+                        functions, module, string_pool, class_metadata, type_aliases,
+                        function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+                    )?;
+                    Self::exit_scope(scope, variables, variable_types);
                 }
                 if !else_has_return {
                     builder.ins().jump(merge_block, &[]);
@@ -1505,21 +3327,20 @@ impl CodeGenerator {
 
                 // Loop header: evaluate condition
                 builder.switch_to_block(loop_header);
-                let condition_val = Self::generate_expression_helper(builder, condition, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let condition_val = Self::generate_expression_helper(builder, condition, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
                 let _zero = builder.ins().iconst(I32, 0);
                 let condition_bool = builder.ins().icmp_imm(IntCC::NotEqual, condition_val, 0);
                 builder.ins().brif(condition_bool, loop_body, &[], loop_exit, &[]);
 
                 // Loop body
                 builder.switch_to_block(loop_body);
-                let mut body_has_return = false;
-                for stmt in &body.statements {
-                    body_has_return |= Self::generate_statement_helper(
-                        builder, stmt, variables, variable_types, variable_counter,
-                        functions, module, string_counter, class_metadata, type_aliases,
-                        function_name, function_return_type, test_mode, symbol_table
-            )?;
-                }
+                let scope = Self::enter_scope(function_name, &body.statements, variables, variable_types);
+                let body_has_return = Self::generate_block_statements(
+                    builder, &body.statements, variables, variable_types, variable_counter,
+                    functions, module, string_pool, class_metadata, type_aliases,
+                    function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+                )?;
+                Self::exit_scope(scope, variables, variable_types);
                 if !body_has_return {
                     builder.ins().jump(loop_header, &[]);
                 }
@@ -1534,24 +3355,68 @@ impl CodeGenerator {
 
                 Ok(false) // while loops don't guarantee return
             }
-            Statement::For { variable, iterable, body, .. } => {
+            Statement::For { variable, variable_type, value_binding, iterable, body, .. } => {
                 // Check if this is a range-based for loop
-                if let Expression::Range { start, end, inclusive, .. } = iterable {
+                if let Expression::Range { start, end, inclusive, step, .. } = iterable {
                     // Range-based for loop
                     return Self::generate_range_for_loop(
-                        builder, variable, start, end, *inclusive, body,
-                        variables, variable_types, variable_counter, functions, module, string_counter, class_metadata, type_aliases,
-                        function_name, function_return_type, test_mode, symbol_table
+                        builder, variable, start, end, *inclusive, step.as_deref(), body,
+                        variables, variable_types, variable_counter, functions, module, string_pool, class_metadata, type_aliases,
+                        function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+                    );
+                }
+
+                // Dict iteration binds each key and its value; a list of pairs (such as
+                // `list.enumerate()`) goes through the array loop and binds both tuple elements
+                let iterates_pairs = value_binding.is_some()
+                    && matches!(Self::infer_expression_type(iterable, variable_types), VariableType::Array(_));
+                if let Some((value_name, value_type)) = value_binding.as_ref().filter(|_| !iterates_pairs) {
+                    let value_var_type = Self::ast_type_to_variable_type_static(type_aliases, value_type);
+                    return Self::generate_dict_for_loop(
+                        builder, variable, value_name, &value_var_type, iterable, body,
+                        variables, variable_types, variable_counter, functions, module, string_pool, class_metadata, type_aliases,
+                        function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
                     );
                 }
 
                 // Array-based for loop (existing code)
+                // Sets are iterated by converting them to a list first
+                let is_set = Self::is_set_type(iterable, variable_types);
+
                 // Infer the element type from the iterable expression
-                let element_type = Self::infer_element_type(iterable, variable_types);
+                // (sets don't track their element type, so use the loop variable's annotation)
+                let element_type = if let Some((_, value_type)) = value_binding {
+                    VariableType::Tuple(vec![
+                        Self::ast_type_to_variable_type_static(type_aliases, variable_type),
+                        Self::ast_type_to_variable_type_static(type_aliases, value_type),
+                    ])
+                } else if is_set {
+                    Self::ast_type_to_variable_type_static(type_aliases, variable_type)
+                } else {
+                    Self::infer_element_type(iterable, variable_types)
+                };
                 let element_cranelift_type = Self::variable_type_to_cranelift_type(&element_type);
 
                 // Evaluate iterable
-                let array_val = Self::generate_expression_helper(builder, iterable, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let iterable_val = Self::generate_expression_helper(builder, iterable, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let array_val = if is_set {
+                    let to_list_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // set pointer
+                        sig.returns.push(AbiParam::new(I64)); // array pointer
+                        sig
+                    };
+
+                    let to_list_id = module.declare_function("plat_set_to_list", Linkage::Import, &to_list_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let to_list_ref = module.declare_func_in_func(to_list_id, builder.func);
+
+                    let call = builder.ins().call(to_list_ref, &[iterable_val]);
+                    builder.inst_results(call)[0]
+                } else {
+                    iterable_val
+                };
 
                 // Get array length
                 let len_sig = {
@@ -1585,6 +3450,8 @@ impl CodeGenerator {
                 // Store in variables map with proper element type
                 let old_variable = variables.insert(variable.clone(), element_var);
                 let old_type = variable_types.insert(variable.clone(), element_type.clone());
+                let old_value_binding = value_binding.as_ref()
+                    .map(|(value_name, _)| (value_name, variables.get(value_name).copied(), variable_types.get(value_name).cloned()));
 
                 // Create blocks
                 let loop_header = builder.create_block();
@@ -1640,16 +3507,30 @@ impl CodeGenerator {
                 // Set loop variable to current element
                 builder.def_var(element_var, element_val);
 
-                // Execute loop body statements
-                let mut body_has_return = false;
-                for stmt in &body.statements {
-                    body_has_return |= Self::generate_statement_helper(
-                        builder, stmt, variables, variable_types, variable_counter,
-                        functions, module, string_counter, class_metadata, type_aliases,
-                        function_name, function_return_type, test_mode, symbol_table
-            )?;
+                // A list of pairs binds each tuple element to its own loop variable
+                if let (Some((value_name, _)), VariableType::Tuple(pair_types)) = (value_binding, &element_type) {
+                    let cranelift_types: Vec<Type> = pair_types.iter().map(Self::variable_type_to_cranelift_type).collect();
+                    let (offsets, _size) = Self::tuple_layout(&cranelift_types);
+                    for (index, name) in [variable, value_name].into_iter().enumerate() {
+                        let value = builder.ins().load(cranelift_types[index], MemFlags::new(), element_val, offsets[index]);
+                        let binding_var = Variable::from_u32(*variable_counter);
+                        *variable_counter += 1;
+                        builder.declare_var(binding_var, cranelift_types[index]);
+                        builder.def_var(binding_var, value);
+                        variables.insert(name.clone(), binding_var);
+                        variable_types.insert(name.clone(), pair_types[index].clone());
+                    }
                 }
 
+                // Execute loop body statements
+                let scope = Self::enter_scope(function_name, &body.statements, variables, variable_types);
+                let body_has_return = Self::generate_block_statements(
+                    builder, &body.statements, variables, variable_types, variable_counter,
+                    functions, module, string_pool, class_metadata, type_aliases,
+                    function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+                )?;
+                Self::exit_scope(scope, variables, variable_types);
+
                 // Increment index
                 if !body_has_return {
                     let one = builder.ins().iconst(I32, 1);
@@ -1677,6 +3558,16 @@ impl CodeGenerator {
                 } else {
                     variable_types.remove(variable);
                 }
+                if let Some((value_name, old_var, old_typ)) = old_value_binding {
+                    match old_var {
+                        Some(old_var) => { variables.insert(value_name.clone(), old_var); }
+                        None => { variables.remove(value_name); }
+                    }
+                    match old_typ {
+                        Some(old_typ) => { variable_types.insert(value_name.clone(), old_typ); }
+                        None => { variable_types.remove(value_name); }
+                    }
+                }
 
                 Ok(false) // for loops don't guarantee return
             }
@@ -1702,28 +3593,13 @@ impl CodeGenerator {
                 let scope_id = builder.inst_results(call_inst)[0];
 
                 // Execute the concurrent block body
-                let mut body_returned = false;
-                for stmt in &body.statements {
-                    let returned = Self::generate_statement_helper(
-                        builder,
-                        stmt,
-                        variables,
-                        variable_types,
-                        variable_counter,
-                        functions,
-                        module,
-                        string_counter,
-                        class_metadata,
-                        type_aliases,
-                        function_name,
-                        function_return_type,
-                        test_mode, symbol_table
-            )?;
-                    if returned {
-                        body_returned = true;
-                        break;
-                    }
-                }
+                let scope = Self::enter_scope(function_name, &body.statements, variables, variable_types);
+                let body_returned = Self::generate_block_statements(
+                    builder, &body.statements, variables, variable_types, variable_counter,
+                    functions, module, string_pool, class_metadata, type_aliases,
+                    function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+                )?;
+                Self::exit_scope(scope, variables, variable_types);
 
                 // Declare plat_scope_exit function
                 let mut exit_sig = module.make_signature();
@@ -1744,7 +3620,232 @@ impl CodeGenerator {
 
                 Ok(body_returned)
             }
+            Statement::Select { arms, default, .. } => {
+                // Lay the channel IDs out in a stack array for plat_select
+                let channels_slot = builder.create_sized_stack_slot(StackSlotData::new(
+                    StackSlotKind::ExplicitSlot,
+                    (arms.len() * 8) as u32,
+                    3, // 8-byte alignment
+                ));
+                for (index, arm) in arms.iter().enumerate() {
+                    let channel_id = Self::generate_expression_helper(builder, &arm.channel, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    builder.ins().stack_store(channel_id, channels_slot, (index * 8) as i32);
+                }
+                let value_slot = builder.create_sized_stack_slot(StackSlotData::new(StackSlotKind::ExplicitSlot, 8, 3));
+
+                let mut select_sig = module.make_signature();
+                select_sig.call_conv = CallConv::SystemV;
+                select_sig.params.push(AbiParam::new(I64)); // channel ID array
+                select_sig.params.push(AbiParam::new(I32)); // channel count
+                select_sig.params.push(AbiParam::new(I32)); // blocking flag
+                select_sig.params.push(AbiParam::new(I64)); // out value pointer
+                select_sig.returns.push(AbiParam::new(I32)); // chosen index, or -1
+
+                let select_id = module.declare_function("plat_select", Linkage::Import, &select_sig)
+                    .map_err(CodegenError::ModuleError)?;
+                let select_ref = module.declare_func_in_func(select_id, builder.func);
+
+                let channels_addr = builder.ins().stack_addr(I64, channels_slot, 0);
+                let count = builder.ins().iconst(I32, arms.len() as i64);
+                // A default arm makes the select non-blocking
+                let blocking = builder.ins().iconst(I32, default.is_none() as i64);
+                let value_addr = builder.ins().stack_addr(I64, value_slot, 0);
+                let call = builder.ins().call(select_ref, &[channels_addr, count, blocking, value_addr]);
+                let chosen = builder.inst_results(call)[0];
+
+                // Branch cascade on the chosen index
+                let merge_block = builder.create_block();
+                let mut all_return = true;
+                for (index, arm) in arms.iter().enumerate() {
+                    let arm_block = builder.create_block();
+                    let next_block = builder.create_block();
+                    let is_chosen = builder.ins().icmp_imm(IntCC::Equal, chosen, index as i64);
+                    builder.ins().brif(is_chosen, arm_block, &[], next_block, &[]);
+
+                    builder.switch_to_block(arm_block);
+                    builder.seal_block(arm_block);
+
+                    let element_type = match &arm.channel {
+                        Expression::Identifier { name, .. } => match variable_types.get(name) {
+                            Some(VariableType::Channel(inner)) => (**inner).clone(),
+                            _ => VariableType::Int32,
+                        },
+                        _ => VariableType::Int32,
+                    };
+
+                    // plat_select widens integers to i64 and returns floats as their bits
+                    let bits = builder.ins().stack_load(I64, value_slot, 0);
+                    let element_cranelift_type = Self::variable_type_to_cranelift_type(&element_type);
+                    let value = match element_cranelift_type {
+                        I64 => bits,
+                        F64 => builder.ins().bitcast(F64, MemFlags::new(), bits),
+                        F32 => {
+                            let low = builder.ins().ireduce(I32, bits);
+                            builder.ins().bitcast(F32, MemFlags::new(), low)
+                        }
+                        narrow => builder.ins().ireduce(narrow, bits),
+                    };
+
+                    let binding_var = Variable::from_u32(*variable_counter);
+                    *variable_counter += 1;
+                    builder.declare_var(binding_var, element_cranelift_type);
+                    builder.def_var(binding_var, value);
+                    let old_variable = variables.insert(arm.binding.clone(), binding_var);
+                    let old_type = variable_types.insert(arm.binding.clone(), element_type);
+
+                    let scope = Self::enter_scope(function_name, &arm.body.statements, variables, variable_types);
+                    let arm_returned = Self::generate_block_statements(
+                        builder, &arm.body.statements, variables, variable_types, variable_counter,
+                        functions, module, string_pool, class_metadata, type_aliases,
+                        function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+                    )?;
+                    Self::exit_scope(scope, variables, variable_types);
+                    if !arm_returned {
+                        builder.ins().jump(merge_block, &[]);
+                    }
+                    all_return &= arm_returned;
+
+                    // Restore the outer binding, if the arm shadowed one
+                    match old_variable {
+                        Some(old_var) => variables.insert(arm.binding.clone(), old_var),
+                        None => variables.remove(&arm.binding),
+                    };
+                    match old_type {
+                        Some(old_typ) => variable_types.insert(arm.binding.clone(), old_typ),
+                        None => variable_types.remove(&arm.binding),
+                    };
+
+                    builder.switch_to_block(next_block);
+                    builder.seal_block(next_block);
+                }
+
+                // No channel delivered a value: run the default arm, if there is one
+                let mut default_returned = false;
+                if let Some(default_block) = default {
+                    let scope = Self::enter_scope(function_name, &default_block.statements, variables, variable_types);
+                    default_returned = Self::generate_block_statements(
+                        builder, &default_block.statements, variables, variable_types, variable_counter,
+                        functions, module, string_pool, class_metadata, type_aliases,
+                        function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+                    )?;
+                    Self::exit_scope(scope, variables, variable_types);
+                }
+                if !default_returned {
+                    builder.ins().jump(merge_block, &[]);
+                }
+
+                builder.switch_to_block(merge_block);
+                builder.seal_block(merge_block);
+
+                Ok(all_return && default_returned)
+            }
+        }
+    }
+
+    /// Permute argument values (in call-site order) into the declared parameter order.
+    /// Leaves them untouched if the names don't line up one-to-one.
+    fn order_arguments_by_params(args: &[ast::NamedArg], values: Vec<Value>, params: &[(String, HirType)]) -> Vec<Value> {
+        if args.len() != params.len() {
+            return values;
+        }
+        let ordered: Option<Vec<Value>> = params.iter()
+            .map(|(param_name, _)| args.iter().position(|arg| &arg.name == param_name).map(|i| values[i]))
+            .collect();
+        ordered.unwrap_or(values)
+    }
+
+    /// Generate a block's statements in order, stopping at the first one that always returns.
+    /// Anything after it is reported as unreachable. Returns whether the block returned.
+    fn generate_block_statements(
+        builder: &mut FunctionBuilder,
+        statements: &[Statement],
+        variables: &mut HashMap<String, Variable>,
+        variable_types: &mut HashMap<String, VariableType>,
+        variable_counter: &mut u32,
+        functions: &HashMap<String, FuncId>,
+        module: &mut ObjectModule,
+        string_pool: &mut StringPool,
+        class_metadata: &HashMap<String, ClassMetadata>,
+        type_aliases: &HashMap<String, AstType>,
+        function_name: &str,
+        function_return_type: &Option<AstType>,
+        tail_call: Option<TailCallTarget>,
+        stack_arrays: &HashSet<String>,
+        test_mode: bool,
+        symbol_table: Option<&plat_hir::ModuleSymbolTable>
+    ) -> Result<bool, CodegenError> {
+        for (index, statement) in statements.iter().enumerate() {
+            let returned = Self::generate_statement_helper(
+                builder, statement, variables, variable_types, variable_counter,
+                functions, module, string_pool, class_metadata, type_aliases,
+                function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+            )?;
+            if returned {
+                Self::warn_unreachable(function_name, &statements[index + 1..]);
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Report statements skipped because an earlier statement in the block always returns
+    fn warn_unreachable(function_name: &str, dead: &[Statement]) {
+        if !dead.is_empty() {
+            eprintln!(
+                "Warning: unreachable code in '{}': {} statement(s) after return are never executed",
+                function_name, dead.len()
+            );
+        }
+    }
+
+    /// Report `let`/`var` names declared more than once directly in one block
+    fn warn_redeclared(function_name: &str, statements: &[Statement]) {
+        let mut declared = HashSet::new();
+        for stmt in statements {
+            if let Statement::Let { name, .. } | Statement::Var { name, .. } = stmt {
+                if !declared.insert(name.as_str()) {
+                    eprintln!(
+                        "Warning: '{}' is redeclared in the same scope in '{}' and shadows the earlier binding",
+                        name, function_name
+                    );
+                }
+            }
+        }
+    }
+
+    /// Open a nested block scope, remembering the enclosing bindings so the block's own
+    /// declarations can shadow them without replacing them once the block ends
+    fn enter_scope(
+        function_name: &str,
+        statements: &[Statement],
+        variables: &HashMap<String, Variable>,
+        variable_types: &HashMap<String, VariableType>,
+    ) -> (HashMap<String, Variable>, HashMap<String, VariableType>) {
+        Self::warn_redeclared(function_name, statements);
+        (variables.clone(), variable_types.clone())
+    }
+
+    /// Close a block scope opened by `enter_scope`, dropping its declarations
+    fn exit_scope(
+        scope: (HashMap<String, Variable>, HashMap<String, VariableType>),
+        variables: &mut HashMap<String, Variable>,
+        variable_types: &mut HashMap<String, VariableType>,
+    ) {
+        (*variables, *variable_types) = scope;
+    }
+
+    /// Resolve a call target the way direct calls do: exact name first, then a module-mangled match
+    fn lookup_function_id(function: &str, functions: &HashMap<String, FuncId>) -> Option<FuncId> {
+        if let Some(&id) = functions.get(function) {
+            return Some(id);
         }
+        if function.contains("::") {
+            return None;
+        }
+        let suffix = format!("::{}", function);
+        functions.iter()
+            .find(|(name, _)| name.ends_with(&suffix))
+            .map(|(_, &id)| id)
     }
 
     fn generate_range_for_loop(
@@ -1753,90 +3854,306 @@ impl CodeGenerator {
         start: &Expression,
         end: &Expression,
         inclusive: bool,
+        step: Option<&Expression>,
         body: &Block,
         variables: &mut HashMap<String, Variable>,
         variable_types: &mut HashMap<String, VariableType>,
         variable_counter: &mut u32,
         functions: &HashMap<String, FuncId>,
         module: &mut ObjectModule,
-        string_counter: &mut usize,
+        string_pool: &mut StringPool,
         class_metadata: &HashMap<String, ClassMetadata>,
         type_aliases: &HashMap<String, AstType>,
         function_name: &str,
         function_return_type: &Option<AstType>,
+        tail_call: Option<TailCallTarget>,
+        stack_arrays: &HashSet<String>,
         test_mode: bool,
         symbol_table: Option<&plat_hir::ModuleSymbolTable>
     ) -> Result<bool, CodegenError> {
         // Evaluate start and end expressions
-        let start_val = Self::generate_expression_helper(builder, start, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-        let end_val = Self::generate_expression_helper(builder, end, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+        let start_val = Self::generate_expression_helper(builder, start, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+        let end_val = Self::generate_expression_helper(builder, end, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+        // Infer the integer type from start expression (both should be same type due to HIR check)
+        let int_type = Self::infer_expression_type(start, variable_types);
+        let cranelift_type = Self::variable_type_to_cranelift_type(&int_type);
+
+        // The direction comes from the step's sign when one is given; otherwise a range whose
+        // literal bounds run backwards (`10..0`) counts down by one
+        let no_constants = HashMap::new();
+        let constant_int = |expr: &Expression| match Self::fold_constant(expr, &no_constants) {
+            Ok(Literal::Integer(value, _, _)) => Some(value),
+            _ => None,
+        };
+        let (step_val, descending) = match step {
+            Some(step) => {
+                let value = Self::generate_expression_helper(builder, step, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let value = Self::coerce_int_width(builder, value, cranelift_type);
+                match constant_int(step) {
+                    Some(0) => return Err(CodegenError::UnsupportedFeature("Range step must be nonzero".to_string())),
+                    Some(constant) => (value, Some(constant < 0)),
+                    None => {
+                        // A computed step is checked once, before the loop starts
+                        let zero = builder.ins().iconst(cranelift_type, 0);
+                        let nonzero = builder.ins().icmp(IntCC::NotEqual, value, zero);
+                        let nonzero = builder.ins().uextend(I32, nonzero);
+                        let message_id = string_pool.intern(module, "Range step must be nonzero")?;
+                        let message_gv = module.declare_data_in_func(message_id, builder.func);
+                        let message = builder.ins().symbol_value(I64, message_gv);
+
+                        let assert_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I32)); // condition (bool as i32)
+                            sig.params.push(AbiParam::new(I64)); // message pointer
+                            sig
+                        };
+                        let assert_id = module.declare_function("plat_assert", Linkage::Import, &assert_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let assert_ref = module.declare_func_in_func(assert_id, builder.func);
+                        builder.ins().call(assert_ref, &[nonzero, message]);
+
+                        (value, None)
+                    }
+                }
+            }
+            None => {
+                let descending = matches!((constant_int(start), constant_int(end)), (Some(start), Some(end)) if start > end);
+                (builder.ins().iconst(cranelift_type, if descending { -1 } else { 1 }), Some(descending))
+            }
+        };
+
+        // Create loop variable
+        let loop_var = Variable::from_u32(*variable_counter);
+        *variable_counter += 1;
+        builder.declare_var(loop_var, cranelift_type);
+        builder.def_var(loop_var, start_val);
+
+        // Store in variables map
+        let old_variable = variables.insert(variable.to_string(), loop_var);
+        let old_type = variable_types.insert(variable.to_string(), int_type);
+
+        // Create blocks
+        let loop_header = builder.create_block();
+        let loop_body = builder.create_block();
+        let loop_exit = builder.create_block();
+
+        // Jump to loop header
+        builder.ins().jump(loop_header, &[]);
+
+        // Loop header: check condition
+        builder.switch_to_block(loop_header);
+        let current_val = builder.use_var(loop_var);
+
+        // For inclusive ranges (..=), condition is: current_val <= end_val (>= when descending)
+        // For exclusive ranges (..), condition is: current_val < end_val (> when descending)
+        let (ascending_cc, descending_cc) = if inclusive {
+            (IntCC::SignedLessThanOrEqual, IntCC::SignedGreaterThanOrEqual)
+        } else {
+            (IntCC::SignedLessThan, IntCC::SignedGreaterThan)
+        };
+        let condition = match descending {
+            Some(false) => builder.ins().icmp(ascending_cc, current_val, end_val),
+            Some(true) => builder.ins().icmp(descending_cc, current_val, end_val),
+            None => {
+                // Step sign only known at runtime: pick the comparison by it
+                let ascending_cond = builder.ins().icmp(ascending_cc, current_val, end_val);
+                let descending_cond = builder.ins().icmp(descending_cc, current_val, end_val);
+                let step_negative = builder.ins().icmp_imm(IntCC::SignedLessThan, step_val, 0);
+                builder.ins().select(step_negative, descending_cond, ascending_cond)
+            }
+        };
+
+        builder.ins().brif(condition, loop_body, &[], loop_exit, &[]);
+
+        // Loop body: execute statements
+        builder.switch_to_block(loop_body);
+
+        let scope = Self::enter_scope(function_name, &body.statements, variables, variable_types);
+        let body_has_return = Self::generate_block_statements(
+            builder, &body.statements, variables, variable_types, variable_counter,
+            functions, module, string_pool, class_metadata, type_aliases,
+            function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+        )?;
+        Self::exit_scope(scope, variables, variable_types);
+
+        // Increment loop variable
+        if !body_has_return {
+            let current_val = builder.use_var(loop_var);
+            let next_val = builder.ins().iadd(current_val, step_val);
+            builder.def_var(loop_var, next_val);
+            builder.ins().jump(loop_header, &[]);
+        }
+
+        // Seal blocks
+        builder.seal_block(loop_header);
+        builder.seal_block(loop_body);
+
+        // Loop exit
+        builder.switch_to_block(loop_exit);
+        builder.seal_block(loop_exit);
+
+        // Restore old variable binding if it existed
+        if let Some(old_var) = old_variable {
+            variables.insert(variable.to_string(), old_var);
+        } else {
+            variables.remove(variable);
+        }
+        if let Some(old_typ) = old_type {
+            variable_types.insert(variable.to_string(), old_typ);
+        } else {
+            variable_types.remove(variable);
+        }
+
+        Ok(false) // for loops don't guarantee return
+    }
+
+    fn generate_dict_for_loop(
+        builder: &mut FunctionBuilder,
+        key_variable: &str,
+        value_variable: &str,
+        value_type: &VariableType,
+        iterable: &Expression,
+        body: &Block,
+        variables: &mut HashMap<String, Variable>,
+        variable_types: &mut HashMap<String, VariableType>,
+        variable_counter: &mut u32,
+        functions: &HashMap<String, FuncId>,
+        module: &mut ObjectModule,
+        string_pool: &mut StringPool,
+        class_metadata: &HashMap<String, ClassMetadata>,
+        type_aliases: &HashMap<String, AstType>,
+        function_name: &str,
+        function_return_type: &Option<AstType>,
+        tail_call: Option<TailCallTarget>,
+        stack_arrays: &HashSet<String>,
+        test_mode: bool,
+        symbol_table: Option<&plat_hir::ModuleSymbolTable>
+    ) -> Result<bool, CodegenError> {
+        // Evaluate the dict and snapshot its keys into an array
+        let dict_val = Self::generate_expression_helper(builder, iterable, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+        let keys_sig = {
+            let mut sig = module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.params.push(AbiParam::new(I64)); // dict pointer
+            sig.returns.push(AbiParam::new(I64)); // keys array pointer
+            sig
+        };
+        let keys_id = module.declare_function("plat_dict_keys", Linkage::Import, &keys_sig)
+            .map_err(CodegenError::ModuleError)?;
+        let keys_ref = module.declare_func_in_func(keys_id, builder.func);
+        let call = builder.ins().call(keys_ref, &[dict_val]);
+        let keys_val = builder.inst_results(call)[0];
 
-        // Infer the integer type from start expression (both should be same type due to HIR check)
-        let int_type = Self::infer_expression_type(start, variable_types);
-        let cranelift_type = Self::variable_type_to_cranelift_type(&int_type);
+        // Get number of keys
+        let len_sig = {
+            let mut sig = module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.params.push(AbiParam::new(I64)); // array pointer
+            sig.returns.push(AbiParam::new(I64)); // length
+            sig
+        };
+        let len_id = module.declare_function("plat_array_len", Linkage::Import, &len_sig)
+            .map_err(CodegenError::ModuleError)?;
+        let len_ref = module.declare_func_in_func(len_id, builder.func);
+        let call = builder.ins().call(len_ref, &[keys_val]);
+        let keys_len = builder.inst_results(call)[0];
 
-        // Create loop variable
-        let loop_var = Variable::from_u32(*variable_counter);
+        // Create loop variable for index
+        let index_var = Variable::from_u32(*variable_counter);
         *variable_counter += 1;
-        builder.declare_var(loop_var, cranelift_type);
-        builder.def_var(loop_var, start_val);
+        builder.declare_var(index_var, I64);
+        let zero = builder.ins().iconst(I64, 0);
+        builder.def_var(index_var, zero);
 
-        // Store in variables map
-        let old_variable = variables.insert(variable.to_string(), loop_var);
-        let old_type = variable_types.insert(variable.to_string(), int_type);
+        // Create loop variables for key and value
+        let key_var = Variable::from_u32(*variable_counter);
+        *variable_counter += 1;
+        builder.declare_var(key_var, I64);
+
+        let value_cranelift_type = Self::variable_type_to_cranelift_type(value_type);
+        let value_var = Variable::from_u32(*variable_counter);
+        *variable_counter += 1;
+        builder.declare_var(value_var, value_cranelift_type);
+
+        let old_key_variable = variables.insert(key_variable.to_string(), key_var);
+        let old_key_type = variable_types.insert(key_variable.to_string(), VariableType::String);
+        let old_value_variable = variables.insert(value_variable.to_string(), value_var);
+        let old_value_type = variable_types.insert(value_variable.to_string(), value_type.clone());
 
         // Create blocks
         let loop_header = builder.create_block();
         let loop_body = builder.create_block();
         let loop_exit = builder.create_block();
 
-        // Jump to loop header
         builder.ins().jump(loop_header, &[]);
 
-        // Loop header: check condition
+        // Loop header: check if index < number of keys
         builder.switch_to_block(loop_header);
-        let current_val = builder.use_var(loop_var);
-
-        // For inclusive ranges (..=), condition is: current_val <= end_val
-        // For exclusive ranges (..), condition is: current_val < end_val
-        let condition = if inclusive {
-            if cranelift_type == I32 {
-                builder.ins().icmp(IntCC::SignedLessThanOrEqual, current_val, end_val)
-            } else {
-                builder.ins().icmp(IntCC::SignedLessThanOrEqual, current_val, end_val)
-            }
-        } else {
-            if cranelift_type == I32 {
-                builder.ins().icmp(IntCC::SignedLessThan, current_val, end_val)
-            } else {
-                builder.ins().icmp(IntCC::SignedLessThan, current_val, end_val)
-            }
-        };
-
+        let current_index = builder.use_var(index_var);
+        let condition = builder.ins().icmp(IntCC::SignedLessThan, current_index, keys_len);
         builder.ins().brif(condition, loop_body, &[], loop_exit, &[]);
 
-        // Loop body: execute statements
+        // Loop body: fetch key and value, then execute statements
         builder.switch_to_block(loop_body);
 
-        let mut body_has_return = false;
-        for stmt in &body.statements {
-            body_has_return |= Self::generate_statement_helper(
-                builder, stmt, variables, variable_types, variable_counter,
-                functions, module, string_counter, class_metadata, type_aliases,
-                function_name, function_return_type, test_mode, symbol_table
-            )?;
-        }
+        let get_sig = {
+            let mut sig = module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.params.push(AbiParam::new(I64)); // array pointer
+            sig.params.push(AbiParam::new(I64)); // index
+            sig.returns.push(AbiParam::new(I64)); // element value
+            sig
+        };
+        let get_id = module.declare_function("plat_array_get", Linkage::Import, &get_sig)
+            .map_err(CodegenError::ModuleError)?;
+        let get_ref = module.declare_func_in_func(get_id, builder.func);
+        let call = builder.ins().call(get_ref, &[keys_val, current_index]);
+        let key_val = builder.inst_results(call)[0];
+        builder.def_var(key_var, key_val);
 
-        // Increment loop variable
+        // The key is known to be present, so the raw lookup is safe here
+        let dict_get_sig = {
+            let mut sig = module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.params.push(AbiParam::new(I64)); // dict pointer
+            sig.params.push(AbiParam::new(I64)); // key pointer
+            sig.returns.push(AbiParam::new(I64)); // value
+            sig
+        };
+        let dict_get_id = module.declare_function("plat_dict_get", Linkage::Import, &dict_get_sig)
+            .map_err(CodegenError::ModuleError)?;
+        let dict_get_ref = module.declare_func_in_func(dict_get_id, builder.func);
+        let call = builder.ins().call(dict_get_ref, &[dict_val, key_val]);
+        let value_i64 = builder.inst_results(call)[0];
+
+        // Convert the stored i64 to the value's Cranelift type
+        let value_val = match value_cranelift_type {
+            I8 | I16 | I32 => builder.ins().ireduce(value_cranelift_type, value_i64),
+            F64 => builder.ins().bitcast(F64, MemFlags::new(), value_i64),
+            F32 => {
+                let bits = builder.ins().ireduce(I32, value_i64);
+                builder.ins().bitcast(F32, MemFlags::new(), bits)
+            }
+            _ => value_i64,
+        };
+        builder.def_var(value_var, value_val);
+
+        let scope = Self::enter_scope(function_name, &body.statements, variables, variable_types);
+        let body_has_return = Self::generate_block_statements(
+            builder, &body.statements, variables, variable_types, variable_counter,
+            functions, module, string_pool, class_metadata, type_aliases,
+            function_name, function_return_type, tail_call, stack_arrays, test_mode, symbol_table
+        )?;
+        Self::exit_scope(scope, variables, variable_types);
+
+        // Increment index
         if !body_has_return {
-            let current_val = builder.use_var(loop_var);
-            let one = if cranelift_type == I32 {
-                builder.ins().iconst(I32, 1)
-            } else {
-                builder.ins().iconst(I64, 1)
-            };
-            let next_val = builder.ins().iadd(current_val, one);
-            builder.def_var(loop_var, next_val);
+            let one = builder.ins().iconst(I64, 1);
+            let next_index = builder.ins().iadd(current_index, one);
+            builder.def_var(index_var, next_index);
             builder.ins().jump(loop_header, &[]);
         }
 
@@ -1848,16 +4165,21 @@ impl CodeGenerator {
         builder.switch_to_block(loop_exit);
         builder.seal_block(loop_exit);
 
-        // Restore old variable binding if it existed
-        if let Some(old_var) = old_variable {
-            variables.insert(variable.to_string(), old_var);
-        } else {
-            variables.remove(variable);
-        }
-        if let Some(old_typ) = old_type {
-            variable_types.insert(variable.to_string(), old_typ);
-        } else {
-            variable_types.remove(variable);
+        // Restore old variable bindings if they existed
+        for (name, old_var, old_typ) in [
+            (key_variable, old_key_variable, old_key_type),
+            (value_variable, old_value_variable, old_value_type),
+        ] {
+            if let Some(old_var) = old_var {
+                variables.insert(name.to_string(), old_var);
+            } else {
+                variables.remove(name);
+            }
+            if let Some(old_typ) = old_typ {
+                variable_types.insert(name.to_string(), old_typ);
+            } else {
+                variable_types.remove(name);
+            }
         }
 
         Ok(false) // for loops don't guarantee return
@@ -1871,7 +4193,7 @@ impl CodeGenerator {
         variable_types: &HashMap<String, VariableType>,
         functions: &HashMap<String, FuncId>,
         module: &mut ObjectModule,
-        string_counter: &mut usize,
+        string_pool: &mut StringPool,
         variable_counter: &mut u32,
         class_metadata: &HashMap<String, ClassMetadata>,
         test_mode: bool,
@@ -1880,21 +4202,303 @@ impl CodeGenerator {
         match expr {
             Expression::Literal(Literal::Array(elements, _)) => {
                 // Use expected type information for array generation
-                Self::generate_typed_array_literal(builder, elements, expected_type, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)
+                Self::generate_typed_array_literal(builder, elements, expected_type, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
             }
             Expression::Literal(Literal::Dict(pairs, _)) => {
                 // Use expected type information for dict generation
-                Self::generate_typed_dict_literal(builder, pairs, expected_type, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)
+                Self::generate_typed_dict_literal(builder, pairs, expected_type, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
             }
             Expression::Literal(Literal::Set(elements, _)) => {
                 // Use expected type information for set generation
-                Self::generate_typed_set_literal(builder, elements, expected_type, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)
+                Self::generate_typed_set_literal(builder, elements, expected_type, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
+            }
+            Expression::Tuple { elements, .. } => {
+                Self::generate_tuple_literal(builder, elements, expected_type, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
             }
             _ => {
                 // For non-array expressions, use the regular helper
-                Self::generate_expression_helper(builder, expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)
+                Self::generate_expression_helper(builder, expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
+            }
+        }
+    }
+
+    /// Call `plat_panic(message)`, which aborts. The placeholder result only satisfies
+    /// the caller's need for a value; it is never observed.
+    fn generate_panic_call(builder: &mut FunctionBuilder, module: &mut ObjectModule, message_val: Value) -> Result<Value, CodegenError> {
+        let panic_sig = {
+            let mut sig = module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.params.push(AbiParam::new(I64)); // message pointer
+            sig
+        };
+
+        let panic_id = module.declare_function("plat_panic", Linkage::Import, &panic_sig)
+            .map_err(CodegenError::ModuleError)?;
+        let panic_ref = module.declare_func_in_func(panic_id, builder.func);
+
+        builder.ins().call(panic_ref, &[message_val]);
+        Ok(builder.ins().iconst(I64, 0))
+    }
+
+    /// Sign-extend or truncate an integer value to the given width
+    fn coerce_int_width(builder: &mut FunctionBuilder, value: Value, target: Type) -> Value {
+        let current = builder.func.dfg.value_type(value);
+        if current.bits() < target.bits() {
+            builder.ins().sextend(target, value)
+        } else if current.bits() > target.bits() {
+            builder.ins().ireduce(target, value)
+        } else {
+            value
+        }
+    }
+
+    /// Call a user-defined (or cross-module) function, evaluating arguments in source order and
+    /// binding them in the callee's declared order. Returns the call instruction so callers can
+    /// read every result of a multi-value tuple return.
+    fn generate_user_function_call(
+        builder: &mut FunctionBuilder,
+        function: &String,
+        args: &[ast::NamedArg],
+        variables: &HashMap<String, Variable>,
+        variable_types: &HashMap<String, VariableType>,
+        functions: &HashMap<String, FuncId>,
+        module: &mut ObjectModule,
+        string_pool: &mut StringPool,
+        variable_counter: &mut u32,
+        class_metadata: &HashMap<String, ClassMetadata>,
+        test_mode: bool,
+        symbol_table: Option<&plat_hir::ModuleSymbolTable>
+    ) -> Result<Inst, CodegenError> {
+        // Evaluate arguments first (needed to infer signature for cross-module calls)
+        let mut arg_values = Vec::new();
+        for arg in args {
+            let arg_val = Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+            arg_values.push(arg_val);
+        }
+
+        // Items imported by name (`use math::{add}`) are called through their qualified name
+        let imported = symbol_table
+            .filter(|_| !functions.contains_key(function))
+            .and_then(|table| table.imported_item(function))
+            .cloned();
+        let function = imported.as_ref().unwrap_or(function);
+
+        // Look up function in the functions map, keeping the name it resolved to
+        let (callee_name, func_id) = match functions.get_key_value(function) {
+            Some((name, &id)) => (name.as_str(), id),
+            None => {
+                // Function not found in map
+                // If it doesn't contain "::", it might be a same-module call with a simple name
+                // Try to find it in the functions map with a module prefix
+                if !function.contains("::") {
+                    // Look for any function that ends with "::function_name" in the map
+                    let suffix = format!("::{}", function);
+                    let maybe_mangled = functions.keys()
+                        .find(|k| k.ends_with(&suffix))
+                        .map(|k| k.as_str());
+
+                    if let Some(mangled_name) = maybe_mangled {
+                        (mangled_name, functions[mangled_name])
+                    } else {
+                        return Err(CodegenError::UndefinedFunction(function.clone()));
+                    }
+                } else {
+                    // Cross-module call - look up signature from symbol table
+                    let sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+
+                        // Try to get function signature from symbol table
+                        if let Some(sym_table) = symbol_table {
+                            if let Some(plat_hir::Symbol::Function(func_sig)) = sym_table.global_symbols.get(function) {
+                                // Use actual parameter types from symbol table
+                                for (_, param_type) in &func_sig.params {
+                                    let cranelift_type = Self::hir_type_to_cranelift(param_type);
+                                    sig.params.push(AbiParam::new(cranelift_type));
+                                }
+
+                                // Use actual return type from symbol table; tuples return one value per element
+                                if let HirType::Tuple(element_types) = &func_sig.return_type {
+                                    for element_type in element_types {
+                                        sig.returns.push(AbiParam::new(Self::hir_type_to_cranelift(element_type)));
+                                    }
+                                } else {
+                                    let return_cranelift_type = Self::hir_type_to_cranelift(&func_sig.return_type);
+                                    sig.returns.push(AbiParam::new(return_cranelift_type));
+                                }
+                            } else {
+                                // Fallback: infer from arguments if not in symbol table
+                                for arg_val in &arg_values {
+                                    let arg_type = builder.func.dfg.value_type(*arg_val);
+                                    sig.params.push(AbiParam::new(arg_type));
+                                }
+                                sig.returns.push(AbiParam::new(I64)); // Default to i64 return
+                            }
+                        } else {
+                            // No symbol table available - use old behavior
+                            for arg_val in &arg_values {
+                                let arg_type = builder.func.dfg.value_type(*arg_val);
+                                sig.params.push(AbiParam::new(arg_type));
+                            }
+                            sig.returns.push(AbiParam::new(I64));
+                        }
+
+                        sig
+                    };
+
+                    let func_id = module.declare_function(function, Linkage::Import, &sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    (function.as_str(), func_id)
+                }
+            }
+        };
+
+        // Arguments were evaluated in source order; bind them in the callee's declared order
+        if let Some(plat_hir::Symbol::Function(signature)) = symbol_table.and_then(|table| table.global_symbols.get(callee_name)) {
+            arg_values = Self::order_arguments_by_params(args, arg_values, &signature.params);
+        }
+
+        // Get function reference for calling
+        let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+        // Make the function call
+        Ok(builder.ins().call(func_ref, &arg_values))
+    }
+
+    /// The value of a call expression: unit (0) when the callee returns nothing, the single
+    /// result, or a GC tuple packed from a multi-value tuple return
+    fn call_result_value(builder: &mut FunctionBuilder, module: &mut ObjectModule, call: Inst) -> Result<Value, CodegenError> {
+        let results = builder.inst_results(call).to_vec();
+        match results.len() {
+            0 => Ok(builder.ins().iconst(I32, 0)),
+            1 => Ok(results[0]),
+            _ => Self::pack_tuple(builder, module, &results),
+        }
+    }
+
+    /// The elements of a tuple-typed expression as separate values. Tuple literals and calls to
+    /// functions with a multi-value tuple return never touch the heap; any other tuple is
+    /// loaded field by field from its GC struct.
+    fn generate_tuple_values(
+        builder: &mut FunctionBuilder,
+        expr: &Expression,
+        element_types: &[Type],
+        variables: &HashMap<String, Variable>,
+        variable_types: &HashMap<String, VariableType>,
+        functions: &HashMap<String, FuncId>,
+        module: &mut ObjectModule,
+        string_pool: &mut StringPool,
+        variable_counter: &mut u32,
+        class_metadata: &HashMap<String, ClassMetadata>,
+        test_mode: bool,
+        symbol_table: Option<&plat_hir::ModuleSymbolTable>
+    ) -> Result<Vec<Value>, CodegenError> {
+        match expr {
+            Expression::Tuple { elements, .. } if elements.len() == element_types.len() => {
+                let mut values = Vec::with_capacity(elements.len());
+                for (element, &element_type) in elements.iter().zip(element_types) {
+                    let value = Self::generate_expression_helper(builder, element, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let value = if element_type.is_int() && builder.func.dfg.value_type(value).is_int() {
+                        Self::coerce_int_width(builder, value, element_type)
+                    } else {
+                        value
+                    };
+                    values.push(value);
+                }
+                Ok(values)
             }
+            Expression::Call { function, args, .. } if Self::returns_multiple_values(function, functions, module) => {
+                let call = Self::generate_user_function_call(builder, function, args, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                Ok(builder.inst_results(call).to_vec())
+            }
+            _ => {
+                let tuple_ptr = Self::generate_expression_helper(builder, expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let (offsets, _size) = Self::tuple_layout(element_types);
+                Ok(element_types.iter().zip(offsets)
+                    .map(|(&element_type, offset)| builder.ins().load(element_type, MemFlags::new(), tuple_ptr, offset))
+                    .collect())
+            }
+        }
+    }
+
+    /// Whether `function` is a user function declared with a multi-value tuple return
+    fn returns_multiple_values(function: &str, functions: &HashMap<String, FuncId>, module: &ObjectModule) -> bool {
+        Self::lookup_function_id(function, functions)
+            .is_some_and(|id| module.declarations().get_function_decl(id).signature.returns.len() > 1)
+    }
+
+    /// Compute the byte offset of each tuple element and the total struct size
+    fn tuple_layout(element_types: &[Type]) -> (Vec<i32>, i64) {
+        let mut offsets = Vec::with_capacity(element_types.len());
+        let mut current_offset: i32 = 0;
+        for ty in element_types {
+            let alignment = ty.bytes() as i32;
+            if current_offset % alignment != 0 {
+                current_offset = ((current_offset / alignment) + 1) * alignment;
+            }
+            offsets.push(current_offset);
+            current_offset += alignment;
+        }
+        // Round up so the allocation stays 8-byte aligned
+        let size = ((current_offset as i64 + 7) / 8) * 8;
+        (offsets, size.max(8))
+    }
+
+    /// Generate a tuple literal as a GC-allocated struct of its elements
+    fn generate_tuple_literal(
+        builder: &mut FunctionBuilder,
+        elements: &[Expression],
+        expected_type: Option<&AstType>,
+        variables: &HashMap<String, Variable>,
+        variable_types: &HashMap<String, VariableType>,
+        functions: &HashMap<String, FuncId>,
+        module: &mut ObjectModule,
+        string_pool: &mut StringPool,
+        variable_counter: &mut u32,
+        class_metadata: &HashMap<String, ClassMetadata>,
+        test_mode: bool,
+        symbol_table: Option<&plat_hir::ModuleSymbolTable>
+    ) -> Result<Value, CodegenError> {
+        let expected_elements = match expected_type {
+            Some(AstType::Tuple(types)) if types.len() == elements.len() => Some(types),
+            _ => None,
+        };
+
+        let mut values = Vec::with_capacity(elements.len());
+        for (i, element) in elements.iter().enumerate() {
+            let expected = expected_elements.map(|types| &types[i]);
+            let value = Self::generate_expression_with_expected_type(builder, element, expected, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+            values.push(value);
+        }
+
+        Self::pack_tuple(builder, module, &values)
+    }
+
+    /// Store already-generated element values into a new GC-allocated tuple struct
+    fn pack_tuple(builder: &mut FunctionBuilder, module: &mut ObjectModule, values: &[Value]) -> Result<Value, CodegenError> {
+        let element_types: Vec<Type> = values.iter().map(|v| builder.func.dfg.value_type(*v)).collect();
+        let (offsets, size) = Self::tuple_layout(&element_types);
+
+        let gc_alloc_sig = {
+            let mut sig = module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.params.push(AbiParam::new(I64)); // size
+            sig.returns.push(AbiParam::new(I64)); // pointer
+            sig
+        };
+        let gc_alloc_id = module.declare_function("plat_gc_alloc", Linkage::Import, &gc_alloc_sig)
+            .map_err(CodegenError::ModuleError)?;
+        let gc_alloc_ref = module.declare_func_in_func(gc_alloc_id, builder.func);
+
+        let size_val = builder.ins().iconst(I64, size);
+        let call = builder.ins().call(gc_alloc_ref, &[size_val]);
+        let tuple_ptr = builder.inst_results(call)[0];
+
+        for (value, offset) in values.iter().zip(offsets) {
+            builder.ins().store(MemFlags::new(), *value, tuple_ptr, offset);
         }
+
+        Ok(tuple_ptr)
     }
 
     fn generate_typed_dict_literal(
@@ -1905,7 +4509,7 @@ impl CodeGenerator {
         variable_types: &HashMap<String, VariableType>,
         functions: &HashMap<String, FuncId>,
         module: &mut ObjectModule,
-        string_counter: &mut usize,
+        string_pool: &mut StringPool,
         variable_counter: &mut u32,
         class_metadata: &HashMap<String, ClassMetadata>,
         test_mode: bool,
@@ -1948,11 +4552,11 @@ impl CodeGenerator {
 
         for (key_expr, value_expr) in pairs {
             // Evaluate key (must be string)
-            let key_val = Self::generate_expression_helper(builder, key_expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+            let key_val = Self::generate_expression_helper(builder, key_expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
             keys.push(key_val);
 
             // Evaluate value
-            let value_val = Self::generate_expression_helper(builder, value_expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+            let value_val = Self::generate_expression_helper(builder, value_expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
             values.push(value_val);
 
             // Determine value type
@@ -2030,7 +4634,7 @@ impl CodeGenerator {
         variable_types: &HashMap<String, VariableType>,
         functions: &HashMap<String, FuncId>,
         module: &mut ObjectModule,
-        string_counter: &mut usize,
+        string_pool: &mut StringPool,
         variable_counter: &mut u32,
         class_metadata: &HashMap<String, ClassMetadata>,
         test_mode: bool,
@@ -2071,7 +4675,7 @@ impl CodeGenerator {
 
         for element_expr in elements {
             // Evaluate element
-            let value_val = Self::generate_expression_helper(builder, element_expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+            let value_val = Self::generate_expression_helper(builder, element_expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
             values.push(value_val);
 
             // Determine value type
@@ -2143,7 +4747,24 @@ impl CodeGenerator {
         variable_types: &HashMap<String, VariableType>,
         functions: &HashMap<String, FuncId>,
         module: &mut ObjectModule,
-        string_counter: &mut usize,
+        string_pool: &mut StringPool,
+        variable_counter: &mut u32,
+        class_metadata: &HashMap<String, ClassMetadata>,
+        test_mode: bool,
+        symbol_table: Option<&plat_hir::ModuleSymbolTable>
+    ) -> Result<Value, CodegenError> {
+        Self::generate_expression_kind(builder, expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
+            .map_err(|error| error.at(expr.span()))
+    }
+
+    fn generate_expression_kind(
+        builder: &mut FunctionBuilder,
+        expr: &Expression,
+        variables: &HashMap<String, Variable>,
+        variable_types: &HashMap<String, VariableType>,
+        functions: &HashMap<String, FuncId>,
+        module: &mut ObjectModule,
+        string_pool: &mut StringPool,
         variable_counter: &mut u32,
         class_metadata: &HashMap<String, ClassMetadata>,
         test_mode: bool,
@@ -2151,11 +4772,16 @@ impl CodeGenerator {
     ) -> Result<Value, CodegenError> {
         match expr {
             Expression::Literal(literal) => {
-                Self::generate_literal(builder, literal, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)
+                Self::generate_literal(builder, literal, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
             }
             Expression::Identifier { name, .. } => {
                 if let Some(&var) = variables.get(name) {
                     Ok(builder.use_var(var))
+                } else if let Some(global_addr) = Self::global_variable_address(builder, module, name) {
+                    let value_type = variable_types.get(name)
+                        .map(Self::variable_type_to_cranelift_type)
+                        .unwrap_or(I64);
+                    Ok(builder.ins().load(value_type, MemFlags::new(), global_addr, 0))
                 } else {
                     Err(CodegenError::UndefinedVariable(name.clone()))
                 }
@@ -2167,13 +4793,17 @@ impl CodeGenerator {
                     BinaryOp::Divide | BinaryOp::Modulo | BinaryOp::Equal |
                     BinaryOp::NotEqual | BinaryOp::Less | BinaryOp::LessEqual |
                     BinaryOp::Greater | BinaryOp::GreaterEqual => {
-                        let left_val = Self::generate_expression_helper(builder, left, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let right_val = Self::generate_expression_helper(builder, right, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let left_val = Self::generate_expression_helper(builder, left, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let right_val = Self::generate_expression_helper(builder, right, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Determine if we're working with floats or strings
                         let left_type = Self::infer_expression_type(left, variable_types);
-                        let is_float = matches!(left_type, VariableType::Float8 | VariableType::Float16 | VariableType::Float32 | VariableType::Float64);
-                        let is_string = matches!(left_type, VariableType::String);
+                        let is_float = matches!(left_type, VariableType::Float8 | VariableType::Float16 | VariableType::Float32 | VariableType::Float64)
+                            || builder.func.dfg.value_type(left_val).is_float();
+                        // Either side may carry the type, e.g. `text.trim() == "abc"`
+                        let is_string = matches!(left_type, VariableType::String)
+                            || (matches!(op, BinaryOp::Equal | BinaryOp::NotEqual)
+                                && matches!(Self::infer_expression_type(right, variable_types), VariableType::String));
 
                         match op {
                             BinaryOp::Add => {
@@ -2222,6 +4852,17 @@ impl CodeGenerator {
                                 }
                             }
                             BinaryOp::Modulo => Ok(builder.ins().srem(left_val, right_val)),
+                            // Collections and class instances compare by contents, not identity
+                            BinaryOp::Equal | BinaryOp::NotEqual if !is_string && Self::compares_structurally(&left_type, functions) => {
+                                let equal = Self::generate_structural_equality(builder, module, functions, left_val, right_val, &left_type)?
+                                    .expect("heap types always have a structural comparison");
+                                if matches!(op, BinaryOp::Equal) {
+                                    Ok(equal)
+                                } else {
+                                    let not_equal = builder.ins().icmp_imm(IntCC::Equal, equal, 0);
+                                    Ok(builder.ins().uextend(I32, not_equal))
+                                }
+                            }
                             BinaryOp::Equal => {
                                 if is_string {
                                     // String equality comparison
@@ -2234,7 +4875,7 @@ impl CodeGenerator {
                                         sig
                                     };
 
-                                    let func_id = module.declare_function("plat_string_equals", Linkage::Import, &func_sig)
+                                    let func_id = module.declare_function("plat_string_eq", Linkage::Import, &func_sig)
                                         .map_err(CodegenError::ModuleError)?;
                                     let func_ref = module.declare_func_in_func(func_id, builder.func);
 
@@ -2260,7 +4901,7 @@ impl CodeGenerator {
                                         sig
                                     };
 
-                                    let func_id = module.declare_function("plat_string_equals", Linkage::Import, &func_sig)
+                                    let func_id = module.declare_function("plat_string_eq", Linkage::Import, &func_sig)
                                         .map_err(CodegenError::ModuleError)?;
                                     let func_ref = module.declare_func_in_func(func_id, builder.func);
 
@@ -2319,11 +4960,11 @@ impl CodeGenerator {
                     }
                     BinaryOp::And => {
                         // Short-circuit AND: evaluate left first
-                        let left_val = Self::generate_expression_helper(builder, left, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let left_val = Self::generate_expression_helper(builder, left, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // If left is false, don't evaluate right
                         let zero = builder.ins().iconst(I32, 0);
-                        let left_is_true = builder.ins().icmp_imm(IntCC::NotEqual, left_val, 0);
+                        let left_is_true = Self::is_truthy(builder, left_val);
 
                         // Create blocks for short-circuit evaluation
                         let eval_right_block = builder.create_block();
@@ -2340,8 +4981,8 @@ impl CodeGenerator {
                         builder.seal_block(eval_right_block);
 
                         // Now evaluate the right operand
-                        let right_val = Self::generate_expression_helper(builder, right, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let right_is_true = builder.ins().icmp_imm(IntCC::NotEqual, right_val, 0);
+                        let right_val = Self::generate_expression_helper(builder, right, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let right_is_true = Self::is_truthy(builder, right_val);
                         let right_as_i32 = builder.ins().uextend(I32, right_is_true);
                         builder.ins().jump(merge_block, &[right_as_i32]);
 
@@ -2353,11 +4994,11 @@ impl CodeGenerator {
                     }
                     BinaryOp::Or => {
                         // Short-circuit OR: evaluate left first
-                        let left_val = Self::generate_expression_helper(builder, left, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let left_val = Self::generate_expression_helper(builder, left, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // If left is true, don't evaluate right
                         let one = builder.ins().iconst(I32, 1);
-                        let left_is_false = builder.ins().icmp_imm(IntCC::Equal, left_val, 0);
+                        let left_is_true = Self::is_truthy(builder, left_val);
 
                         // Create blocks for short-circuit evaluation
                         let eval_right_block = builder.create_block();
@@ -2367,15 +5008,15 @@ impl CodeGenerator {
                         builder.append_block_param(merge_block, I32);
 
                         // If left is false, evaluate right; otherwise, short-circuit to true
-                        builder.ins().brif(left_is_false, eval_right_block, &[], merge_block, &[one]);
+                        builder.ins().brif(left_is_true, merge_block, &[one], eval_right_block, &[]);
 
                         // Evaluate right expression
                         builder.switch_to_block(eval_right_block);
                         builder.seal_block(eval_right_block);
 
                         // Now evaluate the right operand
-                        let right_val = Self::generate_expression_helper(builder, right, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let right_is_true = builder.ins().icmp_imm(IntCC::NotEqual, right_val, 0);
+                        let right_val = Self::generate_expression_helper(builder, right, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let right_is_true = Self::is_truthy(builder, right_val);
                         let right_as_i32 = builder.ins().uextend(I32, right_is_true);
                         builder.ins().jump(merge_block, &[right_as_i32]);
 
@@ -2388,10 +5029,16 @@ impl CodeGenerator {
                 }
             }
             Expression::Unary { op, operand, .. } => {
-                let operand_val = Self::generate_expression_helper(builder, operand, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let operand_val = Self::generate_expression_helper(builder, operand, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                 match op {
-                    UnaryOp::Negate => Ok(builder.ins().ineg(operand_val)),
+                    UnaryOp::Negate => {
+                        if builder.func.dfg.value_type(operand_val).is_float() {
+                            Ok(builder.ins().fneg(operand_val))
+                        } else {
+                            Ok(builder.ins().ineg(operand_val))
+                        }
+                    }
                     UnaryOp::Not => {
                         // Convert to boolean (0 = false, non-zero = true), then flip
                         let zero = builder.ins().iconst(I32, 0);
@@ -2401,13 +5048,16 @@ impl CodeGenerator {
                 }
             }
             Expression::Assignment { target, value, .. } => {
-                let val = Self::generate_expression_helper(builder, value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let val = Self::generate_expression_helper(builder, value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                 match target.as_ref() {
                     Expression::Identifier { name, .. } => {
                         if let Some(&var) = variables.get(name) {
                             builder.def_var(var, val);
                             Ok(val)
+                        } else if let Some(global_addr) = Self::global_variable_address(builder, module, name) {
+                            builder.ins().store(MemFlags::new(), val, global_addr, 0);
+                            Ok(val)
                         } else {
                             Err(CodegenError::UndefinedVariable(name.clone()))
                         }
@@ -2420,7 +5070,7 @@ impl CodeGenerator {
                         // 3. Store the value at object_ptr + offset
 
                         // Get the object value (class instance pointer)
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Determine class name from the object type
                         let class_name = Self::get_class_name(object, variable_types)
@@ -2429,10 +5079,10 @@ impl CodeGenerator {
                             ))?;
 
                         // Look up field offset from class metadata
-                        let (offset, _field_type) = Self::get_field_info_static(class_metadata, &class_name, member)?;
+                        let (offset, field_type) = Self::get_field_info_static(class_metadata, &class_name, member)?;
 
                         // Store the value at the computed offset
-                        builder.ins().store(MemFlags::new(), val, object_val, offset);
+                        Self::store_class_field(builder, module, field_type, val, object_val, offset)?;
 
                         Ok(val)
                     }
@@ -2456,23 +5106,49 @@ impl CodeGenerator {
                     // Generate code for the condition
                     let condition_val = Self::generate_expression_helper(
                         builder, &condition_arg.value, variables, variable_types,
-                        functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table
+                        functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
             )?;
 
                     // Generate code for the optional message
                     let message_val = if let Some(msg_arg) = message_arg {
                         Self::generate_expression_helper(
                             builder, &msg_arg.value, variables, variable_types,
-                            functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table
+                            functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
             )?
                     } else {
                         // Use null pointer for default message
                         builder.ins().iconst(I64, 0)
                     };
 
-                    // In test mode, use plat_assert_test which returns Bool
-                    // In normal mode, use plat_assert which exits on failure
-                    if test_mode {
+                    // Source location attached by `annotate_assert_locations` (test mode with source only)
+                    let location = args.iter().find(|arg| arg.name == "__line")
+                        .zip(args.iter().find(|arg| arg.name == "__column"));
+
+                    // In test mode, use plat_assert_test which returns Bool (plat_assert_test_loc when
+                    // the location is known). In normal mode, use plat_assert which exits on failure
+                    if let (true, Some((line_arg, column_arg))) = (test_mode, location) {
+                        let line_val = Self::generate_expression_helper(builder, &line_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let column_val = Self::generate_expression_helper(builder, &column_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        let assert_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I32)); // condition (bool as i32)
+                            sig.params.push(AbiParam::new(I64)); // message pointer
+                            sig.params.push(AbiParam::new(I32)); // line
+                            sig.params.push(AbiParam::new(I32)); // column
+                            sig.returns.push(AbiParam::new(I32)); // returns bool
+                            sig
+                        };
+
+                        let assert_id = module.declare_function("plat_assert_test_loc", Linkage::Import, &assert_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let assert_ref = module.declare_func_in_func(assert_id, builder.func);
+
+                        let call = builder.ins().call(assert_ref, &[condition_val, message_val, line_val, column_val]);
+                        let result = builder.inst_results(call)[0];
+                        return Ok(result);
+                    } else if test_mode {
                         // Declare plat_assert_test function (returns bool)
                         let assert_sig = {
                             let mut sig = module.make_signature();
@@ -2553,6 +5229,69 @@ impl CodeGenerator {
                     return Ok(result);
                 }
 
+                // Handle built-in __bench_reset function (bench runner internal)
+                if function == "__bench_reset" {
+                    let reset_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig
+                    };
+
+                    let reset_id = module.declare_function("plat_bench_reset", Linkage::Import, &reset_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let reset_ref = module.declare_func_in_func(reset_id, builder.func);
+
+                    builder.ins().call(reset_ref, &[]);
+                    return Ok(builder.ins().iconst(I64, 0));
+                }
+
+                // Handle built-in __bench_report function (bench runner internal)
+                if function == "__bench_report" {
+                    let name_arg = args.iter().find(|arg| arg.name == "name")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("__bench_report missing 'name' parameter".to_string()))?;
+                    let name_val = Self::generate_expression_helper(builder, &name_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let report_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // bench name
+                        sig
+                    };
+
+                    let report_id = module.declare_function("plat_bench_report", Linkage::Import, &report_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let report_ref = module.declare_func_in_func(report_id, builder.func);
+
+                    builder.ins().call(report_ref, &[name_val]);
+                    return Ok(builder.ins().iconst(I64, 0));
+                }
+
+                // Handle built-in __describe_object function (print/interpolation of a class without to_string)
+                if function == "__describe_object" {
+                    let name_arg = args.iter().find(|arg| arg.name == "type_name")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("__describe_object missing 'type_name' parameter".to_string()))?;
+                    let object_arg = args.iter().find(|arg| arg.name == "object")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("__describe_object missing 'object' parameter".to_string()))?;
+                    let name_val = Self::generate_expression_helper(builder, &name_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let object_val = Self::generate_expression_helper(builder, &object_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let describe_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // type name
+                        sig.params.push(AbiParam::new(I64)); // instance pointer
+                        sig.returns.push(AbiParam::new(I64)); // description string
+                        sig
+                    };
+
+                    let describe_id = module.declare_function("plat_object_describe", Linkage::Import, &describe_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let describe_ref = module.declare_func_in_func(describe_id, builder.func);
+
+                    let call = builder.ins().call(describe_ref, &[name_val, object_val]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
                 // Handle built-in tcp_listen function
                 if function == "tcp_listen" {
                     // tcp_listen(host: String, port: Int32) -> Result<Int32, String>
@@ -2561,8 +5300,8 @@ impl CodeGenerator {
                     let port_arg = args.iter().find(|arg| arg.name == "port")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("tcp_listen missing 'port' parameter".to_string()))?;
 
-                    let host_val = Self::generate_expression_helper(builder, &host_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let port_val = Self::generate_expression_helper(builder, &port_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let host_val = Self::generate_expression_helper(builder, &host_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let port_val = Self::generate_expression_helper(builder, &port_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2587,7 +5326,7 @@ impl CodeGenerator {
                     let listener_arg = args.iter().find(|arg| arg.name == "listener")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("tcp_accept missing 'listener' parameter".to_string()))?;
 
-                    let listener_val = Self::generate_expression_helper(builder, &listener_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let listener_val = Self::generate_expression_helper(builder, &listener_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2613,8 +5352,8 @@ impl CodeGenerator {
                     let port_arg = args.iter().find(|arg| arg.name == "port")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("tcp_connect missing 'port' parameter".to_string()))?;
 
-                    let host_val = Self::generate_expression_helper(builder, &host_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let port_val = Self::generate_expression_helper(builder, &port_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let host_val = Self::generate_expression_helper(builder, &host_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let port_val = Self::generate_expression_helper(builder, &port_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2641,8 +5380,8 @@ impl CodeGenerator {
                     let max_bytes_arg = args.iter().find(|arg| arg.name == "max_bytes")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("tcp_read missing 'max_bytes' parameter".to_string()))?;
 
-                    let socket_val = Self::generate_expression_helper(builder, &socket_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let max_bytes_val = Self::generate_expression_helper(builder, &max_bytes_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let socket_val = Self::generate_expression_helper(builder, &socket_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let max_bytes_val = Self::generate_expression_helper(builder, &max_bytes_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2669,8 +5408,8 @@ impl CodeGenerator {
                     let data_arg = args.iter().find(|arg| arg.name == "data")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("tcp_write missing 'data' parameter".to_string()))?;
 
-                    let socket_val = Self::generate_expression_helper(builder, &socket_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let data_val = Self::generate_expression_helper(builder, &data_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let socket_val = Self::generate_expression_helper(builder, &socket_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let data_val = Self::generate_expression_helper(builder, &data_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2695,7 +5434,7 @@ impl CodeGenerator {
                     let socket_arg = args.iter().find(|arg| arg.name == "socket")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("tcp_close missing 'socket' parameter".to_string()))?;
 
-                    let socket_val = Self::generate_expression_helper(builder, &socket_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let socket_val = Self::generate_expression_helper(builder, &socket_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2713,6 +5452,98 @@ impl CodeGenerator {
                     return Ok(builder.inst_results(call)[0]);
                 }
 
+                // Handle built-in udp_bind function
+                if function == "udp_bind" {
+                    // udp_bind(host: String, port: Int32) -> Result<Int32, String>
+                    let host_arg = args.iter().find(|arg| arg.name == "host")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("udp_bind missing 'host' parameter".to_string()))?;
+                    let port_arg = args.iter().find(|arg| arg.name == "port")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("udp_bind missing 'port' parameter".to_string()))?;
+
+                    let host_val = Self::generate_expression_helper(builder, &host_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let port_val = Self::generate_expression_helper(builder, &port_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // host (string pointer)
+                        sig.params.push(AbiParam::new(I32)); // port
+                        sig.returns.push(AbiParam::new(I64)); // Result enum pointer
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_udp_bind", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[host_val, port_val]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
+                // Handle built-in udp_send_to function
+                if function == "udp_send_to" {
+                    // udp_send_to(socket: Int32, data: String, host: String, port: Int32) -> Result<Int32, String>
+                    let socket_arg = args.iter().find(|arg| arg.name == "socket")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("udp_send_to missing 'socket' parameter".to_string()))?;
+                    let data_arg = args.iter().find(|arg| arg.name == "data")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("udp_send_to missing 'data' parameter".to_string()))?;
+                    let host_arg = args.iter().find(|arg| arg.name == "host")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("udp_send_to missing 'host' parameter".to_string()))?;
+                    let port_arg = args.iter().find(|arg| arg.name == "port")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("udp_send_to missing 'port' parameter".to_string()))?;
+
+                    let socket_val = Self::generate_expression_helper(builder, &socket_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let data_val = Self::generate_expression_helper(builder, &data_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let host_val = Self::generate_expression_helper(builder, &host_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let port_val = Self::generate_expression_helper(builder, &port_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I32)); // socket fd
+                        sig.params.push(AbiParam::new(I64)); // data (string pointer)
+                        sig.params.push(AbiParam::new(I64)); // host (string pointer)
+                        sig.params.push(AbiParam::new(I32)); // port
+                        sig.returns.push(AbiParam::new(I64)); // Result enum pointer
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_udp_send_to", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[socket_val, data_val, host_val, port_val]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
+                // Handle built-in udp_recv_from function
+                if function == "udp_recv_from" {
+                    // udp_recv_from(socket: Int32, max_bytes: Int32) -> Result<String, String>
+                    let socket_arg = args.iter().find(|arg| arg.name == "socket")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("udp_recv_from missing 'socket' parameter".to_string()))?;
+                    let max_bytes_arg = args.iter().find(|arg| arg.name == "max_bytes")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("udp_recv_from missing 'max_bytes' parameter".to_string()))?;
+
+                    let socket_val = Self::generate_expression_helper(builder, &socket_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let max_bytes_val = Self::generate_expression_helper(builder, &max_bytes_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I32)); // socket fd
+                        sig.params.push(AbiParam::new(I32)); // max_bytes
+                        sig.returns.push(AbiParam::new(I64)); // Result enum pointer
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_udp_recv_from", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[socket_val, max_bytes_val]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
                 // Handle built-in file_open function
                 if function == "file_open" {
                     // file_open(path: String, mode: String) -> Result<Int32, String>
@@ -2721,8 +5552,8 @@ impl CodeGenerator {
                     let mode_arg = args.iter().find(|arg| arg.name == "mode")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_open missing 'mode' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let mode_val = Self::generate_expression_helper(builder, &mode_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let mode_val = Self::generate_expression_helper(builder, &mode_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2741,6 +5572,86 @@ impl CodeGenerator {
                     return Ok(builder.inst_results(call)[0]);
                 }
 
+                // Whole-file file_read, told apart from the fd form by its 'path' argument
+                if function == "file_read" && args.iter().any(|arg| arg.name == "path") {
+                    // file_read(path: String) -> Result<String, String>
+                    let path_arg = args.iter().find(|arg| arg.name == "path")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("file_read missing 'path' parameter".to_string()))?;
+
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // path string pointer
+                        sig.returns.push(AbiParam::new(I64)); // Result enum pointer
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_file_read_all", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[path_val]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
+                // Whole-file file_write, told apart from the fd form by its 'path' argument
+                if function == "file_write" && args.iter().any(|arg| arg.name == "path") {
+                    // file_write(path: String, contents: String) -> Result<Int32, String>
+                    let path_arg = args.iter().find(|arg| arg.name == "path")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("file_write missing 'path' parameter".to_string()))?;
+                    let contents_arg = args.iter().find(|arg| arg.name == "contents")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("file_write missing 'contents' parameter".to_string()))?;
+
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let contents_val = Self::generate_expression_helper(builder, &contents_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // path string pointer
+                        sig.params.push(AbiParam::new(I64)); // contents string pointer
+                        sig.returns.push(AbiParam::new(I64)); // Result enum pointer
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_file_write_all", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[path_val, contents_val]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
+                // Handle built-in file_append function
+                if function == "file_append" {
+                    // file_append(path: String, contents: String) -> Result<Int32, String>
+                    let path_arg = args.iter().find(|arg| arg.name == "path")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("file_append missing 'path' parameter".to_string()))?;
+                    let contents_arg = args.iter().find(|arg| arg.name == "contents")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("file_append missing 'contents' parameter".to_string()))?;
+
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let contents_val = Self::generate_expression_helper(builder, &contents_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // path string pointer
+                        sig.params.push(AbiParam::new(I64)); // contents string pointer
+                        sig.returns.push(AbiParam::new(I64)); // Result enum pointer
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_file_append", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[path_val, contents_val]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
                 // Handle built-in file_read function
                 if function == "file_read" {
                     // file_read(fd: Int32, max_bytes: Int32) -> Result<String, String>
@@ -2749,8 +5660,8 @@ impl CodeGenerator {
                     let max_bytes_arg = args.iter().find(|arg| arg.name == "max_bytes")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_read missing 'max_bytes' parameter".to_string()))?;
 
-                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let max_bytes_val = Self::generate_expression_helper(builder, &max_bytes_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let max_bytes_val = Self::generate_expression_helper(builder, &max_bytes_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2777,8 +5688,8 @@ impl CodeGenerator {
                     let data_arg = args.iter().find(|arg| arg.name == "data")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_write missing 'data' parameter".to_string()))?;
 
-                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let data_val = Self::generate_expression_helper(builder, &data_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let data_val = Self::generate_expression_helper(builder, &data_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2803,7 +5714,7 @@ impl CodeGenerator {
                     let fd_arg = args.iter().find(|arg| arg.name == "fd")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_close missing 'fd' parameter".to_string()))?;
 
-                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2827,7 +5738,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_exists missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2851,7 +5762,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_size missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2875,7 +5786,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_is_dir missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2899,7 +5810,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_delete missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2926,8 +5837,8 @@ impl CodeGenerator {
                     let new_path_arg = args.iter().find(|arg| arg.name == "new_path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_rename missing 'new_path' parameter".to_string()))?;
 
-                    let old_path_val = Self::generate_expression_helper(builder, &old_path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let new_path_val = Self::generate_expression_helper(builder, &new_path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let old_path_val = Self::generate_expression_helper(builder, &old_path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let new_path_val = Self::generate_expression_helper(builder, &new_path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2952,7 +5863,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("dir_create missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -2976,7 +5887,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("dir_create_all missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3000,7 +5911,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("dir_remove missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3024,7 +5935,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("dir_list missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3050,8 +5961,8 @@ impl CodeGenerator {
                     let max_bytes_arg = args.iter().find(|arg| arg.name == "max_bytes")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_read_binary missing 'max_bytes' parameter".to_string()))?;
 
-                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let max_bytes_val = Self::generate_expression_helper(builder, &max_bytes_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let max_bytes_val = Self::generate_expression_helper(builder, &max_bytes_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3078,8 +5989,8 @@ impl CodeGenerator {
                     let data_arg = args.iter().find(|arg| arg.name == "data")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_write_binary missing 'data' parameter".to_string()))?;
 
-                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let data_val = Self::generate_expression_helper(builder, &data_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let data_val = Self::generate_expression_helper(builder, &data_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3108,9 +6019,9 @@ impl CodeGenerator {
                     let whence_arg = args.iter().find(|arg| arg.name == "whence")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_seek missing 'whence' parameter".to_string()))?;
 
-                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let offset_val = Self::generate_expression_helper(builder, &offset_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let whence_val = Self::generate_expression_helper(builder, &whence_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let offset_val = Self::generate_expression_helper(builder, &offset_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let whence_val = Self::generate_expression_helper(builder, &whence_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3136,7 +6047,7 @@ impl CodeGenerator {
                     let fd_arg = args.iter().find(|arg| arg.name == "fd")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_tell missing 'fd' parameter".to_string()))?;
 
-                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3160,7 +6071,7 @@ impl CodeGenerator {
                     let fd_arg = args.iter().find(|arg| arg.name == "fd")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_rewind missing 'fd' parameter".to_string()))?;
 
-                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let fd_val = Self::generate_expression_helper(builder, &fd_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3187,8 +6098,8 @@ impl CodeGenerator {
                     let mode_arg = args.iter().find(|arg| arg.name == "mode")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_chmod missing 'mode' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let mode_val = Self::generate_expression_helper(builder, &mode_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let mode_val = Self::generate_expression_helper(builder, &mode_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3213,7 +6124,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_get_permissions missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3237,7 +6148,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_modified_time missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3261,7 +6172,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_created_time missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3288,8 +6199,8 @@ impl CodeGenerator {
                     let link_arg = args.iter().find(|arg| arg.name == "link")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("symlink_create missing 'link' parameter".to_string()))?;
 
-                    let target_val = Self::generate_expression_helper(builder, &target_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let link_val = Self::generate_expression_helper(builder, &link_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let target_val = Self::generate_expression_helper(builder, &target_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let link_val = Self::generate_expression_helper(builder, &link_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3314,7 +6225,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("symlink_read missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3338,7 +6249,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("file_is_symlink missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3362,7 +6273,7 @@ impl CodeGenerator {
                     let path_arg = args.iter().find(|arg| arg.name == "path")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("symlink_delete missing 'path' parameter".to_string()))?;
 
-                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let path_val = Self::generate_expression_helper(builder, &path_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3386,7 +6297,7 @@ impl CodeGenerator {
                     let capacity_arg = args.iter().find(|arg| arg.name == "capacity")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("channel_init missing 'capacity' parameter".to_string()))?;
 
-                    let capacity_val = Self::generate_expression_helper(builder, &capacity_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let capacity_val = Self::generate_expression_helper(builder, &capacity_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     // TODO: Infer the channel element type from context
                     // For now, default to Int32
@@ -3408,6 +6319,84 @@ impl CodeGenerator {
                     return Ok(builder.inst_results(call)[0]);
                 }
 
+                // Handle built-in atomic integer functions: an AtomicInt is a pointer to a
+                // GC-allocated i64 cell that is only touched with atomic instructions
+                if matches!(function.as_str(), "atomic_init" | "atomic_add" | "atomic_load" | "atomic_store" | "compare_and_swap") {
+                    let mut arg_val = |name: &str| -> Result<Value, CodegenError> {
+                        let arg = args.iter().find(|arg| arg.name == name)
+                            .ok_or_else(|| CodegenError::UnsupportedFeature(format!("{} missing '{}' parameter", function, name)))?;
+                        Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
+                    };
+                    let flags = MemFlags::trusted();
+
+                    return match function.as_str() {
+                        "atomic_init" => {
+                            // atomic_init(value: Int64) -> AtomicInt
+                            let initial = arg_val("value")?;
+                            let alloc_sig = {
+                                let mut sig = module.make_signature();
+                                sig.call_conv = CallConv::SystemV;
+                                sig.params.push(AbiParam::new(I64)); // size
+                                sig.returns.push(AbiParam::new(I64)); // pointer
+                                sig
+                            };
+                            let alloc_id = module.declare_function("plat_gc_alloc_atomic", Linkage::Import, &alloc_sig)
+                                .map_err(CodegenError::ModuleError)?;
+                            let alloc_ref = module.declare_func_in_func(alloc_id, builder.func);
+                            let size = builder.ins().iconst(I64, 8);
+                            let call = builder.ins().call(alloc_ref, &[size]);
+                            let cell = builder.inst_results(call)[0];
+                            builder.ins().atomic_store(flags, initial, cell);
+                            Ok(cell)
+                        }
+                        "atomic_add" => {
+                            // atomic_add(ptr: AtomicInt, delta: Int64) -> Int64 (the previous value)
+                            let cell = arg_val("ptr")?;
+                            let delta = arg_val("delta")?;
+                            Ok(builder.ins().atomic_rmw(I64, flags, AtomicRmwOp::Add, cell, delta))
+                        }
+                        "atomic_load" => {
+                            // atomic_load(ptr: AtomicInt) -> Int64
+                            let cell = arg_val("ptr")?;
+                            Ok(builder.ins().atomic_load(I64, flags, cell))
+                        }
+                        "atomic_store" => {
+                            // atomic_store(ptr: AtomicInt, val: Int64)
+                            let cell = arg_val("ptr")?;
+                            let value = arg_val("val")?;
+                            builder.ins().atomic_store(flags, value, cell);
+                            Ok(builder.ins().iconst(I32, 0))
+                        }
+                        _ => {
+                            // compare_and_swap(ptr: AtomicInt, expected: Int64, new: Int64) -> Bool
+                            let cell = arg_val("ptr")?;
+                            let expected = arg_val("expected")?;
+                            let replacement = arg_val("new")?;
+                            let previous = builder.ins().atomic_cas(flags, cell, expected, replacement);
+                            let swapped = builder.ins().icmp(IntCC::Equal, previous, expected);
+                            Ok(builder.ins().uextend(I32, swapped))
+                        }
+                    };
+                }
+
+                // Handle built-in mutex_init function
+                if function == "mutex_init" {
+                    // mutex_init() -> Mutex
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.returns.push(AbiParam::new(I64)); // mutex handle
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_mutex_create", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
                 // Handle built-in time_now function
                 if function == "time_now" {
                     // time_now() -> Int64
@@ -3426,13 +6415,32 @@ impl CodeGenerator {
                     return Ok(builder.inst_results(call)[0]);
                 }
 
+                // Handle built-in monotonic clock functions
+                if function == "now_millis" || function == "now_nanos" {
+                    // now_millis() -> Int64, now_nanos() -> Int64
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.returns.push(AbiParam::new(I64)); // elapsed monotonic time
+                        sig
+                    };
+
+                    let runtime_name = if function == "now_millis" { "plat_now_millis" } else { "plat_now_nanos" };
+                    let func_id = module.declare_function(runtime_name, Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
                 // Handle built-in time_sleep function
                 if function == "time_sleep" {
                     // time_sleep(millis: Int64) -> Bool
                     let millis_arg = args.iter().find(|arg| arg.name == "millis")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("time_sleep missing 'millis' parameter".to_string()))?;
 
-                    let millis_val = Self::generate_expression_helper(builder, &millis_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let millis_val = Self::generate_expression_helper(builder, &millis_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3450,13 +6458,13 @@ impl CodeGenerator {
                     return Ok(builder.ins().iconst(I32, 1));
                 }
 
-                // Handle built-in env_get function
-                if function == "env_get" {
-                    // env_get(name: String) -> Option<String>
+                // Handle built-in env_get function (also available as the shorter `env`)
+                if function == "env_get" || function == "env" {
+                    // env(name: String) -> Option<String>
                     let name_arg = args.iter().find(|arg| arg.name == "name")
-                        .ok_or_else(|| CodegenError::UnsupportedFeature("env_get missing 'name' parameter".to_string()))?;
+                        .ok_or_else(|| CodegenError::UnsupportedFeature(format!("{} missing 'name' parameter", function)))?;
 
-                    let name_val = Self::generate_expression_helper(builder, &name_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let name_val = Self::generate_expression_helper(builder, &name_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3482,8 +6490,8 @@ impl CodeGenerator {
                     let value_arg = args.iter().find(|arg| arg.name == "value")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("env_set missing 'value' parameter".to_string()))?;
 
-                    let name_val = Self::generate_expression_helper(builder, &name_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let value_val = Self::generate_expression_helper(builder, &value_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let name_val = Self::generate_expression_helper(builder, &name_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let value_val = Self::generate_expression_helper(builder, &value_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3524,20 +6532,28 @@ impl CodeGenerator {
 
                 // Handle built-in random_int function
                 if function == "random_int" {
-                    // random_int(min: Int64, max: Int64) -> Int64
+                    // random_int(min: T, max: T) -> T for T = Int32 or Int64
                     let min_arg = args.iter().find(|arg| arg.name == "min")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("random_int missing 'min' parameter".to_string()))?;
                     let max_arg = args.iter().find(|arg| arg.name == "max")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("random_int missing 'max' parameter".to_string()))?;
 
-                    let min_val = Self::generate_expression_helper(builder, &min_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    let max_val = Self::generate_expression_helper(builder, &max_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let min_val = Self::generate_expression_helper(builder, &min_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let max_val = Self::generate_expression_helper(builder, &max_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    // Int32 bounds are widened for the runtime call and the result narrowed back
+                    let is_int32 = builder.func.dfg.value_type(min_val) == I32;
+                    let (min_val, max_val) = if is_int32 {
+                        (builder.ins().sextend(I64, min_val), builder.ins().sextend(I64, max_val))
+                    } else {
+                        (min_val, max_val)
+                    };
 
                     let func_sig = {
                         let mut sig = module.make_signature();
                         sig.call_conv = CallConv::SystemV;
-                        sig.params.push(AbiParam::new(I64)); // min
-                        sig.params.push(AbiParam::new(I64)); // max
+                        sig.params.push(AbiParam::new(I64)); // min (inclusive)
+                        sig.params.push(AbiParam::new(I64)); // max (exclusive)
                         sig.returns.push(AbiParam::new(I64)); // random value
                         sig
                     };
@@ -3547,7 +6563,11 @@ impl CodeGenerator {
                     let func_ref = module.declare_func_in_func(func_id, builder.func);
 
                     let call = builder.ins().call(func_ref, &[min_val, max_val]);
-                    return Ok(builder.inst_results(call)[0]);
+                    let result = builder.inst_results(call)[0];
+                    if is_int32 {
+                        return Ok(builder.ins().ireduce(I32, result));
+                    }
+                    return Ok(result);
                 }
 
                 // Handle built-in random_float function
@@ -3568,13 +6588,75 @@ impl CodeGenerator {
                     return Ok(builder.inst_results(call)[0]);
                 }
 
+                // Handle built-in seed_random function
+                if function == "seed_random" {
+                    // seed_random(seed: Int64) -> Unit
+                    let seed_arg = args.iter().find(|arg| arg.name == "seed")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("seed_random missing 'seed' parameter".to_string()))?;
+
+                    let seed_val = Self::generate_expression_helper(builder, &seed_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // seed
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_seed_random", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    builder.ins().call(func_ref, &[seed_val]);
+                    return Ok(builder.ins().iconst(I32, 0));
+                }
+
+                // Handle built-in format function
+                if function == "format" {
+                    // format(template: String, args: List[String]) -> String
+                    let template_arg = args.iter().find(|arg| arg.name == "template")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("format missing 'template' parameter".to_string()))?;
+                    let args_arg = args.iter().find(|arg| arg.name == "args")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("format missing 'args' parameter".to_string()))?;
+
+                    let template_val = Self::generate_expression_helper(builder, &template_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    // An inline array literal must be built as a List[String]
+                    let args_type = AstType::List(Box::new(AstType::String));
+                    let args_val = Self::generate_expression_with_expected_type(builder, &args_arg.value, Some(&args_type), variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.params.push(AbiParam::new(I64)); // template
+                        sig.params.push(AbiParam::new(I64)); // args array
+                        sig.returns.push(AbiParam::new(I64)); // formatted string
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_format", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[template_val, args_val]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
+                // Handle built-in panic function
+                if function == "panic" {
+                    // panic(message: String) -> Never
+                    let message_arg = args.iter().find(|arg| arg.name == "message")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("panic missing 'message' parameter".to_string()))?;
+                    let message_val = Self::generate_expression_helper(builder, &message_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    return Self::generate_panic_call(builder, module, message_val);
+                }
+
                 // Handle built-in process_exit function
                 if function == "process_exit" {
                     // process_exit(code: Int32) -> Never
                     let code_arg = args.iter().find(|arg| arg.name == "code")
                         .ok_or_else(|| CodegenError::UnsupportedFeature("process_exit missing 'code' parameter".to_string()))?;
 
-                    let code_val = Self::generate_expression_helper(builder, &code_arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let code_val = Self::generate_expression_helper(builder, &code_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     let func_sig = {
                         let mut sig = module.make_signature();
@@ -3593,6 +6675,39 @@ impl CodeGenerator {
                     return Ok(builder.ins().iconst(I32, 0));
                 }
 
+                // Handle built-in drop function (a user-defined `drop` takes precedence)
+                if function == "drop" && !functions.contains_key("drop") {
+                    // drop(value: T) -> Unit
+                    let value_arg = args.iter().find(|arg| arg.name == "value")
+                        .ok_or_else(|| CodegenError::UnsupportedFeature("drop missing 'value' parameter".to_string()))?;
+                    let value_val = Self::generate_expression_helper(builder, &value_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                    // Only GC-allocated containers and class instances are freed; strings may
+                    // point at static data and enums may be packed, so everything else is a no-op
+                    let value_type = Self::infer_expression_type(&value_arg.value, variable_types);
+                    let frees_value = match &value_type {
+                        VariableType::Array(_) | VariableType::Dict(_, _) | VariableType::Set => true,
+                        VariableType::Class(class_name) => class_metadata.contains_key(class_name),
+                        _ => false,
+                    };
+                    if frees_value {
+                        let free_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // pointer
+                            sig
+                        };
+                        // An array's elements live in a separate buffer that a view borrows from
+                        // its source, so the runtime decides whether to free it
+                        let free_name = if matches!(value_type, VariableType::Array(_)) { "plat_array_free" } else { "plat_gc_free" };
+                        let free_id = module.declare_function(free_name, Linkage::Import, &free_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let free_ref = module.declare_func_in_func(free_id, builder.func);
+                        builder.ins().call(free_ref, &[value_val]);
+                    }
+                    return Ok(builder.ins().iconst(I32, 0));
+                }
+
                 // Handle built-in process_args function
                 if function == "process_args" {
                     // process_args() -> String
@@ -3611,6 +6726,24 @@ impl CodeGenerator {
                     return Ok(builder.inst_results(call)[0]);
                 }
 
+                // Handle built-in args function
+                if function == "args" {
+                    // args() -> List[String]
+                    let func_sig = {
+                        let mut sig = module.make_signature();
+                        sig.call_conv = CallConv::SystemV;
+                        sig.returns.push(AbiParam::new(I64)); // string array pointer
+                        sig
+                    };
+
+                    let func_id = module.declare_function("plat_args", Linkage::Import, &func_sig)
+                        .map_err(CodegenError::ModuleError)?;
+                    let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                    let call = builder.ins().call(func_ref, &[]);
+                    return Ok(builder.inst_results(call)[0]);
+                }
+
                 // Check if this is actually a class constructor with no arguments (e.g., Empty())
                 // This happens when a class has no fields and uses a default init
                 if args.is_empty() && class_metadata.contains_key(function) {
@@ -3618,7 +6751,9 @@ impl CodeGenerator {
                     // Generate the same code as ConstructorCall but with no field initialization
                     let metadata = class_metadata.get(function).unwrap();
                     let class_size = metadata.size as i64;
-                    let has_vtable = metadata.has_vtable;
+                    // generate_vtables emits no vtable object for classes without virtual methods
+                    // generate_vtables emits no vtable object for classes without virtual methods
+                let has_vtable = metadata.has_vtable && !metadata.virtual_methods.is_empty();
 
                     // Allocate memory using GC
                     let gc_alloc_sig = {
@@ -3653,217 +6788,106 @@ impl CodeGenerator {
                         let vtable_addr = builder.ins().global_value(I64, vtable_ref);
 
                         // Store vtable pointer at offset 0
-                        builder.ins().store(MemFlags::new(), vtable_addr, class_ptr, 0);
-                    }
-
-                    // No field initialization needed (no fields)
-                    // Return the class pointer
-                    return Ok(class_ptr);
-                }
-
-                // Evaluate arguments first (needed to infer signature for cross-module calls)
-                let mut arg_values = Vec::new();
-                for arg in args {
-                    let arg_val = Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                    arg_values.push(arg_val);
-                }
-
-                // Look up function in the functions map
-                let func_id = match functions.get(function) {
-                    Some(&id) => id,
-                    None => {
-                        // Function not found in map
-                        // If it doesn't contain "::", it might be a same-module call with a simple name
-                        // Try to find it in the functions map with a module prefix
-                        if !function.contains("::") {
-                            // Look for any function that ends with "::function_name" in the map
-                            let suffix = format!("::{}", function);
-                            let maybe_mangled = functions.keys()
-                                .find(|k| k.ends_with(&suffix))
-                                .map(|k| k.as_str());
-
-                            if let Some(mangled_name) = maybe_mangled {
-                                functions[mangled_name]
-                            } else {
-                                return Err(CodegenError::UndefinedFunction(function.clone()));
-                            }
-                        } else {
-                            // Cross-module call - look up signature from symbol table
-                            let sig = {
-                                let mut sig = module.make_signature();
-                                sig.call_conv = CallConv::SystemV;
-
-                                // Try to get function signature from symbol table
-                                if let Some(sym_table) = symbol_table {
-                                    if let Some(plat_hir::Symbol::Function(func_sig)) = sym_table.global_symbols.get(function) {
-                                        // Use actual parameter types from symbol table
-                                        for (_, param_type) in &func_sig.params {
-                                            let cranelift_type = Self::hir_type_to_cranelift(param_type);
-                                            sig.params.push(AbiParam::new(cranelift_type));
-                                        }
-
-                                        // Use actual return type from symbol table
-                                        let return_cranelift_type = Self::hir_type_to_cranelift(&func_sig.return_type);
-                                        sig.returns.push(AbiParam::new(return_cranelift_type));
-                                    } else {
-                                        // Fallback: infer from arguments if not in symbol table
-                                        for arg_val in &arg_values {
-                                            let arg_type = builder.func.dfg.value_type(*arg_val);
-                                            sig.params.push(AbiParam::new(arg_type));
-                                        }
-                                        sig.returns.push(AbiParam::new(I64)); // Default to i64 return
-                                    }
-                                } else {
-                                    // No symbol table available - use old behavior
-                                    for arg_val in &arg_values {
-                                        let arg_type = builder.func.dfg.value_type(*arg_val);
-                                        sig.params.push(AbiParam::new(arg_type));
-                                    }
-                                    sig.returns.push(AbiParam::new(I64));
-                                }
-
-                                sig
-                            };
-
-                            module.declare_function(function, Linkage::Import, &sig)
-                                .map_err(CodegenError::ModuleError)?
-                        }
-                    }
-                };
-
-                // Get function reference for calling
-                let func_ref = module.declare_func_in_func(func_id, builder.func);
-
-                // Make the function call
-                let call = builder.ins().call(func_ref, &arg_values);
-                let results = builder.inst_results(call);
+                        builder.ins().store(MemFlags::new(), vtable_addr, class_ptr, 0);
+                    }
 
-                // Return the first result (or unit if no results)
-                if results.is_empty() {
-                    // Function returns void, return 0
-                    Ok(builder.ins().iconst(I32, 0))
-                } else {
-                    Ok(results[0])
+                    // No field initialization needed (no fields)
+                    // Return the class pointer
+                    return Ok(class_ptr);
                 }
+
+                let call = Self::generate_user_function_call(builder, function, args, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                Self::call_result_value(builder, module, call)
             }
             Expression::Index { object, index, .. } => {
-                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                let index_val = Self::generate_expression_helper(builder, index, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let index_val = Self::generate_expression_helper(builder, index, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                // Dispatch on the container type; every runtime lookup returns (found, value)
+                let (func_name, key_type, element_type) = if Self::is_dict_type(object, variable_types) {
+                    let value_type = match Self::infer_expression_type(object, variable_types) {
+                        VariableType::Dict(_, value_type) => *value_type,
+                        _ => VariableType::Int64,
+                    };
+                    ("plat_dict_get_safe", I64, value_type)
+                } else if Self::is_string_type(object, variable_types) {
+                    ("plat_string_char_at_safe", I32, VariableType::String)
+                } else {
+                    ("plat_array_get_safe", I32, Self::infer_element_type(object, variable_types))
+                };
 
-                // Use safe get that returns Option<T>
                 let func_sig = {
                     let mut sig = module.make_signature();
                     sig.call_conv = CallConv::SystemV;
-                    sig.params.push(AbiParam::new(I64)); // array pointer
-                    sig.params.push(AbiParam::new(I32)); // index
+                    sig.params.push(AbiParam::new(I64)); // container pointer
+                    sig.params.push(AbiParam::new(key_type)); // index or key pointer
                     sig.returns.push(AbiParam::new(I32)); // found (bool)
                     sig.returns.push(AbiParam::new(I64)); // value
                     sig
                 };
 
-                let func_id = module.declare_function("plat_array_get_safe", Linkage::Import, &func_sig)
+                let func_id = module.declare_function(func_name, Linkage::Import, &func_sig)
                     .map_err(CodegenError::ModuleError)?;
                 let func_ref = module.declare_func_in_func(func_id, builder.func);
 
-                // Ensure index is i32 (convert from i64 if needed)
+                // Ensure integer indices are i32 (convert from i64 if needed)
                 let index_type = builder.func.dfg.value_type(index_val);
-                eprintln!("DEBUG: Index type for array access: {:?}", index_type);
-                let index_i32 = if index_type == I64 {
-                    eprintln!("DEBUG: Converting index from i64 to i32");
+                let index_arg = if key_type == I32 && index_type == I64 {
                     builder.ins().ireduce(I32, index_val)
                 } else {
                     index_val
                 };
 
-                let call = builder.ins().call(func_ref, &[object_val, index_i32]);
+                let call = builder.ins().call(func_ref, &[object_val, index_arg]);
                 let results = builder.inst_results(call);
 
                 let found = results[0]; // i32: 0 or 1
                 let value = results[1]; // i64
 
-                // Compute discriminants for Option variants
-                let none_disc = Self::variant_discriminant("Option", "None") as i64;
-                let some_disc = Self::variant_discriminant("Option", "Some") as i64;
-
-                // Create blocks for conditional
-                let some_block = builder.create_block();
-                let none_block = builder.create_block();
-                let merge_block = builder.create_block();
-
-                // Add parameter to merge block for the result
-                builder.append_block_param(merge_block, I64);
-
-                // Branch based on found
-                builder.ins().brif(found, some_block, &[], none_block, &[]);
-
-                // Some block: create Option::Some(value)
-                builder.switch_to_block(some_block);
-                builder.seal_block(some_block);
-
-                // Check if value needs heap allocation (for pointer types)
-                let element_type = Self::infer_element_type(object, variable_types);
-                let needs_heap = matches!(element_type,
-                    VariableType::String | VariableType::Array(_) | VariableType::Class(_) | VariableType::Enum(_)
-                );
-
-                let some_value = if needs_heap {
-                    // Allocate: [discriminant:i32][padding:i32][value:i64]
-                    let gc_alloc_sig = {
-                        let mut sig = module.make_signature();
-                        sig.call_conv = CallConv::SystemV;
-                        sig.params.push(AbiParam::new(I64));
-                        sig.returns.push(AbiParam::new(I64));
-                        sig
-                    };
-                    let gc_alloc_id = module.declare_function("plat_gc_alloc", Linkage::Import, &gc_alloc_sig)
-                        .map_err(CodegenError::ModuleError)?;
-                    let gc_alloc_ref = module.declare_func_in_func(gc_alloc_id, builder.func);
-
-                    let size = builder.ins().iconst(I64, 16);
-                    let alloc_call = builder.ins().call(gc_alloc_ref, &[size]);
-                    let ptr = builder.inst_results(alloc_call)[0];
-
-                    let disc_val = builder.ins().iconst(I32, some_disc);
-                    builder.ins().store(MemFlags::new(), disc_val, ptr, 0);
-                    builder.ins().store(MemFlags::new(), value, ptr, 8);
-
-                    ptr
-                } else {
-                    // Pack: discriminant in high 32 bits, value in low 32 bits
-                    let disc_64 = builder.ins().iconst(I64, some_disc);
-                    let disc_shifted = builder.ins().ishl_imm(disc_64, 32);
-                    let value_32 = builder.ins().ireduce(I32, value);
-                    let value_64 = builder.ins().uextend(I64, value_32);
-                    builder.ins().bor(disc_shifted, value_64)
-                };
-
-                builder.ins().jump(merge_block, &[some_value]);
-
-                // None block: create Option::None
-                builder.switch_to_block(none_block);
-                builder.seal_block(none_block);
-
-                let none_disc_64 = builder.ins().iconst(I64, none_disc);
-                let none_value = builder.ins().ishl_imm(none_disc_64, 32);
-
-                builder.ins().jump(merge_block, &[none_value]);
-
-                // Merge block
-                builder.switch_to_block(merge_block);
-                builder.seal_block(merge_block);
-
-                let result = builder.block_params(merge_block)[0];
-                Ok(result)
+                Self::generate_option_from_found(builder, module, found, value, &element_type)
             }
             Expression::MethodCall { object, method, args, .. } => {
                 eprintln!("DEBUG MethodCall: method='{}', object={:?}", method, object);
                 match method.as_str() {
+                    // User-defined enum methods take the enum value as implicit self
+                    method_name if Self::enum_method_name(object, method_name, variable_types, functions).is_some() => {
+                        let qualified_name = Self::enum_method_name(object, method_name, variable_types, functions).unwrap();
+                        let func_id = functions[&qualified_name];
+
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let mut call_args = vec![object_val];
+                        for arg in args {
+                            let arg_val = Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                            call_args.push(arg_val);
+                        }
+
+                        let func_ref = module.declare_func_in_func(func_id, builder.func);
+                        let call = builder.ins().call(func_ref, &call_args);
+                        Self::call_result_value(builder, module, call)
+                    }
+                    // Option/Result discriminant checks
+                    "is_some" | "is_none" | "is_ok" | "is_err"
+                        if Self::get_class_name(object, variable_types).map_or(true, |name| name == "Option" || name == "Result") =>
+                    {
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let disc_i32 = Self::generate_enum_discriminant(builder, object_val);
+
+                        let variant = match method.as_str() {
+                            "is_some" => "Some",
+                            "is_none" => "None",
+                            "is_ok" => "Ok",
+                            _ => "Err",
+                        };
+                        let expected = builder.ins().iconst(I32, Self::variant_discriminant("", variant) as i64);
+                        let cmp = builder.ins().icmp(IntCC::Equal, disc_i32, expected);
+                        Ok(builder.ins().uextend(I32, cmp))
+                    }
                     "len" => {
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature("len() method takes no arguments".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "len".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Declare plat_array_len function
                         let len_sig = {
@@ -3890,10 +6914,10 @@ impl CodeGenerator {
                     // Type-dispatched methods
                     "length" => {
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature("length() method takes no arguments".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "length".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Determine object type for dispatch
                         let is_set = Self::is_set_type(object, variable_types);
@@ -3955,11 +6979,11 @@ impl CodeGenerator {
                     }
                     "concat" => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("concat() method takes exactly one argument".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "concat".to_string(), expected: 1, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let arg_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let arg_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -3979,16 +7003,45 @@ impl CodeGenerator {
                     }
                     "contains" => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("contains() method takes exactly one argument".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "contains".to_string(), expected: 1, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let arg_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let arg_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Determine object type for dispatch
                         let is_set = Self::is_set_type(object, variable_types);
+                        let is_list = Self::is_list_type(object, variable_types);
 
-                        if is_set {
+                        if is_list {
+                            // Array contains; the runtime compares strings by content and everything else by value
+                            let element_tag = Self::array_element_tag(&Self::infer_element_type(object, variable_types));
+                            let element_tag_val = builder.ins().iconst(I32, element_tag as i64);
+
+                            let func_sig = {
+                                let mut sig = module.make_signature();
+                                sig.call_conv = CallConv::SystemV;
+                                sig.params.push(AbiParam::new(I64)); // array pointer
+                                sig.params.push(AbiParam::new(I64)); // value (as i64)
+                                sig.params.push(AbiParam::new(I32)); // element type tag
+                                sig.returns.push(AbiParam::new(I32)); // bool as i32
+                                sig
+                            };
+
+                            let func_id = module.declare_function("plat_array_contains", Linkage::Import, &func_sig)
+                                .map_err(CodegenError::ModuleError)?;
+                            let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                            // Convert value to i64 if needed; the runtime widens elements with sign extension
+                            let value_64 = if builder.func.dfg.value_type(arg_val) == I64 {
+                                arg_val
+                            } else {
+                                builder.ins().sextend(I64, arg_val)
+                            };
+
+                            let call = builder.ins().call(func_ref, &[object_val, value_64, element_tag_val]);
+                            Ok(builder.inst_results(call)[0])
+                        } else if is_set {
                             // Set contains
                             let value_type = Self::get_set_value_type(&args[0].value, variable_types);
 
@@ -4037,11 +7090,11 @@ impl CodeGenerator {
                     }
                     "starts_with" | "ends_with" => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature(format!("{}() method takes exactly one argument", method)));
+                            return Err(CodegenError::ArityMismatch { function: method.to_string(), expected: 1, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let arg_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let arg_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4062,10 +7115,10 @@ impl CodeGenerator {
                     }
                     "trim" | "trim_left" | "trim_right" => {
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature(format!("{}() method takes no arguments", method)));
+                            return Err(CodegenError::ArityMismatch { function: method.to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4085,12 +7138,12 @@ impl CodeGenerator {
                     }
                     "replace" | "replace_all" => {
                         if args.len() != 2 {
-                            return Err(CodegenError::UnsupportedFeature(format!("{}() method takes exactly two arguments", method)));
+                            return Err(CodegenError::ArityMismatch { function: method.to_string(), expected: 2, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let from_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let to_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let from_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let to_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4111,35 +7164,71 @@ impl CodeGenerator {
                         Ok(builder.inst_results(call)[0])
                     }
                     "split" => {
-                        if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("split() method takes exactly one argument".to_string()));
+                        if args.is_empty() || args.len() > 2 {
+                            return Err(CodegenError::UnsupportedFeature("split() method takes a delimiter and an optional 'limit'".to_string()));
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let delimiter_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let delimiter_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let mut call_args = vec![object_val, delimiter_val];
+
+                        // With a limit, the last part keeps any remaining delimiters
+                        let func_name = if let Some(limit_arg) = args.get(1) {
+                            let limit_val = Self::generate_expression_helper(builder, &limit_arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                            call_args.push(limit_val);
+                            "plat_string_splitn"
+                        } else {
+                            "plat_string_split"
+                        };
 
                         let func_sig = {
                             let mut sig = module.make_signature();
                             sig.call_conv = CallConv::SystemV;
                             sig.params.push(AbiParam::new(I64)); // string pointer
                             sig.params.push(AbiParam::new(I64)); // delimiter string pointer
+                            if call_args.len() == 3 {
+                                sig.params.push(AbiParam::new(I32)); // limit
+                            }
+                            sig.returns.push(AbiParam::new(I64)); // result array pointer
+                            sig
+                        };
+
+                        let func_id = module.declare_function(func_name, Linkage::Import, &func_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                        let call = builder.ins().call(func_ref, &call_args);
+                        Ok(builder.inst_results(call)[0])
+                    }
+                    "lines" | "chars" => {
+                        if !args.is_empty() {
+                            return Err(CodegenError::ArityMismatch { function: method.to_string(), expected: 0, got: args.len(), span: None });
+                        }
+
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        let func_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // string pointer
                             sig.returns.push(AbiParam::new(I64)); // result array pointer
                             sig
                         };
 
-                        let func_id = module.declare_function("plat_string_split", Linkage::Import, &func_sig)
+                        let func_name = if method == "lines" { "plat_string_lines" } else { "plat_string_chars" };
+                        let func_id = module.declare_function(func_name, Linkage::Import, &func_sig)
                             .map_err(CodegenError::ModuleError)?;
                         let func_ref = module.declare_func_in_func(func_id, builder.func);
 
-                        let call = builder.ins().call(func_ref, &[object_val, delimiter_val]);
+                        let call = builder.ins().call(func_ref, &[object_val]);
                         Ok(builder.inst_results(call)[0])
                     }
                     "is_alpha" | "is_numeric" | "is_alphanumeric" => {
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature(format!("{}() method takes no arguments", method)));
+                            return Err(CodegenError::ArityMismatch { function: method.to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4159,10 +7248,10 @@ impl CodeGenerator {
                     }
                     "parse_int" | "parse_int64" | "parse_float" | "parse_bool" => {
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature(format!("{}() method takes no arguments", method)));
+                            return Err(CodegenError::ArityMismatch { function: method.to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4182,12 +7271,12 @@ impl CodeGenerator {
                     }
                     "substring" => {
                         if args.len() != 2 {
-                            return Err(CodegenError::UnsupportedFeature("substring() method takes exactly two arguments (start_index, end_index)".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "substring".to_string(), expected: 2, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let start_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let end_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let start_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let end_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4208,11 +7297,11 @@ impl CodeGenerator {
                     }
                     "char_at" => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("char_at() method takes exactly one argument (index)".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "char_at".to_string(), expected: 1, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4233,11 +7322,11 @@ impl CodeGenerator {
                     // Array methods (only for lists, not dicts)
                     "get" if Self::is_list_type(object, variable_types) => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("get() method takes exactly one argument".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "get".to_string(), expected: 1, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4263,24 +7352,19 @@ impl CodeGenerator {
 
                         let call = builder.ins().call(func_ref, &[object_val, index_i32]);
                         let results = builder.inst_results(call);
+                        let (found, value) = (results[0], results[1]);
 
-                        // For now, return packed Option<T> as i64 (found in high bit, value in low bits)
-                        // found is i32 (0 or 1), value is i64
-                        let found = results[0];
-                        let value = results[1];
-                        let found_64 = builder.ins().uextend(I64, found);
-                        let found_shifted = builder.ins().ishl_imm(found_64, 63);
-                        let result = builder.ins().bor(found_shifted, value);
-                        Ok(result)
+                        let element_type = Self::infer_element_type(object, variable_types);
+                        Self::generate_option_from_found(builder, module, found, value, &element_type)
                     }
                     "set" => {
                         if args.len() != 2 {
-                            return Err(CodegenError::UnsupportedFeature("set() method takes exactly two arguments".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "set".to_string(), expected: 2, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let value_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let value_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Convert value to i64 if needed
                         let value_64 = if builder.func.dfg.value_type(value_val) == I32 {
@@ -4310,11 +7394,11 @@ impl CodeGenerator {
                     }
                     "push" => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("push() method takes exactly one argument".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "push".to_string(), expected: 1, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Convert value to i64 if needed
                         let value_64 = if builder.func.dfg.value_type(value_val) == I32 {
@@ -4343,10 +7427,10 @@ impl CodeGenerator {
                     }
                     "pop" => {
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature("pop() method takes no arguments".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "pop".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4366,11 +7450,11 @@ impl CodeGenerator {
                     }
                     "append" => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("append() method takes exactly one argument".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "append".to_string(), expected: 1, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Convert value to i64 if needed
                         let value_64 = if builder.func.dfg.value_type(value_val) == I32 {
@@ -4399,12 +7483,12 @@ impl CodeGenerator {
                     }
                     "insert_at" => {
                         if args.len() != 2 {
-                            return Err(CodegenError::UnsupportedFeature("insert_at() method takes exactly two arguments".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "insert_at".to_string(), expected: 2, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let value_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let value_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Convert value to i64 if needed
                         let value_64 = if builder.func.dfg.value_type(value_val) == I32 {
@@ -4434,11 +7518,11 @@ impl CodeGenerator {
                     }
                     "remove_at" => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("remove_at() method takes exactly one argument".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "remove_at".to_string(), expected: 1, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let index_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
@@ -4454,23 +7538,57 @@ impl CodeGenerator {
                             .map_err(CodegenError::ModuleError)?;
                         let func_ref = module.declare_func_in_func(func_id, builder.func);
 
-                        let call = builder.ins().call(func_ref, &[object_val, index_val]);
+                        // Ensure index is i32 (convert from i64 if needed)
+                        let index_i32 = if builder.func.dfg.value_type(index_val) == I64 {
+                            builder.ins().ireduce(I32, index_val)
+                        } else {
+                            index_val
+                        };
+
+                        let call = builder.ins().call(func_ref, &[object_val, index_i32]);
                         let results = builder.inst_results(call);
+                        let (found, value) = (results[0], results[1]);
 
-                        // Return packed Option<T> as i64 (found in high bit, value in low bits)
-                        let found = results[0];
-                        let value = results[1];
-                        let found_64 = builder.ins().uextend(I64, found);
-                        let found_shifted = builder.ins().ishl_imm(found_64, 63);
-                        let result = builder.ins().bor(found_shifted, value);
-                        Ok(result)
+                        let element_type = Self::infer_element_type(object, variable_types);
+                        Self::generate_option_from_found(builder, module, found, value, &element_type)
+                    }
+                    // Shallow copy of a collection; elements (including strings) are shared, the storage is not
+                    "clone" if Self::is_list_type(object, variable_types) || Self::is_dict_type(object, variable_types) || Self::is_set_type(object, variable_types) => {
+                        if !args.is_empty() {
+                            return Err(CodegenError::ArityMismatch { function: "clone".to_string(), expected: 0, got: args.len(), span: None });
+                        }
+
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        let func_name = if Self::is_set_type(object, variable_types) {
+                            "plat_set_clone"
+                        } else if Self::is_dict_type(object, variable_types) {
+                            "plat_dict_clone"
+                        } else {
+                            "plat_array_clone"
+                        };
+
+                        let func_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // collection pointer
+                            sig.returns.push(AbiParam::new(I64)); // new collection pointer
+                            sig
+                        };
+
+                        let func_id = module.declare_function(func_name, Linkage::Import, &func_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                        let call = builder.ins().call(func_ref, &[object_val]);
+                        Ok(builder.inst_results(call)[0])
                     }
                     "clear" => {
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature("clear() method takes no arguments".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "clear".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Determine object type for dispatch
                         let is_set = Self::is_set_type(object, variable_types);
@@ -4501,212 +7619,581 @@ impl CodeGenerator {
                                 sig
                             };
 
-                            let func_id = module.declare_function("plat_dict_clear", Linkage::Import, &func_sig)
-                                .map_err(CodegenError::ModuleError)?;
-                            let func_ref = module.declare_func_in_func(func_id, builder.func);
+                            let func_id = module.declare_function("plat_dict_clear", Linkage::Import, &func_sig)
+                                .map_err(CodegenError::ModuleError)?;
+                            let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                            builder.ins().call(func_ref, &[object_val]);
+                            let zero = builder.ins().iconst(I32, 0);
+                            Ok(zero) // Unit type represented as 0
+                        } else {
+                            // Array clear (default case)
+                            let func_sig = {
+                                let mut sig = module.make_signature();
+                                sig.call_conv = CallConv::SystemV;
+                                sig.params.push(AbiParam::new(I64)); // array pointer (mutable)
+                                sig.returns.push(AbiParam::new(I32)); // success (bool)
+                                sig
+                            };
+
+                            let func_id = module.declare_function("plat_array_clear", Linkage::Import, &func_sig)
+                                .map_err(CodegenError::ModuleError)?;
+                            let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                            let _call = builder.ins().call(func_ref, &[object_val]);
+                            // Returns success as i32, but we're treating this as void operation for now
+                            let zero = builder.ins().iconst(I32, 0);
+                            Ok(zero)
+                        }
+                    }
+                    "index_of" => {
+                        if args.len() != 1 {
+                            return Err(CodegenError::ArityMismatch { function: "index_of".to_string(), expected: 1, got: args.len(), span: None });
+                        }
+
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        if Self::is_string_type(object, variable_types) {
+                            // The runtime returns (found: i32, index: i32), which the C ABI
+                            // packs into a single register: found in the low 32 bits, index in the high 32 bits
+                            let func_sig = {
+                                let mut sig = module.make_signature();
+                                sig.call_conv = CallConv::SystemV;
+                                sig.params.push(AbiParam::new(I64)); // string pointer
+                                sig.params.push(AbiParam::new(I64)); // needle pointer
+                                sig.returns.push(AbiParam::new(I64)); // (found, index) pair
+                                sig
+                            };
+
+                            let func_id = module.declare_function("plat_string_index_of", Linkage::Import, &func_sig)
+                                .map_err(CodegenError::ModuleError)?;
+                            let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                            let call = builder.ins().call(func_ref, &[object_val, value_val]);
+                            let pair = builder.inst_results(call)[0];
+                            let found = builder.ins().ireduce(I32, pair);
+                            let index_64 = builder.ins().ushr_imm(pair, 32);
+
+                            // Build Option<i32>: discriminant in high 32 bits, index in low 32 bits
+                            let none_disc = Self::variant_discriminant("Option", "None") as i64;
+                            let some_disc = Self::variant_discriminant("Option", "Some") as i64;
+                            let disc = {
+                                let some_disc_val = builder.ins().iconst(I64, some_disc);
+                                let none_disc_val = builder.ins().iconst(I64, none_disc);
+                                builder.ins().select(found, some_disc_val, none_disc_val)
+                            };
+                            let disc_shifted = builder.ins().ishl_imm(disc, 32);
+                            let zero = builder.ins().iconst(I64, 0);
+                            let payload = builder.ins().select(found, index_64, zero);
+                            let result = builder.ins().bor(disc_shifted, payload);
+                            return Ok(result);
+                        }
+
+                        // Widen the same way the runtime reads Int32 elements
+                        let value_64 = if builder.func.dfg.value_type(value_val) == I32 {
+                            builder.ins().sextend(I64, value_val)
+                        } else {
+                            value_val
+                        };
+
+                        let func_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // array pointer
+                            sig.params.push(AbiParam::new(I64)); // value to find
+                            sig.returns.push(AbiParam::new(I32)); // found (bool)
+                            sig.returns.push(AbiParam::new(I64)); // index
+                            sig
+                        };
+
+                        let func_id = module.declare_function("plat_array_index_of", Linkage::Import, &func_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                        let call = builder.ins().call(func_ref, &[object_val, value_64]);
+                        let results = builder.inst_results(call);
+
+                        let (found, index) = (results[0], results[1]);
+
+                        Self::generate_option_from_found(builder, module, found, index, &VariableType::Int32)
+                    }
+                    "count" => {
+                        if args.len() != 1 {
+                            return Err(CodegenError::ArityMismatch { function: "count".to_string(), expected: 1, got: args.len(), span: None });
+                        }
+
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        // Convert value to i64 if needed
+                        let value_64 = if builder.func.dfg.value_type(value_val) == I32 {
+                            builder.ins().uextend(I64, value_val)
+                        } else {
+                            value_val
+                        };
+
+                        let func_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // array pointer
+                            sig.params.push(AbiParam::new(I64)); // value to count
+                            sig.returns.push(AbiParam::new(I32)); // count
+                            sig
+                        };
+
+                        let func_id = module.declare_function("plat_array_count", Linkage::Import, &func_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                        let call = builder.ins().call(func_ref, &[object_val, value_64]);
+                        Ok(builder.inst_results(call)[0])
+                    }
+                    "slice" => {
+                        if args.len() != 2 {
+                            return Err(CodegenError::ArityMismatch { function: "slice".to_string(), expected: 2, got: args.len(), span: None });
+                        }
+
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let start_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let end_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        let func_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // array pointer
+                            sig.params.push(AbiParam::new(I32)); // start index
+                            sig.params.push(AbiParam::new(I32)); // end index
+                            sig.returns.push(AbiParam::new(I64)); // new array pointer
+                            sig
+                        };
+
+                        let func_id = module.declare_function("plat_array_slice", Linkage::Import, &func_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                        let call = builder.ins().call(func_ref, &[object_val, start_val, end_val]);
+                        Ok(builder.inst_results(call)[0])
+                    }
+                    "view" if !Self::is_class_type(object, variable_types) => {
+                        if args.len() != 2 {
+                            return Err(CodegenError::ArityMismatch { function: "view".to_string(), expected: 2, got: args.len(), span: None });
+                        }
+
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let start_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let end_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        let func_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // array pointer
+                            sig.params.push(AbiParam::new(I32)); // start index
+                            sig.params.push(AbiParam::new(I32)); // end index
+                            sig.returns.push(AbiParam::new(I64)); // view sharing the array's storage
+                            sig
+                        };
+
+                        let func_id = module.declare_function("plat_array_view", Linkage::Import, &func_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let func_ref = module.declare_func_in_func(func_id, builder.func);
 
-                            builder.ins().call(func_ref, &[object_val]);
-                            let zero = builder.ins().iconst(I32, 0);
-                            Ok(zero) // Unit type represented as 0
-                        } else {
-                            // Array clear (default case)
-                            let func_sig = {
-                                let mut sig = module.make_signature();
-                                sig.call_conv = CallConv::SystemV;
-                                sig.params.push(AbiParam::new(I64)); // array pointer (mutable)
-                                sig.returns.push(AbiParam::new(I32)); // success (bool)
-                                sig
-                            };
+                        let call = builder.ins().call(func_ref, &[object_val, start_val, end_val]);
+                        Ok(builder.inst_results(call)[0])
+                    }
+                    // abs/min/max/clamp/rem_euclid keep the receiver's width; abs also covers floats
+                    "abs" | "min" | "max" | "clamp" | "rem_euclid" if !Self::is_class_type(object, variable_types) => {
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let receiver_type = builder.func.dfg.value_type(object_val);
+                        if receiver_type.is_float() && method == "abs" {
+                            return Ok(builder.ins().fabs(object_val));
+                        }
+                        if !receiver_type.is_int() {
+                            return Err(CodegenError::TypeMismatch { expected: format!("integer receiver for {}()", method), got: receiver_type.to_string(), span: None });
+                        }
 
-                            let func_id = module.declare_function("plat_array_clear", Linkage::Import, &func_sig)
-                                .map_err(CodegenError::ModuleError)?;
-                            let func_ref = module.declare_func_in_func(func_id, builder.func);
+                        let mut named_arg = |name: &str, builder: &mut FunctionBuilder| -> Result<Value, CodegenError> {
+                            let arg = args.iter().find(|arg| arg.name == name)
+                                .ok_or_else(|| CodegenError::UnsupportedFeature(format!("{}() requires a '{}' parameter", method, name)))?;
+                            Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
+                        };
 
-                            let _call = builder.ins().call(func_ref, &[object_val]);
-                            // Returns success as i32, but we're treating this as void operation for now
-                            let zero = builder.ins().iconst(I32, 0);
-                            Ok(zero)
-                        }
+                        Ok(match method.as_str() {
+                            "abs" => builder.ins().iabs(object_val),
+                            "min" => {
+                                let other = named_arg("other", builder)?;
+                                builder.ins().smin(object_val, other)
+                            }
+                            "max" => {
+                                let other = named_arg("other", builder)?;
+                                builder.ins().smax(object_val, other)
+                            }
+                            "rem_euclid" => {
+                                // `%` truncates (sign of the dividend); shift a negative remainder
+                                // up by |other| so the result is always in 0..|other|
+                                let other = named_arg("other", builder)?;
+                                let remainder = builder.ins().srem(object_val, other);
+                                let shifted_up = builder.ins().iadd(remainder, other);
+                                let shifted_down = builder.ins().isub(remainder, other);
+                                let other_negative = builder.ins().icmp_imm(IntCC::SignedLessThan, other, 0);
+                                let adjusted = builder.ins().select(other_negative, shifted_down, shifted_up);
+                                let remainder_negative = builder.ins().icmp_imm(IntCC::SignedLessThan, remainder, 0);
+                                builder.ins().select(remainder_negative, adjusted, remainder)
+                            }
+                            _ => {
+                                let low = named_arg("low", builder)?;
+                                let high = named_arg("high", builder)?;
+                                let at_least_low = builder.ins().smax(object_val, low);
+                                builder.ins().smin(at_least_low, high)
+                            }
+                        })
                     }
-                    "index_of" => {
-                        if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("index_of() method takes exactly one argument".to_string()));
+                    // Strings hash their contents; integers of every width hash their sign-extended value
+                    "hash" if !Self::is_class_type(object, variable_types) => {
+                        if !args.is_empty() {
+                            return Err(CodegenError::ArityMismatch { function: "hash".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-
-                        // Convert value to i64 if needed
-                        let value_64 = if builder.func.dfg.value_type(value_val) == I32 {
-                            builder.ins().uextend(I64, value_val)
+                        let is_string = Self::infer_expression_type(object, variable_types) == VariableType::String;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = if builder.func.dfg.value_type(object_val) == I64 {
+                            object_val
                         } else {
-                            value_val
+                            builder.ins().sextend(I64, object_val)
                         };
 
                         let func_sig = {
                             let mut sig = module.make_signature();
                             sig.call_conv = CallConv::SystemV;
-                            sig.params.push(AbiParam::new(I64)); // array pointer
-                            sig.params.push(AbiParam::new(I64)); // value to find
-                            sig.returns.push(AbiParam::new(I32)); // found (bool)
-                            sig.returns.push(AbiParam::new(I32)); // index
+                            sig.params.push(AbiParam::new(I64)); // string pointer or integer value
+                            sig.returns.push(AbiParam::new(I64)); // hash
                             sig
                         };
 
-                        let func_id = module.declare_function("plat_array_index_of", Linkage::Import, &func_sig)
+                        let func_name = if is_string { "plat_string_hash" } else { "plat_int_hash" };
+                        let func_id = module.declare_function(func_name, Linkage::Import, &func_sig)
                             .map_err(CodegenError::ModuleError)?;
                         let func_ref = module.declare_func_in_func(func_id, builder.func);
 
-                        let call = builder.ins().call(func_ref, &[object_val, value_64]);
-                        let results = builder.inst_results(call);
+                        let call = builder.ins().call(func_ref, &[object_val]);
+                        Ok(builder.inst_results(call)[0])
+                    }
+                    // Float math maps straight onto Cranelift instructions; round is round-half-to-even
+                    "floor" | "ceil" | "round" | "sqrt" if !Self::is_class_type(object, variable_types) => {
+                        if !args.is_empty() {
+                            return Err(CodegenError::ArityMismatch { function: method.to_string(), expected: 0, got: args.len(), span: None });
+                        }
 
-                        // Return packed Option<i32> as i64 (found in high bit, index in low bits)
-                        let found = results[0];
-                        let index = results[1];
-                        let found_64 = builder.ins().uextend(I64, found);
-                        let index_64 = builder.ins().uextend(I64, index);
-                        let found_shifted = builder.ins().ishl_imm(found_64, 63);
-                        let result = builder.ins().bor(found_shifted, index_64);
-                        Ok(result)
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let receiver_type = builder.func.dfg.value_type(object_val);
+                        if !receiver_type.is_float() {
+                            return Err(CodegenError::TypeMismatch { expected: format!("float receiver for {}()", method), got: receiver_type.to_string(), span: None });
+                        }
+
+                        Ok(match method.as_str() {
+                            "floor" => builder.ins().floor(object_val),
+                            "ceil" => builder.ins().ceil(object_val),
+                            "round" => builder.ins().nearest(object_val),
+                            _ => builder.ins().sqrt(object_val),
+                        })
                     }
-                    "count" => {
+                    "to_string_precision" if Self::is_float_type(object, variable_types, class_metadata) => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("count() method takes exactly one argument".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "to_string_precision".to_string(), expected: 1, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let digits_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        // The runtime formats in f64; narrower floats widen losslessly
+                        let value_f64 = match builder.func.dfg.value_type(object_val) {
+                            F64 => object_val,
+                            F32 => builder.ins().fpromote(F64, object_val),
+                            other => return Err(CodegenError::TypeMismatch {
+                                expected: "float receiver for to_string_precision()".to_string(),
+                                got: other.to_string(),
+                                span: None,
+                            }),
+                        };
 
-                        // Convert value to i64 if needed
-                        let value_64 = if builder.func.dfg.value_type(value_val) == I32 {
-                            builder.ins().uextend(I64, value_val)
-                        } else {
-                            value_val
+                        let func_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(F64)); // value
+                            sig.params.push(AbiParam::new(I32)); // fractional digits
+                            sig.returns.push(AbiParam::new(I64)); // string pointer
+                            sig
                         };
 
+                        let func_id = module.declare_function("plat_float_to_string_precision", Linkage::Import, &func_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                        let call = builder.ins().call(func_ref, &[value_f64, digits_val]);
+                        Ok(builder.inst_results(call)[0])
+                    }
+                    "chunks" if !Self::is_class_type(object, variable_types) => {
+                        if args.len() != 1 {
+                            return Err(CodegenError::ArityMismatch { function: "chunks".to_string(), expected: 1, got: args.len(), span: None });
+                        }
+
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let size_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
                         let func_sig = {
                             let mut sig = module.make_signature();
                             sig.call_conv = CallConv::SystemV;
                             sig.params.push(AbiParam::new(I64)); // array pointer
-                            sig.params.push(AbiParam::new(I64)); // value to count
-                            sig.returns.push(AbiParam::new(I32)); // count
+                            sig.params.push(AbiParam::new(I32)); // chunk size
+                            sig.returns.push(AbiParam::new(I64)); // array of chunk arrays
                             sig
                         };
 
-                        let func_id = module.declare_function("plat_array_count", Linkage::Import, &func_sig)
+                        let func_id = module.declare_function("plat_array_chunks", Linkage::Import, &func_sig)
                             .map_err(CodegenError::ModuleError)?;
                         let func_ref = module.declare_func_in_func(func_id, builder.func);
 
-                        let call = builder.ins().call(func_ref, &[object_val, value_64]);
+                        let call = builder.ins().call(func_ref, &[object_val, size_val]);
                         Ok(builder.inst_results(call)[0])
                     }
-                    "slice" => {
-                        if args.len() != 2 {
-                            return Err(CodegenError::UnsupportedFeature("slice() method takes exactly two arguments".to_string()));
+                    "flatten" if !Self::is_class_type(object, variable_types) => {
+                        if !args.is_empty() {
+                            return Err(CodegenError::ArityMismatch { function: "flatten".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let start_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let end_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         let func_sig = {
                             let mut sig = module.make_signature();
                             sig.call_conv = CallConv::SystemV;
-                            sig.params.push(AbiParam::new(I64)); // array pointer
-                            sig.params.push(AbiParam::new(I32)); // start index
-                            sig.params.push(AbiParam::new(I32)); // end index
-                            sig.returns.push(AbiParam::new(I64)); // new array pointer
+                            sig.params.push(AbiParam::new(I64)); // array of array pointers
+                            sig.returns.push(AbiParam::new(I64)); // flattened array pointer
                             sig
                         };
 
-                        let func_id = module.declare_function("plat_array_slice", Linkage::Import, &func_sig)
+                        let func_id = module.declare_function("plat_array_flatten", Linkage::Import, &func_sig)
                             .map_err(CodegenError::ModuleError)?;
                         let func_ref = module.declare_func_in_func(func_id, builder.func);
 
-                        let call = builder.ins().call(func_ref, &[object_val, start_val, end_val]);
+                        let call = builder.ins().call(func_ref, &[object_val]);
                         Ok(builder.inst_results(call)[0])
                     }
-                    "all" => {
+                    "zip" if !Self::is_class_type(object, variable_types) => {
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("all() method takes exactly one argument".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "zip".to_string(), expected: 1, got: args.len(), span: None });
+                        }
+
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let other_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        let func_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // first array pointer
+                            sig.params.push(AbiParam::new(I64)); // second array pointer
+                            sig.returns.push(AbiParam::new(I64)); // array of tuple pointers
+                            sig
+                        };
+
+                        let func_id = module.declare_function("plat_array_zip", Linkage::Import, &func_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let func_ref = module.declare_func_in_func(func_id, builder.func);
+
+                        let call = builder.ins().call(func_ref, &[object_val, other_val]);
+                        Ok(builder.inst_results(call)[0])
+                    }
+                    "enumerate" if !Self::is_class_type(object, variable_types) => {
+                        if !args.is_empty() {
+                            return Err(CodegenError::ArityMismatch { function: "enumerate".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
-                        // For now, use simplified version that checks if all elements are truthy
                         let func_sig = {
                             let mut sig = module.make_signature();
                             sig.call_conv = CallConv::SystemV;
                             sig.params.push(AbiParam::new(I64)); // array pointer
-                            sig.returns.push(AbiParam::new(I32)); // all are truthy (bool)
+                            sig.returns.push(AbiParam::new(I64)); // array of (index, element) tuple pointers
                             sig
                         };
 
-                        let func_id = module.declare_function("plat_array_all_truthy", Linkage::Import, &func_sig)
+                        let func_id = module.declare_function("plat_array_enumerate", Linkage::Import, &func_sig)
                             .map_err(CodegenError::ModuleError)?;
                         let func_ref = module.declare_func_in_func(func_id, builder.func);
 
                         let call = builder.ins().call(func_ref, &[object_val]);
                         Ok(builder.inst_results(call)[0])
                     }
-                    "any" => {
-                        if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("any() method takes exactly one argument".to_string()));
+                    "unique" if !Self::is_class_type(object, variable_types) => {
+                        if !args.is_empty() {
+                            return Err(CodegenError::ArityMismatch { function: "unique".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        let element_tag = Self::array_element_tag(&Self::infer_element_type(object, variable_types));
+                        let element_tag_val = builder.ins().iconst(I32, element_tag as i64);
 
-                        // For now, use simplified version that checks if any element is truthy
                         let func_sig = {
                             let mut sig = module.make_signature();
                             sig.call_conv = CallConv::SystemV;
                             sig.params.push(AbiParam::new(I64)); // array pointer
-                            sig.returns.push(AbiParam::new(I32)); // any are truthy (bool)
+                            sig.params.push(AbiParam::new(I32)); // element type tag
+                            sig.returns.push(AbiParam::new(I64)); // new array pointer
                             sig
                         };
 
-                        let func_id = module.declare_function("plat_array_any_truthy", Linkage::Import, &func_sig)
+                        let func_id = module.declare_function("plat_array_unique", Linkage::Import, &func_sig)
                             .map_err(CodegenError::ModuleError)?;
                         let func_ref = module.declare_func_in_func(func_id, builder.func);
 
-                        let call = builder.ins().call(func_ref, &[object_val]);
+                        let call = builder.ins().call(func_ref, &[object_val, element_tag_val]);
                         Ok(builder.inst_results(call)[0])
                     }
+                    "all" => {
+                        Err(CodegenError::UnsupportedFeature(
+                            "all() requires a predicate closure, and closures are not supported yet".to_string()
+                        ))
+                    }
+                    "any" => {
+                        Err(CodegenError::UnsupportedFeature(
+                            "any() requires a predicate closure, and closures are not supported yet".to_string()
+                        ))
+                    }
+                    "find" if !Self::is_class_type(object, variable_types) => {
+                        // find(predicate = f): call the named predicate on each element in order and
+                        // stop at the first one it accepts
+                        let predicate = match args.first().map(|arg| &arg.value) {
+                            Some(Expression::Identifier { name, .. }) => name,
+                            _ => return Err(CodegenError::UnsupportedFeature("find() 'predicate' must name a function".to_string())),
+                        };
+                        let predicate_id = Self::lookup_function_id(predicate, functions)
+                            .ok_or_else(|| CodegenError::UndefinedFunction(predicate.clone()))?;
+                        let param_type = module.declarations().get_function_decl(predicate_id).signature.params
+                            .first()
+                            .map(|param| param.value_type)
+                            .ok_or_else(|| CodegenError::ArityMismatch { function: predicate.clone(), expected: 1, got: 0, span: None })?;
+
+                        let array_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        let len_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // array pointer
+                            sig.returns.push(AbiParam::new(I64)); // length
+                            sig
+                        };
+                        let len_id = module.declare_function("plat_array_len", Linkage::Import, &len_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let get_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // array pointer
+                            sig.params.push(AbiParam::new(I64)); // index
+                            sig.returns.push(AbiParam::new(I64)); // element value
+                            sig
+                        };
+                        let get_id = module.declare_function("plat_array_get", Linkage::Import, &get_sig)
+                            .map_err(CodegenError::ModuleError)?;
+
+                        let len_ref = module.declare_func_in_func(len_id, builder.func);
+                        let call = builder.ins().call(len_ref, &[array_val]);
+                        let array_len = builder.inst_results(call)[0];
+
+                        let loop_header = builder.create_block();
+                        let loop_body = builder.create_block();
+                        let next_block = builder.create_block();
+                        let done_block = builder.create_block();
+                        builder.append_block_param(loop_header, I64); // index
+                        builder.append_block_param(done_block, I32); // found
+                        builder.append_block_param(done_block, I64); // element
+
+                        let zero = builder.ins().iconst(I64, 0);
+                        builder.ins().jump(loop_header, &[zero]);
+
+                        // Header: stop with None once every element has been tried
+                        builder.switch_to_block(loop_header);
+                        let index = builder.block_params(loop_header)[0];
+                        let in_bounds = builder.ins().icmp(IntCC::SignedLessThan, index, array_len);
+                        let not_found = builder.ins().iconst(I32, 0);
+                        builder.ins().brif(in_bounds, loop_body, &[], done_block, &[not_found, zero]);
+
+                        // Body: the first element the predicate accepts ends the search
+                        builder.switch_to_block(loop_body);
+                        builder.seal_block(loop_body);
+                        let get_ref = module.declare_func_in_func(get_id, builder.func);
+                        let call = builder.ins().call(get_ref, &[array_val, index]);
+                        let element = builder.inst_results(call)[0];
+                        let predicate_arg = if param_type == I64 {
+                            element
+                        } else {
+                            builder.ins().ireduce(param_type, element)
+                        };
+                        let predicate_ref = module.declare_func_in_func(predicate_id, builder.func);
+                        let call = builder.ins().call(predicate_ref, &[predicate_arg]);
+                        let accepted = builder.inst_results(call)[0];
+                        let found = builder.ins().iconst(I32, 1);
+                        builder.ins().brif(accepted, done_block, &[found, element], next_block, &[]);
+
+                        builder.switch_to_block(next_block);
+                        builder.seal_block(next_block);
+                        let next_index = builder.ins().iadd_imm(index, 1);
+                        builder.ins().jump(loop_header, &[next_index]);
+                        builder.seal_block(loop_header);
+
+                        builder.switch_to_block(done_block);
+                        builder.seal_block(done_block);
+                        let found = builder.block_params(done_block)[0];
+                        let element = builder.block_params(done_block)[1];
+
+                        let element_type = Self::infer_element_type(object, variable_types);
+                        Self::generate_option_from_found(builder, module, found, element, &element_type)
+                    }
                     // Dict-specific methods
                     method_name if Self::is_dict_type(object, variable_types) => {
                         match method_name {
                             "get" => {
                                 if args.len() != 1 {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.get() method takes exactly one argument".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.get".to_string(), expected: 1, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
+                                // Use safe get that returns Option<V>
                                 let func_sig = {
                                     let mut sig = module.make_signature();
                                     sig.call_conv = CallConv::SystemV;
                                     sig.params.push(AbiParam::new(I64)); // dict pointer
                                     sig.params.push(AbiParam::new(I64)); // key pointer
+                                    sig.returns.push(AbiParam::new(I32)); // found (bool)
                                     sig.returns.push(AbiParam::new(I64)); // value
                                     sig
                                 };
 
-                                let func_id = module.declare_function("plat_dict_get", Linkage::Import, &func_sig)
+                                let func_id = module.declare_function("plat_dict_get_safe", Linkage::Import, &func_sig)
                                     .map_err(CodegenError::ModuleError)?;
                                 let func_ref = module.declare_func_in_func(func_id, builder.func);
 
                                 let call = builder.ins().call(func_ref, &[object_val, key_val]);
-                                Ok(builder.inst_results(call)[0])
+                                let results = builder.inst_results(call);
+                                let found = results[0];
+                                let value = results[1];
+
+                                let value_type = match Self::infer_expression_type(object, variable_types) {
+                                    VariableType::Dict(_, value_type) => *value_type,
+                                    _ => VariableType::Int64,
+                                };
+                                Self::generate_option_from_found(builder, module, found, value, &value_type)
                             }
                             "set" => {
                                 if args.len() != 2 {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.set() method takes exactly two arguments".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.set".to_string(), expected: 2, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let value_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let value_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 // Determine value type
                                 let value_type = Self::get_dict_value_type(&args[1].value, variable_types);
@@ -4733,12 +8220,12 @@ impl CodeGenerator {
                             "insert" => {
                                 // insert() is an alias for set()
                                 if args.len() != 2 {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.insert() method takes exactly two arguments".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.insert".to_string(), expected: 2, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let value_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let value_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 // Determine value type
                                 let value_type = Self::get_dict_value_type(&args[1].value, variable_types);
@@ -4764,11 +8251,11 @@ impl CodeGenerator {
                             }
                             "remove" => {
                                 if args.len() != 1 {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.remove() method takes exactly one argument".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.remove".to_string(), expected: 1, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -4788,10 +8275,10 @@ impl CodeGenerator {
                             }
                             "clear" => {
                                 if !args.is_empty() {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.clear() method takes no arguments".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.clear".to_string(), expected: 0, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -4809,10 +8296,10 @@ impl CodeGenerator {
                             }
                             "length" => {
                                 if !args.is_empty() {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.length() method takes no arguments".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.length".to_string(), expected: 0, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -4831,10 +8318,10 @@ impl CodeGenerator {
                             }
                             "keys" => {
                                 if !args.is_empty() {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.keys() method takes no arguments".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.keys".to_string(), expected: 0, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -4853,10 +8340,10 @@ impl CodeGenerator {
                             }
                             "values" => {
                                 if !args.is_empty() {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.values() method takes no arguments".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.values".to_string(), expected: 0, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -4875,11 +8362,11 @@ impl CodeGenerator {
                             }
                             "has_key" => {
                                 if args.len() != 1 {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.has_key() method takes exactly one argument".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.has_key".to_string(), expected: 1, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -4899,11 +8386,11 @@ impl CodeGenerator {
                             }
                             "has_value" => {
                                 if args.len() != 1 {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.has_value() method takes exactly one argument".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.has_value".to_string(), expected: 1, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 // Determine value type
                                 let value_type = Self::get_dict_value_type(&args[0].value, variable_types);
@@ -4928,11 +8415,11 @@ impl CodeGenerator {
                             }
                             "merge" => {
                                 if args.len() != 1 {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.merge() method takes exactly one argument".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.merge".to_string(), expected: 1, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let other_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let other_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -4951,12 +8438,12 @@ impl CodeGenerator {
                             }
                             "get_or" => {
                                 if args.len() != 2 {
-                                    return Err(CodegenError::UnsupportedFeature("Dict.get_or() method takes exactly two arguments".to_string()));
+                                    return Err(CodegenError::ArityMismatch { function: "Dict.get_or".to_string(), expected: 2, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let default_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let key_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let default_val = Self::generate_expression_helper(builder, &args[1].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -4975,7 +8462,7 @@ impl CodeGenerator {
                                 let call = builder.ins().call(func_ref, &[object_val, key_val, default_val]);
                                 Ok(builder.inst_results(call)[0])
                             }
-                            _ => Err(CodegenError::UnsupportedFeature(format!("Dict method '{}' not implemented", method)))
+                            _ => Err(CodegenError::UnknownMethod { ty: "Dict".to_string(), method: method.clone(), span: None })
                         }
                     }
                     // Set-only methods (not overlapping with other types)
@@ -4983,11 +8470,11 @@ impl CodeGenerator {
                         match method.as_str() {
                             "add" | "remove" => {
                                 if args.len() != 1 {
-                                    return Err(CodegenError::UnsupportedFeature(format!("Set.{}() method takes exactly one argument", method)));
+                                    return Err(CodegenError::ArityMismatch { function: format!("Set.{}", method), expected: 1, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let value_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 // Determine value type
                                 let value_type = Self::get_set_value_type(&args[0].value, variable_types);
@@ -5020,11 +8507,11 @@ impl CodeGenerator {
                             }
                             "union" | "intersection" | "difference" => {
                                 if args.len() != 1 {
-                                    return Err(CodegenError::UnsupportedFeature(format!("Set.{}() method takes exactly one argument", method)));
+                                    return Err(CodegenError::ArityMismatch { function: format!("Set.{}", method), expected: 1, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let other_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let other_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -5045,11 +8532,11 @@ impl CodeGenerator {
                             }
                             "is_subset_of" | "is_superset_of" | "is_disjoint_from" => {
                                 if args.len() != 1 {
-                                    return Err(CodegenError::UnsupportedFeature(format!("Set.{}() method takes exactly one argument", method)));
+                                    return Err(CodegenError::ArityMismatch { function: format!("Set.{}", method), expected: 1, got: args.len(), span: None });
                                 }
 
-                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                                let other_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                                let other_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                                 let func_sig = {
                                     let mut sig = module.make_signature();
@@ -5068,14 +8555,66 @@ impl CodeGenerator {
                                 let call = builder.ins().call(func_ref, &[object_val, other_val]);
                                 Ok(builder.inst_results(call)[0])
                             }
-                            _ => Err(CodegenError::UnsupportedFeature(format!("Set method '{}' not implemented", method)))
+                            _ => Err(CodegenError::UnknownMethod { ty: "Set".to_string(), method: method.clone(), span: None })
+                        }
+                    }
+                    // Mutex methods
+                    "lock" | "unlock" | "with_lock"
+                        if Self::get_class_name(object, variable_types).as_deref() == Some("Mutex") && !class_metadata.contains_key("Mutex") =>
+                    {
+                        let mutex_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        let mutex_sig = {
+                            let mut sig = module.make_signature();
+                            sig.call_conv = CallConv::SystemV;
+                            sig.params.push(AbiParam::new(I64)); // mutex handle
+                            sig
+                        };
+                        let lock_id = module.declare_function("plat_mutex_lock", Linkage::Import, &mutex_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let unlock_id = module.declare_function("plat_mutex_unlock", Linkage::Import, &mutex_sig)
+                            .map_err(CodegenError::ModuleError)?;
+
+                        match method.as_str() {
+                            "lock" => {
+                                let lock_ref = module.declare_func_in_func(lock_id, builder.func);
+                                builder.ins().call(lock_ref, &[mutex_val]);
+                                Ok(builder.ins().iconst(I32, 0))
+                            }
+                            "unlock" => {
+                                let unlock_ref = module.declare_func_in_func(unlock_id, builder.func);
+                                builder.ins().call(unlock_ref, &[mutex_val]);
+                                Ok(builder.ins().iconst(I32, 0))
+                            }
+                            _ => {
+                                // with_lock(action = f): the critical section is a separate function,
+                                // so every return inside it comes back here and reaches the unlock
+                                let action = match args.first().map(|arg| &arg.value) {
+                                    Some(Expression::Identifier { name, .. }) => name,
+                                    _ => return Err(CodegenError::UnsupportedFeature("with_lock() 'action' must name a function".to_string())),
+                                };
+                                let action_id = Self::lookup_function_id(action, functions)
+                                    .ok_or_else(|| CodegenError::UndefinedFunction(action.clone()))?;
+
+                                let lock_ref = module.declare_func_in_func(lock_id, builder.func);
+                                builder.ins().call(lock_ref, &[mutex_val]);
+
+                                let action_ref = module.declare_func_in_func(action_id, builder.func);
+                                let call = builder.ins().call(action_ref, &[]);
+                                let result = builder.inst_results(call).first().copied();
+
+                                let unlock_ref = module.declare_func_in_func(unlock_id, builder.func);
+                                builder.ins().call(unlock_ref, &[mutex_val]);
+
+                                Ok(result.unwrap_or_else(|| builder.ins().iconst(I32, 0)))
+                            }
                         }
                     }
                     // Channel methods
                     "send" => {
                         // Channel<T>.send(value) method
                         if args.len() != 1 {
-                            return Err(CodegenError::UnsupportedFeature("send() method takes exactly one argument".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "send".to_string(), expected: 1, got: args.len(), span: None });
                         }
 
                         // Determine the channel element type from the object
@@ -5090,8 +8629,8 @@ impl CodeGenerator {
                         };
 
                         // Generate the channel ID and value
-                        let channel_id = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let value = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let channel_id = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let value = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Determine which send function to call based on element type
                         let send_func_name = match channel_element_type {
@@ -5123,7 +8662,7 @@ impl CodeGenerator {
                     "recv" => {
                         // Channel<T>.recv() method
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature("recv() method takes no arguments".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "recv".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
                         // Determine the channel element type from the object
@@ -5138,7 +8677,7 @@ impl CodeGenerator {
                         };
 
                         // Generate the channel ID
-                        let channel_id = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let channel_id = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Determine which recv function to call based on element type
                         let (recv_func_name, use_out_param) = match channel_element_type {
@@ -5198,11 +8737,11 @@ impl CodeGenerator {
                     "close" => {
                         // Channel<T>.close() method
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature("close() method takes no arguments".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "close".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
                         // Generate the channel ID
-                        let channel_id = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let channel_id = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Declare and call the close function
                         let mut close_sig = module.make_signature();
@@ -5221,24 +8760,17 @@ impl CodeGenerator {
                     "await" => {
                         // Task.await() method
                         if !args.is_empty() {
-                            return Err(CodegenError::UnsupportedFeature("await() method takes no arguments".to_string()));
+                            return Err(CodegenError::ArityMismatch { function: "await".to_string(), expected: 0, got: args.len(), span: None });
                         }
 
-                        // Determine the inner type of the Task<T> from the object
-                        let task_inner_type = if let Expression::Identifier { name, .. } = object.as_ref() {
-                            if let Some(VariableType::Task(inner)) = variable_types.get(name) {
-                                (**inner).clone()
-                            } else {
-                                // Fallback to Int32 if type not found or not a Task
-                                VariableType::Int32
-                            }
-                        } else {
-                            // For complex expressions, default to Int32
-                            VariableType::Int32
+                        // Determine the inner type of the Task<T> from the object, defaulting to Int32
+                        let task_inner_type = match Self::infer_expression_type(object, variable_types) {
+                            VariableType::Task(inner) => *inner,
+                            _ => VariableType::Int32,
                         };
 
                         // Generate the task handle value
-                        let task_handle = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let task_handle = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                         // Get the appropriate await function name based on inner type
                         let await_func_name = Self::get_await_function_name(&task_inner_type);
@@ -5265,9 +8797,88 @@ impl CodeGenerator {
 
                         Ok(result)
                     }
+                    "cancel" | "try_await" if matches!(Self::infer_expression_type(object, variable_types), VariableType::Task(_)) => {
+                        if !args.is_empty() {
+                            return Err(CodegenError::ArityMismatch { function: method.to_string(), expected: 0, got: args.len(), span: None });
+                        }
+
+                        let task_inner_type = match Self::infer_expression_type(object, variable_types) {
+                            VariableType::Task(inner) => *inner,
+                            _ => VariableType::Int32,
+                        };
+                        let task_handle = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+
+                        if method == "cancel" {
+                            // Task.cancel(): cooperative, the runtime checks it at await points
+                            let mut cancel_sig = module.make_signature();
+                            cancel_sig.call_conv = CallConv::SystemV;
+                            cancel_sig.params.push(AbiParam::new(I64)); // Task handle
+
+                            let cancel_id = module.declare_function("plat_task_cancel", Linkage::Import, &cancel_sig)
+                                .map_err(CodegenError::ModuleError)?;
+                            let cancel_ref = module.declare_func_in_func(cancel_id, builder.func);
+                            builder.ins().call(cancel_ref, &[task_handle]);
+                            return Ok(builder.ins().iconst(I32, 0));
+                        }
+
+                        // Task.try_await(): wait for the task to settle, and only await its result (which
+                        // panics for a cancelled task) when it was not cancelled
+                        let mut cancelled_sig = module.make_signature();
+                        cancelled_sig.call_conv = CallConv::SystemV;
+                        cancelled_sig.params.push(AbiParam::new(I64)); // Task handle
+                        cancelled_sig.returns.push(AbiParam::new(I32)); // 1 if cancelled
+
+                        let cancelled_id = module.declare_function("plat_task_await_cancelled", Linkage::Import, &cancelled_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let cancelled_ref = module.declare_func_in_func(cancelled_id, builder.func);
+                        let call = builder.ins().call(cancelled_ref, &[task_handle]);
+                        let cancelled = builder.inst_results(call)[0];
+                        let found = builder.ins().icmp_imm(IntCC::Equal, cancelled, 0);
+
+                        // generate_option_from_found expects narrow payloads widened to i64
+                        let result_type = Self::variable_type_to_cranelift_type(&task_inner_type);
+                        let payload_type = if result_type == F64 { F64 } else { I64 };
+                        let result_block = builder.create_block();
+                        let merge_block = builder.create_block();
+                        builder.append_block_param(merge_block, payload_type);
+                        let no_payload = if payload_type == F64 {
+                            builder.ins().f64const(0.0)
+                        } else {
+                            builder.ins().iconst(I64, 0)
+                        };
+                        builder.ins().brif(found, result_block, &[], merge_block, &[no_payload]);
+
+                        builder.switch_to_block(result_block);
+                        builder.seal_block(result_block);
+                        let await_func_name = Self::get_await_function_name(&task_inner_type);
+                        let mut await_sig = module.make_signature();
+                        await_sig.call_conv = CallConv::SystemV;
+                        await_sig.params.push(AbiParam::new(I64)); // Task handle
+                        await_sig.returns.push(AbiParam::new(result_type)); // Result value
+
+                        let await_id = module.declare_function(await_func_name, Linkage::Import, &await_sig)
+                            .map_err(CodegenError::ModuleError)?;
+                        let await_ref = module.declare_func_in_func(await_id, builder.func);
+                        let call = builder.ins().call(await_ref, &[task_handle]);
+                        let result = builder.inst_results(call)[0];
+                        let payload = match result_type {
+                            I64 | F64 => result,
+                            F32 => {
+                                let bits = builder.ins().bitcast(I32, MemFlags::new(), result);
+                                builder.ins().uextend(I64, bits)
+                            }
+                            _ => builder.ins().uextend(I64, result),
+                        };
+                        builder.ins().jump(merge_block, &[payload]);
+
+                        builder.switch_to_block(merge_block);
+                        builder.seal_block(merge_block);
+                        let payload = builder.block_params(merge_block)[0];
+                        Self::generate_option_from_found(builder, module, found, payload, &task_inner_type)
+                    }
                     // Class methods
                     method_name if Self::is_class_type(object, variable_types) => {
-                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let object_val = Self::generate_expression_helper(builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
                         let class_name = Self::get_class_name(object, variable_types).unwrap_or_else(|| "Unknown".to_string());
 
                         // Check if this is a virtual method call that needs dynamic dispatch
@@ -5280,7 +8891,7 @@ impl CodeGenerator {
                         let mut call_args = vec![object_val]; // Start with self
                         for (i, arg) in args.iter().enumerate() {
                             eprintln!("DEBUG: Processing argument {} of type {:?}", i, arg);
-                            let arg_val = Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                            let arg_val = Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
                             call_args.push(arg_val);
                         }
 
@@ -5320,16 +8931,7 @@ impl CodeGenerator {
 
                             // Perform indirect call through function pointer
                             let call = builder.ins().call_indirect(sig, func_ptr, &call_args);
-
-                            // Check if the method has a return value
-                            let results = builder.inst_results(call);
-                            if results.is_empty() {
-                                // Void method - return unit (0) as I32
-                                Ok(builder.ins().iconst(I32, 0))
-                            } else {
-                                // Method with return value - return as-is
-                                Ok(results[0])
-                            }
+                            Self::call_result_value(builder, module, call)
                         } else {
                             // Static dispatch (compile-time resolution)
                             eprintln!("DEBUG: Using static dispatch for method '{}' on class '{}'", method_name, class_name);
@@ -5347,20 +8949,47 @@ impl CodeGenerator {
 
                             // Call the method directly
                             let call = builder.ins().call(func_ref, &call_args);
-
-                            // Check if the method has a return value
-                            let results = builder.inst_results(call);
-                            if results.is_empty() {
-                                // Void method - return unit (0) as I32
-                                Ok(builder.ins().iconst(I32, 0))
-                            } else {
-                                // Method with return value - return as-is
-                                Ok(results[0])
-                            }
+                            Self::call_result_value(builder, module, call)
                         }
                     }
-                    _ => Err(CodegenError::UnsupportedFeature(format!("Method '{}' not implemented", method)))
+                    _ => Err(CodegenError::UnknownMethod {
+                        ty: format!("{:?}", Self::infer_expression_type(object, variable_types)),
+                        method: method.clone(),
+                        span: None,
+                    })
+                }
+            }
+            Expression::SuperCall { method, args, .. } => {
+                // Statically call the parent's implementation, bypassing the vtable
+                let class_name = match variable_types.get("self") {
+                    Some(VariableType::Class(class_name)) => class_name.clone(),
+                    _ => return Err(CodegenError::UnsupportedFeature(
+                        "'super' can only be used within class methods".to_string()
+                    )),
+                };
+                let parent_name = class_metadata.get(&class_name)
+                    .and_then(|metadata| metadata.parent_class.clone())
+                    .ok_or_else(|| CodegenError::UnsupportedFeature(
+                        format!("Class '{}' has no parent class for 'super' call", class_name)
+                    ))?;
+
+                let func_name = format!("{}__{}", parent_name, method);
+                let func_id = *functions.get(&func_name)
+                    .ok_or_else(|| CodegenError::UnsupportedFeature(
+                        format!("Method function '{}' not found", func_name)
+                    ))?;
+
+                let self_var = *variables.get("self")
+                    .ok_or_else(|| CodegenError::UndefinedVariable("self".to_string()))?;
+                let mut call_args = vec![builder.use_var(self_var)];
+                for arg in args {
+                    let arg_val = Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    call_args.push(arg_val);
                 }
+
+                let func_ref = module.declare_func_in_func(func_id, builder.func);
+                let call = builder.ins().call(func_ref, &call_args);
+                Self::call_result_value(builder, module, call)
             }
             Expression::EnumConstructor { enum_name, variant, args, .. } => {
                 let discriminant = Self::variant_discriminant(enum_name, variant);
@@ -5373,7 +9002,7 @@ impl CodeGenerator {
                 } else if args.len() == 1 {
                     // Check if the argument is a pointer type (String, Array, etc.)
                     // that cannot be packed into 32 bits
-                    let arg_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let arg_val = Self::generate_expression_helper(builder, &args[0].value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     // Determine if we need heap allocation based on the argument type
                     let needs_heap = match &args[0].value {
@@ -5383,9 +9012,15 @@ impl CodeGenerator {
                         Expression::Literal(Literal::Dict(_, _)) => true,
                         Expression::Literal(Literal::Set(_, _)) => true,
                         Expression::Identifier { name, .. } => {
-                            matches!(variable_types.get(name), Some(VariableType::String) | Some(VariableType::Array(_)) | Some(VariableType::Dict) | Some(VariableType::Set) | Some(VariableType::Class(_)))
+                            matches!(variable_types.get(name), Some(VariableType::String) | Some(VariableType::Array(_)) | Some(VariableType::Dict(_, _)) | Some(VariableType::Set) | Some(VariableType::Class(_)))
                         }
+                        Expression::ConstructorCall { .. } => true,
                         _ => false,
+                    } || {
+                        // 64-bit payloads (Int64, Float64, pointers) don't fit beside the
+                        // discriminant, and match extraction always loads them from the heap
+                        let arg_type = builder.func.dfg.value_type(arg_val);
+                        arg_type == I64 || arg_type == F64
                     };
 
                     if needs_heap {
@@ -5404,8 +9039,9 @@ impl CodeGenerator {
                             .map_err(CodegenError::ModuleError)?;
                         let gc_alloc_ref = module.declare_func_in_func(gc_alloc_id, builder.func);
 
-                        // Allocate space for discriminant (4 bytes) + pointer (8 bytes)
-                        let size_val = builder.ins().iconst(I64, 12);
+                        // Layout matches runtime-built enums and match extraction:
+                        // [discriminant:i32][padding:i32][pointer:i64]
+                        let size_val = builder.ins().iconst(I64, 16);
                         let call_inst = builder.ins().call(gc_alloc_ref, &[size_val]);
                         let ptr = builder.inst_results(call_inst)[0];
 
@@ -5413,8 +9049,8 @@ impl CodeGenerator {
                         let disc_val = builder.ins().iconst(I32, discriminant as i64);
                         builder.ins().store(MemFlags::new(), disc_val, ptr, 0);
 
-                        // Store pointer at offset 4
-                        builder.ins().store(MemFlags::new(), arg_val, ptr, 4);
+                        // Store pointer at offset 8
+                        builder.ins().store(MemFlags::new(), arg_val, ptr, 8);
 
                         Ok(ptr)
                     } else {
@@ -5441,7 +9077,7 @@ impl CodeGenerator {
                     }
                 } else {
                     // Multiple fields - allocate struct on GC heap
-                    // Layout: [discriminant:i32][field1][field2]...[fieldN]
+                    // Layout: [discriminant:i32][padding:i32][field1:8]...[fieldN:8]
 
                     // Declare GC allocation function
                     let gc_alloc_name = "plat_gc_alloc";
@@ -5457,8 +9093,8 @@ impl CodeGenerator {
                         .map_err(CodegenError::ModuleError)?;
                     let gc_alloc_ref = module.declare_func_in_func(gc_alloc_id, builder.func);
 
-                    // Calculate size needed: discriminant (4 bytes) + args.len() * 4 bytes (assuming i32)
-                    let total_size = 4 + args.len() * 4;
+                    // Discriminant and padding, then an 8-byte slot per field
+                    let total_size = Self::enum_field_offset(args.len());
                     let size_val = builder.ins().iconst(I64, total_size as i64);
 
                     // Allocate memory
@@ -5469,68 +9105,35 @@ impl CodeGenerator {
                     let disc_val = builder.ins().iconst(I32, discriminant as i64);
                     builder.ins().store(MemFlags::new(), disc_val, ptr, 0);
 
-                    // Store each field
+                    // Store each field in its slot; narrow integers are widened so the match
+                    // extraction can load them as Int32
                     for (i, arg) in args.iter().enumerate() {
-                        let arg_val = Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
-                        let offset = 4 + (i * 4) as i32; // discriminant + field index * field_size
-                        builder.ins().store(MemFlags::new(), arg_val, ptr, offset);
+                        let arg_val = Self::generate_expression_helper(builder, &arg.value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        let arg_val = match builder.func.dfg.value_type(arg_val) {
+                            I8 | I16 => builder.ins().sextend(I32, arg_val),
+                            _ => arg_val,
+                        };
+                        builder.ins().store(MemFlags::new(), arg_val, ptr, Self::enum_field_offset(i));
                     }
 
                     Ok(ptr)
                 }
             }
             Expression::Match { value, arms, .. } => {
-                let value_val = Self::generate_expression_helper(builder, value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let value_val = Self::generate_expression_helper(builder, value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                 if arms.is_empty() {
                     return Err(CodegenError::UnsupportedFeature(
                         "Empty match expressions not supported".to_string()
                     ));
                 }
+                Self::verify_match_exhaustive(arms, symbol_table)?;
 
-                // For enum values, detect packed vs heap format at runtime
-                let disc_i32 = {
-                    // Try packed format first - discriminant in high 32 bits
-                    let packed_disc = builder.ins().ushr_imm(value_val, 32);
-                    let packed_disc_i32 = builder.ins().ireduce(I32, packed_disc);
-
-                    // Heap format if value looks like a valid pointer address
-                    // Heuristic: heap pointers are typically in range [0x1000, 0x7FFFFFFFFFFF]
-                    // Packed enums have discriminant in high 32 bits, often > 0x7FFFFFFFFFFF
-                    let min_addr = builder.ins().iconst(I64, 0x1000);
-                    let max_pointer = builder.ins().iconst(I64, 0x7FFFFFFFFFFF); // Max 47-bit address
-
-                    // Check if value is in typical pointer range
-                    let above_min = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::UnsignedGreaterThan, value_val, min_addr);
-                    let below_max = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::UnsignedLessThan, value_val, max_pointer);
-                    let use_heap = builder.ins().band(above_min, below_max);
-
-                    let packed_block = builder.create_block();
-                    let heap_block = builder.create_block();
-                    let done_block = builder.create_block();
-                    builder.append_block_param(done_block, I32);
-
-                    builder.ins().brif(use_heap, heap_block, &[], packed_block, &[]);
-
-                    // Packed format: use extracted discriminant
-                    builder.switch_to_block(packed_block);
-                    builder.seal_block(packed_block);
-                    builder.ins().jump(done_block, &[packed_disc_i32]);
-
-                    // Heap format: load discriminant from memory
-                    builder.switch_to_block(heap_block);
-                    builder.seal_block(heap_block);
-                    let heap_disc = builder.ins().load(I32, MemFlags::new(), value_val, 0);
-                    builder.ins().jump(done_block, &[heap_disc]);
-
-                    builder.switch_to_block(done_block);
-                    builder.seal_block(done_block);
-
-                    builder.block_params(done_block)[0]
-                };
+                // For enum values, detect packed vs heap format at runtime
+                let disc_i32 = Self::generate_enum_discriminant(builder, value_val);
 
                 // Determine the return type for the match expression early
-                let match_return_type = Self::determine_match_return_type(arms, variable_types);
+                let match_return_type = Self::determine_match_return_type(value, arms, variable_types);
                 let cont_param_type = match match_return_type {
                     VariableType::String | VariableType::Array(_) | VariableType::Enum(_) | VariableType::Class(_) | VariableType::Int64 => I64,
                     VariableType::Float64 => F64,
@@ -5545,38 +9148,63 @@ impl CodeGenerator {
                 }
                 let cont_block = builder.create_block();
 
-                // Generate cascade of conditional branches
+                // Unit variants carry no payload, so the discriminant alone picks the arm. Variant
+                // discriminants are name hashes rather than dense indices, so `Switch` lowers this
+                // to a balanced search (with jump tables for any clustered ranges)
+                let unit_discriminants: Option<Vec<u32>> = arms.iter()
+                    .map(|arm| match &arm.pattern {
+                        Pattern::EnumVariant { variant, bindings, .. } if bindings.is_empty() => {
+                            Some(Self::variant_discriminant("", variant))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                let switch_discriminants = unit_discriminants.filter(|discriminants| {
+                    discriminants.len() >= SWITCH_MATCH_MIN_ARMS
+                        && discriminants.iter().collect::<HashSet<_>>().len() == discriminants.len()
+                });
+
                 let initial_block = builder.current_block().unwrap();
                 let mut current_block = initial_block;
                 let mut sealed_blocks = Vec::new();
 
-                for (i, arm) in arms.iter().enumerate() {
-                    let arm_disc = if let Pattern::EnumVariant { variant, .. } = &arm.pattern {
-                        Self::variant_discriminant("", variant)
-                    } else {
-                        return Err(CodegenError::UnsupportedFeature("Non-enum patterns not supported".to_string()));
-                    };
-
-                    if i == arms.len() - 1 {
-                        // Last arm - unconditional jump (exhaustiveness guaranteed by HIR)
-                        builder.ins().jump(arm_blocks[i], &[]);
-                    } else {
-                        // Check if discriminant matches this arm
-                        let expected = builder.ins().iconst(I32, arm_disc as i64);
-                        let is_match = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::Equal, disc_i32, expected);
-
-                        // Create next comparison block for remaining arms
-                        let next_block = builder.create_block();
-                        builder.ins().brif(is_match, arm_blocks[i], &[], next_block, &[]);
+                if let Some(discriminants) = &switch_discriminants {
+                    // The last arm is the default, as in the cascade (exhaustiveness verified above)
+                    let mut switch = Switch::new();
+                    for (i, discriminant) in discriminants[..discriminants.len() - 1].iter().enumerate() {
+                        switch.set_entry(*discriminant as u128, arm_blocks[i]);
+                    }
+                    switch.emit(builder, disc_i32, arm_blocks[arms.len() - 1]);
+                } else {
+                    // Generate cascade of conditional branches
+                    for (i, arm) in arms.iter().enumerate() {
+                        let arm_disc = if let Pattern::EnumVariant { variant, .. } = &arm.pattern {
+                            Self::variant_discriminant("", variant)
+                        } else {
+                            return Err(CodegenError::UnsupportedFeature("Non-enum patterns not supported".to_string()));
+                        };
 
-                        // Switch to next comparison block
-                        builder.switch_to_block(next_block);
-                        // Only seal if it's not the initial block
-                        if current_block != initial_block {
-                            builder.seal_block(current_block);
+                        if i == arms.len() - 1 {
+                            // Last arm - unconditional jump (exhaustiveness verified above)
+                            builder.ins().jump(arm_blocks[i], &[]);
+                        } else {
+                            // Check if discriminant matches this arm
+                            let expected = builder.ins().iconst(I32, arm_disc as i64);
+                            let is_match = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::Equal, disc_i32, expected);
+
+                            // Create next comparison block for remaining arms
+                            let next_block = builder.create_block();
+                            builder.ins().brif(is_match, arm_blocks[i], &[], next_block, &[]);
+
+                            // Switch to next comparison block
+                            builder.switch_to_block(next_block);
+                            // Only seal if it's not the initial block
+                            if current_block != initial_block {
+                                builder.seal_block(current_block);
+                            }
+                            sealed_blocks.push(current_block);
+                            current_block = next_block;
                         }
-                        sealed_blocks.push(current_block);
-                        current_block = next_block;
                     }
                 }
 
@@ -5586,109 +9214,22 @@ impl CodeGenerator {
                     let mut arm_variables = variables.clone();
                     let mut arm_variable_types = variable_types.clone();
 
+                    // `name @ pattern` binds the whole matched value with the scrutinee's type
+                    if let Some(binding) = &arm.binding {
+                        let whole_var = Variable::from_u32(*variable_counter);
+                        *variable_counter += 1;
+                        builder.declare_var(whole_var, builder.func.dfg.value_type(value_val));
+                        builder.def_var(whole_var, value_val);
+                        arm_variables.insert(binding.clone(), whole_var);
+                        arm_variable_types.insert(binding.clone(), Self::infer_expression_type(value, variable_types));
+                    }
+
                     // Handle pattern bindings for this arm
                     if let Pattern::EnumVariant { bindings, .. } = &arm.pattern {
-                        for (binding_idx, (binding_name, binding_type)) in bindings.iter().enumerate() {
-                            if !binding_name.is_empty() {
-                                // Determine the Cranelift type and VariableType based on the AST type
-                                let (var_type, cranelift_type, is_string) = match binding_type {
-                                    AstType::String => (VariableType::String, I64, true),
-                                    AstType::Int32 => (VariableType::Int32, I32, false),
-                                    AstType::Int64 => (VariableType::Int64, I64, false),
-                                    AstType::Bool => (VariableType::Bool, I32, false),
-                                    AstType::Float32 => (VariableType::Float32, F32, false),
-                                    AstType::Float64 => (VariableType::Float64, F64, false),
-                                    AstType::List(_) => (VariableType::Array(Box::new(VariableType::Int32)), I64, false),
-                                    AstType::Dict(_, _) => (VariableType::Dict, I64, false),
-                                    AstType::Set(_) => (VariableType::Set, I64, false),
-                                    AstType::Named(name, _) => (VariableType::Class(name.clone()), I64, false),
-                                    _ => (VariableType::Int32, I32, false), // Fallback for other types
-                                };
-
-                                // Use runtime detection to handle both packed and heap formats
-                                // This is needed because FFI functions return heap pointers,
-                                // while Plat functions return packed values
-                                // Note: Int64, Float64, List, Dict, Set, and Named types cannot be packed,
-                                // so they always use heap format (i64 pointers)
-                                let is_always_heap = is_string || matches!(binding_type,
-                                    AstType::Int64 | AstType::Float64 |
-                                    AstType::List(_) | AstType::Dict(_, _) | AstType::Set(_) |
-                                    AstType::Named(_, _)
-                                );
-                                let field_val = if bindings.len() == 1 && !is_always_heap {
-                                    // Single 32-bit field: detect format at runtime
-                                    let min_addr = builder.ins().iconst(I64, 0x1000);
-                                    let max_pointer = builder.ins().iconst(I64, 0x7FFFFFFFFFFF);
-
-                                    let above_min = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::UnsignedGreaterThan, value_val, min_addr);
-                                    let below_max = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::UnsignedLessThan, value_val, max_pointer);
-                                    let use_heap = builder.ins().band(above_min, below_max);
-
-                                    // Create blocks for packed vs heap extraction
-                                    let packed_extract = builder.create_block();
-                                    let heap_extract = builder.create_block();
-                                    let extract_done = builder.create_block();
-                                    builder.append_block_param(extract_done, cranelift_type);
-
-                                    builder.ins().brif(use_heap, heap_extract, &[], packed_extract, &[]);
-
-                                    // Packed format: value in low 32 bits (for primitives)
-                                    builder.switch_to_block(packed_extract);
-                                    builder.seal_block(packed_extract);
-                                    let packed_val = if cranelift_type == I32 {
-                                        builder.ins().ireduce(I32, value_val)
-                                    } else {
-                                        value_val // Already I64 or other type
-                                    };
-                                    builder.ins().jump(extract_done, &[packed_val]);
-
-                                    // Heap format: load from offset (4 or 8 depending on type)
-                                    builder.switch_to_block(heap_extract);
-                                    builder.seal_block(heap_extract);
-                                    // 8-byte types (Int64, Float64, String) start at offset 8, 4-byte types at offset 4
-                                    let offset = match binding_type {
-                                        AstType::Int64 | AstType::Float64 | AstType::String => 8,
-                                        _ => 4,
-                                    };
-                                    let heap_val = builder.ins().load(cranelift_type, MemFlags::new(), value_val, offset);
-                                    builder.ins().jump(extract_done, &[heap_val]);
-
-                                    // Done block
-                                    builder.switch_to_block(extract_done);
-                                    builder.seal_block(extract_done);
-                                    builder.block_params(extract_done)[0]
-                                } else {
-                                    // Multi-field, string, or Int64/Float64: always use heap format
-                                    // For single-field Int64/Float64: value_val is a heap pointer, load at offset 8
-                                    // For multi-field: calculate offset based on field index and size
-                                    if bindings.len() == 1 {
-                                        // Single field, must be heap format (Int64/Float64/String)
-                                        let offset = 8; // All 8-byte types start at offset 8
-                                        builder.ins().load(cranelift_type, MemFlags::new(), value_val, offset)
-                                    } else {
-                                        // Multi-field: calculate field size and offset
-                                        let field_size = match binding_type {
-                                            AstType::Int64 | AstType::Float64 | AstType::String => 8,
-                                            _ => 4,
-                                        };
-
-                                        let base_offset = if field_size == 8 { 8 } else { 4 };
-                                        let offset = base_offset + (binding_idx * field_size) as i32;
-                                        builder.ins().load(cranelift_type, MemFlags::new(), value_val, offset)
-                                    }
-                                };
-
-                                let var = Variable::from_u32(*variable_counter);
-                                *variable_counter += 1;
-                                builder.declare_var(var, cranelift_type);
-                                builder.def_var(var, field_val);
-                                arm_variables.insert(binding_name.clone(), var);
-                                arm_variable_types.insert(binding_name.clone(), var_type);
-                            }
-                        }
+                        Self::bind_enum_payload(builder, value_val, bindings, &mut arm_variables, &mut arm_variable_types, variable_counter);
                     }
 
-                    let arm_result = Self::generate_expression_helper(builder, &arm.body, &arm_variables, &arm_variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let arm_result = Self::generate_expression_helper(builder, &arm.body, &arm_variables, &arm_variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                     // Convert arm result to match the expected continuation block type
                     let converted_result = {
@@ -5698,6 +9239,12 @@ impl CodeGenerator {
                             match (arm_result_type, cont_param_type) {
                                 (I64, I32) => builder.ins().ireduce(I32, arm_result),
                                 (I32, I64) => builder.ins().uextend(I64, arm_result),
+                                // Diverging arms (e.g. panic) yield an I64 placeholder
+                                (I64, F64) => builder.ins().bitcast(F64, MemFlags::new(), arm_result),
+                                (I64, F32) => {
+                                    let narrowed = builder.ins().ireduce(I32, arm_result);
+                                    builder.ins().bitcast(F32, MemFlags::new(), narrowed)
+                                }
                                 _ => arm_result, // Same type or unsupported conversion
                             }
                         } else {
@@ -5728,7 +9275,7 @@ impl CodeGenerator {
             }
             Expression::Try { expression, .. } => {
                 // Generate code for the expression
-                let expr_val = Self::generate_expression_helper(builder, expression, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                let expr_val = Self::generate_expression_helper(builder, expression, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
 
                 // The ? operator desugars to:
                 // match expr {
@@ -5855,11 +9402,12 @@ impl CodeGenerator {
 
                 // First, evaluate the object expression to get the class pointer
                 let object_val = Self::generate_expression_helper(
-                    builder, object, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table
+                    builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
             )?;
 
                 // Determine class name from the object type
                 let class_name = Self::get_class_name(object, variable_types)
+                    .or_else(|| Self::call_return_class(object, symbol_table))
                     .ok_or_else(|| CodegenError::UnsupportedFeature(
                         format!("Cannot determine class type for member access")
                     ))?;
@@ -5868,10 +9416,65 @@ impl CodeGenerator {
                 let (offset, field_type) = Self::get_field_info_static(class_metadata, &class_name, member)?;
 
                 // Load the value from the computed offset
-                let field_value = builder.ins().load(field_type, MemFlags::new(), object_val, offset);
+                let field_value = Self::load_class_field(builder, module, field_type, object_val, offset)?;
 
                 Ok(field_value)
             }
+            Expression::OptionalMemberAccess { object, member, .. } => {
+                // Desugars to:
+                // match object {
+                //     Option::Some(x) -> Option::Some(x.member),
+                //     Option::None -> Option::None,
+                // }
+                let class_name = Self::optional_chain_class(object, variable_types, class_metadata)
+                    .ok_or_else(|| CodegenError::UnsupportedFeature(
+                        "Optional chaining requires a class field of type Option<Class> as the receiver".to_string()
+                    ))?;
+                let field = class_metadata.get(&class_name)
+                    .and_then(|metadata| metadata.fields.iter().find(|f| &f.name == member))
+                    .ok_or_else(|| CodegenError::UnsupportedFeature(
+                        format!("Unknown field '{}' in class '{}'", member, class_name)
+                    ))?;
+                let field_is_option = matches!(&field.ty, AstType::Named(name, _) if name == "Option");
+                let (offset, field_type) = (field.offset, field.cranelift_type);
+
+                let option_val = Self::generate_expression_helper(
+                    builder, object, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
+                )?;
+                let disc = Self::enum_discriminant(builder, option_val);
+                let some_const = builder.ins().iconst(I32, Self::variant_discriminant("Option", "Some") as i64);
+                let is_some = builder.ins().icmp(IntCC::Equal, disc, some_const);
+
+                let some_block = builder.create_block();
+                let none_block = builder.create_block();
+                let cont_block = builder.create_block();
+                builder.append_block_param(cont_block, I64);
+                builder.ins().brif(is_some, some_block, &[], none_block, &[]);
+
+                // Some: class payloads are boxed as [discriminant:i32][padding:i32][pointer:i64]
+                builder.switch_to_block(some_block);
+                builder.seal_block(some_block);
+                let object_ptr = builder.ins().load(I64, MemFlags::new(), option_val, 8);
+                let field_value = Self::load_class_field(builder, module, field_type, object_ptr, offset)?;
+                let wrapped = if field_is_option {
+                    // Option fields flatten into the chain's Option
+                    field_value
+                } else {
+                    Self::wrap_option_some(builder, module, field_value)?
+                };
+                builder.ins().jump(cont_block, &[wrapped]);
+
+                // None: propagate None
+                builder.switch_to_block(none_block);
+                builder.seal_block(none_block);
+                let none_disc = builder.ins().iconst(I64, Self::variant_discriminant("Option", "None") as i64);
+                let none_val = builder.ins().ishl_imm(none_disc, 32);
+                builder.ins().jump(cont_block, &[none_val]);
+
+                builder.switch_to_block(cont_block);
+                builder.seal_block(cont_block);
+                Ok(builder.block_params(cont_block)[0])
+            }
             Expression::ConstructorCall { class_name, args, .. } => {
                 // Create a new class instance using direct memory allocation
                 // Look up class size from metadata
@@ -5880,7 +9483,8 @@ impl CodeGenerator {
                         format!("Unknown class '{}' in constructor", class_name)
                     ))?;
                 let class_size = metadata.size as i64;
-                let has_vtable = metadata.has_vtable;
+                // generate_vtables emits no vtable object for classes without virtual methods
+                let has_vtable = metadata.has_vtable && !metadata.virtual_methods.is_empty();
 
                 // Allocate memory using GC
                 let gc_alloc_sig = {
@@ -5921,20 +9525,38 @@ impl CodeGenerator {
                 }
 
                 // Set each field from the named arguments using direct memory stores
+                let mut arg_values = HashMap::new();
                 for arg in args {
                     let field_name = &arg.name;
                     let field_value_expr = &arg.value;
 
                     // Evaluate the field value
                     let field_value = Self::generate_expression_helper(
-                        builder, field_value_expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table
+                        builder, field_value_expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
             )?;
 
                     // Look up field offset from class metadata
-                    let (offset, _field_type) = Self::get_field_info_static(class_metadata, class_name, field_name)?;
+                    let (offset, field_type) = Self::get_field_info_static(class_metadata, class_name, field_name)?;
 
                     // Store the value at the computed offset
-                    builder.ins().store(MemFlags::new(), field_value, class_ptr, offset);
+                    Self::store_class_field(builder, module, field_type, field_value, class_ptr, offset)?;
+                    arg_values.insert(field_name.as_str(), field_value);
+                }
+
+                // Run an explicit init body on the new instance, passing arguments in parameter order
+                let init_name = format!("{}__init", class_name);
+                if let (Some(init_params), Some(&init_id)) = (&metadata.init_params, functions.get(&init_name)) {
+                    let mut call_args = vec![class_ptr];
+                    for param in init_params {
+                        let value = arg_values.get(param.as_str()).copied().ok_or_else(|| {
+                            CodegenError::UnsupportedFeature(
+                                format!("Constructor for '{}' is missing argument '{}'", class_name, param)
+                            )
+                        })?;
+                        call_args.push(value);
+                    }
+                    let init_ref = module.declare_func_in_func(init_id, builder.func);
+                    builder.ins().call(init_ref, &call_args);
                 }
 
                 // Return the class pointer
@@ -5960,7 +9582,7 @@ impl CodeGenerator {
 
                 // Evaluate condition
                 let cond_val = Self::generate_expression_helper(
-                    builder, condition, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table
+                    builder, condition, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
             )?;
 
                 // Convert i32 bool to i8 for conditional branch
@@ -5973,7 +9595,7 @@ impl CodeGenerator {
                 builder.switch_to_block(then_block);
                 builder.seal_block(then_block);
                 let then_val = Self::generate_expression_helper(
-                    builder, then_branch, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table
+                    builder, then_branch, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
             )?;
                 builder.ins().jump(cont_block, &[then_val]);
 
@@ -5985,7 +9607,7 @@ impl CodeGenerator {
                 builder.seal_block(else_block);
                 let else_val = if let Some(else_expr) = else_branch {
                     Self::generate_expression_helper(
-                        builder, else_expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table
+                        builder, else_expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
             )?
                 } else {
                     // If no else branch, default to 0 with the correct type
@@ -6004,7 +9626,7 @@ impl CodeGenerator {
             Expression::Cast { value, target_type, .. } => {
                 // Generate the value to cast
                 let value_val = Self::generate_expression_helper(
-                    builder, value, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table
+                    builder, value, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
             )?;
 
                 // Determine source type
@@ -6064,16 +9686,53 @@ impl CodeGenerator {
                     (VariableType::Int64, AstType::Int32) => builder.ins().ireduce(I32, value_val),
                     (VariableType::Int64, AstType::Int64) => value_val,
 
-                    // Float to float conversions
+                    // Float to float conversions. Widening to F64 is exact; narrowing
+                    // from F64 rounds to the nearest representable F32 (ties to even),
+                    // and values beyond the F32 range become infinity.
                     (VariableType::Float8 | VariableType::Float16 | VariableType::Float32, AstType::Float64) => {
                         builder.ins().fpromote(F64, value_val)
                     }
                     (VariableType::Float64, AstType::Float8 | AstType::Float16 | AstType::Float32) => {
                         builder.ins().fdemote(F32, value_val)
                     }
+                    // Float8/Float16/Float32 are all computed in F32, so these are no-ops
+                    (VariableType::Float8 | VariableType::Float16 | VariableType::Float32, AstType::Float8 | AstType::Float16 | AstType::Float32) => value_val,
+                    (VariableType::Float64, AstType::Float64) => value_val,
+
+                    // Bool to int conversions: true is 1, false is 0
+                    (VariableType::Bool, AstType::Int8 | AstType::Int16 | AstType::Int32 | AstType::Int64) => {
+                        let target = match target_type {
+                            AstType::Int8 => I8,
+                            AstType::Int16 => I16,
+                            AstType::Int32 => I32,
+                            _ => I64,
+                        };
+                        let current = builder.func.dfg.value_type(value_val);
+                        if current.bits() < target.bits() {
+                            builder.ins().uextend(target, value_val)
+                        } else if current.bits() > target.bits() {
+                            builder.ins().ireduce(target, value_val)
+                        } else {
+                            value_val
+                        }
+                    }
+
+                    // Int to bool conversions: any non-zero value is true
+                    (VariableType::Int8 | VariableType::Int16 | VariableType::Int32 | VariableType::Int64, AstType::Bool) => {
+                        let is_nonzero = builder.ins().icmp_imm(IntCC::NotEqual, value_val, 0);
+                        builder.ins().uextend(I32, is_nonzero)
+                    }
+                    (VariableType::Bool, AstType::Bool) => value_val,
 
-                    // Same type (no-op, but we still return the value)
-                    _ => value_val
+                    // Newtype conversions share the underlying representation
+                    (_, AstType::Named(_, _)) => value_val,
+
+                    (source, target) => {
+                        return Err(CodegenError::UnsupportedFeature(format!(
+                            "Cannot cast {:?} to {:?}: casts are only supported between numeric types",
+                            source, target
+                        )));
+                    }
                 };
 
                 Ok(result)
@@ -6084,8 +9743,9 @@ impl CodeGenerator {
                 let empty_locals = HashMap::new();  // Spawn body starts with no local variables
                 Self::find_captured_variables(body, &empty_locals, &mut captured_vars);
 
-                // Filter captured_vars to only include those that exist in outer scope
-                captured_vars.retain(|name| variable_types.contains_key(name));
+                // Filter captured_vars to only include locals of the outer scope
+                // (module-level variables are read from their data objects directly)
+                captured_vars.retain(|name| variables.contains_key(name));
 
                 // Infer the return type of the spawn closure
                 let closure_return_type = if let Expression::Block(block) = body.as_ref() {
@@ -6095,8 +9755,7 @@ impl CodeGenerator {
                 };
 
                 // Create a unique closure function name
-                let closure_name = format!("__spawn_closure_{}", string_counter);
-                *string_counter += 1;
+                let closure_name = string_pool.next_name("__spawn_closure_");
 
                 // Create the closure function signature with the inferred return type
                 let cranelift_return_type = Self::variable_type_to_cranelift_type(&closure_return_type);
@@ -6129,7 +9788,7 @@ impl CodeGenerator {
                         };
                         AstType::List(Box::new(ast_elem_type))
                     }
-                    VariableType::Dict => AstType::Dict(Box::new(AstType::String), Box::new(AstType::Int64)),
+                    VariableType::Dict(_, _) => AstType::Dict(Box::new(AstType::String), Box::new(AstType::Int64)),
                     VariableType::Set => AstType::Set(Box::new(AstType::Int64)),
                     VariableType::Class(name) => AstType::Named(name.clone(), vec![]),
                     VariableType::Enum(name) => AstType::Named(name.clone(), vec![]),
@@ -6207,7 +9866,10 @@ impl CodeGenerator {
 
                     // Generate the body
                     let mut closure_variables = HashMap::new();
-                    let mut closure_variable_types = HashMap::new();
+                    let mut closure_variable_types: HashMap<String, VariableType> = variable_types.iter()
+                        .filter(|(name, _)| !variables.contains_key(*name) && module.get_name(&Self::global_symbol_name(name)).is_some())
+                        .map(|(name, var_type)| (name.clone(), var_type.clone()))
+                        .collect();
                     let mut closure_variable_counter = 0;
 
                     // Extract captured variables from context
@@ -6234,24 +9896,24 @@ impl CodeGenerator {
                     if let Expression::Block(block) = body.as_ref() {
                         // Generate statements in the block
                         let empty_type_aliases = HashMap::new(); // No type aliases in closure scope
-                        let mut has_return = false;
-                        for stmt in &block.statements {
-                            has_return |= Self::generate_statement_helper(
-                                &mut closure_builder,
-                                stmt,
-                                &mut closure_variables,
-                                &mut closure_variable_types,
-                                &mut closure_variable_counter,
-                                functions,
-                                module,
-                                string_counter,
-                                class_metadata,
-                                &empty_type_aliases,
-                                &closure_name,
-                                &Some(return_ast_type.clone()),
-                                test_mode, symbol_table
-            )?;
-                        }
+                        Self::warn_redeclared(&closure_name, &block.statements);
+                        let has_return = Self::generate_block_statements(
+                            &mut closure_builder,
+                            &block.statements,
+                            &mut closure_variables,
+                            &mut closure_variable_types,
+                            &mut closure_variable_counter,
+                            functions,
+                            module,
+                            string_pool,
+                            class_metadata,
+                            &empty_type_aliases,
+                            &closure_name,
+                            &Some(return_ast_type.clone()),
+                            None,
+                            &HashSet::new(),
+                            test_mode, symbol_table
+                        )?;
 
                         // If the block didn't have a return, add a default return
                         if !has_return {
@@ -6273,7 +9935,7 @@ impl CodeGenerator {
                             &closure_variable_types,
                             functions,
                             module,
-                            string_counter,
+                            string_pool,
                             &mut closure_variable_counter,
                             class_metadata,
                             test_mode, symbol_table
@@ -6299,7 +9961,7 @@ impl CodeGenerator {
                         VariableType::Float64 => "plat_spawn_task_f64_ctx",
                         VariableType::String => "plat_spawn_task_string_ctx",
                         VariableType::Array(_) => "plat_spawn_task_ptr_ctx",
-                        VariableType::Dict => "plat_spawn_task_ptr_ctx",
+                        VariableType::Dict(_, _) => "plat_spawn_task_ptr_ctx",
                         VariableType::Set => "plat_spawn_task_ptr_ctx",
                         VariableType::Class(_) => "plat_spawn_task_ptr_ctx",
                         VariableType::Enum(_) => "plat_spawn_task_ptr_ctx",
@@ -6342,6 +10004,43 @@ impl CodeGenerator {
 
                 Ok(task_handle)
             }
+            Expression::Tuple { elements, .. } => {
+                Self::generate_tuple_literal(builder, elements, None, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)
+            }
+            Expression::TupleAccess { tuple, index, .. } => {
+                // `f().0` on a multi-value tuple return reads the result directly, with no tuple struct
+                if let Expression::Call { function, .. } = tuple.as_ref() {
+                    if Self::returns_multiple_values(function, functions, module) {
+                        let func_id = Self::lookup_function_id(function, functions).unwrap();
+                        let return_types: Vec<Type> = module.declarations().get_function_decl(func_id).signature.returns.iter()
+                            .map(|param| param.value_type)
+                            .collect();
+                        let values = Self::generate_tuple_values(builder, tuple, &return_types, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+                        return values.get(*index).copied().ok_or_else(|| CodegenError::UnsupportedFeature(
+                            format!("Tuple index {} is out of bounds", index)
+                        ));
+                    }
+                }
+
+                let element_types = match Self::infer_expression_type(tuple, variable_types) {
+                    VariableType::Tuple(element_types) => element_types,
+                    _ => return Err(CodegenError::UnsupportedFeature(
+                        "Cannot determine tuple element types; bind the tuple to a typed variable first".to_string()
+                    )),
+                };
+
+                let cranelift_types: Vec<Type> = element_types.iter().map(Self::variable_type_to_cranelift_type).collect();
+                let element_type = *cranelift_types.get(*index).ok_or_else(|| CodegenError::UnsupportedFeature(
+                    format!("Tuple index {} is out of bounds", index)
+                ))?;
+
+                let tuple_ptr = Self::generate_expression_helper(
+                    builder, tuple, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
+                )?;
+
+                let (offsets, _size) = Self::tuple_layout(&cranelift_types);
+                Ok(builder.ins().load(element_type, MemFlags::new(), tuple_ptr, offsets[*index]))
+            }
             _ => {
                 // TODO: Implement any remaining expressions
                 Err(CodegenError::UnsupportedFeature("Complex expressions not yet implemented".to_string()))
@@ -6357,7 +10056,7 @@ impl CodeGenerator {
         variable_types: &HashMap<String, VariableType>,
         functions: &HashMap<String, FuncId>,
         module: &mut ObjectModule,
-        string_counter: &mut usize,
+        string_pool: &mut StringPool,
         variable_counter: &mut u32,
         class_metadata: &HashMap<String, ClassMetadata>,
         test_mode: bool,
@@ -6383,6 +10082,7 @@ impl CodeGenerator {
                 AstType::Float64 => "plat_array_create_f64",
                 AstType::String => "plat_array_create_string",
                 AstType::Named(_, _) => "plat_array_create_class", // Custom class types
+                AstType::List(_) | AstType::Dict(_, _) | AstType::Set(_) => "plat_array_create_class", // Nested collection pointers
                 _ => "plat_array_create_i32", // fallback for unknown types
             };
 
@@ -6438,13 +10138,14 @@ impl CodeGenerator {
             AstType::Float64 => (std::mem::size_of::<f64>(), "plat_array_create_f64"),
             AstType::String => (std::mem::size_of::<*const u8>(), "plat_array_create_string"),
             AstType::Named(_, _) => (std::mem::size_of::<*const u8>(), "plat_array_create_class"), // Custom class pointers
+            AstType::List(_) | AstType::Dict(_, _) | AstType::Set(_) => (std::mem::size_of::<*const u8>(), "plat_array_create_class"), // Nested collection pointers
             _ => (std::mem::size_of::<i32>(), "plat_array_create_i32"), // fallback
         };
 
         // Generate all element values
         let mut element_values = Vec::new();
         for element in elements {
-            let element_val = Self::generate_expression_helper(builder, element, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+            let element_val = Self::generate_expression_helper(builder, element, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
             element_values.push(element_val);
         }
 
@@ -6488,6 +10189,67 @@ impl CodeGenerator {
         Ok(array_ptr)
     }
 
+    /// Build a non-escaping array literal entirely in one stack slot: a `RuntimeArray`
+    /// header followed by the element data, so no GC allocation is needed. Only used for
+    /// locals picked by `find_stack_arrays`, which never reach code that could grow or
+    /// retain the array.
+    fn generate_stack_array_literal(
+        builder: &mut FunctionBuilder,
+        elements: &[Expression],
+        element_type: &AstType,
+        variables: &HashMap<String, Variable>,
+        variable_types: &HashMap<String, VariableType>,
+        functions: &HashMap<String, FuncId>,
+        module: &mut ObjectModule,
+        string_pool: &mut StringPool,
+        variable_counter: &mut u32,
+        class_metadata: &HashMap<String, ClassMetadata>,
+        test_mode: bool,
+        symbol_table: Option<&plat_hir::ModuleSymbolTable>
+    ) -> Result<Value, CodegenError> {
+        let element_var_type = match element_type {
+            AstType::Int64 => VariableType::Int64,
+            _ => VariableType::Int32,
+        };
+        let element_ty = Self::variable_type_to_cranelift_type(&element_var_type);
+        let element_tag = Self::array_element_tag(&element_var_type);
+        let element_size = element_ty.bytes();
+
+        let mut element_values = Vec::new();
+        for element in elements {
+            let value = Self::generate_expression_helper(builder, element, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
+            let value = if builder.func.dfg.value_type(value) == I32 && element_ty == I64 {
+                builder.ins().sextend(I64, value)
+            } else {
+                value
+            };
+            element_values.push(value);
+        }
+
+        let data_offset = RUNTIME_ARRAY_HEADER_SIZE as i32;
+        let slot_size = RUNTIME_ARRAY_HEADER_SIZE + element_size * elements.len() as u32;
+        let slot = builder.create_sized_stack_slot(StackSlotData::new(StackSlotKind::ExplicitSlot, slot_size, 3));
+
+        for (i, &value) in element_values.iter().enumerate() {
+            builder.ins().stack_store(value, slot, data_offset + (i as u32 * element_size) as i32);
+        }
+
+        // Header: data pointer, length, capacity, element size, element type tag, owns-data flag
+        let data_ptr = builder.ins().stack_addr(I64, slot, data_offset);
+        let count = builder.ins().iconst(I64, elements.len() as i64);
+        let size_val = builder.ins().iconst(I64, element_size as i64);
+        let tag = builder.ins().iconst(I8, element_tag as i64);
+        let owns_data = builder.ins().iconst(I8, 0);
+        builder.ins().stack_store(data_ptr, slot, 0);
+        builder.ins().stack_store(count, slot, 8);
+        builder.ins().stack_store(count, slot, 16);
+        builder.ins().stack_store(size_val, slot, 24);
+        builder.ins().stack_store(tag, slot, 32);
+        builder.ins().stack_store(owns_data, slot, 33);
+
+        Ok(builder.ins().stack_addr(I64, slot, 0))
+    }
+
     fn generate_literal(
         builder: &mut FunctionBuilder,
         literal: &Literal,
@@ -6495,7 +10257,7 @@ impl CodeGenerator {
         variable_types: &HashMap<String, VariableType>,
         functions: &HashMap<String, FuncId>,
         module: &mut ObjectModule,
-        string_counter: &mut usize,
+        string_pool: &mut StringPool,
         variable_counter: &mut u32,
         class_metadata: &HashMap<String, ClassMetadata>,
         test_mode: bool,
@@ -6550,23 +10312,8 @@ impl CodeGenerator {
                 // Now we need to copy the string data to the allocated memory
                 // For this, we'll create a static string and use memcpy
 
-                // Create a unique string constant name for the source data
-                let string_name = format!("str_{}", *string_counter);
-                *string_counter += 1;
-
-                // Create string data (null-terminated for C compatibility)
-                let mut string_data = s.as_bytes().to_vec();
-                string_data.push(0); // null terminator
-
-                // Declare data object for the source string
-                let string_id = module.declare_data(&string_name, Linkage::Local, false, false)
-                    .map_err(CodegenError::ModuleError)?;
-
-                // Define the string data
-                let mut data_desc = DataDescription::new();
-                data_desc.define(string_data.into_boxed_slice());
-                module.define_data(string_id, &data_desc)
-                    .map_err(CodegenError::ModuleError)?;
+                // Source data is shared by every literal with the same contents
+                let string_id = string_pool.intern(module, s)?;
 
                 // Get a reference to the source string data
                 let string_ref = module.declare_data_in_func(string_id, builder.func);
@@ -6594,18 +10341,8 @@ impl CodeGenerator {
             }
             Literal::InterpolatedString(parts, _) => {
                 if parts.is_empty() {
-                    // Empty interpolated string - create empty string constant
-                    let string_name = format!("str_{}", *string_counter);
-                    *string_counter += 1;
-
-                    let string_data = vec![0u8]; // Just null terminator
-                    let string_id = module.declare_data(&string_name, Linkage::Local, false, false)
-                        .map_err(CodegenError::ModuleError)?;
-                    let mut data_desc = DataDescription::new();
-                    data_desc.define(string_data.into_boxed_slice());
-                    module.define_data(string_id, &data_desc)
-                        .map_err(CodegenError::ModuleError)?;
-
+                    // Empty interpolated string - use the shared empty string constant
+                    let string_id = string_pool.intern(module, "")?;
                     let string_ref = module.declare_data_in_func(string_id, builder.func);
                     return Ok(builder.ins().symbol_value(I64, string_ref));
                 }
@@ -6626,7 +10363,7 @@ impl CodeGenerator {
 
                             // Generate the expression value
                             let expr_val = Self::generate_expression_helper(
-                                builder, expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table
+                                builder, expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table
             )?;
                             expression_data.push((expr_val, expr.as_ref()));
                         }
@@ -6634,17 +10371,7 @@ impl CodeGenerator {
                 }
 
                 // Create template string constant
-                let template_name = format!("str_{}", *string_counter);
-                *string_counter += 1;
-                let mut template_data = template.as_bytes().to_vec();
-                template_data.push(0); // null terminator
-
-                let template_id = module.declare_data(&template_name, Linkage::Local, false, false)
-                    .map_err(CodegenError::ModuleError)?;
-                let mut template_desc = DataDescription::new();
-                template_desc.define(template_data.into_boxed_slice());
-                module.define_data(template_id, &template_desc)
-                    .map_err(CodegenError::ModuleError)?;
+                let template_id = string_pool.intern(module, &template)?;
 
                 let template_ref = module.declare_data_in_func(template_id, builder.func);
                 let template_ptr = builder.ins().symbol_value(I64, template_ref);
@@ -6685,6 +10412,21 @@ impl CodeGenerator {
                             let call = builder.ins().call(convert_ref, &[expr_val]);
                             builder.inst_results(call)[0]
                         }
+                        // Int64 literals (including inlined constants) share the I64 type with string pointers
+                        Expression::Literal(Literal::Integer(_, IntType::I64, _)) => {
+                            let convert_sig = {
+                                let mut sig = module.make_signature();
+                                sig.call_conv = CallConv::SystemV;
+                                sig.params.push(AbiParam::new(I64));
+                                sig.returns.push(AbiParam::new(I64));
+                                sig
+                            };
+                            let convert_id = module.declare_function("plat_i64_to_string", Linkage::Import, &convert_sig)
+                                .map_err(CodegenError::ModuleError)?;
+                            let convert_ref = module.declare_func_in_func(convert_id, builder.func);
+                            let call = builder.ins().call(convert_ref, &[expr_val]);
+                            builder.inst_results(call)[0]
+                        }
                         Expression::Identifier { name, .. } => {
                             // Use the variable type information to determine conversion
                             match variable_types.get(name) {
@@ -6707,7 +10449,7 @@ impl CodeGenerator {
                                     let call = builder.ins().call(convert_ref, &[expr_val]);
                                     builder.inst_results(call)[0]
                                 }
-                                Some(VariableType::Dict) => {
+                                Some(VariableType::Dict(_, _)) => {
                                     // Dict variable, convert to string representation
                                     let convert_sig = {
                                         let mut sig = module.make_signature();
@@ -6864,6 +10606,11 @@ impl CodeGenerator {
                                     let call = builder.ins().call(convert_ref, &[expr_val]);
                                     builder.inst_results(call)[0]
                                 }
+                                Some(VariableType::Tuple(_)) => {
+                                    return Err(CodegenError::UnsupportedFeature(
+                                        format!("Cannot interpolate tuple '{}'; interpolate its elements instead", name)
+                                    ));
+                                }
                                 None => {
                                     // Unknown variable type, fall back to runtime type detection
                                     let val_type = builder.func.dfg.value_type(expr_val);
@@ -7079,7 +10826,7 @@ impl CodeGenerator {
                 // First, evaluate all elements
                 let mut element_values = Vec::new();
                 for element in elements {
-                    let element_val = Self::generate_expression_helper(builder, element, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let element_val = Self::generate_expression_helper(builder, element, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
                     element_values.push(element_val);
                 }
 
@@ -7156,11 +10903,11 @@ impl CodeGenerator {
 
                 for (key_expr, value_expr) in pairs {
                     // Evaluate key (must be string)
-                    let key_val = Self::generate_expression_helper(builder, key_expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let key_val = Self::generate_expression_helper(builder, key_expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
                     keys.push(key_val);
 
                     // Evaluate value
-                    let value_val = Self::generate_expression_helper(builder, value_expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let value_val = Self::generate_expression_helper(builder, value_expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
                     values.push(value_val);
 
                     // Determine value type (simplified - assuming i32 values for now)
@@ -7259,7 +11006,7 @@ impl CodeGenerator {
 
                 for element_expr in elements {
                     // Evaluate element
-                    let value_val = Self::generate_expression_helper(builder, element_expr, variables, variable_types, functions, module, string_counter, variable_counter, class_metadata, test_mode, symbol_table)?;
+                    let value_val = Self::generate_expression_helper(builder, element_expr, variables, variable_types, functions, module, string_pool, variable_counter, class_metadata, test_mode, symbol_table)?;
                     values.push(value_val);
 
                     // Determine value type
@@ -7324,6 +11071,285 @@ impl CodeGenerator {
         }
     }
 
+    /// Wrap a `(found, value)` pair returned by a runtime lookup into an `Option<T>`.
+    /// Values that cannot be packed into 32 bits are boxed as [discriminant:i32][padding:i32][value:i64]
+    fn generate_option_from_found(
+        builder: &mut FunctionBuilder,
+        module: &mut ObjectModule,
+        found: Value,
+        value: Value,
+        value_type: &VariableType,
+    ) -> Result<Value, CodegenError> {
+        // Compute discriminants for Option variants
+        let none_disc = Self::variant_discriminant("Option", "None") as i64;
+        let some_disc = Self::variant_discriminant("Option", "Some") as i64;
+
+        // Create blocks for conditional
+        let some_block = builder.create_block();
+        let none_block = builder.create_block();
+        let merge_block = builder.create_block();
+
+        // Add parameter to merge block for the result
+        builder.append_block_param(merge_block, I64);
+
+        // Branch based on found
+        builder.ins().brif(found, some_block, &[], none_block, &[]);
+
+        // Some block: create Option::Some(value)
+        builder.switch_to_block(some_block);
+        builder.seal_block(some_block);
+
+        // Check if value needs heap allocation (pointer and 64-bit types)
+        let needs_heap = matches!(value_type,
+            VariableType::String | VariableType::Array(_) | VariableType::Dict(_, _) | VariableType::Set |
+            VariableType::Class(_) | VariableType::Enum(_) | VariableType::Int64 | VariableType::Float64
+        );
+
+        let some_value = if needs_heap {
+            let gc_alloc_sig = {
+                let mut sig = module.make_signature();
+                sig.call_conv = CallConv::SystemV;
+                sig.params.push(AbiParam::new(I64));
+                sig.returns.push(AbiParam::new(I64));
+                sig
+            };
+            let gc_alloc_id = module.declare_function("plat_gc_alloc", Linkage::Import, &gc_alloc_sig)
+                .map_err(CodegenError::ModuleError)?;
+            let gc_alloc_ref = module.declare_func_in_func(gc_alloc_id, builder.func);
+
+            let size = builder.ins().iconst(I64, 16);
+            let alloc_call = builder.ins().call(gc_alloc_ref, &[size]);
+            let ptr = builder.inst_results(alloc_call)[0];
+
+            let disc_val = builder.ins().iconst(I32, some_disc);
+            builder.ins().store(MemFlags::new(), disc_val, ptr, 0);
+            builder.ins().store(MemFlags::new(), value, ptr, 8);
+
+            ptr
+        } else {
+            // Pack: discriminant in high 32 bits, value in low 32 bits
+            let disc_64 = builder.ins().iconst(I64, some_disc);
+            let disc_shifted = builder.ins().ishl_imm(disc_64, 32);
+            let value_32 = builder.ins().ireduce(I32, value);
+            let value_64 = builder.ins().uextend(I64, value_32);
+            builder.ins().bor(disc_shifted, value_64)
+        };
+
+        builder.ins().jump(merge_block, &[some_value]);
+
+        // None block: create Option::None
+        builder.switch_to_block(none_block);
+        builder.seal_block(none_block);
+
+        let none_disc_64 = builder.ins().iconst(I64, none_disc);
+        let none_value = builder.ins().ishl_imm(none_disc_64, 32);
+
+        builder.ins().jump(merge_block, &[none_value]);
+
+        // Merge block
+        builder.switch_to_block(merge_block);
+        builder.seal_block(merge_block);
+
+        Ok(builder.block_params(merge_block)[0])
+    }
+
+    /// Read an enum's discriminant, detecting packed vs heap format at runtime
+    fn enum_discriminant(builder: &mut FunctionBuilder, enum_val: Value) -> Value {
+        // Packed format: discriminant in high 32 bits
+        let packed_disc = builder.ins().ushr_imm(enum_val, 32);
+        let packed_disc_i32 = builder.ins().ireduce(I32, packed_disc);
+
+        // Heap format if value looks like a valid pointer address
+        let min_addr = builder.ins().iconst(I64, 0x1000);
+        let max_pointer = builder.ins().iconst(I64, 0x7FFFFFFFFFFF);
+        let above_min = builder.ins().icmp(IntCC::UnsignedGreaterThan, enum_val, min_addr);
+        let below_max = builder.ins().icmp(IntCC::UnsignedLessThan, enum_val, max_pointer);
+        let use_heap = builder.ins().band(above_min, below_max);
+
+        let packed_block = builder.create_block();
+        let heap_block = builder.create_block();
+        let done_block = builder.create_block();
+        builder.append_block_param(done_block, I32);
+        builder.ins().brif(use_heap, heap_block, &[], packed_block, &[]);
+
+        builder.switch_to_block(packed_block);
+        builder.seal_block(packed_block);
+        builder.ins().jump(done_block, &[packed_disc_i32]);
+
+        builder.switch_to_block(heap_block);
+        builder.seal_block(heap_block);
+        let heap_disc = builder.ins().load(I32, MemFlags::new(), enum_val, 0);
+        builder.ins().jump(done_block, &[heap_disc]);
+
+        builder.switch_to_block(done_block);
+        builder.seal_block(done_block);
+        builder.block_params(done_block)[0]
+    }
+
+    /// Build `Option::Some(value)` using the same encoding as enum constructors
+    fn wrap_option_some(builder: &mut FunctionBuilder, module: &mut ObjectModule, value: Value) -> Result<Value, CodegenError> {
+        let discriminant = Self::variant_discriminant("Option", "Some") as i64;
+        let value_type = builder.func.dfg.value_type(value);
+
+        if value_type == I64 || value_type == F64 {
+            // 64-bit payloads don't fit beside the discriminant; box them
+            let mut sig = module.make_signature();
+            sig.call_conv = CallConv::SystemV;
+            sig.params.push(AbiParam::new(I64));
+            sig.returns.push(AbiParam::new(I64));
+            let gc_alloc_id = module.declare_function("plat_gc_alloc", Linkage::Import, &sig)
+                .map_err(CodegenError::ModuleError)?;
+            let gc_alloc_ref = module.declare_func_in_func(gc_alloc_id, builder.func);
+            let size_val = builder.ins().iconst(I64, 16);
+            let call = builder.ins().call(gc_alloc_ref, &[size_val]);
+            let ptr = builder.inst_results(call)[0];
+
+            let disc_val = builder.ins().iconst(I32, discriminant);
+            builder.ins().store(MemFlags::new(), disc_val, ptr, 0);
+            builder.ins().store(MemFlags::new(), value, ptr, 8);
+            return Ok(ptr);
+        }
+
+        let disc_val = builder.ins().iconst(I64, discriminant);
+        let disc_shifted = builder.ins().ishl_imm(disc_val, 32);
+        let value_i64 = if value_type == F32 {
+            let as_i32 = builder.ins().bitcast(I32, MemFlags::new(), value);
+            builder.ins().uextend(I64, as_i32)
+        } else {
+            builder.ins().uextend(I64, value)
+        };
+        Ok(builder.ins().bor(disc_shifted, value_i64))
+    }
+
+    /// The class wrapped by an `Option<Class>` type, if any
+    fn option_payload_class(ty: &AstType) -> Option<String> {
+        match ty {
+            AstType::Named(name, type_params) if name == "Option" && type_params.len() == 1 => {
+                match &type_params[0] {
+                    AstType::Named(class_name, _) => Some(class_name.clone()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve the class inside the Option that an optional chain receiver evaluates to
+    fn optional_chain_class(expr: &Expression, variable_types: &HashMap<String, VariableType>, class_metadata: &HashMap<String, ClassMetadata>) -> Option<String> {
+        let (owner_class, member) = match expr {
+            Expression::MemberAccess { object, member, .. } => {
+                (Self::get_class_name(object, variable_types)?, member)
+            }
+            Expression::OptionalMemberAccess { object, member, .. } => {
+                (Self::optional_chain_class(object, variable_types, class_metadata)?, member)
+            }
+            _ => return None,
+        };
+
+        let field = class_metadata.get(&owner_class)?.fields.iter().find(|f| &f.name == member)?;
+        match (&field.ty, expr) {
+            // A plain class field reached through `?.` is implicitly wrapped in Some
+            (AstType::Named(class_name, _), Expression::OptionalMemberAccess { .. })
+                if class_metadata.contains_key(class_name) => Some(class_name.clone()),
+            (ty, _) => Self::option_payload_class(ty),
+        }
+    }
+
+    /// Confirm a match covers every variant of the matched enum (or has a catch-all arm).
+    /// The last arm is emitted as an unconditional jump, so a gap here would silently
+    /// route unmatched values into it.
+    fn verify_match_exhaustive(arms: &[MatchArm], symbol_table: Option<&plat_hir::ModuleSymbolTable>) -> Result<(), CodegenError> {
+        if arms.iter().any(|arm| matches!(arm.pattern, Pattern::Identifier { .. })) {
+            return Ok(());
+        }
+
+        let covered: HashSet<&str> = arms.iter()
+            .filter_map(|arm| match &arm.pattern {
+                Pattern::EnumVariant { variant, .. } => Some(variant.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        let named_enum = arms.iter().find_map(|arm| match &arm.pattern {
+            Pattern::EnumVariant { enum_name: Some(name), .. } => Some(name.clone()),
+            _ => None,
+        });
+
+        // Resolve the enum's full variant list: built-ins first, then the single user enum
+        // from the symbol table that declares every matched variant
+        let candidates: Vec<&plat_hir::EnumInfo> = symbol_table.into_iter()
+            .flat_map(|table| table.global_symbols.values())
+            .filter_map(|symbol| match symbol {
+                plat_hir::Symbol::Enum(info) => Some(info),
+                _ => None,
+            })
+            .filter(|info| named_enum.as_ref().map_or(true, |name| info.name == *name))
+            .filter(|info| covered.iter().all(|variant| info.variants.contains_key(*variant)))
+            .collect();
+        let (enum_name, variants): (String, Vec<String>) = match named_enum.as_deref() {
+            Some("Option") => ("Option".to_string(), vec!["Some".to_string(), "None".to_string()]),
+            Some("Result") => ("Result".to_string(), vec!["Ok".to_string(), "Err".to_string()]),
+            None if covered.contains("Some") || covered.contains("None") => ("Option".to_string(), vec!["Some".to_string(), "None".to_string()]),
+            None if covered.contains("Ok") || covered.contains("Err") => ("Result".to_string(), vec!["Ok".to_string(), "Err".to_string()]),
+            _ => match candidates.as_slice() {
+                [info] => (info.name.clone(), info.variants.keys().cloned().collect()),
+                // Unknown or ambiguous enum: HIR has already checked it
+                _ => return Ok(()),
+            },
+        };
+
+        let mut missing: Vec<String> = variants.into_iter()
+            .filter(|variant| !covered.contains(variant.as_str()))
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        missing.sort();
+        Err(CodegenError::NonExhaustiveMatch { enum_name, missing })
+    }
+
+    /// Extract the discriminant of an enum value, handling both the packed
+    /// (discriminant in the high 32 bits) and heap-allocated representations.
+    fn generate_enum_discriminant(builder: &mut FunctionBuilder, value_val: Value) -> Value {
+        // Try packed format first - discriminant in high 32 bits
+        let packed_disc = builder.ins().ushr_imm(value_val, 32);
+        let packed_disc_i32 = builder.ins().ireduce(I32, packed_disc);
+
+        // Heap format if value looks like a valid pointer address
+        // Heuristic: heap pointers are typically in range [0x1000, 0x7FFFFFFFFFFF]
+        // Packed enums have discriminant in high 32 bits, often > 0x7FFFFFFFFFFF
+        let min_addr = builder.ins().iconst(I64, 0x1000);
+        let max_pointer = builder.ins().iconst(I64, 0x7FFFFFFFFFFF); // Max 47-bit address
+
+        // Check if value is in typical pointer range
+        let above_min = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::UnsignedGreaterThan, value_val, min_addr);
+        let below_max = builder.ins().icmp(cranelift_codegen::ir::condcodes::IntCC::UnsignedLessThan, value_val, max_pointer);
+        let use_heap = builder.ins().band(above_min, below_max);
+
+        let packed_block = builder.create_block();
+        let heap_block = builder.create_block();
+        let done_block = builder.create_block();
+        builder.append_block_param(done_block, I32);
+
+        builder.ins().brif(use_heap, heap_block, &[], packed_block, &[]);
+
+        // Packed format: use extracted discriminant
+        builder.switch_to_block(packed_block);
+        builder.seal_block(packed_block);
+        builder.ins().jump(done_block, &[packed_disc_i32]);
+
+        // Heap format: load discriminant from memory
+        builder.switch_to_block(heap_block);
+        builder.seal_block(heap_block);
+        let heap_disc = builder.ins().load(I32, MemFlags::new(), value_val, 0);
+        builder.ins().jump(done_block, &[heap_disc]);
+
+        builder.switch_to_block(done_block);
+        builder.seal_block(done_block);
+
+        builder.block_params(done_block)[0]
+    }
+
     fn variant_discriminant(_enum_name: &str, variant_name: &str) -> u32 {
         // Simple hash function for variant discriminants
         // In a real implementation, this would be tracked per enum
@@ -7335,16 +11361,19 @@ impl CodeGenerator {
         hash
     }
 
-    /// Check if a type is Result<Int*, E> or Option<Int*>
-    fn is_result_or_option_with_int_return(ty: &AstType) -> bool {
+    /// Check if a type is Result<Int* | Unit, E> or Option<Int* | Unit>, which `main` turns into an exit code
+    fn is_result_or_option_exit_code_return(ty: &AstType) -> bool {
+        let is_exit_code_payload = |payload: &AstType| match payload {
+            AstType::Int8 | AstType::Int16 | AstType::Int32 | AstType::Int64 => true,
+            AstType::Named(name, params) => name == "Unit" && params.is_empty(),
+            _ => false,
+        };
         match ty {
             AstType::Named(name, type_params) if name == "Result" && type_params.len() >= 1 => {
-                // Check if first type parameter is an integer type
-                matches!(&type_params[0], AstType::Int8 | AstType::Int16 | AstType::Int32 | AstType::Int64)
+                is_exit_code_payload(&type_params[0])
             }
             AstType::Named(name, type_params) if name == "Option" && type_params.len() == 1 => {
-                // Check if type parameter is an integer type
-                matches!(&type_params[0], AstType::Int8 | AstType::Int16 | AstType::Int32 | AstType::Int64)
+                is_exit_code_payload(&type_params[0])
             }
             _ => false
         }
@@ -7356,7 +11385,7 @@ impl CodeGenerator {
             Expression::Identifier { name, .. } => {
                 // Look up variable type
                 if let Some(var_type) = variable_types.get(name) {
-                    matches!(var_type, VariableType::Dict)
+                    matches!(var_type, VariableType::Dict(_, _))
                 } else {
                     false
                 }
@@ -7409,6 +11438,22 @@ impl CodeGenerator {
         }
     }
 
+    /// Compare a value against zero of its own Cranelift type, giving an i8 truth flag.
+    /// Logical operators only type-check on `Bool`, but pointers (i64) and floats are
+    /// handled too so a narrower zero is never compared against a wider operand.
+    fn is_truthy(builder: &mut FunctionBuilder, value: Value) -> Value {
+        let value_type = builder.func.dfg.value_type(value);
+        if value_type == F32 {
+            let zero = builder.ins().f32const(0.0);
+            builder.ins().fcmp(FloatCC::NotEqual, value, zero)
+        } else if value_type == F64 {
+            let zero = builder.ins().f64const(0.0);
+            builder.ins().fcmp(FloatCC::NotEqual, value, zero)
+        } else {
+            builder.ins().icmp_imm(IntCC::NotEqual, value, 0)
+        }
+    }
+
     fn is_list_type(expr: &Expression, variable_types: &HashMap<String, VariableType>) -> bool {
         match expr {
             Expression::Literal(Literal::Array(_, _)) => true,
@@ -7424,13 +11469,42 @@ impl CodeGenerator {
         }
     }
 
+    fn is_string_type(expr: &Expression, variable_types: &HashMap<String, VariableType>) -> bool {
+        match expr {
+            Expression::Literal(Literal::String(_, _)) | Expression::Literal(Literal::InterpolatedString(_, _)) => true,
+            Expression::Identifier { name, .. } => {
+                // Look up variable type
+                if let Some(var_type) = variable_types.get(name) {
+                    matches!(var_type, VariableType::String)
+                } else {
+                    false
+                }
+            }
+            _ => false
+        }
+    }
+
+    /// Whether `expr` is a float: a float literal, variable or expression, or a float field of a class instance
+    fn is_float_type(expr: &Expression, variable_types: &HashMap<String, VariableType>, class_metadata: &HashMap<String, ClassMetadata>) -> bool {
+        if let Expression::MemberAccess { object, member, .. } = expr {
+            if let Some(class_name) = Self::get_class_name(object, variable_types) {
+                return class_metadata.get(&class_name)
+                    .and_then(|metadata| metadata.fields.iter().find(|field| &field.name == member))
+                    .is_some_and(|field| matches!(field.ty, AstType::Float8 | AstType::Float16 | AstType::Float32 | AstType::Float64));
+            }
+        }
+        matches!(
+            Self::infer_expression_type(expr, variable_types),
+            VariableType::Float8 | VariableType::Float16 | VariableType::Float32 | VariableType::Float64
+        )
+    }
+
     fn is_class_type(expr: &Expression, variable_types: &HashMap<String, VariableType>) -> bool {
         match expr {
             Expression::ConstructorCall { .. } => true,
             Expression::Self_ { .. } => {
-                // self is always a class instance when it appears
-                eprintln!("DEBUG is_class_type: Expression::Self_ => true");
-                true
+                // self is a class instance unless we're inside an enum method
+                !matches!(variable_types.get("self"), Some(VariableType::Enum(_)))
             }
             Expression::Identifier { name, .. } => {
                 // Look up variable type
@@ -7469,6 +11543,40 @@ impl CodeGenerator {
         }
     }
 
+    /// Resolve the class a cross-module call returns, from its signature in the symbol table
+    fn call_return_class(expr: &Expression, symbol_table: Option<&plat_hir::ModuleSymbolTable>) -> Option<String> {
+        let Expression::Call { function, .. } = expr else {
+            return None;
+        };
+        match symbol_table?.global_symbols.get(function) {
+            Some(plat_hir::Symbol::Function(signature)) => match &signature.return_type {
+                HirType::Class(class_name, _) => Some(class_name.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Resolve `object.method()` to a declared `Enum::method` function, if there is one.
+    /// Annotated enum variables are tracked as `Class(name)`, so both kinds are accepted and the
+    /// `::` naming (class methods use `__`) decides.
+    fn enum_method_name(object: &Expression, method: &str, variable_types: &HashMap<String, VariableType>, functions: &HashMap<String, FuncId>) -> Option<String> {
+        let type_name = match object {
+            Expression::EnumConstructor { enum_name, .. } => enum_name.clone(),
+            Expression::Identifier { name, .. } => match variable_types.get(name) {
+                Some(VariableType::Enum(name)) | Some(VariableType::Class(name)) => name.clone(),
+                _ => return None,
+            },
+            Expression::Self_ { .. } => match variable_types.get("self") {
+                Some(VariableType::Enum(name)) => name.clone(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let qualified_name = format!("{}::{}", type_name, method);
+        functions.contains_key(&qualified_name).then_some(qualified_name)
+    }
+
     fn get_set_value_type(expr: &Expression, variable_types: &HashMap<String, VariableType>) -> u8 {
         // Import the constants from runtime
         const SET_VALUE_TYPE_I32: u8 = 0;
@@ -7506,10 +11614,62 @@ pub enum CodegenError {
     UnsupportedTarget,
     IsaCreationFailed,
     UnsupportedFeature(String),
+    UnknownMethod { ty: String, method: String, span: Option<ErrorSpan> },
+    ArityMismatch { function: String, expected: usize, got: usize, span: Option<ErrorSpan> },
+    TypeMismatch { expected: String, got: String, span: Option<ErrorSpan> },
     UndefinedVariable(String),
     UndefinedFunction(String),
     SettingsError(cranelift_codegen::settings::SetError),
     AssertError(String),
+    DebugInfoError(gimli::write::Error),
+    NonExhaustiveMatch { enum_name: String, missing: Vec<String> },
+    TypeAliasCycle(Vec<String>),
+}
+
+impl CodegenError {
+    /// Record the span of the expression that failed, unless a nested expression already did
+    fn at(mut self, expr_span: ast::Span) -> Self {
+        match &mut self {
+            CodegenError::UnknownMethod { span, .. }
+            | CodegenError::ArityMismatch { span, .. }
+            | CodegenError::TypeMismatch { span, .. } if span.is_none() => {
+                *span = Some(ErrorSpan { span: expr_span, position: None });
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Resolve a recorded span to its file, line and column
+    fn locate(mut self, file: &str, line_starts: &[usize]) -> Self {
+        match &mut self {
+            CodegenError::UnknownMethod { span: Some(error_span), .. }
+            | CodegenError::ArityMismatch { span: Some(error_span), .. }
+            | CodegenError::TypeMismatch { span: Some(error_span), .. } => {
+                let (line, column) = debug_info::line_and_column(line_starts, error_span.span.start);
+                error_span.position = Some((file.to_string(), line as usize, column as usize));
+            }
+            _ => {}
+        }
+        self
+    }
+}
+
+/// Where in the source a codegen error was raised. `position` holds the file, line and
+/// column once the generator has the source text attached
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorSpan {
+    pub span: ast::Span,
+    pub position: Option<(String, usize, usize)>,
+}
+
+impl ErrorSpan {
+    fn prefix(span: &Option<ErrorSpan>) -> String {
+        match span {
+            Some(ErrorSpan { position: Some((file, line, column)), .. }) => format!("{}:{}:{}: ", file, line, column),
+            _ => String::new(),
+        }
+    }
 }
 
 impl From<cranelift_codegen::settings::SetError> for CodegenError {
@@ -7526,10 +11686,25 @@ impl std::fmt::Display for CodegenError {
             CodegenError::UnsupportedTarget => write!(f, "Unsupported target platform"),
             CodegenError::IsaCreationFailed => write!(f, "Failed to create ISA"),
             CodegenError::UnsupportedFeature(msg) => write!(f, "Unsupported feature: {}", msg),
+            CodegenError::UnknownMethod { ty, method, span } => {
+                write!(f, "{}Unknown method '{}' on {}", ErrorSpan::prefix(span), method, ty)
+            }
+            CodegenError::ArityMismatch { function, expected, got, span } => {
+                let plural = if *expected == 1 { "" } else { "s" };
+                write!(f, "{}{}() takes {} argument{}, got {}", ErrorSpan::prefix(span), function, expected, plural, got)
+            }
+            CodegenError::TypeMismatch { expected, got, span } => {
+                write!(f, "{}Type mismatch: expected {}, got {}", ErrorSpan::prefix(span), expected, got)
+            }
             CodegenError::UndefinedVariable(name) => write!(f, "Undefined variable: {}", name),
             CodegenError::UndefinedFunction(name) => write!(f, "Undefined function: {}", name),
             CodegenError::SettingsError(e) => write!(f, "Settings error: {}", e),
             CodegenError::AssertError(msg) => write!(f, "Assert error: {}", msg),
+            CodegenError::DebugInfoError(e) => write!(f, "Debug info error: {}", e),
+            CodegenError::NonExhaustiveMatch { enum_name, missing } => {
+                write!(f, "Non-exhaustive match on '{}': missing variant(s) {}", enum_name, missing.join(", "))
+            }
+            CodegenError::TypeAliasCycle(chain) => write!(f, "Type alias cycle: {}", chain.join(" -> ")),
         }
     }
 }
@@ -0,0 +1,357 @@
+/// Constant propagation of immutable locals, run on the AST before codegen
+/// A `let` whose initializer folds to a scalar literal is replaced by that literal at every
+/// use in its scope, so later initializers can fold in turn (`let y = x * 3` becomes `6`).
+
+use crate::CodeGenerator;
+use plat_ast::{Block, Expression, InterpolationPart, Literal, Pattern, Program, Statement};
+use plat_ast::Type as AstType;
+use std::collections::{HashMap, HashSet};
+
+/// Substitute constant `let` bindings throughout every function body in the program
+pub(crate) fn propagate_let_constants(program: &mut Program) {
+    let functions = program.functions.iter_mut()
+        .chain(program.enums.iter_mut().flat_map(|e| e.methods.iter_mut()))
+        .chain(program.classes.iter_mut().flat_map(|c| c.methods.iter_mut()))
+        .chain(program.test_blocks.iter_mut().flat_map(|t| t.functions.iter_mut()))
+        .chain(program.bench_blocks.iter_mut().flat_map(|b| b.functions.iter_mut()));
+
+    for function in functions {
+        let mut assigned = HashSet::new();
+        collect_assigned_in_block(&function.body, &mut assigned);
+        propagate_in_block(&mut function.body, &HashMap::new(), &assigned);
+    }
+}
+
+/// Declared types whose folded literal keeps the same runtime representation
+fn is_propagatable_type(ty: &AstType) -> bool {
+    matches!(
+        ty,
+        AstType::Bool | AstType::Int8 | AstType::Int16 | AstType::Int32 | AstType::Int64 | AstType::Float32 | AstType::Float64
+    )
+}
+
+fn propagate_in_block(block: &mut Block, outer: &HashMap<String, Literal>, assigned: &HashSet<String>) {
+    // Declarations inside the block go out of scope with it
+    let mut known = outer.clone();
+    for stmt in &mut block.statements {
+        propagate_in_statement(stmt, &mut known, assigned);
+    }
+}
+
+fn propagate_in_statement(stmt: &mut Statement, known: &mut HashMap<String, Literal>, assigned: &HashSet<String>) {
+    match stmt {
+        Statement::Let { name, ty, value, .. } => {
+            propagate_in_expression(value, known, assigned);
+            let folded = CodeGenerator::fold_constant(value, &HashMap::new()).ok()
+                .filter(|_| is_propagatable_type(ty) && !assigned.contains(name.as_str()));
+            match folded {
+                Some(literal) => {
+                    let literal = CodeGenerator::coerce_constant(literal, ty);
+                    *value = Expression::Literal(literal.clone());
+                    known.insert(name.clone(), literal);
+                }
+                None => {
+                    known.remove(name.as_str());
+                }
+            }
+        }
+        Statement::Var { name, value, .. } => {
+            propagate_in_expression(value, known, assigned);
+            known.remove(name.as_str());
+        }
+        Statement::LetPattern { pattern, value, .. } => {
+            propagate_in_expression(value, known, assigned);
+            forget_pattern_bindings(pattern, known);
+        }
+        Statement::Expression(expression) | Statement::Print { value: expression, .. } => {
+            propagate_in_expression(expression, known, assigned);
+        }
+        Statement::Return { value, .. } => {
+            if let Some(expr) = value {
+                propagate_in_expression(expr, known, assigned);
+            }
+        }
+        Statement::If { condition, then_branch, else_branch, .. } => {
+            propagate_in_expression(condition, known, assigned);
+            propagate_in_block(then_branch, known, assigned);
+            if let Some(else_block) = else_branch {
+                propagate_in_block(else_block, known, assigned);
+            }
+        }
+        Statement::While { condition, body, .. } => {
+            propagate_in_expression(condition, known, assigned);
+            propagate_in_block(body, known, assigned);
+        }
+        Statement::For { variable, value_binding, iterable, body, .. } => {
+            propagate_in_expression(iterable, known, assigned);
+            let mut inner = known.clone();
+            inner.remove(variable.as_str());
+            if let Some((value_name, _)) = value_binding {
+                inner.remove(value_name.as_str());
+            }
+            propagate_in_block(body, &inner, assigned);
+        }
+        Statement::Concurrent { body, .. } => {
+            propagate_in_block(body, known, assigned);
+        }
+        Statement::Select { arms, default, .. } => {
+            for arm in arms {
+                propagate_in_expression(&mut arm.channel, known, assigned);
+                let mut inner = known.clone();
+                inner.remove(arm.binding.as_str());
+                propagate_in_block(&mut arm.body, &inner, assigned);
+            }
+            if let Some(default_block) = default {
+                propagate_in_block(default_block, known, assigned);
+            }
+        }
+    }
+}
+
+fn propagate_in_expression(expr: &mut Expression, known: &HashMap<String, Literal>, assigned: &HashSet<String>) {
+    match expr {
+        Expression::Identifier { name, span } => {
+            if let Some(literal) = known.get(name.as_str()) {
+                let span = *span;
+                *expr = Expression::Literal(match literal.clone() {
+                    Literal::Bool(b, _) => Literal::Bool(b, span),
+                    Literal::Integer(v, int_type, _) => Literal::Integer(v, int_type, span),
+                    Literal::Float(v, float_type, _) => Literal::Float(v, float_type, span),
+                    other => other,
+                });
+            }
+        }
+        Expression::Literal(literal) => match literal {
+            Literal::InterpolatedString(parts, _) => {
+                for part in parts {
+                    if let InterpolationPart::Expression(expr) = part {
+                        propagate_in_expression(expr, known, assigned);
+                    }
+                }
+            }
+            Literal::Array(elements, _) | Literal::Set(elements, _) => {
+                for element in elements {
+                    propagate_in_expression(element, known, assigned);
+                }
+            }
+            Literal::Dict(pairs, _) => {
+                for (key, value) in pairs {
+                    propagate_in_expression(key, known, assigned);
+                    propagate_in_expression(value, known, assigned);
+                }
+            }
+            _ => {}
+        },
+        Expression::Self_ { .. } => {}
+        Expression::Binary { left, right, .. }
+        | Expression::Index { object: left, index: right, .. } => {
+            propagate_in_expression(left, known, assigned);
+            propagate_in_expression(right, known, assigned);
+        }
+        Expression::Range { start, end, step, .. } => {
+            propagate_in_expression(start, known, assigned);
+            propagate_in_expression(end, known, assigned);
+            if let Some(step) = step {
+                propagate_in_expression(step, known, assigned);
+            }
+        }
+        Expression::Assignment { target, value, .. } => {
+            // Only `var`s are assigned, but index/member targets may read constants
+            if !matches!(target.as_ref(), Expression::Identifier { .. }) {
+                propagate_in_expression(target, known, assigned);
+            }
+            propagate_in_expression(value, known, assigned);
+        }
+        Expression::Unary { operand: inner, .. }
+        | Expression::MemberAccess { object: inner, .. }
+        | Expression::OptionalMemberAccess { object: inner, .. }
+        | Expression::TupleAccess { tuple: inner, .. }
+        | Expression::Cast { value: inner, .. }
+        | Expression::Try { expression: inner, .. }
+        | Expression::Spawn { body: inner, .. } => propagate_in_expression(inner, known, assigned),
+        Expression::Call { args, .. }
+        | Expression::EnumConstructor { args, .. }
+        | Expression::ConstructorCall { args, .. }
+        | Expression::SuperCall { args, .. } => {
+            for arg in args {
+                propagate_in_expression(&mut arg.value, known, assigned);
+            }
+        }
+        Expression::MethodCall { object, args, .. } => {
+            propagate_in_expression(object, known, assigned);
+            for arg in args {
+                propagate_in_expression(&mut arg.value, known, assigned);
+            }
+        }
+        Expression::Block(block) => propagate_in_block(block, known, assigned),
+        Expression::Match { value, arms, .. } => {
+            propagate_in_expression(value, known, assigned);
+            for arm in arms {
+                let mut inner = known.clone();
+                forget_pattern_bindings(&arm.pattern, &mut inner);
+                if let Some(binding) = &arm.binding {
+                    inner.remove(binding.as_str());
+                }
+                propagate_in_expression(&mut arm.body, &inner, assigned);
+            }
+        }
+        Expression::If { condition, then_branch, else_branch, .. } => {
+            propagate_in_expression(condition, known, assigned);
+            propagate_in_expression(then_branch, known, assigned);
+            if let Some(else_expr) = else_branch {
+                propagate_in_expression(else_expr, known, assigned);
+            }
+        }
+        Expression::Tuple { elements, .. } => {
+            for element in elements {
+                propagate_in_expression(element, known, assigned);
+            }
+        }
+    }
+}
+
+/// A pattern binding shadows any outer constant of the same name
+fn forget_pattern_bindings(pattern: &Pattern, known: &mut HashMap<String, Literal>) {
+    match pattern {
+        Pattern::EnumVariant { bindings, .. } | Pattern::Tuple { bindings, .. } => {
+            for (binding, _) in bindings {
+                known.remove(binding.as_str());
+            }
+        }
+        Pattern::Identifier { name, .. } => {
+            known.remove(name.as_str());
+        }
+        Pattern::Literal(_) => {}
+    }
+}
+
+/// Names that appear as the direct target of an assignment anywhere in the block
+fn collect_assigned_in_block(block: &Block, assigned: &mut HashSet<String>) {
+    for stmt in &block.statements {
+        match stmt {
+            Statement::Let { value, .. } | Statement::Var { value, .. } | Statement::LetPattern { value, .. } => {
+                collect_assigned(value, assigned);
+            }
+            Statement::Expression(expression) | Statement::Print { value: expression, .. } => {
+                collect_assigned(expression, assigned);
+            }
+            Statement::Return { value, .. } => {
+                if let Some(expr) = value {
+                    collect_assigned(expr, assigned);
+                }
+            }
+            Statement::If { condition, then_branch, else_branch, .. } => {
+                collect_assigned(condition, assigned);
+                collect_assigned_in_block(then_branch, assigned);
+                if let Some(else_block) = else_branch {
+                    collect_assigned_in_block(else_block, assigned);
+                }
+            }
+            Statement::While { condition, body, .. } => {
+                collect_assigned(condition, assigned);
+                collect_assigned_in_block(body, assigned);
+            }
+            Statement::For { iterable, body, .. } => {
+                collect_assigned(iterable, assigned);
+                collect_assigned_in_block(body, assigned);
+            }
+            Statement::Concurrent { body, .. } => collect_assigned_in_block(body, assigned),
+            Statement::Select { arms, default, .. } => {
+                for arm in arms {
+                    collect_assigned(&arm.channel, assigned);
+                    collect_assigned_in_block(&arm.body, assigned);
+                }
+                if let Some(default_block) = default {
+                    collect_assigned_in_block(default_block, assigned);
+                }
+            }
+        }
+    }
+}
+
+fn collect_assigned(expr: &Expression, assigned: &mut HashSet<String>) {
+    match expr {
+        Expression::Assignment { target, value, .. } => {
+            if let Expression::Identifier { name, .. } = target.as_ref() {
+                assigned.insert(name.clone());
+            } else {
+                collect_assigned(target, assigned);
+            }
+            collect_assigned(value, assigned);
+        }
+        Expression::Literal(literal) => match literal {
+            Literal::InterpolatedString(parts, _) => {
+                for part in parts {
+                    if let InterpolationPart::Expression(expr) = part {
+                        collect_assigned(expr, assigned);
+                    }
+                }
+            }
+            Literal::Array(elements, _) | Literal::Set(elements, _) => {
+                for element in elements {
+                    collect_assigned(element, assigned);
+                }
+            }
+            Literal::Dict(pairs, _) => {
+                for (key, value) in pairs {
+                    collect_assigned(key, assigned);
+                    collect_assigned(value, assigned);
+                }
+            }
+            _ => {}
+        },
+        Expression::Identifier { .. } | Expression::Self_ { .. } => {}
+        Expression::Binary { left, right, .. }
+        | Expression::Index { object: left, index: right, .. } => {
+            collect_assigned(left, assigned);
+            collect_assigned(right, assigned);
+        }
+        Expression::Range { start, end, step, .. } => {
+            collect_assigned(start, assigned);
+            collect_assigned(end, assigned);
+            if let Some(step) = step {
+                collect_assigned(step, assigned);
+            }
+        }
+        Expression::Unary { operand: inner, .. }
+        | Expression::MemberAccess { object: inner, .. }
+        | Expression::OptionalMemberAccess { object: inner, .. }
+        | Expression::TupleAccess { tuple: inner, .. }
+        | Expression::Cast { value: inner, .. }
+        | Expression::Try { expression: inner, .. }
+        | Expression::Spawn { body: inner, .. } => collect_assigned(inner, assigned),
+        Expression::Call { args, .. }
+        | Expression::EnumConstructor { args, .. }
+        | Expression::ConstructorCall { args, .. }
+        | Expression::SuperCall { args, .. } => {
+            for arg in args {
+                collect_assigned(&arg.value, assigned);
+            }
+        }
+        Expression::MethodCall { object, args, .. } => {
+            collect_assigned(object, assigned);
+            for arg in args {
+                collect_assigned(&arg.value, assigned);
+            }
+        }
+        Expression::Block(block) => collect_assigned_in_block(block, assigned),
+        Expression::Match { value, arms, .. } => {
+            collect_assigned(value, assigned);
+            for arm in arms {
+                collect_assigned(&arm.body, assigned);
+            }
+        }
+        Expression::If { condition, then_branch, else_branch, .. } => {
+            collect_assigned(condition, assigned);
+            collect_assigned(then_branch, assigned);
+            if let Some(else_expr) = else_branch {
+                collect_assigned(else_expr, assigned);
+            }
+        }
+        Expression::Tuple { elements, .. } => {
+            for element in elements {
+                collect_assigned(element, assigned);
+            }
+        }
+    }
+}
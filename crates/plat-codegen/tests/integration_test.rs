@@ -16,11 +16,11 @@ fn main() -> Int32 {
 
     // Parse
     let parser = Parser::new(source).unwrap();
-    let program = parser.parse().unwrap();
+    let mut program = parser.parse().unwrap();
 
     // Type check
     let type_checker = TypeChecker::new();
-    type_checker.check_program(&program).unwrap();
+    type_checker.check_program(&mut program).unwrap();
 
     // Generate code
     let codegen = CodeGenerator::new().unwrap();
@@ -30,6 +30,118 @@ fn main() -> Int32 {
     assert!(!object_bytes.is_empty());
 }
 
+#[test]
+fn test_cross_target_compilation() {
+    let source = r#"
+fn main() -> Int32 {
+    print(value = "Hello, target!");
+    return 0;
+}
+"#;
+
+    let parser = Parser::new(source).unwrap();
+    let mut program = parser.parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+
+    let triple: target_lexicon::Triple = "x86_64-unknown-linux-gnu".parse().unwrap();
+    let codegen = CodeGenerator::new_for_target(triple).unwrap();
+    let object_bytes = codegen.generate_code(&program).unwrap();
+
+    // An ELF object for x86_64 regardless of the host
+    assert_eq!(&object_bytes[..4], b"\x7fELF");
+    assert_eq!(u16::from_le_bytes([object_bytes[18], object_bytes[19]]), 62, "e_machine should be EM_X86_64");
+
+    let triple: target_lexicon::Triple = "x86_64-pc-windows-msvc".parse().unwrap();
+    let codegen = CodeGenerator::new_for_target(triple).unwrap();
+    let object_bytes = codegen.generate_code(&program).unwrap();
+
+    // COFF objects start with the machine type (IMAGE_FILE_MACHINE_AMD64)
+    assert_eq!(u16::from_le_bytes([object_bytes[0], object_bytes[1]]), 0x8664);
+}
+
+#[test]
+fn test_position_dependent_linux_object() {
+    use object::{Object, ObjectSection, RelocationFlags};
+
+    let source = r#"
+fn main() -> Int32 {
+    print(value = "static hello");
+    return 0;
+}
+"#;
+
+    let parser = Parser::new(source).unwrap();
+    let mut program = parser.parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+
+    // Count GOT-relative relocations, which only PIC code needs
+    let got_relocations = |bytes: &[u8]| {
+        let object = object::File::parse(bytes).unwrap();
+        object.sections()
+            .flat_map(|section| section.relocations().collect::<Vec<_>>())
+            .filter(|(_, reloc)| matches!(reloc.flags(), RelocationFlags::Elf { r_type } if r_type == object::elf::R_X86_64_GOTPCREL))
+            .count()
+    };
+
+    let linux: target_lexicon::Triple = "x86_64-unknown-linux-gnu".parse().unwrap();
+    let pic = CodeGenerator::new_for_target(linux.clone()).unwrap().generate_code(&program).unwrap();
+    let non_pic = CodeGenerator::new_for_target(linux.clone()).unwrap().with_pic(false).unwrap().generate_code(&program).unwrap();
+    assert!(got_relocations(&pic) > 0, "PIC object should reach imports through the GOT");
+    assert_eq!(got_relocations(&non_pic), 0, "Non-PIC object should use absolute relocations");
+
+    // Apple targets can't opt out of PIC
+    let mac: target_lexicon::Triple = "x86_64-apple-darwin".parse().unwrap();
+    assert!(CodeGenerator::new_for_target(mac).unwrap().with_pic(false).is_err());
+
+    // A position-dependent executable still links and runs on a matching host
+    if target_lexicon::HOST == linux {
+        let codegen = CodeGenerator::new().unwrap().with_pic(false).unwrap();
+        if let Some(stdout) = compile_and_run(&program, codegen, "non_pic", &["-no-pie"]) {
+            assert!(stdout.contains("static hello"), "Unexpected output: {}", stdout);
+        }
+    }
+}
+
+#[test]
+fn test_opt_levels_emit_valid_objects() {
+    use object::{Object, ObjectSymbol};
+
+    let source = r#"
+fn sum_to(n: Int32) -> Int32 {
+    var total: Int32 = 0;
+    for (i: Int32 in 0..n) {
+        total = total + i;
+    }
+    return total;
+}
+
+fn main() -> Int32 {
+    let total: Int32 = sum_to(n = 10);
+    print(value = "total=${total}");
+    return 0;
+}
+"#;
+
+    let parser = Parser::new(source).unwrap();
+    let mut program = parser.parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+
+    for level in ["none", "speed", "speed_and_size"] {
+        let codegen = CodeGenerator::new().unwrap().with_opt_level(level).unwrap();
+        let object_bytes = codegen.generate_code(&program).unwrap();
+        let object = object::File::parse(&*object_bytes)
+            .unwrap_or_else(|e| panic!("opt_level={} produced an invalid object: {}", level, e));
+        assert!(object.symbols().any(|symbol| symbol.name() == Ok("main")), "opt_level={} lost main", level);
+
+        let codegen = CodeGenerator::new().unwrap().with_opt_level(level).unwrap();
+        if let Some(stdout) = compile_and_run(&program, codegen, level, &[]) {
+            assert!(stdout.contains("total=45"), "opt_level={} output: {}", level, stdout);
+        }
+    }
+
+    assert!(CodeGenerator::new().unwrap().with_opt_level("fastest").is_err());
+}
+
 #[test]
 fn test_arithmetic_compilation() {
     let source = r#"
@@ -47,11 +159,11 @@ fn main() -> Int32 {
 
     // Parse
     let parser = Parser::new(source).unwrap();
-    let program = parser.parse().unwrap();
+    let mut program = parser.parse().unwrap();
 
     // Type check
     let type_checker = TypeChecker::new();
-    type_checker.check_program(&program).unwrap();
+    type_checker.check_program(&mut program).unwrap();
 
     // Generate code
     let codegen = CodeGenerator::new().unwrap();
@@ -74,11 +186,11 @@ fn main() -> Int32 {
 
     // Parse
     let parser = Parser::new(source).unwrap();
-    let program = parser.parse().unwrap();
+    let mut program = parser.parse().unwrap();
 
     // Type check
     let type_checker = TypeChecker::new();
-    type_checker.check_program(&program).unwrap();
+    type_checker.check_program(&mut program).unwrap();
 
     // Generate code
     let codegen = CodeGenerator::new().unwrap();
@@ -99,11 +211,11 @@ fn main() -> Int32 {
 
     // Parse
     let parser = Parser::new(source).unwrap();
-    let program = parser.parse().unwrap();
+    let mut program = parser.parse().unwrap();
 
     // Type check
     let type_checker = TypeChecker::new();
-    type_checker.check_program(&program).unwrap();
+    type_checker.check_program(&mut program).unwrap();
 
     // Generate code
     let codegen = CodeGenerator::new().unwrap();
@@ -112,6 +224,160 @@ fn main() -> Int32 {
     assert!(!object_bytes.is_empty());
 }
 
+#[test]
+fn test_debug_line_section_emitted() {
+    use object::{Object, ObjectSection};
+
+    let source = r#"
+fn add(a: Int32, b: Int32) -> Int32 {
+    return a + b;
+}
+
+fn main() -> Int32 {
+    let result: Int32 = add(a = 1, b = 2);
+    return result;
+}
+"#;
+
+    let parser = Parser::new(source).unwrap();
+    let mut program = parser.parse().unwrap();
+
+    let type_checker = TypeChecker::new();
+    type_checker.check_program(&mut program).unwrap();
+
+    let codegen = CodeGenerator::new().unwrap().with_debug_info("add.plat", source);
+    let object_bytes = codegen.generate_code(&program).unwrap();
+
+    let object_file = object::File::parse(&*object_bytes).unwrap();
+    let debug_line = object_file.section_by_name(".debug_line").expect("missing .debug_line section");
+    assert!(!debug_line.data().unwrap().is_empty());
+    assert!(object_file.section_by_name(".debug_info").is_some());
+
+    // Without a source attached no debug sections are emitted
+    let codegen = CodeGenerator::new().unwrap();
+    let object_bytes = codegen.generate_code(&program).unwrap();
+    let object_file = object::File::parse(&*object_bytes).unwrap();
+    assert!(object_file.section_by_name(".debug_line").is_none());
+}
+
+#[test]
+fn test_repeated_string_literals_share_data() {
+    use object::{Object, ObjectSymbol};
+
+    let source = r#"
+fn greet() -> String {
+    return "hello";
+}
+
+fn main() -> Int32 {
+    print(value = "hello");
+    print(value = "hello");
+    let copy: String = "hello";
+    print(value = copy);
+    print(value = greet());
+    return 0;
+}
+"#;
+
+    let parser = Parser::new(source).unwrap();
+    let mut program = parser.parse().unwrap();
+    let type_checker = TypeChecker::new();
+    type_checker.check_program(&mut program).unwrap();
+
+    let object_bytes = CodeGenerator::new().unwrap().generate_code(&program).unwrap();
+    let object = object::File::parse(&*object_bytes).unwrap();
+    let string_symbols: Vec<String> = object.symbols()
+        .filter_map(|symbol| symbol.name().ok().map(str::to_string))
+        .filter(|name| name.starts_with("str_"))
+        .collect();
+    assert_eq!(string_symbols.len(), 1, "Expected one interned literal, found {:?}", string_symbols);
+}
+
+/// Compile, link against the runtime shared library, and run; None if the runtime isn't built
+fn compile_and_run(program: &plat_ast::Program, codegen: CodeGenerator, name: &str, link_args: &[&str]) -> Option<String> {
+    let runtime_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../target/debug");
+    let runtime_lib = runtime_dir.join("libplat_runtime.so");
+    if !runtime_lib.exists() {
+        return None;
+    }
+
+    let object_bytes = codegen.generate_code(program).unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let object_file = temp_dir.path().join(format!("{}.o", name));
+    let exe_file = temp_dir.path().join(name);
+    fs::write(&object_file, object_bytes).unwrap();
+
+    let link = Command::new("cc")
+        .arg("-o")
+        .arg(&exe_file)
+        .arg(&object_file)
+        .arg(&runtime_lib)
+        .arg("-lm")
+        .arg(format!("-Wl,-rpath,{}", runtime_dir.display()))
+        .args(link_args)
+        .output()
+        .unwrap();
+    assert!(link.status.success(), "Linking failed: {}", String::from_utf8_lossy(&link.stderr));
+
+    let run = Command::new(&exe_file).output().unwrap();
+    assert!(run.status.success(), "Program failed: {}", String::from_utf8_lossy(&run.stderr));
+    Some(String::from_utf8_lossy(&run.stdout).into_owned())
+}
+
+#[test]
+fn test_inlining_preserves_output() {
+    let source = r#"
+fn square(x: Int32) -> Int32 {
+    return x * x;
+}
+
+fn area(width: Int64, height: Int64) -> Int64 {
+    let doubled: Int64 = width * 2i64;
+    return doubled * height;
+}
+
+fn is_small(n: Int32) -> Bool {
+    return n < 10;
+}
+
+fn greet(name: String) -> String {
+    return "hello ${name}";
+}
+
+fn main() -> Int32 {
+    let side: Int32 = 7;
+    let squared: Int32 = square(x = side);
+    let nested: Int32 = square(x = square(x = 2));
+    let total: Int64 = area(width = 3i64, height = 5i64);
+    let small: Bool = is_small(n = side);
+    let big: Bool = is_small(n = 12);
+    print(value = "square=${squared} nested=${nested}");
+    print(value = "area=${total}");
+    print(value = "small=${small} big=${big}");
+    print(value = greet(name = "plat"));
+    return 0;
+}
+"#;
+
+    let parser = Parser::new(source).unwrap();
+    let mut program = parser.parse().unwrap();
+    let type_checker = TypeChecker::new();
+    type_checker.check_program(&mut program).unwrap();
+
+    // The pass must actually rewrite call sites
+    let plain_object = CodeGenerator::new().unwrap().generate_code(&program).unwrap();
+    let inlined_object = CodeGenerator::new().unwrap().with_inlining().generate_code(&program).unwrap();
+    assert_ne!(plain_object, inlined_object);
+
+    let Some(plain) = compile_and_run(&program, CodeGenerator::new().unwrap(), "plain", &[]) else { return };
+    let inlined = compile_and_run(&program, CodeGenerator::new().unwrap().with_inlining(), "inlined", &[]).unwrap();
+    assert_eq!(plain, inlined);
+    assert!(plain.contains("square=49 nested=16"), "Unexpected output: {}", plain);
+    assert!(plain.contains("area=30"), "Unexpected output: {}", plain);
+    assert!(plain.contains("small=1 big=0"), "Unexpected output: {}", plain);
+    assert!(plain.contains("hello plat"), "Unexpected output: {}", plain);
+}
+
 // This test actually tries to compile and run a simple program
 #[test]
 #[ignore] // Ignore by default as it requires linking
@@ -124,11 +390,11 @@ fn main() -> Int32 {
 
     // Parse
     let parser = Parser::new(source).unwrap();
-    let program = parser.parse().unwrap();
+    let mut program = parser.parse().unwrap();
 
     // Type check
     let type_checker = TypeChecker::new();
-    type_checker.check_program(&program).unwrap();
+    type_checker.check_program(&mut program).unwrap();
 
     // Generate code
     let codegen = CodeGenerator::new().unwrap();
@@ -155,4 +421,546 @@ fn main() -> Int32 {
             assert_eq!(run_result.status.code(), Some(42));
         }
     }
-}
\ No newline at end of file
+}
+#[test]
+fn test_non_exhaustive_match_is_rejected() {
+    let source = r#"
+enum Shape {
+    Circle(Int32),
+    Square(Int32),
+    Point
+}
+
+fn area(s: Shape) -> Int32 {
+    return match s {
+        Shape::Circle(r: Int32) -> r * r * 3,
+        Shape::Square(side: Int32) -> side * side
+    };
+}
+
+fn main() -> Int32 {
+    return area(s = Shape::Point);
+}
+"#;
+
+    // Skip type checking, which would report the missing variant before codegen
+    let parser = Parser::new(source).unwrap();
+    let program = parser.parse().unwrap();
+
+    let codegen = CodeGenerator::new().unwrap();
+    match codegen.generate_code(&program) {
+        Err(plat_codegen::CodegenError::NonExhaustiveMatch { enum_name, missing }) => {
+            assert_eq!(enum_name, "Shape");
+            assert_eq!(missing, vec!["Point".to_string()]);
+        }
+        other => panic!("Expected NonExhaustiveMatch, got {:?}", other.map(|bytes| bytes.len())),
+    }
+
+    let exhaustive = source.replace("side * side\n", "side * side,\n        Shape::Point -> 0\n");
+    let mut program = Parser::new(&exhaustive).unwrap().parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+    assert!(CodeGenerator::new().unwrap().generate_code(&program).is_ok());
+}
+
+#[test]
+fn test_override_signature_must_match_virtual_method() {
+    let source = r#"
+class Shape {
+    let size: Int32;
+
+    pub virtual fn scaled(factor: Int32) -> Int32 {
+        return self.size * factor;
+    }
+}
+
+class Square : Shape {
+    let size: Int32;
+
+    pub override fn scaled(factor: Float64) -> Int32 {
+        return self.size * self.size;
+    }
+}
+
+fn main() -> Int32 {
+    return 0;
+}
+"#;
+
+    // Skip type checking so the mismatch reaches vtable layout
+    let program = Parser::new(source).unwrap().parse().unwrap();
+    match CodeGenerator::new().unwrap().generate_code(&program) {
+        Err(plat_codegen::CodegenError::UnsupportedFeature(message)) => {
+            assert!(message.contains("Override 'Square::scaled'"), "Unexpected message: {}", message);
+            assert!(message.contains("Float64"), "Unexpected message: {}", message);
+        }
+        other => panic!("Expected UnsupportedFeature, got {:?}", other.map(|bytes| bytes.len())),
+    }
+
+    let matching = source.replace("factor: Float64", "factor: Int32");
+    let mut program = Parser::new(&matching).unwrap().parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+    assert!(CodeGenerator::new().unwrap().generate_code(&program).is_ok());
+}
+
+#[test]
+fn test_cyclic_type_alias_is_an_error() {
+    let source = r#"
+type Meters = Distance;
+type Distance = Length;
+type Length = Meters;
+
+fn main() -> Int32 {
+    return 0;
+}
+"#;
+
+    // Skip type checking, which only sees aliases declared earlier in the file
+    let program = Parser::new(source).unwrap().parse().unwrap();
+    match CodeGenerator::new().unwrap().generate_code(&program) {
+        Err(plat_codegen::CodegenError::TypeAliasCycle(chain)) => {
+            assert_eq!(chain, vec!["Meters", "Distance", "Length", "Meters"]);
+        }
+        other => panic!("Expected TypeAliasCycle, got {:?}", other.map(|bytes| bytes.len())),
+    }
+}
+
+#[test]
+fn test_cast_from_string_is_rejected() {
+    let source = r#"
+fn main() -> Int32 {
+    let name: String = "42";
+    let n: Int32 = cast(value = name, target = Int32);
+    return n;
+}
+"#;
+
+    // Skip type checking, which rejects the cast before codegen sees it
+    let program = Parser::new(source).unwrap().parse().unwrap();
+    match CodeGenerator::new().unwrap().generate_code(&program) {
+        Err(plat_codegen::CodegenError::UnsupportedFeature(message)) => {
+            assert!(message.contains("Cannot cast String to Int32"), "Unexpected message: {}", message);
+        }
+        other => panic!("Expected UnsupportedFeature, got {:?}", other.map(|bytes| bytes.len())),
+    }
+}
+
+#[test]
+fn test_logical_operators_compare_operands_at_their_own_width() {
+    let source = r#"
+fn main() -> Int32 {
+    let names: List[String] = ["a"];
+    let ratio: Float64 = 0.5;
+    let wide: Int64 = 0i64;
+    let a: Bool = names and ratio;
+    let b: Bool = wide or ratio;
+    return 0;
+}
+"#;
+
+    // Skip type checking, which only accepts Bool operands; codegen must still emit valid IR
+    let program = Parser::new(source).unwrap().parse().unwrap();
+    assert!(CodeGenerator::new().unwrap().generate_code(&program).is_ok());
+}
+
+#[test]
+fn test_missing_virtual_method_implementation_is_an_error() {
+    let library = r#"
+pub class Shape {
+    let sides: Int32;
+
+    init(sides: Int32) -> Shape {
+        self.sides = sides;
+        return self;
+    }
+
+    pub virtual fn area() -> Int32 {
+        return 0;
+    }
+}
+"#;
+    let source = r#"
+class Square : Shape {
+    let sides: Int32;
+
+    init(sides: Int32) -> Square {
+        self.sides = sides;
+        return self;
+    }
+}
+
+fn main() -> Int32 {
+    return 0;
+}
+"#;
+
+    // The inherited `area` slot needs `shapes::Shape__area`, which is defined in another module.
+    // The parser only accepts a plain parent name, so point the subclass at the imported class directly
+    let library = Parser::new(library).unwrap().parse().unwrap();
+    let mut program = Parser::new(source).unwrap().parse().unwrap();
+    program.classes[0].parent_class = Some("shapes::Shape".to_string());
+    let generator = CodeGenerator::new().unwrap()
+        .with_imported_classes(vec![("shapes".to_string(), library.classes[0].clone())]);
+    match generator.generate_code(&program) {
+        Err(plat_codegen::CodegenError::UnsupportedFeature(message)) => {
+            assert!(message.contains("Class 'Square' has no implementation of virtual method 'area'"), "Unexpected message: {}", message);
+        }
+        other => panic!("Expected UnsupportedFeature, got {:?}", other.map(|bytes| bytes.len())),
+    }
+}
+
+#[test]
+fn test_wrong_arity_method_call_reports_arity_mismatch() {
+    let source = r#"
+fn main() -> Int32 {
+    let data: List[Int32] = [1, 2, 3];
+    let part: List[Int32] = data.slice(start = 1);
+    return part.len();
+}
+"#;
+
+    // Skip type checking, which rejects the call before codegen sees it
+    let program = Parser::new(source).unwrap().parse().unwrap();
+    match CodeGenerator::new().unwrap().generate_code(&program) {
+        Err(error @ plat_codegen::CodegenError::ArityMismatch { .. }) => {
+            assert!(matches!(
+                &error,
+                plat_codegen::CodegenError::ArityMismatch { function, expected: 2, got: 1, .. } if function == "slice"
+            ), "Unexpected error: {:?}", error);
+            assert_eq!(error.to_string(), "slice() takes 2 arguments, got 1");
+        }
+        other => panic!("Expected ArityMismatch, got {:?}", other.map(|bytes| bytes.len())),
+    }
+}
+
+#[test]
+fn test_unknown_method_error_reports_source_location() {
+    let source = r#"
+fn main() -> Int32 {
+    let name: String = "plat";
+    let loud: String = name.shout();
+    return loud.length();
+}
+"#;
+
+    let program = Parser::new(source).unwrap().parse().unwrap();
+    let generator = CodeGenerator::new().unwrap().with_debug_info("shout.plat", source);
+    match generator.generate_code(&program) {
+        Err(error @ plat_codegen::CodegenError::UnknownMethod { .. }) => {
+            let plat_codegen::CodegenError::UnknownMethod { method, span: Some(span), .. } = &error else {
+                panic!("Expected a span on {:?}", error);
+            };
+            assert_eq!(method, "shout");
+            assert_eq!(&source[span.span.start..span.span.end], "name.shout()");
+            assert_eq!(span.position, Some(("shout.plat".to_string(), 4, 24)));
+            assert!(error.to_string().starts_with("shout.plat:4:24: Unknown method 'shout'"), "Unexpected message: {}", error);
+        }
+        other => panic!("Expected UnknownMethod, got {:?}", other.map(|bytes| bytes.len())),
+    }
+}
+
+#[test]
+fn test_bench_mode_wraps_bench_functions_with_timing_hooks() {
+    use object::{Object, ObjectSection, ObjectSymbol, RelocationTarget};
+
+    let source = r#"
+bench math {
+    fn bench_add() {
+        let x: Int32 = 1 + 2;
+    }
+
+    fn bench_mul() {
+        let y: Int32 = 3 * 4;
+    }
+}
+
+fn main() -> Int32 {
+    return 0;
+}
+"#;
+
+    let parser = Parser::new(source).unwrap();
+    let mut program = parser.parse().unwrap();
+    TypeChecker::new().with_bench_mode().check_program(&mut program).unwrap();
+
+    let object_bytes = CodeGenerator::new().unwrap().with_bench_mode().generate_code(&program).unwrap();
+    let object = object::File::parse(&*object_bytes).unwrap();
+
+    for bench in ["bench_add", "bench_mul"] {
+        let wrapper = object.symbols()
+            .find(|symbol| symbol.name() == Ok(bench))
+            .unwrap_or_else(|| panic!("missing wrapper for {}", bench));
+        let section = object.section_by_index(wrapper.section_index().unwrap()).unwrap();
+        let range = wrapper.address()..wrapper.address() + wrapper.size();
+
+        // Calls made by the wrapper, in code order
+        let mut calls: Vec<(u64, String)> = section.relocations()
+            .filter(|(offset, _)| range.contains(offset))
+            .filter_map(|(offset, relocation)| match relocation.target() {
+                RelocationTarget::Symbol(index) => {
+                    Some((offset, object.symbol_by_index(index).unwrap().name().unwrap().to_string()))
+                }
+                _ => None,
+            })
+            .filter(|(_, name)| !name.starts_with("str_"))
+            .collect();
+        calls.sort();
+        let calls: Vec<String> = calls.into_iter().map(|(_, name)| name).collect();
+        assert_eq!(calls, vec![
+            "plat_bench_start".to_string(),
+            format!("{}__bench_body", bench),
+            "plat_bench_end".to_string(),
+        ]);
+    }
+}
+
+#[test]
+fn test_non_escaping_array_literal_stays_on_stack() {
+    use object::{Object, ObjectSymbol};
+
+    let imported = |program: &plat_ast::Program| -> Vec<String> {
+        let object_bytes = CodeGenerator::new().unwrap().generate_code(program).unwrap();
+        let object = object::File::parse(&*object_bytes).unwrap();
+        object.symbols()
+            .filter(|symbol| symbol.is_undefined())
+            .filter_map(|symbol| symbol.name().ok().map(str::to_string))
+            .collect()
+    };
+
+    let source = r#"
+fn main() -> Int32 {
+    let values: List[Int32] = [3, 1, 4, 1, 5];
+    var sum: Int32 = 0;
+    for (value: Int32 in values) {
+        sum = sum + value;
+    }
+    if (sum == 14 and values.len() == 5) {
+        print(value = "sum ok");
+    }
+    return 0;
+}
+"#;
+
+    let mut program = Parser::new(source).unwrap().parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+    let symbols = imported(&program);
+    assert!(!symbols.iter().any(|name| name.starts_with("plat_array_create") || name == "plat_gc_alloc"),
+        "Non-escaping array should not be heap allocated: {:?}", symbols);
+
+    if let Some(stdout) = compile_and_run(&program, CodeGenerator::new().unwrap(), "stack_array", &[]) {
+        assert!(stdout.contains("sum ok"), "Unexpected output: {}", stdout);
+    }
+
+    // Passing the array to another function lets it escape, so it goes through the runtime
+    let escaping = r#"
+fn first(values: List[Int32]) -> Int32 {
+    return values.len();
+}
+
+fn main() -> Int32 {
+    let values: List[Int32] = [3, 1, 4, 1, 5];
+    return first(values = values);
+}
+"#;
+    let mut program = Parser::new(escaping).unwrap().parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+    assert!(imported(&program).iter().any(|name| name == "plat_array_create_i32"));
+}
+
+#[test]
+fn test_drop_frees_heap_values_only() {
+    use object::{Object, ObjectSymbol};
+
+    let source = r#"
+class Node {
+    let value: Int32;
+}
+
+fn main() -> Int32 {
+    var big: List[Int32] = [];
+    for (i: Int32 in 0..100000) {
+        big.push(value = i);
+    }
+    drop(value = big);
+
+    let node: Node = Node.init(value = 1);
+    drop(value = node);
+
+    let count: Int32 = 3;
+    drop(value = count);
+    print(value = "dropped");
+    return 0;
+}
+"#;
+
+    let mut program = Parser::new(source).unwrap().parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+
+    let object_bytes = CodeGenerator::new().unwrap().generate_code(&program).unwrap();
+    let object = object::File::parse(&*object_bytes).unwrap();
+    assert!(object.symbols().any(|symbol| symbol.is_undefined() && symbol.name() == Ok("plat_gc_free")));
+    assert!(object.symbols().any(|symbol| symbol.is_undefined() && symbol.name() == Ok("plat_array_free")));
+
+    if let Some(stdout) = compile_and_run(&program, CodeGenerator::new().unwrap(), "drop_values", &[]) {
+        assert!(stdout.contains("dropped"), "Unexpected output: {}", stdout);
+    }
+
+    // Dropping a plain integer is a no-op and never reaches the runtime
+    let scalar_only = r#"
+fn main() -> Int32 {
+    let count: Int32 = 3;
+    drop(value = count);
+    return 0;
+}
+"#;
+    let mut program = Parser::new(scalar_only).unwrap().parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+    let object_bytes = CodeGenerator::new().unwrap().generate_code(&program).unwrap();
+    let object = object::File::parse(&*object_bytes).unwrap();
+    assert!(!object.symbols().any(|symbol| symbol.name() == Ok("plat_gc_free")));
+}
+
+#[test]
+fn test_drop_view_keeps_source_list() {
+    let source = r#"
+fn main() -> Int32 {
+    let values: List[Int32] = [10, 20, 30, 40, 50];
+    let window: List[Int32] = values.view(start = 1, end = 4);
+    drop(value = window);
+    var total: Int32 = 0;
+    for (value: Int32 in values) {
+        total = total + value;
+    }
+    print(value = "len=${values.len()} total=${total}");
+    return 0;
+}
+"#;
+
+    let mut program = Parser::new(source).unwrap().parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+
+    if let Some(stdout) = compile_and_run(&program, CodeGenerator::new().unwrap(), "drop_view", &[]) {
+        assert!(stdout.contains("len=5 total=150"), "Unexpected output: {}", stdout);
+    }
+}
+
+#[test]
+fn test_main_symbol_follows_target_convention() {
+    use object::{Object, ObjectSymbol};
+
+    let source = r#"
+fn main() -> Int32 {
+    return 0;
+}
+"#;
+
+    // ELF uses the bare name; Mach-O prefixes C symbols with an underscore
+    for (target, expected) in [("x86_64-unknown-linux-gnu", "main"), ("x86_64-apple-darwin", "_main")] {
+        let parser = Parser::new(source).unwrap();
+        let mut program = parser.parse().unwrap();
+        TypeChecker::new().check_program(&mut program).unwrap();
+
+        let triple: target_lexicon::Triple = target.parse().unwrap();
+        let object_bytes = CodeGenerator::new_for_target(triple).unwrap().generate_code(&program).unwrap();
+        let file = object::File::parse(&*object_bytes).unwrap();
+
+        let exported: Vec<&str> = file.symbols()
+            .filter(|symbol| symbol.is_global() && symbol.is_definition())
+            .filter_map(|symbol| symbol.name().ok())
+            .collect();
+        assert_eq!(exported, vec![expected], "unexpected entry symbols for {}", target);
+    }
+}
+
+#[test]
+fn test_let_constants_propagate_and_fold() {
+    let compile = |source: &str| {
+        let mut program = Parser::new(source).unwrap().parse().unwrap();
+        TypeChecker::new().check_program(&mut program).unwrap();
+        let object_bytes = CodeGenerator::new().unwrap().generate_code(&program).unwrap();
+        (program, object_bytes)
+    };
+
+    // `y` folds to the literal 6, so both programs compile to the same object
+    let (program, propagated) = compile(r#"
+fn main() -> Int32 {
+    let x: Int32 = 2;
+    let y: Int32 = x * 3;
+    print(value = "y=${y}");
+    return 0;
+}
+"#);
+    let (_, folded) = compile(r#"
+fn main() -> Int32 {
+    let x: Int32 = 2;
+    let y: Int32 = 6;
+    print(value = "y=${y}");
+    return 0;
+}
+"#);
+    assert_eq!(propagated, folded);
+
+    // A `var` may change, so its uses keep loading it
+    let (_, mutable) = compile(r#"
+fn main() -> Int32 {
+    var x: Int32 = 2;
+    let y: Int32 = x * 3;
+    print(value = "y=${y}");
+    return 0;
+}
+"#);
+    assert_ne!(mutable, folded);
+
+    if let Some(stdout) = compile_and_run(&program, CodeGenerator::new().unwrap(), "propagated", &[]) {
+        assert!(stdout.contains("y=6"), "Unexpected output: {}", stdout);
+    }
+}
+
+#[test]
+fn test_assertions_can_be_stripped_outside_test_mode() {
+    use object::{Object, ObjectSymbol};
+
+    let imports = |object_bytes: &[u8]| -> Vec<String> {
+        let object = object::File::parse(object_bytes).unwrap();
+        object.symbols()
+            .filter(|symbol| symbol.is_undefined())
+            .filter_map(|symbol| symbol.name().ok().map(str::to_string))
+            .collect()
+    };
+
+    let source = r#"
+fn main() -> Int32 {
+    let x: Int32 = 1;
+    if (x > 0) {
+        assert(condition = x == 2, message = "x should be 2");
+    }
+    print(value = "after assert");
+    return 0;
+}
+"#;
+    let mut program = Parser::new(source).unwrap().parse().unwrap();
+    TypeChecker::new().check_program(&mut program).unwrap();
+
+    let kept = CodeGenerator::new().unwrap().generate_code(&program).unwrap();
+    assert!(imports(&kept).iter().any(|name| name == "plat_assert"));
+
+    let stripped = CodeGenerator::new().unwrap().with_assertions(false).generate_code(&program).unwrap();
+    assert!(!imports(&stripped).iter().any(|name| name.starts_with("plat_assert")));
+
+    // The failing assertion is gone, so the program runs to completion
+    if let Some(stdout) = compile_and_run(&program, CodeGenerator::new().unwrap().with_assertions(false), "stripped", &[]) {
+        assert!(stdout.contains("after assert"), "Unexpected output: {}", stdout);
+    }
+
+    // Test mode always keeps assertions
+    let test_source = r#"
+test checks {
+    fn test_truth() {
+        assert(condition = true);
+    }
+}
+"#;
+    let mut test_program = Parser::new(test_source).unwrap().parse().unwrap();
+    TypeChecker::new().with_test_mode().check_program(&mut test_program).unwrap();
+    let test_object = CodeGenerator::new().unwrap().with_test_mode().with_assertions(false).generate_code(&test_program).unwrap();
+    assert!(imports(&test_object).iter().any(|name| name.starts_with("plat_assert_test")));
+}
@@ -47,6 +47,11 @@ impl Formatter {
                 }
                 self.write(part);
             }
+            if !use_decl.items.is_empty() {
+                self.write("::{");
+                self.write(&use_decl.items.join(", "));
+                self.write("}");
+            }
             self.write_line(";");
         }
 
@@ -69,6 +74,41 @@ impl Formatter {
             self.write_line("");
         }
 
+        // Format constants
+        for constant in &program.constants {
+            if constant.is_public {
+                self.write("pub ");
+            }
+            self.write("const ");
+            self.write(&constant.name);
+            self.write(": ");
+            self.format_type(&constant.ty);
+            self.write(" = ");
+            self.format_expression(&constant.value);
+            self.write_line(";");
+        }
+
+        // Add blank line after constants if there are any
+        if !program.constants.is_empty() {
+            self.write_line("");
+        }
+
+        // Format module-level variables
+        for global in &program.globals {
+            self.write("var ");
+            self.write(&global.name);
+            self.write(": ");
+            self.format_type(&global.ty);
+            self.write(" = ");
+            self.format_expression(&global.value);
+            self.write_line(";");
+        }
+
+        // Add blank line after module-level variables if there are any
+        if !program.globals.is_empty() {
+            self.write_line("");
+        }
+
         let mut items_written = 0;
 
         // Format enums first
@@ -360,6 +400,16 @@ impl Formatter {
                     self.write(">");
                 }
             }
+            Type::Tuple(element_types) => {
+                self.write("(");
+                for (i, element_type) in element_types.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    self.format_type(element_type);
+                }
+                self.write(")");
+            }
         }
     }
 
@@ -403,6 +453,13 @@ impl Formatter {
                 self.format_expression(value);
                 self.write_line(";");
             }
+            Statement::LetPattern { pattern, value, .. } => {
+                self.write("let ");
+                self.format_pattern(pattern);
+                self.write(" = ");
+                self.format_expression(value);
+                self.write_line(";");
+            }
             Statement::Var { name, ty, value, .. } => {
                 self.write("var ");
                 self.write(name);
@@ -441,11 +498,17 @@ impl Formatter {
                 self.write(") ");
                 self.format_if_block(body);
             }
-            Statement::For { variable, variable_type, iterable, body, .. } => {
+            Statement::For { variable, variable_type, value_binding, iterable, body, .. } => {
                 self.write("for (");
                 self.write(variable);
                 self.write(": ");
                 self.format_type(variable_type);
+                if let Some((value_name, value_type)) = value_binding {
+                    self.write(", ");
+                    self.write(value_name);
+                    self.write(": ");
+                    self.format_type(value_type);
+                }
                 self.write(" in ");
                 self.format_expression(iterable);
                 self.write(") ");
@@ -460,6 +523,29 @@ impl Formatter {
                 self.write("concurrent ");
                 self.format_if_block(body);
             }
+            Statement::Select { arms, default, .. } => {
+                self.write_line("select {");
+                self.indent += 1;
+                for arm in arms {
+                    self.write_indent();
+                    self.write("recv ");
+                    self.write(&arm.binding);
+                    self.write(" from ");
+                    self.format_expression(&arm.channel);
+                    self.write(" -> ");
+                    self.format_if_block(&arm.body);
+                    self.write_line("");
+                }
+                if let Some(default) = default {
+                    self.write_indent();
+                    self.write("default -> ");
+                    self.format_if_block(default);
+                    self.write_line("");
+                }
+                self.indent -= 1;
+                self.write_indent();
+                self.write("}");
+            }
         }
     }
 
@@ -544,6 +630,10 @@ impl Formatter {
                 self.indent += 1;
                 for arm in arms {
                     self.write_indent();
+                    if let Some(binding) = &arm.binding {
+                        self.write(binding);
+                        self.write(" @ ");
+                    }
                     self.format_pattern(&arm.pattern);
                     self.write(" -> ");
                     self.format_expression(&arm.body);
@@ -565,6 +655,11 @@ impl Formatter {
                 self.write(".");
                 self.write(member);
             }
+            Expression::OptionalMemberAccess { object, member, .. } => {
+                self.format_expression(object);
+                self.write("?.");
+                self.write(member);
+            }
             Expression::ConstructorCall { class_name, args, .. } => {
                 self.write(class_name);
                 self.write("(");
@@ -592,7 +687,7 @@ impl Formatter {
                 }
                 self.write(")");
             }
-            Expression::Range { start, end, inclusive, .. } => {
+            Expression::Range { start, end, inclusive, step, .. } => {
                 self.format_expression(start);
                 if *inclusive {
                     self.write("..=");
@@ -600,6 +695,10 @@ impl Formatter {
                     self.write("..");
                 }
                 self.format_expression(end);
+                if let Some(step) = step {
+                    self.write(" step ");
+                    self.format_expression(step);
+                }
             }
             Expression::If { condition, then_branch, else_branch, .. } => {
                 self.write("if (");
@@ -622,6 +721,21 @@ impl Formatter {
                 self.write("spawn ");
                 self.format_expression(body);
             }
+            Expression::Tuple { elements, .. } => {
+                self.write("(");
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    self.format_expression(element);
+                }
+                self.write(")");
+            }
+            Expression::TupleAccess { tuple, index, .. } => {
+                self.format_expression(tuple);
+                self.write(".");
+                self.write(&index.to_string());
+            }
         }
     }
 
@@ -645,13 +759,16 @@ impl Formatter {
             Literal::String(value, _) => {
                 self.write("\"");
                 // Escape special characters
-                for c in value.chars() {
+                let mut chars = value.chars().peekable();
+                while let Some(c) = chars.next() {
                     match c {
                         '\n' => self.write("\\n"),
                         '\t' => self.write("\\t"),
                         '\r' => self.write("\\r"),
                         '\\' => self.write("\\\\"),
                         '"' => self.write("\\\""),
+                        '\0' => self.write("\\0"),
+                        '$' if chars.peek() == Some(&'{') => self.write("\\$"),
                         c => self.write(&c.to_string()),
                     }
                 }
@@ -663,13 +780,16 @@ impl Formatter {
                     match part {
                         InterpolationPart::Text(text) => {
                             // Escape special characters in text parts
-                            for c in text.chars() {
+                            let mut chars = text.chars().peekable();
+                            while let Some(c) = chars.next() {
                                 match c {
                                     '\n' => self.write("\\n"),
                                     '\t' => self.write("\\t"),
                                     '\r' => self.write("\\r"),
                                     '\\' => self.write("\\\\"),
                                     '"' => self.write("\\\""),
+                                    '\0' => self.write("\\0"),
+                                    '$' if chars.peek() == Some(&'{') => self.write("\\$"),
                                     c => self.write(&c.to_string()),
                                 }
                             }
@@ -781,6 +901,18 @@ impl Formatter {
                     self.write(")");
                 }
             }
+            Pattern::Tuple { bindings, .. } => {
+                self.write("(");
+                for (i, (binding_name, binding_type)) in bindings.iter().enumerate() {
+                    if i > 0 {
+                        self.write(", ");
+                    }
+                    self.write(binding_name);
+                    self.write(": ");
+                    self.format_type(binding_type);
+                }
+                self.write(")");
+            }
             Pattern::Identifier { name, .. } => {
                 self.write(name);
             }
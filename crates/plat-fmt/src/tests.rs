@@ -252,4 +252,49 @@ fn main() {
         let result = Formatter::format(input).unwrap();
         assert_eq!(result, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_format_const_declarations() {
+        let input = r#"const MAX:Int32=100;pub const HALF:Int32=MAX/2;fn main(){print(value = "hi");}"#;
+
+        let expected = r#"const MAX: Int32 = 100;
+pub const HALF: Int32 = MAX / 2;
+
+fn main() {
+  print(value = "hi");
+}
+"#;
+
+        let result = Formatter::format(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_format_global_var() {
+        let input = r#"var counter:Int32=0;fn main(){counter=counter+1;}"#;
+
+        let expected = r#"var counter: Int32 = 0;
+
+fn main() {
+  counter = counter + 1;
+}
+"#;
+
+        let result = Formatter::format(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_format_tuples() {
+        let input = r#"fn main(){let pair:(Int32,String)=(1,"one");let n:Int32=pair.0;}"#;
+
+        let expected = r#"fn main() {
+  let pair: (Int32, String) = (1, "one");
+  let n: Int32 = pair.0;
+}
+"#;
+
+        let result = Formatter::format(input).unwrap();
+        assert_eq!(result, expected);
+    }
+}
@@ -38,6 +38,22 @@ fn is_title_case(name: &str) -> bool {
     name.chars().all(|c| c.is_alphanumeric())
 }
 
+/// Validates that a name follows SCREAMING_SNAKE_CASE convention (used for constants)
+fn is_screaming_snake_case(name: &str) -> bool {
+    if name.is_empty() {
+        return false;
+    }
+
+    // Must start with uppercase letter
+    let first_char = name.chars().next().unwrap();
+    if !first_char.is_uppercase() {
+        return false;
+    }
+
+    // Can only contain uppercase letters, digits, and underscores
+    name.chars().all(|c| c.is_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
 /// Convert a name to snake_case (simple heuristic)
 fn to_snake_case(name: &str) -> String {
     let mut result = String::new();
@@ -61,6 +77,8 @@ pub struct ModuleSymbolTable {
     pub current_module: String,
     /// Imported modules (from `use` statements)
     pub imports: Vec<String>,
+    /// Qualified paths of items imported by name (from `use module::{item}` statements)
+    pub imported_items: Vec<String>,
     /// Map of qualified names to their types (e.g., "database::connect" -> function signature)
     pub global_symbols: HashMap<String, Symbol>,
 }
@@ -77,6 +95,7 @@ impl ModuleSymbolTable {
         Self {
             current_module: module_path,
             imports: Vec::new(),
+            imported_items: Vec::new(),
             global_symbols: HashMap::new(),
         }
     }
@@ -86,6 +105,21 @@ impl ModuleSymbolTable {
         self.imports.push(module_path);
     }
 
+    /// Add a `use` declaration: a whole module, or only the items it names
+    pub fn add_use(&mut self, use_decl: &UseDecl) {
+        if use_decl.items.is_empty() {
+            self.add_import(use_decl.path.join("::"));
+        } else {
+            self.imported_items.extend(use_decl.imported_paths());
+        }
+    }
+
+    /// The qualified path of an item imported by name, if `name` was imported that way
+    pub fn imported_item(&self, name: &str) -> Option<&String> {
+        self.imported_items.iter()
+            .find(|path| path.rsplit("::").next() == Some(name))
+    }
+
     /// Register a symbol in the current module
     pub fn register(&mut self, name: &str, symbol: Symbol) {
         let qualified_name = if self.current_module.is_empty() {
@@ -144,6 +178,13 @@ impl ModuleSymbolTable {
             return Some(current_qualified);
         }
 
+        // Check items imported by name
+        if let Some(path) = self.imported_item(name) {
+            if self.global_symbols.contains_key(path) {
+                return Some(path.clone());
+            }
+        }
+
         // Check in imported modules
         for import in &self.imports {
             let qualified = format!("{}::{}", import, name);
@@ -168,7 +209,10 @@ pub struct TypeChecker {
     classes: HashMap<String, ClassInfo>,
     type_aliases: HashMap<String, HirType>, // Type alias name -> resolved type
     newtypes: HashMap<String, HirType>, // Newtype name -> underlying type (distinct from aliases)
+    constants: HashMap<String, HirType>, // Const name -> declared type (values are inlined by codegen)
+    globals: HashMap<String, HirType>, // Module-level `var` name -> declared type
     current_function_return_type: Option<HirType>,
+    current_function_name: Option<String>, // Function or `Type.method` being checked, for warnings
     current_class_context: Option<String>, // Track which class we're currently type-checking
     current_method_is_init: bool, // Track if we're currently in an init method
     type_parameters: Vec<String>, // Track current type parameters in scope (like T, U)
@@ -181,6 +225,34 @@ pub struct TypeChecker {
     bench_block_names: HashSet<String>, // Track bench block names for uniqueness validation
     in_concurrent_block: bool, // Track if we're currently inside a concurrent block (for spawn validation)
     filename: String, // Source filename for error reporting
+    method_lowerings: HashMap<(usize, usize), MethodLowering>, // Built-in method call span -> how to rewrite it after checking
+    display_lowerings: HashMap<(usize, usize), DisplayLowering>, // Printed/interpolated class value span -> how it becomes a String
+}
+
+/// How a class value passed to `print` or interpolated into a string is turned into
+/// a String (see `lower_display`)
+#[derive(Debug, Clone)]
+enum DisplayLowering {
+    /// The class defines `to_string() -> String`; call it (through the vtable if virtual)
+    ToString,
+    /// No `to_string`; show the class name and the instance's address
+    Describe { class_name: String },
+}
+
+/// Built-in method calls that are rewritten into `match` expressions once type
+/// checking has recorded what the rewrite needs (see `lower_method_call`)
+#[derive(Debug, Clone)]
+enum MethodLowering {
+    /// `unwrap()`/`expect()` on an Option or Result, with the payload type
+    Unwrap { enum_name: String, payload: HirType },
+    /// `ordinal()` on a unit-only enum, with its variants in declaration order
+    Ordinal { enum_name: String, variants: Vec<String> },
+    /// `Enum::from_ordinal(n = ...)` on a unit-only enum, with its variants in declaration order
+    FromOrdinal { enum_name: String, variants: Vec<String> },
+    /// `to_string()` on an enum without its own, with each variant's payload types in declaration order
+    EnumToString { enum_name: String, variants: Vec<(String, Vec<Type>)> },
+    /// A method declared on a newtype, lowered to a call of its free function with `self` first
+    NewtypeMethod { function: String },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -204,6 +276,9 @@ pub enum HirType {
     Newtype(String), // Distinct type wrapping another type
     Task(Box<HirType>), // Task<T> for concurrent spawn expressions
     Channel(Box<HirType>), // Channel<T> for message passing between tasks
+    AtomicInt, // Shared Int64 cell updated with atomic instructions
+    Mutex, // Runtime lock guarding a critical section
+    Tuple(Vec<HirType>), // (T1, T2, ...) element types
     Unit, // For functions that don't return anything
 }
 
@@ -222,6 +297,7 @@ pub struct EnumInfo {
     pub name: String,
     pub type_params: Vec<String>,
     pub variants: HashMap<String, Vec<HirType>>, // variant name -> field types
+    pub variant_order: Vec<String>, // variant names in declaration order
     pub methods: HashMap<String, FunctionSignature>,
     pub is_public: bool, // true if enum is public
 }
@@ -265,7 +341,10 @@ impl TypeChecker {
             classes: HashMap::new(),
             type_aliases: HashMap::new(),
             newtypes: HashMap::new(),
+            constants: HashMap::new(),
+            globals: HashMap::new(),
             current_function_return_type: None,
+            current_function_name: None,
             current_class_context: None,
             current_method_is_init: false,
             type_parameters: Vec::new(),
@@ -273,6 +352,8 @@ impl TypeChecker {
             module_table: ModuleSymbolTable::new(module_path),
             require_main: true, // Default: require main function
             test_mode: false, // Default: not in test mode
+            method_lowerings: HashMap::new(),
+            display_lowerings: HashMap::new(),
             bench_mode: false, // Default: not in bench mode
             test_block_names: HashSet::new(), // Track test block names
             bench_block_names: HashSet::new(), // Track bench block names
@@ -298,7 +379,10 @@ impl TypeChecker {
             classes: HashMap::new(),
             type_aliases: HashMap::new(),
             newtypes: HashMap::new(),
+            constants: HashMap::new(),
+            globals: HashMap::new(),
             current_function_return_type: None,
+            current_function_name: None,
             current_class_context: None,
             current_method_is_init: false,
             type_parameters: Vec::new(),
@@ -306,6 +390,8 @@ impl TypeChecker {
             module_table,
             require_main: false, // Multi-module: don't require main in every module
             test_mode: false, // Default: not in test mode
+            method_lowerings: HashMap::new(),
+            display_lowerings: HashMap::new(),
             bench_mode: false, // Default: not in bench mode
             test_block_names: HashSet::new(), // Track test block names
             bench_block_names: HashSet::new(), // Track bench block names
@@ -349,6 +435,7 @@ impl TypeChecker {
     fn load_symbols_from_module_table(&mut self) {
         let current_module = &self.module_table.current_module;
         let imports = &self.module_table.imports;
+        let imported_items = &self.module_table.imported_items;
 
         for (qualified_name, symbol) in &self.module_table.global_symbols {
             // Check if this symbol is from the current module or an imported module
@@ -356,10 +443,12 @@ impl TypeChecker {
                 // Root module: load all unqualified symbols AND imported modules
                 !qualified_name.contains("::")
                     || imports.iter().any(|imp| qualified_name.starts_with(&format!("{}::", imp)))
+                    || imported_items.contains(qualified_name)
             } else {
                 // Non-root module: check if symbol is from current module or imported modules
                 qualified_name.starts_with(&format!("{}::", current_module))
                     || imports.iter().any(|imp| qualified_name.starts_with(&format!("{}::", imp)))
+                    || imported_items.contains(qualified_name)
             };
 
             if should_load {
@@ -521,6 +610,7 @@ impl TypeChecker {
                 name: qualified_enum_name.clone(),  // Use fully qualified name
                 type_params: enum_decl.type_params.clone(),
                 variants: HashMap::new(), // Empty for now
+                variant_order: enum_decl.variants.iter().map(|v| v.name.clone()).collect(),
                 methods: HashMap::new(),
                 is_public: enum_decl.is_public,
             };
@@ -562,7 +652,27 @@ impl TypeChecker {
             }
         }
 
-        // Now collect all function declarations (can now reference enums)
+        // Register class names before functions so signatures can mention them
+        // (fields and methods are filled in once all functions are known)
+        for class_decl in &program.classes {
+            let qualified_class_name = if global_symbols.current_module.is_empty() {
+                class_decl.name.clone()
+            } else {
+                format!("{}::{}", global_symbols.current_module, class_decl.name)
+            };
+
+            self.classes.entry(qualified_class_name.clone()).or_insert_with(|| ClassInfo {
+                name: qualified_class_name,
+                type_params: class_decl.type_params.clone(),
+                parent_class: class_decl.parent_class.clone(),
+                fields: HashMap::new(),
+                methods: HashMap::new(),
+                virtual_methods: HashMap::new(),
+                is_public: class_decl.is_public,
+            });
+        }
+
+        // Now collect all function declarations (can now reference enums and classes)
         eprintln!("DEBUG collect_symbols_from_program: Registering {} functions", program.functions.len());
         for func in &program.functions {
             eprintln!("DEBUG collect_symbols_from_program: Processing function '{}', is_public={}", func.name, func.is_public);
@@ -791,6 +901,7 @@ impl TypeChecker {
             name: "Option".to_string(),
             type_params: vec!["T".to_string()],
             variants,
+            variant_order: vec!["Some".to_string(), "None".to_string()],
             methods: HashMap::new(),
             is_public: true, // Built-in types are always public
         };
@@ -809,6 +920,7 @@ impl TypeChecker {
             name: "Result".to_string(),
             type_params: vec!["T".to_string(), "E".to_string()],
             variants,
+            variant_order: vec!["Ok".to_string(), "Err".to_string()],
             methods: HashMap::new(),
             is_public: true, // Built-in types are always public
         };
@@ -840,7 +952,7 @@ impl TypeChecker {
                     ));
                 }
             }
-            self.module_table.add_import(use_decl.path.join("::"));
+            self.module_table.add_use(use_decl);
         }
 
         // Process type aliases
@@ -853,6 +965,30 @@ impl TypeChecker {
             self.collect_newtype(newtype)?;
         }
 
+        // Newtype methods become free functions taking the newtype value as an explicit `self`
+        let newtype_methods: Vec<Function> = program.newtypes.iter_mut()
+            .flat_map(|newtype| {
+                let name = newtype.name.clone();
+                std::mem::take(&mut newtype.methods).into_iter()
+                    .map(move |method| Self::newtype_method_function(&name, method))
+            })
+            .collect();
+        program.functions.extend(newtype_methods);
+
+        // Process constants (in declaration order, so later constants may refer to earlier ones)
+        for constant in &program.constants {
+            self.collect_constant(constant)?;
+        }
+
+        // Associated constants are keyed as ClassName::NAME and may refer to earlier siblings unqualified
+        for class_decl in &program.classes {
+            let old_class_context = self.current_class_context.replace(class_decl.name.clone());
+            let result = class_decl.constants.iter()
+                .try_for_each(|constant| self.collect_constant(constant));
+            self.current_class_context = old_class_context;
+            result?;
+        }
+
         // First pass: register enum names (two-phase for recursive types)
         // Phase 1: Register enum names with empty variants
         for enum_decl in &program.enums {
@@ -953,6 +1089,14 @@ impl TypeChecker {
         // Fill in default arguments for all calls before type checking
         self.fill_default_arguments(program);
 
+        // Expand `init` parameters named after fields into `self.field = param;`
+        self.expand_init_field_shorthand(program);
+
+        // Check module-level variables in declaration order (initializers run in this order at startup)
+        for global in &program.globals {
+            self.check_global_var(global, program.module_decl.is_some())?;
+        }
+
         // Third pass: type check all functions
         for function in &program.functions {
             self.check_function(function)?;
@@ -986,6 +1130,14 @@ impl TypeChecker {
             }
         }
 
+        // Lower unwrap/expect/ordinal calls into matches, and printed class values into
+        // `to_string` calls, now that their types are known
+        if !self.method_lowerings.is_empty() || !self.display_lowerings.is_empty() {
+            self.fill_default_arguments(program);
+            // `Enum::from_ordinal` calls now target generated helpers; add one per enum
+            program.functions.extend(self.from_ordinal_helpers());
+        }
+
         Ok(())
     }
 
@@ -1062,6 +1214,188 @@ impl TypeChecker {
         Ok(())
     }
 
+    /// Name of the free function backing a newtype method (`UserId.to_string` -> `__user_id__to_string`)
+    fn newtype_method_name(newtype_name: &str, method: &str) -> String {
+        format!("__{}__{}", to_snake_case(newtype_name), method)
+    }
+
+    /// Turn `fn method(...)` declared on a newtype into a free function taking `self: Newtype` first
+    fn newtype_method_function(newtype_name: &str, mut method: Function) -> Function {
+        method.name = Self::newtype_method_name(newtype_name, &method.name);
+        method.params.insert(0, Parameter {
+            name: "self".to_string(),
+            ty: Type::Named(newtype_name.to_string(), Vec::new()),
+            default_value: None,
+            span: method.span,
+        });
+        method
+    }
+
+    fn collect_constant(&mut self, constant: &ConstDecl) -> Result<(), DiagnosticError> {
+        // Class-associated constants live in the same table under a qualified key
+        let key = match &self.current_class_context {
+            Some(class_name) => format!("{}::{}", class_name, constant.name),
+            None => constant.name.clone(),
+        };
+
+        // Validate constant name follows SCREAMING_SNAKE_CASE
+        if !is_screaming_snake_case(&constant.name) {
+            return Err(DiagnosticError::Rich(
+                Diagnostic::syntax_error(
+                    &self.filename,
+                    constant.span,
+                    format!("Constant name '{}' must be SCREAMING_SNAKE_CASE", constant.name)
+                )
+                .with_label("constant names must use uppercase letters and underscores")
+                .with_help(format!("Try renaming to: {}", to_snake_case(&constant.name).to_uppercase()))
+            ));
+        }
+
+        // Check for duplicate constant definitions
+        if self.constants.contains_key(&key) {
+            return Err(DiagnosticError::Type(
+                format!("Constant '{}' is already defined", constant.name)
+            ));
+        }
+
+        // Constants are inlined at use sites, so only scalar types are allowed
+        let declared_type = self.ast_type_to_hir_type(&constant.ty)?;
+        if !matches!(declared_type,
+            HirType::Bool | HirType::Int8 | HirType::Int16 | HirType::Int32 | HirType::Int64 |
+            HirType::Float8 | HirType::Float16 | HirType::Float32 | HirType::Float64 | HirType::String)
+        {
+            return Err(DiagnosticError::Type(
+                format!("Constant '{}' must have a primitive type (Bool, integer, float or String), found {:?}", constant.name, declared_type)
+            ));
+        }
+
+        if !self.is_constant_expression(&constant.value) {
+            return Err(DiagnosticError::Rich(
+                Diagnostic::syntax_error(
+                    &self.filename,
+                    constant.span,
+                    format!("Constant '{}' must be initialized with a constant expression", constant.name)
+                )
+                .with_label("value cannot be evaluated at compile time")
+                .with_help("Use literals, other constants, and arithmetic, comparison or logical operators")
+            ));
+        }
+
+        // Constants are checked outside any function, so only other constants are in scope
+        self.push_scope();
+        let value_type = self.check_expression(&constant.value, Some(&declared_type));
+        self.pop_scope();
+        let value_type = value_type?;
+
+        if !self.is_assignable(&declared_type, &value_type) {
+            return Err(DiagnosticError::Rich(
+                Diagnostic::type_mismatch(
+                    &self.filename,
+                    constant.span,
+                    &format!("{:?}", declared_type),
+                    &format!("{:?}", value_type)
+                )
+                .with_label("constant type doesn't match value type")
+                .with_help(format!("Change the type annotation to {:?} or convert the value", value_type))
+            ));
+        }
+
+        self.constants.insert(key, declared_type);
+        Ok(())
+    }
+
+    fn check_global_var(&mut self, global: &GlobalVar, in_named_module: bool) -> Result<(), DiagnosticError> {
+        // Initializers run from main, which only the entry file defines
+        if in_named_module {
+            return Err(DiagnosticError::Rich(
+                Diagnostic::syntax_error(
+                    &self.filename,
+                    global.span,
+                    format!("Module-level variable '{}' is only supported in the entry file", global.name)
+                )
+                .with_label("declared in a library module")
+                .with_help("Move the variable to the file containing main, or use a const")
+            ));
+        }
+
+        // Validate variable name follows snake_case
+        if !is_snake_case(&global.name) {
+            return Err(DiagnosticError::Rich(
+                Diagnostic::syntax_error(
+                    &self.filename,
+                    global.span,
+                    format!("Variable name '{}' must be snake_case", global.name)
+                )
+                .with_label("variable names must use lowercase and underscores")
+                .with_help(format!("Try renaming to: {}", to_snake_case(&global.name)))
+            ));
+        }
+
+        if self.globals.contains_key(&global.name) {
+            return Err(DiagnosticError::Type(
+                format!("Module-level variable '{}' is already defined", global.name)
+            ));
+        }
+        if self.constants.contains_key(&global.name) {
+            return Err(DiagnosticError::Type(
+                format!("Module-level variable '{}' conflicts with an existing constant", global.name)
+            ));
+        }
+
+        let declared_type = self.ast_type_to_hir_type(&global.ty)?;
+
+        // Only constants and previously declared globals are in scope for the initializer
+        self.push_scope();
+        let value_type = self.check_expression(&global.value, Some(&declared_type));
+        self.pop_scope();
+        let value_type = value_type?;
+
+        if !self.is_assignable(&declared_type, &value_type) {
+            return Err(DiagnosticError::Rich(
+                Diagnostic::type_mismatch(
+                    &self.filename,
+                    global.span,
+                    &format!("{:?}", declared_type),
+                    &format!("{:?}", value_type)
+                )
+                .with_label("type annotation doesn't match value type")
+                .with_help(format!("Change the type annotation to {:?} or convert the value", value_type))
+            ));
+        }
+
+        self.globals.insert(global.name.clone(), declared_type);
+        Ok(())
+    }
+
+    /// Locals may not shadow module-level variables, so every use of the name refers to the same storage
+    fn check_global_shadowing(&self, name: &str) -> Result<(), DiagnosticError> {
+        if self.globals.contains_key(name) {
+            return Err(DiagnosticError::Type(
+                format!("'{}' shadows a module-level variable", name)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether an expression can be evaluated at compile time (literals, constants and operators on them)
+    fn is_constant_expression(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::Literal(Literal::Bool(..))
+            | Expression::Literal(Literal::Integer(..))
+            | Expression::Literal(Literal::Float(..))
+            | Expression::Literal(Literal::String(..)) => true,
+            Expression::Identifier { name, .. } => self.lookup_constant(name).is_some(),
+            Expression::EnumConstructor { enum_name, variant, args, .. } => {
+                args.is_empty() && self.constants.contains_key(&format!("{}::{}", enum_name, variant))
+            }
+            Expression::Unary { operand, .. } => self.is_constant_expression(operand),
+            Expression::Binary { left, right, .. } => {
+                self.is_constant_expression(left) && self.is_constant_expression(right)
+            }
+            _ => false,
+        }
+    }
+
     /// Phase 1: Register enum name with empty variants (supports recursive types)
     fn register_enum_name(&mut self, enum_decl: &EnumDecl) -> Result<(), DiagnosticError> {
         // Validate enum name follows TitleCase
@@ -1095,6 +1429,7 @@ impl TypeChecker {
                 name: enum_decl.name.clone(),
                 type_params: enum_decl.type_params.clone(),
                 variants: HashMap::new(), // Empty for now
+                variant_order: enum_decl.variants.iter().map(|v| v.name.clone()).collect(),
                 methods: HashMap::new(),
                 is_public: enum_decl.is_public,
             };
@@ -1592,9 +1927,11 @@ impl TypeChecker {
 
         let signature = self.functions[&function.name].clone();
         self.current_function_return_type = Some(signature.return_type.clone());
+        self.current_function_name = Some(function.name.clone());
 
         // Add parameters to scope
         for (param, (param_name, param_type)) in function.params.iter().zip(signature.params.iter()) {
+            self.check_global_shadowing(&param.name)?;
             if self.scopes.last_mut().unwrap().insert(param.name.clone(), param_type.clone()).is_some() {
                 return Err(DiagnosticError::Type(
                     format!("Parameter '{}' is defined multiple times", param.name)
@@ -1607,6 +1944,7 @@ impl TypeChecker {
 
         self.pop_scope();
         self.current_function_return_type = None;
+        self.current_function_name = None;
 
         // Restore old type parameters
         self.type_parameters = old_type_params;
@@ -1657,6 +1995,7 @@ impl TypeChecker {
                 }
 
                 // Check for shadowing (not allowed with let)
+                self.check_global_shadowing(name)?;
                 if self.scopes.last().unwrap().contains_key(name) {
                     return Err(DiagnosticError::Rich(
                         Diagnostic::syntax_error(
@@ -1671,6 +2010,41 @@ impl TypeChecker {
 
                 self.scopes.last_mut().unwrap().insert(name.clone(), explicit_hir_type);
             }
+            Statement::LetPattern { pattern, value, span } => {
+                let value_type = self.check_expression(value, None)?;
+
+                // A let has no fallback branch, so an enum pattern must match every value of its type
+                if let (Pattern::EnumVariant { variant, .. }, HirType::Enum(enum_name, _)) = (pattern, &value_type) {
+                    let variant_count = self.enums.get(enum_name).map_or(0, |info| info.variants.len());
+                    if variant_count != 1 {
+                        return Err(DiagnosticError::Rich(
+                            Diagnostic::syntax_error(
+                                &self.filename,
+                                *span,
+                                format!("Refutable pattern in let: enum '{}' has variants other than '{}'", enum_name, variant)
+                            )
+                            .with_label("this value may not match the pattern")
+                            .with_help("Use a match expression to handle every variant")
+                        ));
+                    }
+                }
+
+                match pattern {
+                    // `let _ = expr;` evaluates the value and binds nothing
+                    Pattern::Identifier { name, .. } if name == "_" => {}
+                    Pattern::EnumVariant { bindings, .. } | Pattern::Tuple { bindings, .. } => {
+                        for (binding_name, _) in bindings {
+                            self.check_global_shadowing(binding_name)?;
+                        }
+
+                        // Bindings go into the current scope, like a plain let
+                        self.check_pattern(pattern, &value_type)?;
+                    }
+                    _ => return Err(DiagnosticError::Type(
+                        "Only tuple, enum variant, and `_` patterns can be destructured by let".to_string()
+                    )),
+                }
+            }
             Statement::Var { name, ty, value, span } => {
                 // Validate variable name follows snake_case
                 if !is_snake_case(name) {
@@ -1706,6 +2080,7 @@ impl TypeChecker {
                 }
 
                 // Check for shadowing (not allowed with var)
+                self.check_global_shadowing(name)?;
                 if self.scopes.last().unwrap().contains_key(name) {
                     return Err(DiagnosticError::Rich(
                         Diagnostic::syntax_error(
@@ -1721,7 +2096,16 @@ impl TypeChecker {
                 self.scopes.last_mut().unwrap().insert(name.clone(), explicit_hir_type);
             }
             Statement::Expression(expr) => {
-                self.check_expression(expr, None)?;
+                let value_type = self.check_expression(expr, None)?;
+
+                // A dropped value is often a forgotten Result; `let _ = ...` makes the intent explicit
+                if value_type != HirType::Unit && !matches!(expr, Expression::Assignment { .. }) {
+                    eprintln!(
+                        "Warning: value of type {:?} is discarded in '{}'; use `let _ = ...` to drop it explicitly",
+                        value_type,
+                        self.current_function_name.as_deref().unwrap_or("<module>")
+                    );
+                }
             }
             Statement::Return { value, .. } => {
                 let expected_return_type = self.current_function_return_type.as_ref()
@@ -1769,7 +2153,7 @@ impl TypeChecker {
                 self.check_block(body)?;
                 self.pop_scope();
             }
-            Statement::For { variable, variable_type, iterable, body, .. } => {
+            Statement::For { variable, variable_type, value_binding, iterable, body, .. } => {
                 // Validate loop variable name follows snake_case
                 if !is_snake_case(variable) {
                     return Err(DiagnosticError::Type(
@@ -1777,9 +2161,63 @@ impl TypeChecker {
                     ));
                 }
 
+                self.check_global_shadowing(variable)?;
+
                 // Convert the explicit variable type annotation to HIR type
                 let explicit_var_type = self.ast_type_to_hir_type(variable_type)?;
 
+                // Dict iteration binds both the key and the value: for (key: K, value: V in dict);
+                // a list of pairs binds both halves: for (index: Int32, item: T in list.enumerate())
+                if let Some((value_name, value_type)) = value_binding {
+                    if !is_snake_case(value_name) {
+                        return Err(DiagnosticError::Type(
+                            format!("Loop variable '{}' must be snake_case", value_name)
+                        ));
+                    }
+                    self.check_global_shadowing(value_name)?;
+                    if value_name == variable {
+                        return Err(DiagnosticError::Type(
+                            format!("Loop variable '{}' is bound twice", value_name)
+                        ));
+                    }
+
+                    let explicit_value_type = self.ast_type_to_hir_type(value_type)?;
+                    let iterable_type = self.check_expression(iterable, None)?;
+                    let (key_type, dict_value_type, source) = match iterable_type {
+                        HirType::Dict(key_type, value_type) => (*key_type, *value_type, "dict"),
+                        HirType::List(element_type) => match *element_type {
+                            HirType::Tuple(mut pair) if pair.len() == 2 => {
+                                let second = pair.pop().unwrap();
+                                (pair.pop().unwrap(), second, "pair")
+                            }
+                            other => return Err(DiagnosticError::Type(
+                                format!("For loop with two bindings over a List needs two-element tuple elements, found {:?}", other)
+                            )),
+                        },
+                        _ => return Err(DiagnosticError::Type(
+                            format!("For loop with key and value bindings can only iterate over Dict types or lists of pairs, found {:?}", iterable_type)
+                        )),
+                    };
+
+                    if explicit_var_type != key_type {
+                        return Err(DiagnosticError::Type(
+                            format!("Loop key type {:?} does not match {} key type {:?}", explicit_var_type, source, key_type)
+                        ));
+                    }
+                    if explicit_value_type != dict_value_type {
+                        return Err(DiagnosticError::Type(
+                            format!("Loop value type {:?} does not match {} value type {:?}", explicit_value_type, source, dict_value_type)
+                        ));
+                    }
+
+                    self.push_scope();
+                    self.scopes.last_mut().unwrap().insert(variable.clone(), explicit_var_type);
+                    self.scopes.last_mut().unwrap().insert(value_name.clone(), explicit_value_type);
+                    self.check_block(body)?;
+                    self.pop_scope();
+                    return Ok(());
+                }
+
                 // Check if the iterable is a Range expression
                 let element_type = if let Expression::Range { .. } = iterable {
                     // Range expressions yield integers, get the type from the range
@@ -1789,11 +2227,15 @@ impl TypeChecker {
                     // Regular collection iteration
                     let iterable_type = self.check_expression(iterable, None)?;
 
-                    // Extract element type from List
+                    // Extract element type from List or Set
                     match iterable_type {
                         HirType::List(element_type) => *element_type,
+                        HirType::Set(element_type) => *element_type,
+                        HirType::Dict(_, _) => return Err(DiagnosticError::Type(
+                            "Dict iteration requires key and value bindings, e.g. for (key: String, value: Int32 in dict)".to_string()
+                        )),
                         _ => return Err(DiagnosticError::Type(
-                            format!("For loop can only iterate over List or Range types, found {:?}", iterable_type)
+                            format!("For loop can only iterate over List, Set, Dict or Range types, found {:?}", iterable_type)
                         )),
                     }
                 };
@@ -1824,6 +2266,7 @@ impl TypeChecker {
                 // Print accepts any type (will be converted to string)
                 match value_type {
                     HirType::Bool | HirType::Int8 | HirType::Int16 | HirType::Int32 | HirType::Int64 | HirType::Float8 | HirType::Float16 | HirType::Float32 | HirType::Float64 | HirType::String => {},
+                    HirType::Class(..) => self.record_display(value, &value_type),
                     _ => return Err(DiagnosticError::Type(
                         format!("Cannot print value of type {:?}", value_type)
                     )),
@@ -1842,6 +2285,29 @@ impl TypeChecker {
                 // Restore the previous concurrent block state
                 self.in_concurrent_block = was_in_concurrent;
             }
+            Statement::Select { arms, default, .. } => {
+                for arm in arms {
+                    let channel_type = self.check_expression(&arm.channel, None)?;
+                    let element_type = match channel_type {
+                        HirType::Channel(element_type) => *element_type,
+                        other => return Err(DiagnosticError::Type(
+                            format!("select can only receive from a Channel, found {:?}", other)
+                        )),
+                    };
+
+                    // The received value is bound only inside its arm
+                    self.push_scope();
+                    self.scopes.last_mut().unwrap().insert(arm.binding.clone(), element_type);
+                    self.check_block(&arm.body)?;
+                    self.pop_scope();
+                }
+
+                if let Some(default_block) = default {
+                    self.push_scope();
+                    self.check_block(default_block)?;
+                    self.pop_scope();
+                }
+            }
         }
         Ok(())
     }
@@ -1970,6 +2436,29 @@ impl TypeChecker {
                     return Ok(HirType::Bool);
                 }
 
+                // Handle built-in __bench_reset function (bench runner internal)
+                if function == "__bench_reset" {
+                    if !args.is_empty() {
+                        return Err(DiagnosticError::Type(
+                            "__bench_reset does not accept any arguments".to_string()
+                        ));
+                    }
+                    return Ok(HirType::Unit);
+                }
+
+                // Handle built-in __bench_report function (bench runner internal)
+                if function == "__bench_report" {
+                    let name_arg = args.iter().find(|arg| arg.name == "name")
+                        .ok_or_else(|| DiagnosticError::Type("__bench_report requires a 'name' parameter".to_string()))?;
+                    let name_type = self.check_expression(&name_arg.value, None)?;
+                    if args.len() != 1 || name_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            "__bench_report takes a single String 'name' argument".to_string()
+                        ));
+                    }
+                    return Ok(HirType::Unit);
+                }
+
                 // Handle built-in tcp_listen function
                 if function == "tcp_listen" {
                     // tcp_listen(host: String, port: Int32) -> Result<Int32, String>
@@ -2138,68 +2627,262 @@ impl TypeChecker {
                     return Ok(HirType::Enum("Result".to_string(), vec![HirType::Bool, HirType::String]));
                 }
 
-                // Handle built-in file_open function
-                if function == "file_open" {
-                    // file_open(path: String, mode: String) -> Result<Int32, String>
+                // Handle built-in udp_bind function
+                if function == "udp_bind" {
+                    // udp_bind(host: String, port: Int32) -> Result<Int32, String>
                     if args.len() != 2 {
                         return Err(DiagnosticError::Type(
-                            "file_open requires exactly 2 arguments: 'path' and 'mode'".to_string()
+                            "udp_bind requires exactly 2 arguments: 'host' and 'port'".to_string()
                         ));
                     }
 
-                    let path_arg = args.iter().find(|arg| arg.name == "path")
-                        .ok_or_else(|| DiagnosticError::Type("file_open requires a 'path' parameter".to_string()))?;
-                    let mode_arg = args.iter().find(|arg| arg.name == "mode")
-                        .ok_or_else(|| DiagnosticError::Type("file_open requires a 'mode' parameter".to_string()))?;
+                    let host_arg = args.iter().find(|arg| arg.name == "host")
+                        .ok_or_else(|| DiagnosticError::Type("udp_bind requires a 'host' parameter".to_string()))?;
+                    let port_arg = args.iter().find(|arg| arg.name == "port")
+                        .ok_or_else(|| DiagnosticError::Type("udp_bind requires a 'port' parameter".to_string()))?;
 
-                    let path_type = self.check_expression(&path_arg.value, None)?;
-                    let mode_type = self.check_expression(&mode_arg.value, None)?;
+                    let host_type = self.check_expression(&host_arg.value, None)?;
+                    let port_type = self.check_expression(&port_arg.value, None)?;
 
-                    if path_type != HirType::String {
+                    if host_type != HirType::String {
                         return Err(DiagnosticError::Type(
-                            format!("file_open 'path' parameter must be String, got {:?}", path_type)
+                            format!("udp_bind 'host' parameter must be String, got {:?}", host_type)
                         ));
                     }
-                    if mode_type != HirType::String {
+                    if port_type != HirType::Int32 {
                         return Err(DiagnosticError::Type(
-                            format!("file_open 'mode' parameter must be String, got {:?}", mode_type)
+                            format!("udp_bind 'port' parameter must be Int32, got {:?}", port_type)
                         ));
                     }
 
                     return Ok(HirType::Enum("Result".to_string(), vec![HirType::Int32, HirType::String]));
                 }
 
-                // Handle built-in file_read function
-                if function == "file_read" {
-                    // file_read(fd: Int32, max_bytes: Int32) -> Result<String, String>
-                    if args.len() != 2 {
+                // Handle built-in udp_send_to function
+                if function == "udp_send_to" {
+                    // udp_send_to(socket: Int32, data: String, host: String, port: Int32) -> Result<Int32, String>
+                    if args.len() != 4 {
                         return Err(DiagnosticError::Type(
-                            "file_read requires exactly 2 arguments: 'fd' and 'max_bytes'".to_string()
+                            "udp_send_to requires exactly 4 arguments: 'socket', 'data', 'host', and 'port'".to_string()
                         ));
                     }
 
-                    let fd_arg = args.iter().find(|arg| arg.name == "fd")
-                        .ok_or_else(|| DiagnosticError::Type("file_read requires a 'fd' parameter".to_string()))?;
-                    let max_bytes_arg = args.iter().find(|arg| arg.name == "max_bytes")
-                        .ok_or_else(|| DiagnosticError::Type("file_read requires a 'max_bytes' parameter".to_string()))?;
+                    let socket_arg = args.iter().find(|arg| arg.name == "socket")
+                        .ok_or_else(|| DiagnosticError::Type("udp_send_to requires a 'socket' parameter".to_string()))?;
+                    let data_arg = args.iter().find(|arg| arg.name == "data")
+                        .ok_or_else(|| DiagnosticError::Type("udp_send_to requires a 'data' parameter".to_string()))?;
+                    let host_arg = args.iter().find(|arg| arg.name == "host")
+                        .ok_or_else(|| DiagnosticError::Type("udp_send_to requires a 'host' parameter".to_string()))?;
+                    let port_arg = args.iter().find(|arg| arg.name == "port")
+                        .ok_or_else(|| DiagnosticError::Type("udp_send_to requires a 'port' parameter".to_string()))?;
 
-                    let fd_type = self.check_expression(&fd_arg.value, None)?;
-                    let max_bytes_type = self.check_expression(&max_bytes_arg.value, None)?;
+                    let socket_type = self.check_expression(&socket_arg.value, None)?;
+                    let data_type = self.check_expression(&data_arg.value, None)?;
+                    let host_type = self.check_expression(&host_arg.value, None)?;
+                    let port_type = self.check_expression(&port_arg.value, None)?;
 
-                    if fd_type != HirType::Int32 {
+                    if socket_type != HirType::Int32 {
                         return Err(DiagnosticError::Type(
-                            format!("file_read 'fd' parameter must be Int32, got {:?}", fd_type)
+                            format!("udp_send_to 'socket' parameter must be Int32, got {:?}", socket_type)
                         ));
                     }
-                    if max_bytes_type != HirType::Int32 {
+                    if data_type != HirType::String {
                         return Err(DiagnosticError::Type(
-                            format!("file_read 'max_bytes' parameter must be Int32, got {:?}", max_bytes_type)
+                            format!("udp_send_to 'data' parameter must be String, got {:?}", data_type)
                         ));
                     }
-
-                    return Ok(HirType::Enum("Result".to_string(), vec![HirType::String, HirType::String]));
-                }
-
+                    if host_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("udp_send_to 'host' parameter must be String, got {:?}", host_type)
+                        ));
+                    }
+                    if port_type != HirType::Int32 {
+                        return Err(DiagnosticError::Type(
+                            format!("udp_send_to 'port' parameter must be Int32, got {:?}", port_type)
+                        ));
+                    }
+
+                    return Ok(HirType::Enum("Result".to_string(), vec![HirType::Int32, HirType::String]));
+                }
+
+                // Handle built-in udp_recv_from function
+                if function == "udp_recv_from" {
+                    // udp_recv_from(socket: Int32, max_bytes: Int32) -> Result<String, String>
+                    if args.len() != 2 {
+                        return Err(DiagnosticError::Type(
+                            "udp_recv_from requires exactly 2 arguments: 'socket' and 'max_bytes'".to_string()
+                        ));
+                    }
+
+                    let socket_arg = args.iter().find(|arg| arg.name == "socket")
+                        .ok_or_else(|| DiagnosticError::Type("udp_recv_from requires a 'socket' parameter".to_string()))?;
+                    let max_bytes_arg = args.iter().find(|arg| arg.name == "max_bytes")
+                        .ok_or_else(|| DiagnosticError::Type("udp_recv_from requires a 'max_bytes' parameter".to_string()))?;
+
+                    let socket_type = self.check_expression(&socket_arg.value, None)?;
+                    let max_bytes_type = self.check_expression(&max_bytes_arg.value, None)?;
+
+                    if socket_type != HirType::Int32 {
+                        return Err(DiagnosticError::Type(
+                            format!("udp_recv_from 'socket' parameter must be Int32, got {:?}", socket_type)
+                        ));
+                    }
+                    if max_bytes_type != HirType::Int32 {
+                        return Err(DiagnosticError::Type(
+                            format!("udp_recv_from 'max_bytes' parameter must be Int32, got {:?}", max_bytes_type)
+                        ));
+                    }
+
+                    return Ok(HirType::Enum("Result".to_string(), vec![HirType::String, HirType::String]));
+                }
+
+                // Handle built-in file_open function
+                if function == "file_open" {
+                    // file_open(path: String, mode: String) -> Result<Int32, String>
+                    if args.len() != 2 {
+                        return Err(DiagnosticError::Type(
+                            "file_open requires exactly 2 arguments: 'path' and 'mode'".to_string()
+                        ));
+                    }
+
+                    let path_arg = args.iter().find(|arg| arg.name == "path")
+                        .ok_or_else(|| DiagnosticError::Type("file_open requires a 'path' parameter".to_string()))?;
+                    let mode_arg = args.iter().find(|arg| arg.name == "mode")
+                        .ok_or_else(|| DiagnosticError::Type("file_open requires a 'mode' parameter".to_string()))?;
+
+                    let path_type = self.check_expression(&path_arg.value, None)?;
+                    let mode_type = self.check_expression(&mode_arg.value, None)?;
+
+                    if path_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("file_open 'path' parameter must be String, got {:?}", path_type)
+                        ));
+                    }
+                    if mode_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("file_open 'mode' parameter must be String, got {:?}", mode_type)
+                        ));
+                    }
+
+                    return Ok(HirType::Enum("Result".to_string(), vec![HirType::Int32, HirType::String]));
+                }
+
+                // Whole-file file_read, told apart from the fd form by its 'path' argument
+                if function == "file_read" && args.iter().any(|arg| arg.name == "path") {
+                    // file_read(path: String) -> Result<String, String>
+                    if args.len() != 1 {
+                        return Err(DiagnosticError::Type(
+                            "file_read requires exactly 1 argument: 'path'".to_string()
+                        ));
+                    }
+
+                    let path_arg = args.iter().find(|arg| arg.name == "path")
+                        .ok_or_else(|| DiagnosticError::Type("file_read requires a 'path' parameter".to_string()))?;
+
+                    let path_type = self.check_expression(&path_arg.value, None)?;
+
+                    if path_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("file_read 'path' parameter must be String, got {:?}", path_type)
+                        ));
+                    }
+
+                    return Ok(HirType::Enum("Result".to_string(), vec![HirType::String, HirType::String]));
+                }
+
+                // Whole-file file_write, told apart from the fd form by its 'path' argument
+                if function == "file_write" && args.iter().any(|arg| arg.name == "path") {
+                    // file_write(path: String, contents: String) -> Result<Int32, String>
+                    if args.len() != 2 {
+                        return Err(DiagnosticError::Type(
+                            "file_write requires exactly 2 arguments: 'path' and 'contents'".to_string()
+                        ));
+                    }
+
+                    let path_arg = args.iter().find(|arg| arg.name == "path")
+                        .ok_or_else(|| DiagnosticError::Type("file_write requires a 'path' parameter".to_string()))?;
+                    let contents_arg = args.iter().find(|arg| arg.name == "contents")
+                        .ok_or_else(|| DiagnosticError::Type("file_write requires a 'contents' parameter".to_string()))?;
+
+                    let path_type = self.check_expression(&path_arg.value, None)?;
+                    let contents_type = self.check_expression(&contents_arg.value, None)?;
+
+                    if path_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("file_write 'path' parameter must be String, got {:?}", path_type)
+                        ));
+                    }
+                    if contents_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("file_write 'contents' parameter must be String, got {:?}", contents_type)
+                        ));
+                    }
+
+                    return Ok(HirType::Enum("Result".to_string(), vec![HirType::Int32, HirType::String]));
+                }
+
+                // Handle built-in file_append function
+                if function == "file_append" {
+                    // file_append(path: String, contents: String) -> Result<Int32, String>
+                    if args.len() != 2 {
+                        return Err(DiagnosticError::Type(
+                            "file_append requires exactly 2 arguments: 'path' and 'contents'".to_string()
+                        ));
+                    }
+
+                    let path_arg = args.iter().find(|arg| arg.name == "path")
+                        .ok_or_else(|| DiagnosticError::Type("file_append requires a 'path' parameter".to_string()))?;
+                    let contents_arg = args.iter().find(|arg| arg.name == "contents")
+                        .ok_or_else(|| DiagnosticError::Type("file_append requires a 'contents' parameter".to_string()))?;
+
+                    let path_type = self.check_expression(&path_arg.value, None)?;
+                    let contents_type = self.check_expression(&contents_arg.value, None)?;
+
+                    if path_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("file_append 'path' parameter must be String, got {:?}", path_type)
+                        ));
+                    }
+                    if contents_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("file_append 'contents' parameter must be String, got {:?}", contents_type)
+                        ));
+                    }
+
+                    return Ok(HirType::Enum("Result".to_string(), vec![HirType::Int32, HirType::String]));
+                }
+
+                // Handle built-in file_read function
+                if function == "file_read" {
+                    // file_read(fd: Int32, max_bytes: Int32) -> Result<String, String>
+                    if args.len() != 2 {
+                        return Err(DiagnosticError::Type(
+                            "file_read requires exactly 2 arguments: 'fd' and 'max_bytes'".to_string()
+                        ));
+                    }
+
+                    let fd_arg = args.iter().find(|arg| arg.name == "fd")
+                        .ok_or_else(|| DiagnosticError::Type("file_read requires a 'fd' parameter".to_string()))?;
+                    let max_bytes_arg = args.iter().find(|arg| arg.name == "max_bytes")
+                        .ok_or_else(|| DiagnosticError::Type("file_read requires a 'max_bytes' parameter".to_string()))?;
+
+                    let fd_type = self.check_expression(&fd_arg.value, None)?;
+                    let max_bytes_type = self.check_expression(&max_bytes_arg.value, None)?;
+
+                    if fd_type != HirType::Int32 {
+                        return Err(DiagnosticError::Type(
+                            format!("file_read 'fd' parameter must be Int32, got {:?}", fd_type)
+                        ));
+                    }
+                    if max_bytes_type != HirType::Int32 {
+                        return Err(DiagnosticError::Type(
+                            format!("file_read 'max_bytes' parameter must be Int32, got {:?}", max_bytes_type)
+                        ));
+                    }
+
+                    return Ok(HirType::Enum("Result".to_string(), vec![HirType::String, HirType::String]));
+                }
+
                 // Handle built-in file_write function
                 if function == "file_write" {
                     // file_write(fd: Int32, data: String) -> Result<Int32, String>
@@ -2843,6 +3526,23 @@ impl TypeChecker {
                     return Ok(HirType::Channel(Box::new(HirType::Int32)));
                 }
 
+                // Handle built-in atomic integer functions
+                if matches!(function.as_str(), "atomic_init" | "atomic_add" | "atomic_load" | "atomic_store" | "compare_and_swap") {
+                    return self.check_atomic_call(function, args);
+                }
+
+                // Handle built-in mutex_init function
+                if function == "mutex_init" {
+                    // mutex_init() -> Mutex
+                    if !args.is_empty() {
+                        return Err(DiagnosticError::Type(
+                            "mutex_init requires no arguments".to_string()
+                        ));
+                    }
+
+                    return Ok(HirType::Mutex);
+                }
+
                 // Handle built-in time_now function
                 if function == "time_now" {
                     // time_now() -> Int64
@@ -2855,6 +3555,18 @@ impl TypeChecker {
                     return Ok(HirType::Int64);
                 }
 
+                // Handle built-in monotonic clock functions
+                if function == "now_millis" || function == "now_nanos" {
+                    // now_millis() -> Int64, now_nanos() -> Int64
+                    if args.len() != 0 {
+                        return Err(DiagnosticError::Type(
+                            format!("{} requires no arguments", function)
+                        ));
+                    }
+
+                    return Ok(HirType::Int64);
+                }
+
                 // Handle built-in time_sleep function
                 if function == "time_sleep" {
                     // time_sleep(millis: Int64) -> Bool
@@ -2878,23 +3590,23 @@ impl TypeChecker {
                     return Ok(HirType::Bool);
                 }
 
-                // Handle built-in env_get function
-                if function == "env_get" {
-                    // env_get(name: String) -> Option<String>
+                // Handle built-in env_get function (also available as the shorter `env`)
+                if function == "env_get" || function == "env" {
+                    // env(name: String) -> Option<String>
                     if args.len() != 1 {
                         return Err(DiagnosticError::Type(
-                            "env_get requires exactly 1 argument: 'name'".to_string()
+                            format!("{} requires exactly 1 argument: 'name'", function)
                         ));
                     }
 
                     let name_arg = args.iter().find(|arg| arg.name == "name")
-                        .ok_or_else(|| DiagnosticError::Type("env_get requires a 'name' parameter".to_string()))?;
+                        .ok_or_else(|| DiagnosticError::Type(format!("{} requires a 'name' parameter", function)))?;
 
                     let name_type = self.check_expression(&name_arg.value, None)?;
 
                     if name_type != HirType::String {
                         return Err(DiagnosticError::Type(
-                            format!("env_get 'name' parameter must be String, got {:?}", name_type)
+                            format!("{} 'name' parameter must be String, got {:?}", function, name_type)
                         ));
                     }
 
@@ -2946,7 +3658,7 @@ impl TypeChecker {
 
                 // Handle built-in random_int function
                 if function == "random_int" {
-                    // random_int(min: Int64, max: Int64) -> Int64
+                    // random_int(min: T, max: T) -> T for T = Int32 or Int64; range is [min, max)
                     if args.len() != 2 {
                         return Err(DiagnosticError::Type(
                             "random_int requires exactly 2 arguments: 'min' and 'max'".to_string()
@@ -2961,18 +3673,18 @@ impl TypeChecker {
                     let min_type = self.check_expression(&min_arg.value, None)?;
                     let max_type = self.check_expression(&max_arg.value, None)?;
 
-                    if min_type != HirType::Int64 {
+                    if min_type != HirType::Int32 && min_type != HirType::Int64 {
                         return Err(DiagnosticError::Type(
-                            format!("random_int 'min' parameter must be Int64, got {:?}", min_type)
+                            format!("random_int 'min' parameter must be Int32 or Int64, got {:?}", min_type)
                         ));
                     }
-                    if max_type != HirType::Int64 {
+                    if max_type != min_type {
                         return Err(DiagnosticError::Type(
-                            format!("random_int 'max' parameter must be Int64, got {:?}", max_type)
+                            format!("random_int 'max' parameter must match 'min' type {:?}, got {:?}", min_type, max_type)
                         ));
                     }
 
-                    return Ok(HirType::Int64);
+                    return Ok(min_type);
                 }
 
                 // Handle built-in random_float function
@@ -2987,6 +3699,83 @@ impl TypeChecker {
                     return Ok(HirType::Float64);
                 }
 
+                // Handle built-in seed_random function
+                if function == "seed_random" {
+                    // seed_random(seed: Int64) -> Unit
+                    if args.len() != 1 {
+                        return Err(DiagnosticError::Type(
+                            "seed_random requires exactly 1 argument: 'seed'".to_string()
+                        ));
+                    }
+
+                    let seed_arg = args.iter().find(|arg| arg.name == "seed")
+                        .ok_or_else(|| DiagnosticError::Type("seed_random requires a 'seed' parameter".to_string()))?;
+
+                    let seed_type = self.check_expression(&seed_arg.value, None)?;
+
+                    if seed_type != HirType::Int64 {
+                        return Err(DiagnosticError::Type(
+                            format!("seed_random 'seed' parameter must be Int64, got {:?}", seed_type)
+                        ));
+                    }
+
+                    return Ok(HirType::Unit);
+                }
+
+                // Handle built-in format function
+                if function == "format" {
+                    // format(template: String, args: List[String]) -> String
+                    if args.len() != 2 {
+                        return Err(DiagnosticError::Type(
+                            "format requires exactly 2 arguments: 'template' and 'args'".to_string()
+                        ));
+                    }
+
+                    let template_arg = args.iter().find(|arg| arg.name == "template")
+                        .ok_or_else(|| DiagnosticError::Type("format requires a 'template' parameter".to_string()))?;
+                    let args_arg = args.iter().find(|arg| arg.name == "args")
+                        .ok_or_else(|| DiagnosticError::Type("format requires an 'args' parameter".to_string()))?;
+
+                    let template_type = self.check_expression(&template_arg.value, None)?;
+                    let args_type = self.check_expression(&args_arg.value, Some(&HirType::List(Box::new(HirType::String))))?;
+
+                    if template_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("format 'template' parameter must be String, got {:?}", template_type)
+                        ));
+                    }
+                    if args_type != HirType::List(Box::new(HirType::String)) {
+                        return Err(DiagnosticError::Type(
+                            format!("format 'args' parameter must be List[String], got {:?}", args_type)
+                        ));
+                    }
+
+                    return Ok(HirType::String);
+                }
+
+                // Handle built-in panic function
+                if function == "panic" {
+                    // panic(message: String) -> Never
+                    if args.len() != 1 {
+                        return Err(DiagnosticError::Type(
+                            "panic requires exactly 1 argument: 'message'".to_string()
+                        ));
+                    }
+
+                    let message_arg = args.iter().find(|arg| arg.name == "message")
+                        .ok_or_else(|| DiagnosticError::Type("panic requires a 'message' parameter".to_string()))?;
+
+                    let message_type = self.check_expression(&message_arg.value, None)?;
+                    if message_type != HirType::String {
+                        return Err(DiagnosticError::Type(
+                            format!("panic 'message' parameter must be String, got {:?}", message_type)
+                        ));
+                    }
+
+                    // panic never returns, so it takes on whatever type its position expects
+                    return Ok(expected_type.cloned().unwrap_or(HirType::Unit));
+                }
+
                 // Handle built-in process_exit function
                 if function == "process_exit" {
                     // process_exit(code: Int32) -> Never (doesn't return, but we use Bool as a placeholder)
@@ -3010,6 +3799,22 @@ impl TypeChecker {
                     return Ok(HirType::Bool);
                 }
 
+                // Handle built-in drop function (a user-defined `drop` takes precedence)
+                if function == "drop" && !self.functions.contains_key("drop") {
+                    // drop(value: T) -> Unit; frees heap-backed values immediately
+                    if args.len() != 1 {
+                        return Err(DiagnosticError::Type(
+                            "drop requires exactly 1 argument: 'value'".to_string()
+                        ));
+                    }
+
+                    let value_arg = args.iter().find(|arg| arg.name == "value")
+                        .ok_or_else(|| DiagnosticError::Type("drop requires a 'value' parameter".to_string()))?;
+                    self.check_expression(&value_arg.value, None)?;
+
+                    return Ok(HirType::Unit);
+                }
+
                 // Handle built-in process_args function
                 if function == "process_args" {
                     // process_args() -> String
@@ -3022,6 +3827,18 @@ impl TypeChecker {
                     return Ok(HirType::String);
                 }
 
+                // Handle built-in args function
+                if function == "args" {
+                    // args() -> List[String]
+                    if args.len() != 0 {
+                        return Err(DiagnosticError::Type(
+                            "args requires no arguments".to_string()
+                        ));
+                    }
+
+                    return Ok(HirType::List(Box::new(HirType::String)));
+                }
+
                 // Try to resolve the function name (handles both local and qualified names)
                 let resolved_name = self.module_table.resolve(function)
                     .unwrap_or_else(|| function.clone());
@@ -3098,6 +3915,12 @@ impl TypeChecker {
 
                 match target.as_ref() {
                     Expression::Identifier { name, .. } => {
+                        if self.lookup_constant(name).is_some() && !self.scopes.iter().any(|scope| scope.contains_key(name)) {
+                            return Err(DiagnosticError::Type(
+                                format!("Cannot assign to constant '{}'", name)
+                            ));
+                        }
+
                         let variable_type = self.lookup_variable(name)?;
 
                         // Check if assignment is type-compatible (allows upcasting)
@@ -3169,30 +3992,155 @@ impl TypeChecker {
             }
             Expression::Index { object, index, .. } => {
                 let object_type = self.check_expression(object, None)?;
-                let index_type = self.check_expression(index, None)?;
 
-                // Index must be i32
-                if index_type != HirType::Int32 {
-                    return Err(DiagnosticError::Type(
-                        format!("Array index must be i32, got {:?}", index_type)
-                    ));
-                }
-
-                // Object must be List - returns Option<element_type>
+                // Every index form is a safe lookup returning Option
                 match object_type {
                     HirType::List(element_type) => {
-                        // Return Option<T> for safe indexing
+                        let index_type = self.check_expression(index, None)?;
+                        if index_type != HirType::Int32 {
+                            return Err(DiagnosticError::Type(
+                                format!("Array index must be i32, got {:?}", index_type)
+                            ));
+                        }
                         Ok(HirType::Enum("Option".to_string(), vec![*element_type]))
                     }
+                    HirType::Dict(key_type, value_type) => {
+                        let index_type = self.check_expression(index, Some(&key_type))?;
+                        if index_type != *key_type {
+                            return Err(DiagnosticError::Type(
+                                format!("Dict key must be {:?}, got {:?}", key_type, index_type)
+                            ));
+                        }
+                        Ok(HirType::Enum("Option".to_string(), vec![*value_type]))
+                    }
+                    HirType::String => {
+                        let index_type = self.check_expression(index, None)?;
+                        if index_type != HirType::Int32 {
+                            return Err(DiagnosticError::Type(
+                                format!("String index must be i32, got {:?}", index_type)
+                            ));
+                        }
+                        Ok(HirType::Enum("Option".to_string(), vec![HirType::String]))
+                    }
                     _ => Err(DiagnosticError::Type(
                         format!("Cannot index into type {:?}", object_type)
                     ))
                 }
             }
-            Expression::MethodCall { object, method, args, .. } => {
+            Expression::MethodCall { object, method, args, span } => {
                 let object_type = self.check_expression(object, None)?;
 
                 match (&object_type, method.as_str()) {
+                    // Option/Result unwrapping (lowered to a match in `fill_default_arguments`)
+                    (HirType::Enum(name, type_params), "unwrap" | "expect")
+                        if (name == "Option" || name == "Result") && !type_params.is_empty() =>
+                    {
+                        if method == "unwrap" && !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "unwrap() method takes no arguments".to_string()
+                            ));
+                        }
+                        if method == "expect" {
+                            if args.len() != 1 || args[0].name != "message" {
+                                return Err(DiagnosticError::Type(
+                                    "expect() method takes exactly one argument: 'message'".to_string()
+                                ));
+                            }
+                            let message_type = self.check_expression(&args[0].value, None)?;
+                            if message_type != HirType::String {
+                                return Err(DiagnosticError::Type(
+                                    format!("expect() 'message' must be String, got {:?}", message_type)
+                                ));
+                            }
+                        }
+                        let payload = type_params[0].clone();
+                        self.method_lowerings.insert(
+                            (span.start, span.end),
+                            MethodLowering::Unwrap { enum_name: name.clone(), payload: payload.clone() },
+                        );
+                        Ok(payload)
+                    }
+                    // Position of a unit-only enum's variant in its declaration
+                    (HirType::Enum(name, _), "ordinal")
+                        if self.enums.get(name).map_or(false, |info| !info.methods.contains_key("ordinal")) =>
+                    {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "ordinal() method takes no arguments".to_string()
+                            ));
+                        }
+                        let enum_info = &self.enums[name];
+                        if enum_info.variants.values().any(|fields| !fields.is_empty()) {
+                            return Err(DiagnosticError::Type(
+                                format!("ordinal() is only available on enums whose variants carry no data, but '{}' has data variants", name)
+                            ));
+                        }
+                        let variants = enum_info.variant_order.clone();
+                        self.method_lowerings.insert(
+                            (span.start, span.end),
+                            MethodLowering::Ordinal { enum_name: name.clone(), variants },
+                        );
+                        Ok(HirType::Int32)
+                    }
+                    // Variant name, followed by any payload in parentheses
+                    (HirType::Enum(name, type_args), "to_string")
+                        if self.enums.get(name).map_or(false, |info| !info.methods.contains_key("to_string")) =>
+                    {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "to_string() method takes no arguments".to_string()
+                            ));
+                        }
+                        let enum_info = &self.enums[name];
+                        let substitution: TypeSubstitution = enum_info.type_params.iter().cloned()
+                            .zip(type_args.iter().cloned())
+                            .collect();
+                        let mut variants = Vec::new();
+                        for variant in &enum_info.variant_order {
+                            // The built-in enums register placeholder payloads; theirs is the matching type argument
+                            let field_types: Vec<HirType> = match (name.as_str(), variant.as_str()) {
+                                ("Option", "Some") | ("Result", "Ok") => type_args.iter().take(1).cloned().collect(),
+                                ("Result", "Err") => type_args.iter().skip(1).take(1).cloned().collect(),
+                                _ => enum_info.variants[variant].iter().map(|field| field.substitute_types(&substitution)).collect(),
+                            };
+                            let fields = field_types.iter()
+                                .map(hir_type_to_ast_type)
+                                .collect::<Option<Vec<_>>>()
+                                .ok_or_else(|| DiagnosticError::Type(
+                                    format!("to_string() cannot describe the payload of '{}::{}'", name, variant)
+                                ))?;
+                            variants.push((variant.clone(), fields));
+                        }
+                        self.method_lowerings.insert(
+                            (span.start, span.end),
+                            MethodLowering::EnumToString { enum_name: name.clone(), variants },
+                        );
+                        Ok(HirType::String)
+                    }
+                    // Option/Result discriminant checks
+                    (HirType::Enum(name, _), "is_some" | "is_none") if name == "Option" => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                format!("{}() method takes no arguments", method)
+                            ));
+                        }
+                        Ok(HirType::Bool)
+                    }
+                    (HirType::Enum(name, _), "map" | "map_err") if name == "Option" || name == "Result" => {
+                        // Mapping needs a function value to apply on the Some/Ok (or Err) path,
+                        // so reject the call until closures exist
+                        Err(DiagnosticError::Type(
+                            format!("{}() requires a closure, and closures are not supported yet", method)
+                        ))
+                    }
+                    (HirType::Enum(name, _), "is_ok" | "is_err") if name == "Result" => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                format!("{}() method takes no arguments", method)
+                            ));
+                        }
+                        Ok(HirType::Bool)
+                    }
                     // Array methods
                     (HirType::List(_), "len") => {
                         if !args.is_empty() {
@@ -3325,6 +4273,15 @@ impl TypeChecker {
                         }
                         Ok(HirType::Unit)
                     }
+                    (list_type @ HirType::List(_), "clone") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "clone() method takes no arguments".to_string()
+                            ));
+                        }
+                        // A new, independent collection holding the same elements
+                        Ok(list_type.clone())
+                    }
                     (HirType::List(element_type), "contains") => {
                         if args.len() != 1 {
                             return Err(DiagnosticError::Type(
@@ -3389,6 +4346,27 @@ impl TypeChecker {
                         // Returns List<T> where T is the element type
                         Ok(HirType::List(element_type.clone()))
                     }
+                    (HirType::List(element_type), "view") => {
+                        if args.len() != 2 {
+                            return Err(DiagnosticError::Type(
+                                "view() method takes exactly two arguments".to_string()
+                            ));
+                        }
+                        let start_type = self.check_expression(&args[0].value, None)?;
+                        let end_type = self.check_expression(&args[1].value, None)?;
+                        if start_type != HirType::Int32 {
+                            return Err(DiagnosticError::Type(
+                                format!("view() method expects i32 start index, got {:?}", start_type)
+                            ));
+                        }
+                        if end_type != HirType::Int32 {
+                            return Err(DiagnosticError::Type(
+                                format!("view() method expects i32 end index, got {:?}", end_type)
+                            ));
+                        }
+                        // A view is a List<T> sharing the receiver's storage
+                        Ok(HirType::List(element_type.clone()))
+                    }
                     (HirType::List(element_type), "concat") => {
                         if args.len() != 1 {
                             return Err(DiagnosticError::Type(
@@ -3405,27 +4383,166 @@ impl TypeChecker {
                             ))
                         }
                     }
-                    (HirType::List(_element_type), "all") => {
+                    (HirType::List(element_type), "chunks") => {
                         if args.len() != 1 {
                             return Err(DiagnosticError::Type(
-                                "all() method takes exactly one argument".to_string()
+                                "chunks() method takes exactly one argument".to_string()
                             ));
                         }
-                        // For now, accept any function - in a more advanced type system,
-                        // we'd check that it's a function T -> bool
-                        let _predicate_type = self.check_expression(&args[0].value, None)?;
-                        Ok(HirType::Bool)
+                        let size_type = self.check_expression(&args[0].value, None)?;
+                        if size_type != HirType::Int32 {
+                            return Err(DiagnosticError::Type(
+                                format!("chunks() method expects i32 size, got {:?}", size_type)
+                            ));
+                        }
+                        Ok(HirType::List(Box::new(HirType::List(element_type.clone()))))
+                    }
+                    (HirType::List(element_type), "flatten") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "flatten() method takes no arguments".to_string()
+                            ));
+                        }
+                        match element_type.as_ref() {
+                            HirType::List(inner_type) => Ok(HirType::List(inner_type.clone())),
+                            other => Err(DiagnosticError::Type(
+                                format!("flatten() requires a list of lists, got list of {:?}", other)
+                            )),
+                        }
+                    }
+                    (HirType::List(element_type), "zip") => {
+                        if args.len() != 1 {
+                            return Err(DiagnosticError::Type(
+                                "zip() method takes exactly one argument".to_string()
+                            ));
+                        }
+                        let other_type = self.check_expression(&args[0].value, None)?;
+                        match other_type {
+                            HirType::List(other_element_type) => Ok(HirType::List(Box::new(HirType::Tuple(vec![
+                                element_type.as_ref().clone(),
+                                *other_element_type,
+                            ])))),
+                            other => Err(DiagnosticError::Type(
+                                format!("zip() method expects a list argument, got {:?}", other)
+                            )),
+                        }
+                    }
+                    (HirType::List(element_type), "enumerate") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "enumerate() method takes no arguments".to_string()
+                            ));
+                        }
+                        Ok(HirType::List(Box::new(HirType::Tuple(vec![HirType::Int32, element_type.as_ref().clone()]))))
+                    }
+                    (HirType::List(element_type), "unique") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "unique() method takes no arguments".to_string()
+                            ));
+                        }
+                        Ok(HirType::List(element_type.clone()))
+                    }
+                    (HirType::List(_element_type), "all") => {
+                        // The predicate argument would be silently ignored without closures,
+                        // so reject the call instead of returning a misleading answer
+                        Err(DiagnosticError::Type(
+                            "all() requires a predicate closure, and closures are not supported yet".to_string()
+                        ))
                     }
                     (HirType::List(_element_type), "any") => {
+                        // The predicate argument would be silently ignored without closures,
+                        // so reject the call instead of returning a misleading answer
+                        Err(DiagnosticError::Type(
+                            "any() requires a predicate closure, and closures are not supported yet".to_string()
+                        ))
+                    }
+                    (HirType::List(element_type), "find") => {
+                        // find(predicate = f) calls a named one-parameter function on each element
+                        // until it returns true, the way with_lock() calls its action
+                        if args.len() != 1 || args[0].name != "predicate" {
+                            return Err(DiagnosticError::Type(
+                                "find() method takes exactly one 'predicate' argument".to_string()
+                            ));
+                        }
+                        let predicate = match &args[0].value {
+                            Expression::Identifier { name, .. } => name,
+                            _ => return Err(DiagnosticError::Type(
+                                "find() 'predicate' must name a function".to_string()
+                            )),
+                        };
+                        let resolved_name = self.module_table.resolve(predicate)
+                            .unwrap_or_else(|| predicate.clone());
+                        let signature = self.functions.get(&resolved_name)
+                            .or_else(|| self.functions.get(predicate))
+                            .ok_or_else(|| DiagnosticError::Type(
+                                format!("find() 'predicate' refers to unknown function '{}'", predicate)
+                            ))?;
+                        let takes_element = matches!(signature.params.as_slice(), [(_, param_type)] if self.is_assignable(param_type, element_type));
+                        if !takes_element || signature.return_type != HirType::Bool {
+                            return Err(DiagnosticError::Type(
+                                format!("find() 'predicate' function '{}' must take one {:?} and return Bool", predicate, element_type)
+                            ));
+                        }
+                        Ok(HirType::Enum("Option".to_string(), vec![(**element_type).clone()]))
+                    }
+                    // Integer methods
+                    (int_type @ (HirType::Int8 | HirType::Int16 | HirType::Int32 | HirType::Int64), "abs" | "min" | "max" | "clamp" | "rem_euclid") => {
+                        let param_names: &[&str] = match method.as_str() {
+                            "abs" => &[],
+                            "clamp" => &["low", "high"],
+                            _ => &["other"],
+                        };
+                        if args.len() != param_names.len() {
+                            return Err(DiagnosticError::Type(
+                                format!("{}() method takes {} argument(s)", method, param_names.len())
+                            ));
+                        }
+                        for param_name in param_names {
+                            let arg = args.iter().find(|arg| arg.name == *param_name)
+                                .ok_or_else(|| DiagnosticError::Type(
+                                    format!("{}() requires a '{}' parameter", method, param_name)
+                                ))?;
+                            let arg_type = self.check_expression(&arg.value, Some(int_type))?;
+                            if arg_type != *int_type {
+                                return Err(DiagnosticError::Type(
+                                    format!("{}() '{}' parameter must be {:?}, got {:?}", method, param_name, int_type, arg_type)
+                                ));
+                            }
+                        }
+                        Ok(int_type.clone())
+                    }
+                    // Stable hashes for building user-defined hash structures
+                    (HirType::String | HirType::Int8 | HirType::Int16 | HirType::Int32 | HirType::Int64, "hash") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "hash() method takes no arguments".to_string()
+                            ));
+                        }
+                        Ok(HirType::Int64)
+                    }
+                    // Float methods
+                    (float_type @ (HirType::Float8 | HirType::Float16 | HirType::Float32 | HirType::Float64), "abs" | "floor" | "ceil" | "round" | "sqrt") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                format!("{}() method takes no arguments", method)
+                            ));
+                        }
+                        Ok(float_type.clone())
+                    }
+                    (HirType::Float8 | HirType::Float16 | HirType::Float32 | HirType::Float64, "to_string_precision") => {
                         if args.len() != 1 {
                             return Err(DiagnosticError::Type(
-                                "any() method takes exactly one argument".to_string()
+                                "to_string_precision() method takes exactly one argument".to_string()
                             ));
                         }
-                        // For now, accept any function - in a more advanced type system,
-                        // we'd check that it's a function T -> bool
-                        let _predicate_type = self.check_expression(&args[0].value, None)?;
-                        Ok(HirType::Bool)
+                        let digits_type = self.check_expression(&args[0].value, None)?;
+                        if digits_type != HirType::Int32 {
+                            return Err(DiagnosticError::Type(
+                                format!("to_string_precision() method expects i32 digits, got {:?}", digits_type)
+                            ));
+                        }
+                        Ok(HirType::String)
                     }
                     // String methods
                     (HirType::String, "length") => {
@@ -3492,6 +4609,21 @@ impl TypeChecker {
                         }
                         Ok(HirType::Bool)
                     }
+                    (HirType::String, "index_of") => {
+                        if args.len() != 1 {
+                            return Err(DiagnosticError::Type(
+                                "index_of() method takes exactly one argument".to_string()
+                            ));
+                        }
+                        let arg_type = self.check_expression(&args[0].value, None)?;
+                        if arg_type != HirType::String {
+                            return Err(DiagnosticError::Type(
+                                format!("index_of() method expects string argument, got {:?}", arg_type)
+                            ));
+                        }
+                        // Returns Option<i32> with a character index
+                        Ok(HirType::Enum("Option".to_string(), vec![HirType::Int32]))
+                    }
                     (HirType::String, "trim") => {
                         if !args.is_empty() {
                             return Err(DiagnosticError::Type(
@@ -3547,9 +4679,9 @@ impl TypeChecker {
                         Ok(HirType::String)
                     }
                     (HirType::String, "split") => {
-                        if args.len() != 1 {
+                        if args.is_empty() || args.len() > 2 {
                             return Err(DiagnosticError::Type(
-                                "split() method takes exactly one argument".to_string()
+                                "split() method takes a delimiter and an optional 'limit'".to_string()
                             ));
                         }
                         let arg_type = self.check_expression(&args[0].value, None)?;
@@ -3558,6 +4690,28 @@ impl TypeChecker {
                                 format!("split() method expects string argument, got {:?}", arg_type)
                             ));
                         }
+                        // At most `limit` parts; 0 means unlimited
+                        if let Some(limit_arg) = args.get(1) {
+                            if limit_arg.name != "limit" {
+                                return Err(DiagnosticError::Type(
+                                    format!("split() has no parameter named '{}'", limit_arg.name)
+                                ));
+                            }
+                            let limit_type = self.check_expression(&limit_arg.value, Some(&HirType::Int32))?;
+                            if limit_type != HirType::Int32 {
+                                return Err(DiagnosticError::Type(
+                                    format!("split() 'limit' must be Int32, got {:?}", limit_type)
+                                ));
+                            }
+                        }
+                        Ok(HirType::List(Box::new(HirType::String)))
+                    }
+                    (HirType::String, "lines" | "chars") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                format!("{}() method takes no arguments", method)
+                            ));
+                        }
                         Ok(HirType::List(Box::new(HirType::String)))
                     }
                     (HirType::String, "is_alpha") => {
@@ -3662,7 +4816,8 @@ impl TypeChecker {
                                 format!("get() method expects key of type {:?}, got {:?}", key_type, arg_type)
                             ));
                         }
-                        Ok((**value_type).clone())
+                        // Returns Option<V> so a missing key can be told apart from a stored value
+                        Ok(HirType::Enum("Option".to_string(), vec![(**value_type).clone()]))
                     }
                     (HirType::Dict(key_type, value_type), "set") => {
                         if args.len() != 2 {
@@ -3727,6 +4882,15 @@ impl TypeChecker {
                         }
                         Ok(HirType::Unit)
                     }
+                    (dict_type @ HirType::Dict(_, _), "clone") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "clone() method takes no arguments".to_string()
+                            ));
+                        }
+                        // A new, independent collection holding the same elements
+                        Ok(dict_type.clone())
+                    }
                     (HirType::Dict(_, _), "length") => {
                         if !args.is_empty() {
                             return Err(DiagnosticError::Type(
@@ -3853,6 +5017,15 @@ impl TypeChecker {
                         }
                         Ok(HirType::Unit)
                     }
+                    (set_type @ HirType::Set(_), "clone") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "clone() method takes no arguments".to_string()
+                            ));
+                        }
+                        // A new, independent collection holding the same elements
+                        Ok(set_type.clone())
+                    }
                     (HirType::Set(_), "length") => {
                         if !args.is_empty() {
                             return Err(DiagnosticError::Type(
@@ -4023,6 +5196,62 @@ impl TypeChecker {
                             ))
                         }
                     }
+                    // Methods declared on a newtype (the underlying type's methods are not available)
+                    (HirType::Newtype(newtype_name), method_name) if self.functions.contains_key(&Self::newtype_method_name(newtype_name, method_name)) => {
+                        let function = Self::newtype_method_name(newtype_name, method_name);
+                        let method_signature = self.functions[&function].clone();
+                        // The first parameter is the implicit self
+                        let params = &method_signature.params[1..];
+
+                        let required_params = method_signature.default_values[1..].iter().take_while(|d| d.is_none()).count();
+                        if args.len() < required_params || args.len() > params.len() {
+                            return Err(DiagnosticError::Type(
+                                format!("Method '{}' of newtype '{}' expects {} arguments, got {}",
+                                       method_name, newtype_name, params.len(), args.len())
+                            ));
+                        }
+
+                        for (arg, (param_name, expected_type)) in args.iter().zip(params.iter()) {
+                            let arg_type = self.check_expression(&arg.value, Some(expected_type))?;
+                            if arg_type != *expected_type {
+                                return Err(DiagnosticError::Type(
+                                    format!("Method '{}' parameter '{}' expects type {:?}, got {:?}",
+                                           method_name, param_name, expected_type, arg_type)
+                                ));
+                            }
+                        }
+
+                        self.method_lowerings.insert(
+                            (span.start, span.end),
+                            MethodLowering::NewtypeMethod { function },
+                        );
+                        Ok(method_signature.return_type.clone())
+                    }
+                    // User-defined enum methods
+                    (HirType::Enum(enum_name, _), method_name) if self.functions.contains_key(&format!("{}::{}", enum_name, method_name)) => {
+                        let qualified_name = format!("{}::{}", enum_name, method_name);
+                        let method_signature = self.functions[&qualified_name].clone();
+
+                        let required_params = method_signature.default_values.iter().take_while(|d| d.is_none()).count();
+                        if args.len() < required_params || args.len() > method_signature.params.len() {
+                            return Err(DiagnosticError::Type(
+                                format!("Method '{}' expects {} arguments, got {}",
+                                       qualified_name, method_signature.params.len(), args.len())
+                            ));
+                        }
+
+                        for (i, (arg, (_param_name, expected_type))) in args.iter().zip(method_signature.params.iter()).enumerate() {
+                            let arg_type = self.check_expression(&arg.value, Some(expected_type))?;
+                            if arg_type != *expected_type {
+                                return Err(DiagnosticError::Type(
+                                    format!("Argument {} of method '{}' has type {:?}, expected {:?}",
+                                           i + 1, qualified_name, arg_type, expected_type)
+                                ));
+                            }
+                        }
+
+                        Ok(method_signature.return_type.clone())
+                    }
                     // Task methods
                     (HirType::Task(inner_type), "await") => {
                         if !args.is_empty() {
@@ -4033,6 +5262,23 @@ impl TypeChecker {
                         // await() returns the inner type T from Task<T>
                         Ok((**inner_type).clone())
                     }
+                    (HirType::Task(inner_type), "try_await") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "try_await() method takes no arguments".to_string()
+                            ));
+                        }
+                        // try_await() returns None if the task was cancelled
+                        Ok(HirType::Enum("Option".to_string(), vec![(**inner_type).clone()]))
+                    }
+                    (HirType::Task(_), "cancel") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                "cancel() method takes no arguments".to_string()
+                            ));
+                        }
+                        Ok(HirType::Unit)
+                    }
                     // Channel methods
                     (HirType::Channel(element_type), "send") => {
                         if args.len() != 1 {
@@ -4058,6 +5304,43 @@ impl TypeChecker {
                         // recv() returns Option<T> where T is the channel element type
                         Ok(HirType::Enum("Option".to_string(), vec![(**element_type).clone()]))
                     }
+                    // Mutex methods
+                    (HirType::Mutex, "lock") | (HirType::Mutex, "unlock") => {
+                        if !args.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                format!("{}() method takes no arguments", method)
+                            ));
+                        }
+                        Ok(HirType::Unit)
+                    }
+                    (HirType::Mutex, "with_lock") => {
+                        // with_lock(action = f) runs a named zero-argument function while holding
+                        // the lock and returns whatever it returns
+                        if args.len() != 1 || args[0].name != "action" {
+                            return Err(DiagnosticError::Type(
+                                "with_lock() method takes exactly one 'action' argument".to_string()
+                            ));
+                        }
+                        let action = match &args[0].value {
+                            Expression::Identifier { name, .. } => name,
+                            _ => return Err(DiagnosticError::Type(
+                                "with_lock() 'action' must name a function".to_string()
+                            )),
+                        };
+                        let resolved_name = self.module_table.resolve(action)
+                            .unwrap_or_else(|| action.clone());
+                        let signature = self.functions.get(&resolved_name)
+                            .or_else(|| self.functions.get(action))
+                            .ok_or_else(|| DiagnosticError::Type(
+                                format!("with_lock() 'action' refers to unknown function '{}'", action)
+                            ))?;
+                        if !signature.params.is_empty() {
+                            return Err(DiagnosticError::Type(
+                                format!("with_lock() 'action' function '{}' must take no parameters", action)
+                            ));
+                        }
+                        Ok(signature.return_type.clone())
+                    }
                     (HirType::Channel(_), "close") => {
                         if !args.is_empty() {
                             return Err(DiagnosticError::Type(
@@ -4077,7 +5360,13 @@ impl TypeChecker {
                 self.pop_scope();
                 Ok(HirType::Unit)
             }
-            Expression::EnumConstructor { enum_name, variant, args, .. } => {
+            // `ClassName::NAME` parses like a payload-less enum variant
+            Expression::EnumConstructor { enum_name, variant, args, .. }
+                if args.is_empty() && self.constants.contains_key(&format!("{}::{}", enum_name, variant)) =>
+            {
+                Ok(self.constants[&format!("{}::{}", enum_name, variant)].clone())
+            }
+            Expression::EnumConstructor { enum_name, variant, args, span } => {
                 // Try to find the enum - try both qualified and unqualified names
                 let enum_info = if let Some(info) = self.enums.get(enum_name) {
                     info.clone()
@@ -4111,6 +5400,34 @@ impl TypeChecker {
                     }
                 }
 
+                // `Enum::from_ordinal(n = ...)` is a built-in constructor unless a variant shadows it
+                if variant == "from_ordinal" && !enum_info.variants.contains_key(variant) {
+                    if enum_info.variants.values().any(|fields| !fields.is_empty()) {
+                        return Err(DiagnosticError::Type(
+                            format!("from_ordinal() is only available on enums whose variants carry no data, but '{}' has data variants", enum_name)
+                        ));
+                    }
+                    if args.len() != 1 || args[0].name != "n" {
+                        return Err(DiagnosticError::Type(
+                            "from_ordinal() takes exactly one argument: 'n'".to_string()
+                        ));
+                    }
+                    let n_type = self.check_expression(&args[0].value, Some(&HirType::Int32))?;
+                    if n_type != HirType::Int32 {
+                        return Err(DiagnosticError::Type(
+                            format!("from_ordinal() 'n' must be Int32, got {:?}", n_type)
+                        ));
+                    }
+                    self.method_lowerings.insert(
+                        (span.start, span.end),
+                        MethodLowering::FromOrdinal { enum_name: enum_name.clone(), variants: enum_info.variant_order.clone() },
+                    );
+                    return Ok(HirType::Enum(
+                        "Option".to_string(),
+                        vec![HirType::Enum(canonical_enum_name.clone(), vec![])],
+                    ));
+                }
+
                 // Check if variant exists
                 let variant_fields = enum_info.variants.get(variant)
                     .ok_or_else(|| DiagnosticError::Type(
@@ -4245,6 +5562,17 @@ impl TypeChecker {
                     // Each arm gets its own scope for pattern bindings
                     self.push_scope();
 
+                    // `name @ pattern` binds the whole matched value alongside the pattern's bindings
+                    if let Some(binding) = &arm.binding {
+                        if !is_snake_case(binding) {
+                            return Err(DiagnosticError::Type(
+                                format!("Pattern binding '{}' must be snake_case", binding)
+                            ));
+                        }
+                        self.check_global_shadowing(binding)?;
+                        self.scopes.last_mut().unwrap().insert(binding.clone(), value_type.clone());
+                    }
+
                     // Type check the pattern
                     self.check_pattern(&arm.pattern, &value_type)?;
 
@@ -4324,8 +5652,9 @@ impl TypeChecker {
                         // Return the class type (for now without generics)
                         Ok(HirType::Class(class_name.clone(), vec![]))
                     }
-                    None => Err(DiagnosticError::Type(
-                        "'self' can only be used within class methods".to_string()
+                    // Enum methods bind self as a scoped variable holding the enum value
+                    None => self.lookup_variable("self").map_err(|_| DiagnosticError::Type(
+                        "'self' can only be used within class or enum methods".to_string()
                     ))
                 }
             }
@@ -4360,6 +5689,51 @@ impl TypeChecker {
                     ))
                 }
             }
+            Expression::OptionalMemberAccess { object, member, span } => {
+                let object_type = self.check_expression(object, None)?;
+
+                // The receiver must be Option<Class>; the result is Option<field type>
+                let class_name = match &object_type {
+                    HirType::Enum(name, type_params) if name == "Option" && type_params.len() == 1 => {
+                        match &type_params[0] {
+                            HirType::Class(class_name, _) => class_name.clone(),
+                            other => return Err(DiagnosticError::Type(
+                                format!("Optional chaining requires an Option of a class instance, got Option<{:?}>", other)
+                            )),
+                        }
+                    }
+                    _ => return Err(DiagnosticError::Rich(
+                        Diagnostic::syntax_error(
+                            &self.filename,
+                            *span,
+                            &format!("'?.' can only be used on Option values, got {:?}", object_type)
+                        )
+                        .with_label("receiver is not an Option")
+                        .with_help("Use '.' for plain member access")
+                    )),
+                };
+
+                let class_info = self.classes.get(&class_name)
+                    .ok_or_else(|| DiagnosticError::Type(
+                        format!("Unknown class '{}'", class_name)
+                    ))?;
+                let field_info = class_info.fields.get(member)
+                    .ok_or_else(|| DiagnosticError::Type(
+                        format!("Class '{}' has no field '{}'", class_name, member)
+                    ))?;
+                if !self.can_access_field(&class_name, field_info.is_public) {
+                    return Err(DiagnosticError::Type(
+                        format!("Field '{}' is private and cannot be accessed from outside class '{}'",
+                               member, class_name)
+                    ));
+                }
+
+                // Option fields are flattened so chains stay a single Option
+                match &field_info.ty {
+                    HirType::Enum(name, _) if name == "Option" => Ok(field_info.ty.clone()),
+                    field_type => Ok(HirType::Enum("Option".to_string(), vec![field_type.clone()])),
+                }
+            }
             Expression::ConstructorCall { class_name, args, .. } => {
                 // Try to find the class - try both qualified and unqualified names
                 // This handles both old check_program path (unqualified) and new collect_symbols path (qualified)
@@ -4489,8 +5863,8 @@ impl TypeChecker {
 
                 for arg in args {
                     if let Some(field_info) = class_info.fields.get(&arg.name) {
-                        let arg_type = self.check_expression(&arg.value, None)?;
                         let expected_type = field_info.ty.substitute_types(&substitution);
+                        let arg_type = self.check_expression(&arg.value, Some(&expected_type))?;
 
                         // Check if argument type is compatible with field type (allows upcasting)
                         if !self.is_assignable(&expected_type, &arg_type) {
@@ -4568,7 +5942,7 @@ impl TypeChecker {
 
                 Ok(parent_method_signature.return_type)
             }
-            Expression::Range { start, end, .. } => {
+            Expression::Range { start, end, step, .. } => {
                 let start_type = self.check_expression(start, None)?;
                 let end_type = self.check_expression(end, None)?;
 
@@ -4592,6 +5966,23 @@ impl TypeChecker {
                     ));
                 }
 
+                if let Some(step) = step {
+                    let step_type = self.check_expression(step, Some(&start_type))?;
+                    if step_type != start_type {
+                        return Err(DiagnosticError::Type(
+                            format!("Range step must have the same type as its bounds: {:?} vs {:?}", step_type, start_type)
+                        ));
+                    }
+                    let is_zero = match step.as_ref() {
+                        Expression::Literal(Literal::Integer(0, _, _)) => true,
+                        Expression::Unary { op: UnaryOp::Negate, operand, .. } => matches!(operand.as_ref(), Expression::Literal(Literal::Integer(0, _, _))),
+                        _ => false,
+                    };
+                    if is_zero {
+                        return Err(DiagnosticError::Type("Range step must be nonzero".to_string()));
+                    }
+                }
+
                 // A range expression is not directly usable except in for loops
                 // We return the element type (the integer type)
                 Ok(start_type)
@@ -4629,6 +6020,28 @@ impl TypeChecker {
                 // Convert AST type to HIR type
                 let target_hir_type = self.ast_type_to_hir_type(target_type)?;
 
+                // A newtype converts explicitly to and from its underlying type
+                let newtype_conversion = match (&value_type, &target_hir_type) {
+                    (HirType::Newtype(name), other) | (other, HirType::Newtype(name)) => {
+                        self.newtypes.get(name) == Some(other)
+                    }
+                    _ => false,
+                };
+                if newtype_conversion {
+                    return Ok(target_hir_type);
+                }
+
+                // Booleans convert to and from integers (true is 1, any non-zero is true)
+                let bool_conversion = match (&value_type, &target_hir_type) {
+                    (HirType::Bool, other) | (other, HirType::Bool) => {
+                        matches!(other, HirType::Bool | HirType::Int8 | HirType::Int16 | HirType::Int32 | HirType::Int64)
+                    }
+                    _ => false,
+                };
+                if bool_conversion {
+                    return Ok(target_hir_type);
+                }
+
                 // Validate that both source and target are numeric types
                 if !self.is_numeric_type(&value_type) {
                     return Err(DiagnosticError::Type(
@@ -4674,6 +6087,38 @@ impl TypeChecker {
                 // Return Task<T> where T is the body's type
                 Ok(HirType::Task(Box::new(body_type)))
             }
+            Expression::Tuple { elements, .. } => {
+                // Propagate expected element types so literals pick up the annotated widths
+                let expected_elements = match expected_type {
+                    Some(HirType::Tuple(types)) if types.len() == elements.len() => Some(types),
+                    _ => None,
+                };
+
+                let mut element_types = Vec::new();
+                for (i, element) in elements.iter().enumerate() {
+                    let expected = expected_elements.map(|types| &types[i]);
+                    element_types.push(self.check_expression(element, expected)?);
+                }
+                Ok(HirType::Tuple(element_types))
+            }
+            Expression::TupleAccess { tuple, index, span } => {
+                let tuple_type = self.check_expression(tuple, None)?;
+                match tuple_type {
+                    HirType::Tuple(element_types) => {
+                        element_types.get(*index).cloned().ok_or_else(|| DiagnosticError::Rich(
+                            Diagnostic::syntax_error(
+                                &self.filename,
+                                *span,
+                                &format!("Tuple index {} is out of bounds", index)
+                            )
+                            .with_label(&format!("tuple has {} elements", element_types.len()))
+                        ))
+                    }
+                    other => Err(DiagnosticError::Type(
+                        format!("Tuple index access is only allowed on tuples, got {:?}", other)
+                    ))
+                }
+            }
         }
     }
 
@@ -4773,7 +6218,16 @@ impl TypeChecker {
                 }
             }
             Literal::String(_, _) => Ok(HirType::String),
-            Literal::InterpolatedString(_, _) => Ok(HirType::String),
+            Literal::InterpolatedString(parts, _) => {
+                // Any type can be interpolated, but the expressions themselves must check
+                for part in parts {
+                    if let InterpolationPart::Expression(expr) = part {
+                        let part_type = self.check_expression(expr, None)?;
+                        self.record_display(expr, &part_type);
+                    }
+                }
+                Ok(HirType::String)
+            }
             Literal::Array(elements, _) => {
                 if elements.is_empty() {
                     // If we have an expected type annotation, use it
@@ -4875,6 +6329,8 @@ impl TypeChecker {
                     (HirType::Int64, HirType::Int64) => Ok(HirType::Int64),
                     (HirType::Float8, HirType::Float8) => Ok(HirType::Float8),
                     (HirType::Float16, HirType::Float16) => Ok(HirType::Float16),
+                    // f16 widens losslessly to f32, so mixed arithmetic promotes
+                    (HirType::Float16, HirType::Float32) | (HirType::Float32, HirType::Float16) => Ok(HirType::Float32),
                     (HirType::Float32, HirType::Float32) => Ok(HirType::Float32),
                     (HirType::Float64, HirType::Float64) => Ok(HirType::Float64),
                     (HirType::String, HirType::String) if matches!(op, BinaryOp::Add) => Ok(HirType::String),
@@ -4979,6 +6435,12 @@ impl TypeChecker {
                 let element_hir_type = self.ast_type_to_hir_type(element_type)?;
                 Ok(HirType::Set(Box::new(element_hir_type)))
             }
+            Type::Tuple(element_types) => {
+                let element_hir_types = element_types.iter()
+                    .map(|ty| self.ast_type_to_hir_type(ty))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(HirType::Tuple(element_hir_types))
+            }
             Type::Named(name, type_params) => {
                 // Check for built-in Task type first
                 if name == "Task" {
@@ -4991,6 +6453,16 @@ impl TypeChecker {
                     return Ok(HirType::Task(Box::new(inner_type)));
                 }
 
+                // Check for built-in AtomicInt type
+                if name == "AtomicInt" && type_params.is_empty() {
+                    return Ok(HirType::AtomicInt);
+                }
+
+                // Check for built-in Mutex type
+                if name == "Mutex" && type_params.is_empty() {
+                    return Ok(HirType::Mutex);
+                }
+
                 // Check for built-in Channel type
                 if name == "Channel" {
                     if type_params.len() != 1 {
@@ -5002,6 +6474,11 @@ impl TypeChecker {
                     return Ok(HirType::Channel(Box::new(inner_type)));
                 }
 
+                // Unit is written by name, e.g. `Result<Unit, String>`
+                if name == "Unit" && type_params.is_empty() {
+                    return Ok(HirType::Unit);
+                }
+
                 // Check if this is a newtype first (distinct from type aliases)
                 if self.newtypes.contains_key(name) {
                     // Newtypes shouldn't have type parameters
@@ -5116,6 +6593,15 @@ impl TypeChecker {
         }
     }
 
+    /// Resolve a constant by name: top-level, qualified `ClassName::NAME`, or an unqualified
+    /// constant associated with the class currently being checked
+    fn lookup_constant(&self, name: &str) -> Option<&HirType> {
+        self.constants.get(name).or_else(|| {
+            let class_name = self.current_class_context.as_ref()?;
+            self.constants.get(&format!("{}::{}", class_name, name))
+        })
+    }
+
     fn lookup_variable(&self, name: &str) -> Result<HirType, DiagnosticError> {
         // Search from innermost to outermost scope
         for scope in self.scopes.iter().rev() {
@@ -5124,6 +6610,11 @@ impl TypeChecker {
             }
         }
 
+        // Fall back to module-level constants and variables
+        if let Some(ty) = self.lookup_constant(name).or_else(|| self.globals.get(name)) {
+            return Ok(ty.clone());
+        }
+
         Err(DiagnosticError::Type(format!("Undefined variable '{}'", name)))
     }
 
@@ -5242,6 +6733,46 @@ impl TypeChecker {
 
                 Ok(())
             }
+            Pattern::Tuple { bindings, .. } => {
+                let element_types = match expected_type {
+                    HirType::Tuple(element_types) => element_types,
+                    _ => return Err(DiagnosticError::Type(
+                        format!("Tuple pattern expects a tuple type, got {:?}", expected_type)
+                    ))
+                };
+
+                if bindings.len() != element_types.len() {
+                    return Err(DiagnosticError::Type(
+                        format!("Tuple has {} elements, but pattern has {} bindings", element_types.len(), bindings.len())
+                    ));
+                }
+
+                for ((binding_name, binding_type), element_type) in bindings.iter().zip(element_types.iter()) {
+                    if !is_snake_case(binding_name) {
+                        return Err(DiagnosticError::Type(
+                            format!("Pattern binding '{}' must be snake_case", binding_name)
+                        ));
+                    }
+
+                    if self.scopes.last().unwrap().contains_key(binding_name) {
+                        return Err(DiagnosticError::Type(
+                            format!("Variable '{}' is already bound in this pattern", binding_name)
+                        ));
+                    }
+
+                    let explicit_binding_type = self.ast_type_to_hir_type(binding_type)?;
+                    if explicit_binding_type != *element_type {
+                        return Err(DiagnosticError::Type(
+                            format!("Pattern binding '{}' has type {:?}, but tuple element has type {:?}",
+                                binding_name, explicit_binding_type, element_type)
+                        ));
+                    }
+
+                    self.scopes.last_mut().unwrap().insert(binding_name.clone(), explicit_binding_type);
+                }
+
+                Ok(())
+            }
             Pattern::Identifier { name, .. } => {
                 // Simple binding pattern
                 self.scopes.last_mut().unwrap().insert(name.clone(), expected_type.clone());
@@ -5271,6 +6802,7 @@ impl TypeChecker {
         // Add method parameters
         for param in &method.params {
             let param_type = self.ast_type_to_hir_type(&param.ty)?;
+            self.check_global_shadowing(&param.name)?;
             if self.scopes.last().unwrap().contains_key(&param.name) {
                 return Err(DiagnosticError::Type(
                     format!("Parameter '{}' shadows another variable", param.name)
@@ -5286,11 +6818,14 @@ impl TypeChecker {
             None => Some(HirType::Unit),
         };
 
+        let old_function_name = self.current_function_name.replace(format!("{}.{}", enum_decl.name, method.name));
+
         // Check method body
         self.check_block(&method.body)?;
 
         // Restore previous return type
         self.current_function_return_type = old_return_type;
+        self.current_function_name = old_function_name;
 
         self.pop_scope();
         Ok(())
@@ -5322,6 +6857,7 @@ impl TypeChecker {
         // Add method parameters
         for param in &method.params {
             let param_type = self.ast_type_to_hir_type(&param.ty)?;
+            self.check_global_shadowing(&param.name)?;
             if self.scopes.last().unwrap().contains_key(&param.name) {
                 return Err(DiagnosticError::Type(
                     format!("Parameter '{}' shadows another variable", param.name)
@@ -5343,11 +6879,14 @@ impl TypeChecker {
             None => Some(HirType::Unit),
         };
 
+        let old_function_name = self.current_function_name.replace(format!("{}.{}", class_decl.name, method.name));
+
         // Check method body
         self.check_block(&method.body)?;
 
         // Restore previous state
         self.current_function_return_type = old_return_type;
+        self.current_function_name = old_function_name;
         self.current_class_context = old_class_context;
         self.current_method_is_init = old_is_init;
         self.type_parameters = old_type_params;
@@ -5480,6 +7019,93 @@ impl TypeChecker {
         Ok(())
     }
 
+    /// Type-check the atomic integer builtins:
+    /// `atomic_init(value)`, `atomic_add(ptr, delta)`, `atomic_load(ptr)`,
+    /// `atomic_store(ptr, val)`, and `compare_and_swap(ptr, expected, new)`
+    fn check_atomic_call(&mut self, function: &str, args: &[NamedArg]) -> Result<HirType, DiagnosticError> {
+        let (params, return_type): (&[(&str, HirType)], HirType) = match function {
+            "atomic_init" => (&[("value", HirType::Int64)], HirType::AtomicInt),
+            "atomic_add" => (&[("ptr", HirType::AtomicInt), ("delta", HirType::Int64)], HirType::Int64),
+            "atomic_load" => (&[("ptr", HirType::AtomicInt)], HirType::Int64),
+            "atomic_store" => (&[("ptr", HirType::AtomicInt), ("val", HirType::Int64)], HirType::Unit),
+            _ => (&[("ptr", HirType::AtomicInt), ("expected", HirType::Int64), ("new", HirType::Int64)], HirType::Bool),
+        };
+
+        if args.len() != params.len() {
+            let names: Vec<String> = params.iter().map(|(name, _)| format!("'{}'", name)).collect();
+            return Err(DiagnosticError::Type(
+                format!("{} requires exactly {} argument(s): {}", function, params.len(), names.join(", "))
+            ));
+        }
+
+        for (name, expected) in params {
+            let arg = args.iter().find(|arg| arg.name == *name)
+                .ok_or_else(|| DiagnosticError::Type(format!("{} requires a '{}' parameter", function, name)))?;
+            let arg_type = self.check_expression(&arg.value, Some(expected))?;
+            if arg_type != *expected {
+                return Err(DiagnosticError::Type(
+                    format!("{} '{}' parameter must be {:?}, got {:?}", function, name, expected, arg_type)
+                ));
+            }
+        }
+
+        Ok(return_type)
+    }
+
+    /// Constructor shorthand: an `init` parameter with the same name and type as one of the
+    /// class's fields is assigned to that field automatically, unless the body already
+    /// assigns `self.<field>` itself. The assignments are prepended to the body so the rest
+    /// of `init` sees the initialized fields.
+    fn expand_init_field_shorthand(&mut self, program: &mut Program) {
+        for class_decl in &mut program.classes {
+            let fields = &class_decl.fields;
+            let old_type_params = self.type_parameters.clone();
+            self.type_parameters.extend(class_decl.type_params.iter().cloned());
+            for method in class_decl.methods.iter_mut().filter(|m| m.name == "init") {
+                let assigned: HashSet<&str> = method.body.statements.iter()
+                    .filter_map(|stmt| match stmt {
+                        Statement::Expression(Expression::Assignment { target, .. }) => match target.as_ref() {
+                            Expression::MemberAccess { object, member, .. }
+                                if matches!(object.as_ref(), Expression::Self_ { .. }) => Some(member.as_str()),
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                    .collect();
+
+                let mut prologue = Vec::new();
+                for param in &method.params {
+                    let Some(field) = fields.iter().find(|f| f.name == param.name) else { continue };
+                    if assigned.contains(param.name.as_str()) {
+                        continue;
+                    }
+                    let same_type = match (self.ast_type_to_hir_type(&param.ty), self.ast_type_to_hir_type(&field.ty)) {
+                        (Ok(param_ty), Ok(field_ty)) => param_ty == field_ty,
+                        _ => false,
+                    };
+                    if !same_type {
+                        continue;
+                    }
+                    prologue.push(Statement::Expression(Expression::Assignment {
+                        target: Box::new(Expression::MemberAccess {
+                            object: Box::new(Expression::Self_ { span: param.span }),
+                            member: param.name.clone(),
+                            span: param.span,
+                        }),
+                        value: Box::new(Expression::Identifier { name: param.name.clone(), span: param.span }),
+                        span: param.span,
+                    }));
+                }
+
+                if !prologue.is_empty() {
+                    prologue.append(&mut method.body.statements);
+                    method.body.statements = prologue;
+                }
+            }
+            self.type_parameters = old_type_params;
+        }
+    }
+
     fn validate_init_method(&mut self, class_decl: &ClassDecl, init_method: &Function) -> Result<(), DiagnosticError> {
         // For now, just check that init method returns the class type or Unit
         match &init_method.return_type {
@@ -5542,8 +7168,11 @@ impl TypeSubstitutable for HirType {
             HirType::Channel(inner_type) => {
                 HirType::Channel(Box::new(inner_type.substitute_types(substitution)))
             }
+            HirType::Tuple(element_types) => {
+                HirType::Tuple(element_types.iter().map(|t| t.substitute_types(substitution)).collect())
+            }
             // Primitive types and newtypes don't need substitution
-            HirType::Bool | HirType::Int8 | HirType::Int16 | HirType::Int32 | HirType::Int64 | HirType::Float8 | HirType::Float16 | HirType::Float32 | HirType::Float64 | HirType::String | HirType::Unit | HirType::Newtype(_) => {
+            HirType::Bool | HirType::Int8 | HirType::Int16 | HirType::Int32 | HirType::Int64 | HirType::Float8 | HirType::Float16 | HirType::Float32 | HirType::Float64 | HirType::String | HirType::Unit | HirType::Newtype(_) | HirType::AtomicInt | HirType::Mutex => {
                 self.clone()
             }
         }
@@ -5705,6 +7334,7 @@ impl Monomorphizer {
             name: specialized_name.clone(),
             type_params: vec![], // Specialized enums are not generic
             variants: specialized_variants,
+            variant_order: enum_info.variant_order.clone(),
             methods: specialized_methods,
             is_public: enum_info.is_public, // Preserve visibility from original
         };
@@ -5799,6 +7429,11 @@ impl TypeChecker {
 
     /// Fill in default arguments for all function, method, and constructor calls in the program
     pub fn fill_default_arguments(&mut self, program: &mut Program) {
+        // Transform module-level variable initializers
+        for global in &mut program.globals {
+            self.fill_defaults_in_expression(&mut global.value, &HashMap::new());
+        }
+
         // Transform all functions
         for function in &mut program.functions {
             self.fill_defaults_in_function(function);
@@ -5849,10 +7484,20 @@ impl TypeChecker {
                         var_types.insert(name.clone(), class_name.clone());
                     }
                 }
-                Statement::For { variable, variable_type, body, .. } => {
+                Statement::LetPattern { pattern: Pattern::EnumVariant { bindings, .. } | Pattern::Tuple { bindings, .. }, .. } => {
+                    for (binding_name, binding_type) in bindings {
+                        if let Type::Named(class_name, _) = binding_type {
+                            var_types.insert(binding_name.clone(), class_name.clone());
+                        }
+                    }
+                }
+                Statement::For { variable, variable_type, value_binding, body, .. } => {
                     if let Type::Named(class_name, _) = variable_type {
                         var_types.insert(variable.clone(), class_name.clone());
                     }
+                    if let Some((value_name, Type::Named(class_name, _))) = value_binding {
+                        var_types.insert(value_name.clone(), class_name.clone());
+                    }
                     self.collect_variable_types(body, var_types);
                 }
                 Statement::If { then_branch, else_branch, .. } => {
@@ -5877,7 +7522,7 @@ impl TypeChecker {
 
     fn fill_defaults_in_statement(&mut self, statement: &mut Statement, var_types: &HashMap<String, String>) {
         match statement {
-            Statement::Let { value, .. } | Statement::Var { value, .. } => {
+            Statement::Let { value, .. } | Statement::Var { value, .. } | Statement::LetPattern { value, .. } => {
                 self.fill_defaults_in_expression(value, var_types);
             }
             Statement::Expression(expr) => {
@@ -5903,11 +7548,274 @@ impl TypeChecker {
             }
             Statement::Print { value, .. } => {
                 self.fill_defaults_in_expression(value, var_types);
+                self.lower_display(value);
+            }
+            Statement::Select { arms, default, .. } => {
+                for arm in arms.iter_mut() {
+                    self.fill_defaults_in_expression(&mut arm.channel, var_types);
+                    self.fill_defaults_in_block(&mut arm.body, var_types);
+                }
+                if let Some(default_block) = default {
+                    self.fill_defaults_in_block(default_block, var_types);
+                }
             }
             _ => {}
         }
     }
 
+    /// Rewrite a built-in method call recorded during type checking into a `match`:
+    /// - `value.unwrap()` / `value.expect(message = m)` on an Option or Result becomes
+    ///   `match value { Some(v) -> v, None -> panic(...) }` (Ok/Err for Result), so the
+    ///   payload is extracted by the match codegen using the payload's declared type
+    /// - `value.ordinal()` becomes `match value { First -> 0, Second -> 1, ... }`, since
+    ///   discriminants are name hashes rather than declaration positions
+    /// - `value.to_string()` on an enum becomes `match value { Red -> "Red", Custom(x) -> "Custom(${x})", ... }`,
+    ///   so unit variants share interned names and the match can dispatch through a switch
+    fn lower_method_call(&self, object: &Expression, method: &str, args: &[NamedArg], span: Span) -> Option<Expression> {
+        match self.method_lowerings.get(&(span.start, span.end))? {
+            MethodLowering::Unwrap { enum_name, payload } if method == "unwrap" || method == "expect" => {
+                Self::lower_unwrap(object, enum_name, payload, args, span)
+            }
+            MethodLowering::Ordinal { enum_name, variants } if method == "ordinal" => {
+                let arms = variants.iter().enumerate()
+                    .map(|(index, variant)| MatchArm {
+                        pattern: Pattern::EnumVariant {
+                            enum_name: Some(enum_name.clone()),
+                            variant: variant.clone(),
+                            bindings: Vec::new(),
+                            span,
+                        },
+                        binding: None,
+                        body: Expression::Literal(Literal::Integer(index as i64, IntType::I32, span)),
+                        span,
+                    })
+                    .collect();
+                Some(Expression::Match { value: Box::new(object.clone()), arms, span })
+            }
+            MethodLowering::EnumToString { enum_name, variants } if method == "to_string" => {
+                let arms = variants.iter()
+                    .map(|(variant, fields)| {
+                        let bindings: Vec<(String, Type)> = fields.iter().enumerate()
+                            .map(|(index, field)| (format!("__field{}", index), field.clone()))
+                            .collect();
+                        let body = if bindings.is_empty() {
+                            Literal::String(variant.clone(), span)
+                        } else {
+                            let mut parts = vec![InterpolationPart::Text(format!("{}(", variant))];
+                            for (index, (binding, _)) in bindings.iter().enumerate() {
+                                if index > 0 {
+                                    parts.push(InterpolationPart::Text(", ".to_string()));
+                                }
+                                parts.push(InterpolationPart::Expression(Box::new(
+                                    Expression::Identifier { name: binding.clone(), span }
+                                )));
+                            }
+                            parts.push(InterpolationPart::Text(")".to_string()));
+                            Literal::InterpolatedString(parts, span)
+                        };
+                        MatchArm {
+                            pattern: Pattern::EnumVariant {
+                                enum_name: Some(enum_name.clone()),
+                                variant: variant.clone(),
+                                bindings,
+                                span,
+                            },
+                            binding: None,
+                            body: Expression::Literal(body),
+                            span,
+                        }
+                    })
+                    .collect();
+                Some(Expression::Match { value: Box::new(object.clone()), arms, span })
+            }
+            MethodLowering::NewtypeMethod { function } => {
+                let receiver = NamedArg { name: "self".to_string(), value: object.clone(), span };
+                Some(Expression::Call {
+                    function: function.clone(),
+                    args: std::iter::once(receiver).chain(args.iter().cloned()).collect(),
+                    span,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Remember how a class value that is printed or interpolated becomes a String: through
+    /// its `to_string() -> String` method when it has one, otherwise a name-and-address description
+    fn record_display(&mut self, expr: &Expression, value_type: &HirType) {
+        let HirType::Class(class_name, _) = value_type else { return };
+        let has_to_string = self.classes.get(class_name)
+            .and_then(|class_info| class_info.methods.get("to_string"))
+            .map_or(false, |signature| signature.params.is_empty() && signature.return_type == HirType::String);
+        let lowering = if has_to_string {
+            DisplayLowering::ToString
+        } else {
+            DisplayLowering::Describe { class_name: class_name.clone() }
+        };
+        let span = expr.span();
+        self.display_lowerings.insert((span.start, span.end), lowering);
+    }
+
+    /// Rewrite a printed or interpolated class value recorded by `record_display` into
+    /// `value.to_string()` or `__describe_object(type_name = "Name", object = value)`
+    fn lower_display(&self, expr: &mut Expression) {
+        let span = expr.span();
+        let Some(lowering) = self.display_lowerings.get(&(span.start, span.end)) else { return };
+        let value = std::mem::replace(expr, Expression::Literal(Literal::String(String::new(), span)));
+        *expr = match lowering {
+            DisplayLowering::ToString => Expression::MethodCall {
+                object: Box::new(value),
+                method: "to_string".to_string(),
+                args: Vec::new(),
+                span,
+            },
+            DisplayLowering::Describe { class_name } => Expression::Call {
+                function: "__describe_object".to_string(),
+                args: vec![
+                    NamedArg { name: "type_name".to_string(), value: Expression::Literal(Literal::String(class_name.clone(), span)), span },
+                    NamedArg { name: "object".to_string(), value, span },
+                ],
+                span,
+            },
+        };
+    }
+
+    /// Name of the generated helper backing `enum_name::from_ordinal`
+    fn from_ordinal_helper_name(enum_name: &str) -> String {
+        format!("__{}_from_ordinal", enum_name.replace("::", "__").to_lowercase())
+    }
+
+    /// Generate `fn __color_from_ordinal(n: Int32) -> Option<Color>` for every enum whose
+    /// `from_ordinal` was called: one `if (n == i) { return Some(Variant); }` per variant
+    /// in declaration order, falling through to `None`
+    fn from_ordinal_helpers(&self) -> Vec<Function> {
+        let mut enums: Vec<(&String, &Vec<String>)> = self.method_lowerings.values()
+            .filter_map(|lowering| match lowering {
+                MethodLowering::FromOrdinal { enum_name, variants } => Some((enum_name, variants)),
+                _ => None,
+            })
+            .collect();
+        enums.sort();
+        enums.dedup_by(|a, b| a.0 == b.0);
+
+        let span = Span::new(0, 0);
+        enums.into_iter()
+            .map(|(enum_name, variants)| {
+                let n = || Expression::Identifier { name: "n".to_string(), span };
+                let mut statements: Vec<Statement> = variants.iter().enumerate()
+                    .map(|(index, variant)| Statement::If {
+                        condition: Expression::Binary {
+                            left: Box::new(n()),
+                            op: BinaryOp::Equal,
+                            right: Box::new(Expression::Literal(Literal::Integer(index as i64, IntType::I32, span))),
+                            span,
+                        },
+                        then_branch: Block {
+                            statements: vec![Statement::Return {
+                                value: Some(Expression::EnumConstructor {
+                                    enum_name: "Option".to_string(),
+                                    variant: "Some".to_string(),
+                                    args: vec![NamedArg {
+                                        name: "field0".to_string(),
+                                        value: Expression::EnumConstructor {
+                                            enum_name: enum_name.clone(),
+                                            variant: variant.clone(),
+                                            args: Vec::new(),
+                                            span,
+                                        },
+                                        span,
+                                    }],
+                                    span,
+                                }),
+                                span,
+                            }],
+                            span,
+                        },
+                        else_branch: None,
+                        span,
+                    })
+                    .collect();
+                statements.push(Statement::Return {
+                    value: Some(Expression::EnumConstructor {
+                        enum_name: "Option".to_string(),
+                        variant: "None".to_string(),
+                        args: Vec::new(),
+                        span,
+                    }),
+                    span,
+                });
+
+                Function {
+                    name: Self::from_ordinal_helper_name(enum_name),
+                    type_params: Vec::new(),
+                    params: vec![Parameter {
+                        name: "n".to_string(),
+                        ty: Type::Int32,
+                        default_value: None,
+                        span,
+                    }],
+                    return_type: Some(Type::Named(
+                        "Option".to_string(),
+                        vec![Type::Named(enum_name.clone(), Vec::new())],
+                    )),
+                    body: Block { statements, span },
+                    is_mutable: false,
+                    is_virtual: false,
+                    is_override: false,
+                    is_public: false,
+                    span,
+                }
+            })
+            .collect()
+    }
+
+    fn lower_unwrap(object: &Expression, enum_name: &str, payload: &HirType, args: &[NamedArg], span: Span) -> Option<Expression> {
+        let payload_type = hir_type_to_ast_type(payload)?;
+        let (success, failure) = if enum_name == "Option" { ("Some", "None") } else { ("Ok", "Err") };
+
+        let message = match args.iter().find(|arg| arg.name == "message") {
+            Some(arg) => arg.value.clone(),
+            None => Expression::Literal(Literal::String(
+                format!("called unwrap() on {} value", if enum_name == "Option" { "a None" } else { "an Err" }),
+                span,
+            )),
+        };
+        let binding = "__unwrapped".to_string();
+
+        Some(Expression::Match {
+            value: Box::new(object.clone()),
+            arms: vec![
+                MatchArm {
+                    pattern: Pattern::EnumVariant {
+                        enum_name: Some(enum_name.to_string()),
+                        variant: success.to_string(),
+                        bindings: vec![(binding.clone(), payload_type)],
+                        span,
+                    },
+                    binding: None,
+                    body: Expression::Identifier { name: binding, span },
+                    span,
+                },
+                MatchArm {
+                    pattern: Pattern::EnumVariant {
+                        enum_name: Some(enum_name.to_string()),
+                        variant: failure.to_string(),
+                        bindings: Vec::new(),
+                        span,
+                    },
+                    binding: None,
+                    body: Expression::Call {
+                        function: "panic".to_string(),
+                        args: vec![NamedArg { name: "message".to_string(), value: message, span }],
+                        span,
+                    },
+                    span,
+                },
+            ],
+            span,
+        })
+    }
+
     fn fill_defaults_in_expression(&mut self, expr: &mut Expression, var_types: &HashMap<String, String>) {
         match expr {
             Expression::Call { function, args, span } => {
@@ -5946,6 +7854,15 @@ impl TypeChecker {
                     self.fill_defaults_in_expression(&mut arg.value, var_types);
                 }
 
+                if let Some(lowered) = self.lower_method_call(object, method, args, *span) {
+                    *expr = lowered;
+                    // Newtype method calls become plain calls, which still need their defaults
+                    if matches!(expr, Expression::Call { .. }) {
+                        self.fill_defaults_in_expression(expr, var_types);
+                    }
+                    return;
+                }
+
                 // Fill in defaults for method calls
                 // Try to determine object type by looking at the object expression
                 let class_name_opt = match object.as_ref() {
@@ -6048,12 +7965,15 @@ impl TypeChecker {
             Expression::Try { expression, .. } => {
                 self.fill_defaults_in_expression(expression, var_types);
             }
-            Expression::MemberAccess { object, .. } => {
+            Expression::MemberAccess { object, .. } | Expression::OptionalMemberAccess { object, .. } => {
                 self.fill_defaults_in_expression(object, var_types);
             }
-            Expression::Range { start, end, .. } => {
+            Expression::Range { start, end, step, .. } => {
                 self.fill_defaults_in_expression(start, var_types);
                 self.fill_defaults_in_expression(end, var_types);
+                if let Some(step) = step {
+                    self.fill_defaults_in_expression(step, var_types);
+                }
             }
             Expression::If { condition, then_branch, else_branch, .. } => {
                 self.fill_defaults_in_expression(condition, var_types);
@@ -6069,6 +7989,7 @@ impl TypeChecker {
                 for part in parts {
                     if let InterpolationPart::Expression(expr) = part {
                         self.fill_defaults_in_expression(expr, var_types);
+                        self.lower_display(expr);
                     }
                 }
             }
@@ -6088,17 +8009,63 @@ impl TypeChecker {
                     self.fill_defaults_in_expression(elem, var_types);
                 }
             }
-            Expression::EnumConstructor { args, .. } => {
-                for arg in args {
+            Expression::EnumConstructor { enum_name, args, span, .. } => {
+                for arg in args.iter_mut() {
                     self.fill_defaults_in_expression(&mut arg.value, var_types);
                 }
+                if let Some(MethodLowering::FromOrdinal { .. }) = self.method_lowerings.get(&(span.start, span.end)) {
+                    *expr = Expression::Call {
+                        function: Self::from_ordinal_helper_name(enum_name),
+                        args: args.clone(),
+                        span: *span,
+                    };
+                }
             }
             Expression::SuperCall { args, .. } => {
                 for arg in args {
                     self.fill_defaults_in_expression(&mut arg.value, var_types);
                 }
             }
+            Expression::Tuple { elements, .. } => {
+                for elem in elements {
+                    self.fill_defaults_in_expression(elem, var_types);
+                }
+            }
+            Expression::TupleAccess { tuple, .. } => {
+                self.fill_defaults_in_expression(tuple, var_types);
+            }
             _ => {}
         }
     }
-}
\ No newline at end of file
+}
+
+/// Spell a checked type back as source syntax, for HIR rewrites that synthesize typed bindings.
+/// Type parameters have no concrete spelling, so they yield None.
+fn hir_type_to_ast_type(ty: &HirType) -> Option<Type> {
+    Some(match ty {
+        HirType::Bool => Type::Bool,
+        HirType::Int8 => Type::Int8,
+        HirType::Int16 => Type::Int16,
+        HirType::Int32 => Type::Int32,
+        HirType::Int64 => Type::Int64,
+        HirType::Float8 => Type::Float8,
+        HirType::Float16 => Type::Float16,
+        HirType::Float32 => Type::Float32,
+        HirType::Float64 => Type::Float64,
+        HirType::String => Type::String,
+        HirType::List(element) => Type::List(Box::new(hir_type_to_ast_type(element)?)),
+        HirType::Dict(key, value) => Type::Dict(Box::new(hir_type_to_ast_type(key)?), Box::new(hir_type_to_ast_type(value)?)),
+        HirType::Set(element) => Type::Set(Box::new(hir_type_to_ast_type(element)?)),
+        HirType::Enum(name, params) | HirType::Class(name, params) => {
+            Type::Named(name.clone(), params.iter().map(hir_type_to_ast_type).collect::<Option<Vec<_>>>()?)
+        }
+        HirType::Newtype(name) => Type::Named(name.clone(), Vec::new()),
+        HirType::Task(inner) => Type::Named("Task".to_string(), vec![hir_type_to_ast_type(inner)?]),
+        HirType::Channel(inner) => Type::Named("Channel".to_string(), vec![hir_type_to_ast_type(inner)?]),
+        HirType::AtomicInt => Type::Named("AtomicInt".to_string(), Vec::new()),
+        HirType::Mutex => Type::Named("Mutex".to_string(), Vec::new()),
+        HirType::Tuple(elements) => Type::Tuple(elements.iter().map(hir_type_to_ast_type).collect::<Option<Vec<_>>>()?),
+        HirType::Unit => Type::Named("Unit".to_string(), Vec::new()),
+        HirType::TypeParameter(_) => return None,
+    })
+}
@@ -195,6 +195,18 @@ mod tests {
         let result = type_check(input);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("boolean operands"));
+
+        // Collections have no truthiness either
+        let input = r#"
+            fn main() {
+                let names: List[String] = ["a"];
+                let x: Bool = names or false;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("boolean operands"));
     }
 
     #[test]
@@ -551,7 +563,23 @@ mod tests {
 
         let result = type_check(input);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("can only iterate over List or Range types"));
+        assert!(result.unwrap_err().to_string().contains("can only iterate over List, Set, Dict or Range types"));
+    }
+
+    #[test]
+    fn test_for_range_step_must_be_nonzero() {
+        let input = r#"
+            fn main() -> Int32 {
+                for (i: Int32 in 0..10 step 0) {
+                    print(value = "${i}");
+                }
+                return 0;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Range step must be nonzero"));
     }
 
     #[test]
@@ -1149,4 +1177,1548 @@ mod tests {
 
         assert!(type_check(input).is_ok());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_string_index_of() {
+        let input = r#"
+            fn main() -> Int32 {
+                let text: String = "hello world";
+                let position: Option<Int32> = text.index_of(needle = "world");
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_string_index_of_wrong_arg_type() {
+        let input = r#"
+            fn main() -> Int32 {
+                let text: String = "hello world";
+                let position: Option<Int32> = text.index_of(needle = 42);
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_dict_get_returns_option() {
+        let input = r#"
+            fn main() -> Int32 {
+                let counts: Dict[String, Int32] = {"apples": 3};
+                let apples: Option<Int32> = counts.get(key = "apples");
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_dict_get_raw_value_rejected() {
+        let input = r#"
+            fn main() -> Int32 {
+                let counts: Dict[String, Int32] = {"apples": 3};
+                let apples: Int32 = counts.get(key = "apples");
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_for_loop_dict_iteration() {
+        let input = r#"
+            fn main() -> Int32 {
+                let scores: Dict[String, Int32] = {"alice": 10, "bob": 20};
+                var total: Int32 = 0;
+                for (name: String, score: Int32 in scores) {
+                    total = total + score;
+                }
+                return total;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_for_loop_dict_iteration_value_type_mismatch() {
+        let input = r#"
+            fn main() -> Int32 {
+                let scores: Dict[String, Int32] = {"alice": 10, "bob": 20};
+                for (name: String, score: String in scores) {
+                    print(value = score);
+                }
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_for_loop_set_iteration() {
+        let input = r#"
+            fn main() -> Int32 {
+                let tags: Set[String] = Set{"red", "green", "blue"};
+                var count: Int32 = 0;
+                for (tag: String in tags) {
+                    count = count + 1;
+                }
+                return count;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_mixed_float16_float32_arithmetic_promotes() {
+        let input = r#"
+            fn main() -> Int32 {
+                let half: Float16 = 1.5f16;
+                let single: Float32 = 2.0f32;
+                let product: Float32 = half * single;
+                let sum: Float32 = single + half;
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+
+        let narrowed = r#"
+            fn main() -> Int32 {
+                let half: Float16 = 1.5f16 + 2.0f32;
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(narrowed).is_err());
+    }
+
+    #[test]
+    fn test_const_declaration_in_arithmetic() {
+        let input = r#"
+            const MAX: Int32 = 100;
+            const HALF: Int32 = MAX / 2;
+            const LIMIT: Int64 = 5_000_000_000i64;
+
+            fn main() -> Int32 {
+                let total: Int32 = MAX + HALF * 2;
+                let big: Int64 = LIMIT;
+                for (i: Int32 in 0..MAX) {
+                    let x: Int32 = i;
+                }
+                return total;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_const_requires_constant_expression() {
+        let input = r#"
+            const NOW: Int32 = compute();
+
+            fn compute() -> Int32 {
+                return 1;
+            }
+
+            fn main() -> Int32 {
+                return NOW;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be initialized with a constant expression"));
+    }
+
+    #[test]
+    fn test_const_type_mismatch() {
+        let input = r#"
+            const MAX: Int32 = "one hundred";
+
+            fn main() -> Int32 {
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_const_cannot_be_assigned() {
+        let input = r#"
+            const MAX: Int32 = 100;
+
+            fn main() -> Int32 {
+                MAX = 5;
+                return 0;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Cannot assign to constant 'MAX'"));
+    }
+
+    #[test]
+    fn test_const_name_must_be_screaming_snake_case() {
+        let input = r#"
+            const maxSize: Int32 = 100;
+
+            fn main() -> Int32 {
+                return 0;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be SCREAMING_SNAKE_CASE"));
+    }
+
+    #[test]
+    fn test_class_associated_constants() {
+        let input = r#"
+            class Circle {
+                const PI: Float64 = 3.14159;
+                const TAU: Float64 = PI * 2.0;
+                let radius: Float64;
+
+                pub fn circumference() -> Float64 {
+                    return TAU * self.radius;
+                }
+            }
+
+            fn main() -> Int32 {
+                let pi: Float64 = Circle::PI;
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+
+        // Unqualified access only resolves inside the owning class
+        let outside = r#"
+            class Circle {
+                const PI: Float64 = 3.14159;
+                let radius: Float64;
+            }
+
+            fn main() -> Int32 {
+                let pi: Float64 = PI;
+                return 0;
+            }
+        "#;
+
+        let result = type_check(outside);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Undefined symbol 'PI'"));
+    }
+
+    #[test]
+    fn test_newtype_methods_do_not_expose_underlying_type() {
+        let input = r#"
+            newtype UserId = Int64 {
+                fn to_string() -> String {
+                    let raw: Int64 = cast(value = self, target = Int64);
+                    return "user-${raw}";
+                }
+            }
+
+            fn main() -> Int32 {
+                let id: UserId = cast(value = 7i64, target = UserId);
+                let label: String = id.to_string();
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+
+        // Int64 methods are not available on the newtype
+        let leaked = input.replace("id.to_string()", "id.abs().to_string()");
+        assert!(type_check(&leaked).is_err());
+
+        // ...and newtype methods are not available on the underlying type
+        let raw = input.replace("id.to_string()", "7i64.to_string()");
+        assert!(type_check(&raw).is_err());
+    }
+
+    #[test]
+    fn test_global_var_read_and_assign() {
+        let input = r#"
+            const START: Int32 = 10;
+            var counter: Int32 = START;
+            var names: List[String] = [];
+
+            fn increment() {
+                counter = counter + 1;
+                names.push(value = "tick");
+            }
+
+            fn main() -> Int32 {
+                increment();
+                return counter;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_global_var_type_mismatch() {
+        let input = r#"
+            var counter: Int32 = "zero";
+
+            fn main() -> Int32 {
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_global_var_shadowing_rejected() {
+        let input = r#"
+            var counter: Int32 = 0;
+
+            fn main() -> Int32 {
+                let counter: Int32 = 5;
+                return counter;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("shadows a module-level variable"));
+    }
+
+    #[test]
+    fn test_global_var_not_allowed_in_library_module() {
+        let input = r#"
+            mod counters;
+
+            var counter: Int32 = 0;
+
+            fn main() -> Int32 {
+                return 0;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("only supported in the entry file"));
+    }
+
+    #[test]
+    fn test_tuple_literal_and_access() {
+        let input = r#"
+            fn split(value: Int32) -> (Int32, String) {
+                return (value, "value");
+            }
+
+            fn main() -> Int32 {
+                let pair: (Int32, String) = split(value = 7);
+                let label: String = pair.1;
+                return pair.0;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_tuple_access_errors() {
+        let wrong_element = r#"
+            fn main() -> Int32 {
+                let pair: (Int32, String) = (1, "one");
+                let label: Int32 = pair.1;
+                return 0;
+            }
+        "#;
+        assert!(type_check(wrong_element).is_err());
+
+        let out_of_bounds = r#"
+            fn main() -> Int32 {
+                let pair: (Int32, String) = (1, "one");
+                return pair.2;
+            }
+        "#;
+        let result = type_check(out_of_bounds);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("out of bounds"));
+    }
+
+    #[test]
+    fn test_optional_chaining_on_option_field() {
+        let input = r#"
+            class Address {
+                pub let city: String;
+                pub let next: Option<Address>;
+            }
+
+            class User {
+                pub let address: Option<Address>;
+            }
+
+            fn main() -> Int32 {
+                let user: User = User.init(address = Option::None);
+                let city: Option<String> = user.address?.city;
+                let next_city: Option<String> = user.address?.next?.city;
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_optional_chaining_requires_option_receiver() {
+        let input = r#"
+            class Address {
+                pub let city: String;
+            }
+
+            fn main() -> Int32 {
+                let address: Address = Address.init(city = "Paris");
+                let city: Option<String> = address?.city;
+                return 0;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("can only be used on Option values"));
+    }
+
+    #[test]
+    fn test_all_any_reject_ignored_predicates() {
+        for method in ["all", "any"] {
+            let input = format!(r#"
+                fn is_positive(x: Int32) -> Bool {{
+                    return x > 0;
+                }}
+
+                fn main() -> Int32 {{
+                    let nums: List[Int32] = [-1, 2, 3];
+                    let result: Bool = nums.{}(predicate = is_positive);
+                    return 0;
+                }}
+            "#, method);
+
+            let result = type_check(&input);
+            assert!(result.is_err(), "{}() should not silently ignore its predicate", method);
+            assert!(result.unwrap_err().to_string().contains("requires a predicate closure"));
+        }
+    }
+
+    #[test]
+    fn test_find_takes_named_predicate() {
+        let input = r#"
+            fn is_even(x: Int32) -> Bool {
+                return x % 2 == 0;
+            }
+
+            fn shout(x: String) -> Bool {
+                return true;
+            }
+
+            fn main() -> Int32 {
+                let nums: List[Int32] = [1, 3, 4, 5];
+                let first: Option<Int32> = nums.find(predicate = is_even);
+                return 0;
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+
+        let mismatched = input.replace("predicate = is_even", "predicate = shout");
+        let result = type_check(&mismatched);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must take one"));
+    }
+
+    #[test]
+    fn test_main_may_return_unit_result() {
+        let input = r#"
+            fn main() -> Result<Unit, String> {
+                return Result::Err(field0 = "boom");
+            }
+        "#;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_format_builtin_types() {
+        let input = r#"
+            fn main() -> Int32 {
+                let s: String = format(template = "{0} + {1}", args = ["1", "2"]);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let s: String = format(template = "{0}", args = [1, 2]);
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must be List[String]"));
+    }
+
+    #[test]
+    fn test_panic_type_checks_in_any_position() {
+        let input = r#"
+            fn non_negative(n: Int32) -> Int32 {
+                if (n < 0) {
+                    panic(message = "negative");
+                }
+                return n;
+            }
+
+            fn unwrap_or_panic(o: Option<String>) -> String {
+                return match o {
+                    Option::Some(s: String) -> s,
+                    Option::None -> panic(message = "missing")
+                };
+            }
+
+            fn main() -> Int32 {
+                let n: Int64 = panic(message = "not yet");
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                panic(message = 42);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_unwrap_and_expect_types() {
+        let input = r#"
+            fn lookup(n: Int32) -> Option<Int32> {
+                return Option::Some(field0 = n);
+            }
+
+            fn parse(n: Int32) -> Result<Float64, String> {
+                return Result::Ok(field0 = 1.5);
+            }
+
+            fn main() -> Int32 {
+                let a: Int32 = lookup(n = 1).unwrap();
+                let b: Float64 = parse(n = 2).expect(message = "parse failed");
+                return a;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let a: String = Option::Some(field0 = 1).unwrap();
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let a: Int32 = Option::Some(field0 = 1).expect(message = 3);
+                return a;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_option_result_discriminant_checks() {
+        let input = r#"
+            fn main() -> Int32 {
+                let o: Option<Int32> = Option::Some(field0 = 1);
+                let r: Result<Int32, String> = Result::Err(field0 = "bad");
+                let a: Bool = o.is_some();
+                let b: Bool = o.is_none();
+                let c: Bool = r.is_ok();
+                let d: Bool = r.is_err();
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let o: Option<Int32> = Option::Some(field0 = 1);
+                let a: Bool = o.is_ok();
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_option_result_map_requires_closure() {
+        let input = r#"
+            fn double(x: Int32) -> Int32 {
+                return x * 2;
+            }
+
+            fn main() -> Int32 {
+                let o: Option<Int32> = Option::Some(field0 = 2);
+                let mapped: Option<Int32> = o.map(f = double);
+                return 0;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("map() requires a closure"));
+    }
+
+    #[test]
+    fn test_ordinal_requires_unit_only_enum() {
+        let input = r#"
+            enum Level {
+                Low,
+                Medium,
+                High
+            }
+
+            fn main() -> Int32 {
+                let level: Level = Level::Medium;
+                return level.ordinal();
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            enum Shape {
+                Point,
+                Circle(Int32)
+            }
+
+            fn main() -> Int32 {
+                let shape: Shape = Shape::Point;
+                return shape.ordinal();
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("carry no data"));
+    }
+
+    #[test]
+    fn test_from_ordinal_returns_option() {
+        let input = r#"
+            enum Level {
+                Low,
+                High
+            }
+
+            fn main() -> Int32 {
+                let level: Option<Level> = Level::from_ordinal(n = 1);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            enum Level {
+                Low,
+                High
+            }
+
+            fn main() -> Int32 {
+                let level: Level = Level::from_ordinal(n = 1);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+
+        let input = r#"
+            enum Shape {
+                Point,
+                Circle(Int32)
+            }
+
+            fn main() -> Int32 {
+                let shape: Option<Shape> = Shape::from_ordinal(n = 0);
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("carry no data"));
+    }
+
+    #[test]
+    fn test_enum_method_matches_self() {
+        let input = r##"
+            enum Color {
+                Red,
+                Custom(Int32),
+
+                fn to_hex() -> String {
+                    return match self {
+                        Color::Red -> "#ff0000",
+                        Color::Custom(v: Int32) -> "custom"
+                    };
+                }
+
+                fn label() -> String {
+                    return self.to_hex();
+                }
+            }
+
+            fn main() -> Int32 {
+                let c: Color = Color::Red;
+                let hex: String = c.label();
+                return 0;
+            }
+        "##;
+
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_enum_method_call_checks_arguments() {
+        let input = r#"
+            enum Color {
+                Red,
+
+                fn mix(amount: Int32) -> Int32 {
+                    return amount;
+                }
+            }
+
+            fn main() -> Int32 {
+                let c: Color = Color::Red;
+                let n: Int32 = c.mix(amount = "lots");
+                return 0;
+            }
+        "#;
+
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Argument 1 of method 'Color::mix'"));
+    }
+
+    #[test]
+    fn test_float_to_string_precision() {
+        let input = r#"
+            fn main() -> Int32 {
+                let pi: Float64 = 3.14159;
+                let rounded: String = pi.to_string_precision(digits = 2);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let count: Int32 = 3;
+                let rounded: String = count.to_string_precision(digits = 2);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_float_math_methods_keep_receiver_type() {
+        let input = r#"
+            fn main() -> Int32 {
+                let x: Float64 = 2.5;
+                let a: Float64 = x.abs();
+                let b: Float64 = x.floor();
+                let c: Float64 = x.ceil();
+                let d: Float64 = x.round();
+                let e: Float64 = x.sqrt();
+                let y: Float32 = 4.0f32;
+                let f: Float32 = y.sqrt();
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let x: Float64 = 2.5;
+                let bad: Float64 = x.sqrt(value = 2.0);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_integer_math_methods() {
+        let input = r#"
+            fn main() -> Int32 {
+                let a: Int32 = (-5).abs();
+                let b: Int32 = 3.max(other = 7);
+                let c: Int32 = 10.clamp(low = 0, high = 5);
+                let big: Int64 = 5i64;
+                let d: Int64 = big.min(other = 2i64);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let big: Int64 = 5i64;
+                let d: Int64 = big.max(other = 2);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let c: Int32 = 10.clamp(low = 0);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_rem_euclid_on_integers() {
+        let input = r#"
+            fn main() -> Int32 {
+                let a: Int32 = (-7).rem_euclid(other = 3);
+                let big: Int64 = -7i64;
+                let b: Int64 = big.rem_euclid(other = 3i64);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let big: Int64 = -7i64;
+                let b: Int64 = big.rem_euclid(other = 3);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_index_on_dicts_and_strings() {
+        let input = r#"
+            fn main() -> Int32 {
+                let ages: Dict[String, Int32] = {"alice": 30};
+                let age: Option<Int32> = ages["alice"];
+                let word: String = "plat";
+                let first: Option<String> = word[0];
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let ages: Dict[String, Int32] = {"alice": 30};
+                let age: Option<Int32> = ages[0];
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Dict key must be"));
+    }
+
+    #[test]
+    fn test_init_field_shorthand_assigns_matching_params() {
+        let input = r#"
+            class Point {
+                let x: Int32;
+                let y: Int32;
+                let z: Int32;
+
+                init(x: Int32, y: Int32, z: Int32) -> Point {
+                    self.z = z * 2;
+                    return self;
+                }
+            }
+
+            fn main() -> Int32 {
+                let p: Point = Point.init(x = 1, y = 2, z = 3);
+                return 0;
+            }
+        "#;
+        let parser = Parser::new(input).unwrap();
+        let mut program = parser.parse().unwrap();
+        TypeChecker::new().check_program(&mut program).unwrap();
+
+        // `z` is assigned explicitly, so only `x` and `y` are filled in
+        let init = &program.classes[0].methods[0];
+        let assigned: Vec<&str> = init.body.statements.iter()
+            .filter_map(|stmt| match stmt {
+                plat_ast::Statement::Expression(plat_ast::Expression::Assignment { target, value, .. }) => {
+                    match (target.as_ref(), value.as_ref()) {
+                        (
+                            plat_ast::Expression::MemberAccess { member, .. },
+                            plat_ast::Expression::Identifier { name, .. },
+                        ) if member == name => Some(member.as_str()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(assigned, vec!["x", "y"]);
+        assert_eq!(init.body.statements.len(), 4);
+    }
+
+    #[test]
+    fn test_drop_builtin() {
+        let input = r#"
+            fn main() -> Int32 {
+                let values: List[Int32] = [1, 2, 3];
+                drop(value = values);
+                drop(value = 5);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let values: List[Int32] = [1, 2, 3];
+                drop(item = values);
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("drop requires a 'value' parameter"));
+    }
+
+    #[test]
+    fn test_atomic_builtins() {
+        let input = r#"
+            fn main() -> Int32 {
+                let counter: AtomicInt = atomic_init(value = 0i64);
+                let previous: Int64 = atomic_add(ptr = counter, delta = 2i64);
+                atomic_store(ptr = counter, val = previous);
+                let swapped: Bool = compare_and_swap(ptr = counter, expected = 0i64, new = 1i64);
+                let current: Int64 = atomic_load(ptr = counter);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let counter: AtomicInt = atomic_init(value = 0i64);
+                atomic_add(ptr = counter, delta = 1);
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("atomic_add 'delta' parameter must be Int64"));
+    }
+
+    #[test]
+    fn test_mutex_with_lock_returns_action_type() {
+        let input = r#"
+            fn next_id() -> Int64 {
+                return 42i64;
+            }
+
+            fn main() -> Int32 {
+                let guard: Mutex = mutex_init();
+                guard.lock();
+                guard.unlock();
+                let id: Int64 = guard.with_lock(action = next_id);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn add(x: Int32) -> Int32 {
+                return x + 1;
+            }
+
+            fn main() -> Int32 {
+                let guard: Mutex = mutex_init();
+                guard.with_lock(action = add);
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("must take no parameters"));
+    }
+
+    #[test]
+    fn test_select_binds_channel_element_type() {
+        let input = r#"
+            fn main() -> Int32 {
+                let numbers: Channel<Int32> = channel_init(capacity = 1);
+                select {
+                    recv n from numbers -> {
+                        let doubled: Int32 = n * 2;
+                    }
+                    default -> {
+                        print(value = "idle");
+                    }
+                }
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let numbers: List[Int32] = [1, 2];
+                select {
+                    recv n from numbers -> {
+                        print(value = "got");
+                    }
+                }
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("select can only receive from a Channel"));
+    }
+
+    #[test]
+    fn test_task_cancel_and_try_await() {
+        let input = r#"
+            fn main() -> Int32 {
+                concurrent {
+                    let task: Task<Int32> = spawn {
+                        return 1;
+                    };
+                    task.cancel();
+                    let result: Option<Int32> = task.try_await();
+                }
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                concurrent {
+                    let task: Task<Int32> = spawn {
+                        return 1;
+                    };
+                    let result: Int32 = task.try_await();
+                }
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_udp_builtins() {
+        let input = r#"
+            fn main() -> Int32 {
+                let bound: Result<Int32, String> = udp_bind(host = "127.0.0.1", port = 0);
+                let sent: Result<Int32, String> = udp_send_to(socket = 1000, data = "hi", host = "127.0.0.1", port = 9000);
+                let received: Result<String, String> = udp_recv_from(socket = 1000, max_bytes = 64);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let sent: Result<Int32, String> = udp_send_to(socket = 1000, data = "hi", host = "127.0.0.1", port = "9000");
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("udp_send_to 'port' parameter must be Int32"));
+    }
+
+    #[test]
+    fn test_whole_file_builtins() {
+        let input = r#"
+            fn main() -> Int32 {
+                let written: Result<Int32, String> = file_write(path = "out.txt", contents = "hello");
+                let appended: Result<Int32, String> = file_append(path = "out.txt", contents = " world");
+                let text: Result<String, String> = file_read(path = "out.txt");
+                let chunk: Result<String, String> = file_read(fd = 3, max_bytes = 64);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let appended: Result<Int32, String> = file_append(path = "out.txt", contents = 42);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_env_builtin_returns_option_string() {
+        let input = r#"
+            fn main() -> Int32 {
+                let home: Option<String> = env(name = "HOME");
+                let path: Option<String> = env_get(name = "PATH");
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let home: Option<String> = env(name = 42);
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("env 'name' parameter must be String"));
+    }
+
+    #[test]
+    fn test_args_builtin_returns_string_list() {
+        let input = r#"
+            fn main() -> Int32 {
+                let argv: List[String] = args();
+                for (arg: String in args()) {
+                    print(value = arg);
+                }
+                return argv.len();
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let argv: List[String] = args(index = 1);
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("args requires no arguments"));
+    }
+
+    #[test]
+    fn test_random_builtins_accept_int32_and_int64() {
+        let input = r#"
+            fn main() -> Int32 {
+                seed_random(seed = 42i64);
+                let small: Int32 = random_int(min = 0, max = 10);
+                let large: Int64 = random_int(min = 0i64, max = 10i64);
+                let unit: Float64 = random_float();
+                return small;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let mixed: Int64 = random_int(min = 0, max = 10i64);
+                return 0;
+            }
+        "#;
+        let result = type_check(input);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("random_int 'max' parameter must match 'min' type"));
+    }
+
+    #[test]
+    fn test_hash_method_on_strings_and_integers() {
+        let input = r#"
+            fn main() -> Int32 {
+                let name: String = "plat";
+                let count: Int32 = 7;
+                let wide: Int64 = 7i64;
+                let h1: Int64 = name.hash();
+                let h2: Int64 = count.hash();
+                let h3: Int64 = wide.hash();
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let ratio: Float64 = 1.5;
+                let h: Int64 = ratio.hash();
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_collection_clone_preserves_type() {
+        let input = r#"
+            fn main() -> Int32 {
+                let numbers: List[Int32] = [1, 2, 3];
+                let numbers_copy: List[Int32] = numbers.clone();
+                let ages: Dict[String, Int32] = {"ann": 30};
+                let ages_copy: Dict[String, Int32] = ages.clone();
+                let tags: Set[String] = Set{"a"};
+                let tags_copy: Set[String] = tags.clone();
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let numbers: List[Int32] = [1, 2, 3];
+                let wrong: List[String] = numbers.clone();
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_bool_casts_only_to_integers() {
+        let input = r#"
+            fn main() -> Int32 {
+                let one: Int32 = cast(value = true, target = Int32);
+                let flag: Bool = cast(value = 0i64, target = Bool);
+                return one;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let ratio: Float64 = cast(value = true, target = Float64);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_let_destructuring() {
+        let input = r#"
+            enum Meters {
+                Value(Int32)
+            }
+
+            fn main() -> Int32 {
+                let pair: (Int32, String) = (7, "seven");
+                let (number: Int32, name: String) = pair;
+                let Meters::Value(meters: Int32) = Meters::Value(field0 = 3);
+                return number + meters;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        // Arity and element types must match the tuple
+        let input = r#"
+            fn main() -> Int32 {
+                let pair: (Int32, String) = (7, "seven");
+                let (number: Int32, name: String, extra: Bool) = pair;
+                return number;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let pair: (Int32, String) = (7, "seven");
+                let (number: Int32, name: Int32) = pair;
+                return number;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+
+        // Option has a second variant, so a let cannot bind its payload
+        let input = r#"
+            fn main() -> Int32 {
+                let maybe: Option<Int32> = Option::Some(field0 = 5);
+                let Option::Some(value: Int32) = maybe;
+                return value;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_discard_binding() {
+        let input = r#"
+            fn count() -> Int32 {
+                return 1;
+            }
+
+            fn main() -> Int32 {
+                let _ = count();
+                let _ = "twice";
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        // `_` never becomes a variable
+        let input = r#"
+            fn main() -> Int32 {
+                let _ = 5;
+                return _;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_enum_to_string() {
+        let input = r#"
+            enum Light {
+                Red,
+                Amber,
+                Green
+            }
+
+            fn main() -> Int32 {
+                let light: Light = Light::Green;
+                let name: String = light.to_string();
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            enum Light {
+                Red,
+                Amber,
+                Green
+            }
+
+            fn main() -> Int32 {
+                let light: Light = Light::Green;
+                let name: String = light.to_string(verbose = true);
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_print_class_value() {
+        let input = r#"
+            class Point {
+                let x: Int32;
+
+                pub fn to_string() -> String {
+                    return "point";
+                }
+            }
+
+            class Blob {
+                let size: Int32;
+            }
+
+            fn main() -> Int32 {
+                let point: Point = Point.init(x = 1);
+                let blob: Blob = Blob.init(size = 2);
+                print(value = point);
+                print(value = "${point} ${blob}");
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+    }
+
+    #[test]
+    fn test_branch_local_variable_is_not_visible_after_branch() {
+        // Every let/var needs an initializer and each block is its own scope, so a name
+        // bound on only one control-flow path is undefined (not uninitialized) after it
+        let input = r#"
+            fn main() -> Int32 {
+                let flag: Bool = true;
+                if (flag) {
+                    let x: Int32 = 5;
+                }
+                return x;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+
+        let input = r#"
+            fn main() -> Int32 {
+                var i: Int32 = 0;
+                while (i < 3) {
+                    var last: Int32 = i;
+                    i = i + 1;
+                }
+                return last;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_list_flatten() {
+        let input = r#"
+            fn main() -> Int32 {
+                let nested: List[List[Int32]] = [[1, 2], [3], [4, 5]];
+                let flat: List[Int32] = nested.flatten();
+                return flat.len();
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let numbers: List[Int32] = [1, 2, 3];
+                let flat: List[Int32] = numbers.flatten();
+                return flat.len();
+            }
+        "#;
+        let err = type_check(input).unwrap_err();
+        assert!(err.to_string().contains("list of lists"));
+    }
+
+    #[test]
+    fn test_list_zip() {
+        let input = r#"
+            fn main() -> Int32 {
+                let names: List[String] = ["a", "b"];
+                let ages: List[Int32] = [1, 2];
+                let pairs: List[(String, Int32)] = names.zip(other = ages);
+                return pairs.len();
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let names: List[String] = ["a", "b"];
+                let ages: List[Int32] = [1, 2];
+                let pairs: List[(Int32, String)] = names.zip(other = ages);
+                return pairs.len();
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_for_loop_over_enumerate_pairs() {
+        let input = r#"
+            fn main() -> Int32 {
+                let values: List[String] = ["a", "b"];
+                var count: Int32 = 0;
+                for (index: Int32, value: String in values.enumerate()) {
+                    count = count + index;
+                }
+                return count;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let values: List[String] = ["a", "b"];
+                for (index: Int32, value: Int32 in values.enumerate()) {
+                    print(value = "${index}");
+                }
+                return 0;
+            }
+        "#;
+        let err = type_check(input).unwrap_err();
+        assert!(err.to_string().contains("does not match pair value type"));
+
+        let input = r#"
+            fn main() -> Int32 {
+                let values: List[Int32] = [1, 2];
+                for (index: Int32, value: Int32 in values) {
+                    print(value = "${index}");
+                }
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_list_view() {
+        let input = r#"
+            fn main() -> Int32 {
+                let data: List[Int32] = [1, 2, 3, 4];
+                let middle: List[Int32] = data.view(start = 1, end = 3);
+                return middle.len();
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let data: List[Int32] = [1, 2, 3, 4];
+                let middle: List[Int32] = data.view(start = 1);
+                return middle.len();
+            }
+        "#;
+        assert!(type_check(input).is_err());
+    }
+
+    #[test]
+    fn test_match_arm_whole_value_binding() {
+        let input = r#"
+            fn main() -> Int32 {
+                let value: Option<Int32> = Option::Some(field0 = 4);
+                let kept: Option<Int32> = match value {
+                    full @ Option::Some(x: Int32) -> full,
+                    Option::None -> Option::None
+                };
+                return 0;
+            }
+        "#;
+        assert!(type_check(input).is_ok());
+
+        // The whole-value binding has the scrutinee's type, not the payload's
+        let input = r#"
+            fn main() -> Int32 {
+                let value: Option<Int32> = Option::Some(field0 = 4);
+                return match value {
+                    full @ Option::Some(x: Int32) -> full,
+                    Option::None -> 0
+                };
+            }
+        "#;
+        assert!(type_check(input).is_err());
+
+        let input = r#"
+            fn main() -> Int32 {
+                let value: Option<Int32> = Option::Some(field0 = 4);
+                return match value {
+                    x @ Option::Some(x: Int32) -> x,
+                    Option::None -> 0
+                };
+            }
+        "#;
+        let err = type_check(input).unwrap_err();
+        assert!(err.to_string().contains("already bound"));
+    }
+}
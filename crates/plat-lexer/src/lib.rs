@@ -60,6 +60,7 @@ impl Lexer {
                     }
                 }
                 '%' => self.add_token(Token::Percent, start),
+                '@' => self.add_token(Token::At, start),
                 '(' => self.add_token(Token::LeftParen, start),
                 ')' => self.add_token(Token::RightParen, start),
                 '{' => self.add_token(Token::LeftBrace, start),
@@ -129,8 +130,28 @@ impl Lexer {
                         self.add_token(Token::Greater, start);
                     }
                 }
-                '?' => self.add_token(Token::Question, start),
-                '"' => self.scan_string(start)?,
+                '?' => {
+                    if self.peek() == Some('.') && self.peek_next() != Some('.') {
+                        self.advance();
+                        self.add_token(Token::QuestionDot, start);
+                    } else {
+                        self.add_token(Token::Question, start);
+                    }
+                }
+                '"' => {
+                    if self.peek() == Some('"') && self.peek_next() == Some('"') {
+                        // Skip the remaining two quotes of the opening """
+                        self.advance();
+                        self.advance();
+                        self.scan_string(start, true)?
+                    } else {
+                        self.scan_string(start, false)?
+                    }
+                }
+                'r' if self.peek() == Some('"') => {
+                    self.advance();
+                    self.scan_raw_string(start)?
+                }
                 c if c.is_ascii_digit() => self.scan_number(start)?,
                 c if c.is_ascii_alphabetic() || c == '_' => self.scan_identifier(start)?,
                 c => {
@@ -193,11 +214,22 @@ impl Lexer {
         }
     }
 
-    fn scan_string(&mut self, start: usize) -> Result<(), DiagnosticError> {
+    /// Check whether the closing delimiter of a string starts at the current position
+    fn at_string_end(&self, multiline: bool) -> bool {
+        if multiline {
+            self.peek() == Some('"') && self.peek_next() == Some('"') && self.input.get(self.current + 2) == Some(&'"')
+        } else {
+            self.peek() == Some('"')
+        }
+    }
+
+    /// Scan a regular ("...") or multiline ("""...""") string literal.
+    /// Both process escape sequences and interpolation; multiline strings may also contain unescaped quotes.
+    fn scan_string(&mut self, start: usize, multiline: bool) -> Result<(), DiagnosticError> {
         let mut parts = Vec::new();
         let mut current_text = String::new();
 
-        while self.peek() != Some('"') && !self.is_at_end() {
+        while !self.at_string_end(multiline) && !self.is_at_end() {
             if self.peek() == Some('$') && self.peek_next() == Some('{') {
                 // Save any text before interpolation
                 if !current_text.is_empty() {
@@ -242,18 +274,8 @@ impl Lexer {
 
                 parts.push(StringPart::Interpolation(expr));
             } else if self.peek() == Some('\\') {
-                self.advance();
-                if let Some(escaped) = self.peek() {
-                    self.advance();
-                    current_text.push(match escaped {
-                        'n' => '\n',
-                        't' => '\t',
-                        'r' => '\r',
-                        '\\' => '\\',
-                        '"' => '"',
-                        c => c,
-                    });
-                }
+                let escaped = self.scan_escape()?;
+                current_text.push(escaped);
             } else {
                 current_text.push(self.advance());
             }
@@ -271,8 +293,12 @@ impl Lexer {
             ));
         }
 
-        // Skip closing "
+        // Skip closing " (or """)
         self.advance();
+        if multiline {
+            self.advance();
+            self.advance();
+        }
 
         // Add any remaining text
         if !current_text.is_empty() {
@@ -294,6 +320,99 @@ impl Lexer {
         Ok(())
     }
 
+    /// Decode an escape sequence starting at the current backslash.
+    /// Supports `\n`, `\t`, `\r`, `\0`, `\\`, `\"`, `\$` and `\u{XXXX}`; anything else is a syntax error.
+    fn scan_escape(&mut self) -> Result<char, DiagnosticError> {
+        let escape_start = self.current;
+        self.advance(); // Skip backslash
+
+        let escaped = match self.peek() {
+            Some(c) => {
+                self.advance();
+                c
+            }
+            None => return Err(self.escape_error(escape_start, "Unterminated escape sequence")),
+        };
+
+        match escaped {
+            'n' => Ok('\n'),
+            't' => Ok('\t'),
+            'r' => Ok('\r'),
+            '0' => Ok('\0'),
+            '\\' => Ok('\\'),
+            '"' => Ok('"'),
+            '$' => Ok('$'),
+            'u' => {
+                if self.peek() != Some('{') {
+                    return Err(self.escape_error(escape_start, "Unicode escape must be written as \\u{XXXX}"));
+                }
+                self.advance();
+
+                let mut digits = String::new();
+                while let Some(c) = self.peek() {
+                    if c == '}' {
+                        break;
+                    }
+                    digits.push(self.advance());
+                }
+
+                if self.peek() != Some('}') {
+                    return Err(self.escape_error(escape_start, "Unterminated unicode escape"));
+                }
+                self.advance();
+
+                if digits.is_empty() || digits.len() > 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(self.escape_error(escape_start, &format!("Invalid unicode escape '\\u{{{}}}'", digits)));
+                }
+
+                u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.escape_error(escape_start, &format!("'\\u{{{}}}' is not a valid unicode code point", digits)))
+            }
+            c => Err(self.escape_error(escape_start, &format!("Unknown escape sequence '\\{}'", c))),
+        }
+    }
+
+    fn escape_error(&self, escape_start: usize, message: &str) -> DiagnosticError {
+        DiagnosticError::Rich(
+            Diagnostic::syntax_error(
+                &self.filename,
+                Span::new(escape_start, self.current),
+                message
+            )
+            .with_label("invalid escape sequence")
+            .with_help("Valid escapes are \\n, \\t, \\r, \\0, \\\\, \\\", \\$ and \\u{XXXX}; use a raw string r\"...\" to avoid escapes")
+        )
+    }
+
+    /// Scan a raw string literal (r"..."): no escape sequences or interpolation are processed
+    fn scan_raw_string(&mut self, start: usize) -> Result<(), DiagnosticError> {
+        let mut text = String::new();
+
+        while self.peek() != Some('"') && !self.is_at_end() {
+            text.push(self.advance());
+        }
+
+        if self.is_at_end() {
+            return Err(DiagnosticError::Rich(
+                Diagnostic::syntax_error(
+                    &self.filename,
+                    Span::new(start, self.current),
+                    "Unterminated raw string literal"
+                )
+                .with_label("raw string started here but never closed")
+                .with_help("Add a closing \" to complete the string")
+            ));
+        }
+
+        // Skip closing "
+        self.advance();
+
+        self.add_token(Token::StringLiteral(text), start);
+        Ok(())
+    }
+
     fn scan_number(&mut self, start: usize) -> Result<(), DiagnosticError> {
         // Scan integer part
         while let Some(c) = self.peek() {
@@ -304,8 +423,11 @@ impl Lexer {
             }
         }
 
+        // A number directly after '.' is a tuple index (e.g., `pair.0.1`), never a float
+        let is_tuple_index = matches!(self.tokens.last().map(|t| &t.token), Some(Token::Dot));
+
         // Check for decimal point (float)
-        let is_float = if self.peek() == Some('.') {
+        let is_float = if self.peek() == Some('.') && !is_tuple_index {
             // Make sure it's not a range operator (..)
             if let Some(next) = self.peek_next() {
                 if next.is_ascii_digit() {
@@ -83,6 +83,35 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_raw_strings() {
+        let input = r#"r"C:\path\no\escapes" r"${not_interpolated}\n""#;
+        let tokens = tokenize(input);
+
+        assert_eq!(tokens, vec![
+            Token::StringLiteral(r"C:\path\no\escapes".to_string()),
+            Token::StringLiteral(r"${not_interpolated}\n".to_string()),
+            Token::Eof,
+        ]);
+    }
+
+    #[test]
+    fn test_multiline_strings() {
+        let input = "\"\"\"line one\nsays \"hi\"\n\\tindented\"\"\"";
+        let tokens = tokenize(input);
+
+        assert_eq!(tokens, vec![
+            Token::StringLiteral("line one\nsays \"hi\"\n\tindented".to_string()),
+            Token::Eof,
+        ]);
+    }
+
+    #[test]
+    fn test_raw_identifier_not_string() {
+        let tokens = tokenize("r + 1");
+        assert_eq!(tokens[0], Token::Ident("r".to_string()));
+    }
+
     #[test]
     fn test_string_interpolation() {
         let input = r#""Hello ${name}!" "Value: ${x + y}" "Nested ${foo${bar}}""#;
@@ -212,9 +241,46 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("Unterminated string"));
     }
 
+    #[test]
+    fn test_string_escape_sequences() {
+        let input = r#""a\nb" "a\tb" "say \"hi\"" "back\\slash" "\${literal}" "smile \u{1F600}" "\u{e9}""#;
+        let tokens = tokenize(input);
+
+        assert_eq!(tokens, vec![
+            Token::StringLiteral("a\nb".to_string()),
+            Token::StringLiteral("a\tb".to_string()),
+            Token::StringLiteral("say \"hi\"".to_string()),
+            Token::StringLiteral("back\\slash".to_string()),
+            Token::StringLiteral("${literal}".to_string()),
+            Token::StringLiteral("smile \u{1F600}".to_string()),
+            Token::StringLiteral("\u{e9}".to_string()),
+            Token::Eof,
+        ]);
+
+        // Unicode escapes decode to their UTF-8 bytes
+        if let Token::StringLiteral(s) = &tokens[5] {
+            assert_eq!(&s.as_bytes()[6..], &[0xF0, 0x9F, 0x98, 0x80]);
+        }
+    }
+
+    #[test]
+    fn test_error_unknown_escape() {
+        let result = Lexer::new(r#""bad \q escape""#).tokenize();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Unknown escape sequence"));
+    }
+
+    #[test]
+    fn test_error_invalid_unicode_escape() {
+        for input in [r#""\u{}""#, r#""\u{XYZ}""#, r#""\u{D800}""#, r#""\u1F600""#, r#""\u{1F600""#] {
+            let result = Lexer::new(input).tokenize();
+            assert!(result.is_err(), "expected error for {}", input);
+        }
+    }
+
     #[test]
     fn test_error_invalid_character() {
-        let input = "let x = @";
+        let input = "let x = ~";
         let lexer = Lexer::new(input);
         let result = lexer.tokenize();
 
@@ -311,4 +377,34 @@ mod tests {
             Token::Eof,
         ]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_tuple_index_after_dot() {
+        use crate::IntType;
+
+        let tokens = tokenize("pair.0.1");
+        assert_eq!(tokens, vec![
+            Token::Ident("pair".to_string()),
+            Token::Dot,
+            Token::IntLiteral(0, IntType::I32),
+            Token::Dot,
+            Token::IntLiteral(1, IntType::I32),
+            Token::Eof,
+        ]);
+    }
+
+    #[test]
+    fn test_optional_chaining_operator() {
+        let tokens = tokenize("user.address?.city result?");
+        assert_eq!(tokens, vec![
+            Token::Ident("user".to_string()),
+            Token::Dot,
+            Token::Ident("address".to_string()),
+            Token::QuestionDot,
+            Token::Ident("city".to_string()),
+            Token::Ident("result".to_string()),
+            Token::Question,
+            Token::Eof,
+        ]);
+    }
+}
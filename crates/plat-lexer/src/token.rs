@@ -29,12 +29,14 @@ pub enum Token {
     Use,
     Type,
     Newtype,
+    Const,
     Test,
     Bench,
     Cast,
     Pub,
     Concurrent,
     Spawn,
+    Select,
 
     // Identifiers and literals
     Ident(String),
@@ -60,6 +62,7 @@ pub enum Token {
     Greater,
     GreaterEq,
     Question,
+    QuestionDot, // ?. (optional chaining)
     DotDot,      // .. (exclusive range)
     DotDotEq,    // ..= (inclusive range)
 
@@ -76,6 +79,7 @@ pub enum Token {
     Colon,
     Dot,
     DoubleColon,
+    At, // @ (binds the whole value in a match arm)
 
     // Special
     Eof,
@@ -134,12 +138,14 @@ impl Token {
             "use" => Some(Token::Use),
             "type" => Some(Token::Type),
             "newtype" => Some(Token::Newtype),
+            "const" => Some(Token::Const),
             "test" => Some(Token::Test),
             "bench" => Some(Token::Bench),
             "cast" => Some(Token::Cast),
             "pub" => Some(Token::Pub),
             "concurrent" => Some(Token::Concurrent),
             "spawn" => Some(Token::Spawn),
+            "select" => Some(Token::Select),
             "and" => Some(Token::And),
             "or" => Some(Token::Or),
             "not" => Some(Token::Not),
@@ -6,6 +6,7 @@
 //! - Circular dependency detection
 //! - Module path resolution based on folder structure
 //! - Object file caching for stdlib modules
+//! - Content-hash object caching for incremental recompilation of user modules
 
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -25,7 +26,8 @@ pub struct ModuleId {
 #[derive(Debug, Clone)]
 pub struct ModuleDependencies {
     pub id: ModuleId,
-    /// Modules imported via `use` statements
+    /// Paths imported via `use` statements: a module path, or a qualified item path
+    /// (e.g., "math::add") for each item named in `use math::{add}`
     pub imports: Vec<String>,
 }
 
@@ -53,6 +55,16 @@ pub enum ModuleError {
         item_name: String,
         locations: Vec<PathBuf>,
     },
+    /// Imported item not defined in its module
+    ItemNotFound {
+        module_path: String,
+        item_name: String,
+    },
+    /// Imported item not declared `pub` in its module
+    PrivateItem {
+        module_path: String,
+        item_name: String,
+    },
 }
 
 impl std::fmt::Display for ModuleError {
@@ -85,6 +97,12 @@ impl std::fmt::Display for ModuleError {
                     locations.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ")
                 )
             }
+            ModuleError::ItemNotFound { module_path, item_name } => {
+                write!(f, "Module '{}' has no item named '{}'", module_path, item_name)
+            }
+            ModuleError::PrivateItem { module_path, item_name } => {
+                write!(f, "'{}' is private to module '{}' and cannot be imported", item_name, module_path)
+            }
         }
     }
 }
@@ -101,6 +119,10 @@ pub struct ModuleResolver {
     modules: HashMap<String, ModuleId>,
     /// Dependency graph
     dependencies: HashMap<String, Vec<String>>,
+    /// Top-level item names per module, with the file that defines each
+    items: HashMap<String, HashMap<String, PathBuf>>,
+    /// Top-level items each module declares `pub`
+    public_items: HashMap<String, HashSet<String>>,
 }
 
 impl ModuleResolver {
@@ -110,6 +132,8 @@ impl ModuleResolver {
             stdlib_dir,
             modules: HashMap::new(),
             dependencies: HashMap::new(),
+            items: HashMap::new(),
+            public_items: HashMap::new(),
         }
     }
 
@@ -196,6 +220,73 @@ impl ModuleResolver {
         Ok(module_id)
     }
 
+    /// Record a file's top-level items (functions, classes, enums) under its module,
+    /// rejecting any name that the module already defines
+    pub fn register_items(
+        &mut self,
+        module_path: &str,
+        file_path: &Path,
+        item_names: &[String],
+    ) -> Result<(), ModuleError> {
+        let module_items = self.items.entry(module_path.to_string()).or_default();
+
+        for name in item_names {
+            if let Some(existing) = module_items.get(name) {
+                return Err(ModuleError::DuplicateDefinition {
+                    module_path: module_path.to_string(),
+                    item_name: name.clone(),
+                    locations: vec![existing.clone(), file_path.to_path_buf()],
+                });
+            }
+            module_items.insert(name.clone(), file_path.to_path_buf());
+        }
+
+        Ok(())
+    }
+
+    /// Record which of a module's top-level items are declared `pub`
+    pub fn register_public_items(&mut self, module_path: &str, item_names: &[String]) {
+        self.public_items.entry(module_path.to_string()).or_default()
+            .extend(item_names.iter().cloned());
+    }
+
+    /// Check that every item named in a `use module::{item}` import exists in its module
+    /// and is public. Whole-module imports, and items of modules that aren't registered,
+    /// are left to later phases.
+    pub fn validate_imports(&self, dependencies: &ModuleDependencies) -> Result<(), ModuleError> {
+        for import in &dependencies.imports {
+            if self.modules.contains_key(import) {
+                continue;
+            }
+            let Some((module_path, item_name)) = import.rsplit_once("::") else {
+                continue;
+            };
+            if !self.modules.contains_key(module_path) {
+                continue;
+            }
+
+            let defined = self.items.get(module_path)
+                .is_some_and(|items| items.contains_key(item_name));
+            if !defined {
+                return Err(ModuleError::ItemNotFound {
+                    module_path: module_path.to_string(),
+                    item_name: item_name.to_string(),
+                });
+            }
+
+            let public = self.public_items.get(module_path)
+                .is_some_and(|items| items.contains(item_name));
+            if !public {
+                return Err(ModuleError::PrivateItem {
+                    module_path: module_path.to_string(),
+                    item_name: item_name.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Add dependencies for a module
     pub fn add_dependencies(&mut self, module_path: &str, imports: Vec<String>) {
         self.dependencies.insert(module_path.to_string(), imports);
@@ -339,6 +430,54 @@ impl ModuleResolver {
         self.register_stdlib_module(file_path, module_path)
     }
 
+    /// Compute a content hash for a module: its source bytes combined with the
+    /// hashes of the modules it imports, so any change below it changes its hash
+    pub fn content_hash(&self, module_path: &str) -> Result<u64, ModuleError> {
+        self.check_circular_dependencies()?;
+        let mut memo = HashMap::new();
+        self.content_hash_memo(module_path, &mut memo)
+    }
+
+    fn content_hash_memo(&self, module_path: &str, memo: &mut HashMap<String, u64>) -> Result<u64, ModuleError> {
+        if let Some(hash) = memo.get(module_path) {
+            return Ok(*hash);
+        }
+
+        let module = self.modules.get(module_path).ok_or_else(|| ModuleError::ModuleNotFound {
+            module_path: module_path.to_string(),
+            searched_paths: vec![self.root_dir.clone()],
+        })?;
+        let source = fs::read(&module.file_path).map_err(|_| ModuleError::ModuleNotFound {
+            module_path: module_path.to_string(),
+            searched_paths: vec![module.file_path.clone()],
+        })?;
+
+        let mut hash = fnv1a(FNV_OFFSET_BASIS, &source);
+
+        // Sort so the hash doesn't depend on import order
+        let mut deps = self.dependencies.get(module_path).cloned().unwrap_or_default();
+        deps.sort();
+        for dep in deps {
+            // Imports that aren't registered modules have no source to hash
+            if !self.modules.contains_key(&dep) {
+                continue;
+            }
+            let dep_hash = self.content_hash_memo(&dep, memo)?;
+            hash = fnv1a(hash, dep.as_bytes());
+            hash = fnv1a(hash, &dep_hash.to_le_bytes());
+        }
+
+        memo.insert(module_path.to_string(), hash);
+        Ok(hash)
+    }
+
+    /// Get the cached object file for a module if it was built from the module's
+    /// current content hash; `None` means the module must be recompiled
+    pub fn cached_object(&self, module_path: &str, cache: &ModuleCache) -> Result<Option<PathBuf>, ModuleError> {
+        let hash = self.content_hash(module_path)?;
+        Ok(cache.get(module_path, hash))
+    }
+
     /// Resolve a module path to its file location
     pub fn resolve_module(&mut self, module_path: &str) -> Result<&ModuleId, ModuleError> {
         // If not already registered and starts with std::, try to discover it
@@ -434,6 +573,127 @@ mod tests {
         assert!(a_pos < b_pos, "a at {}, b at {}", a_pos, b_pos);
         assert!(b_pos < c_pos, "b at {}, c at {}", b_pos, c_pos);
     }
+
+    #[test]
+    fn test_duplicate_function_across_module_files() {
+        let mut resolver = ModuleResolver::new(
+            PathBuf::from("/project"),
+            PathBuf::from("/stdlib")
+        );
+
+        let first = PathBuf::from("/project/jobs/queue.plat");
+        let second = PathBuf::from("/project/jobs/worker.plat");
+
+        resolver.register_items("jobs", &first, &["process".to_string(), "enqueue".to_string()]).unwrap();
+        let result = resolver.register_items("jobs", &second, &["process".to_string()]);
+
+        match result {
+            Err(ModuleError::DuplicateDefinition { module_path, item_name, locations }) => {
+                assert_eq!(module_path, "jobs");
+                assert_eq!(item_name, "process");
+                assert_eq!(locations, vec![first, second]);
+            }
+            other => panic!("Expected duplicate definition, got {:?}", other),
+        }
+
+        // The same name in a different module is fine
+        assert!(resolver.register_items("other", Path::new("/project/other.plat"), &["process".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn test_selective_imports_must_name_public_items() {
+        let mut resolver = ModuleResolver::new(
+            PathBuf::from("/project"),
+            PathBuf::from("/stdlib")
+        );
+
+        let math_file = PathBuf::from("/project/math.plat");
+        resolver.register_module(math_file.clone(), "math").unwrap();
+        let items = ["add".to_string(), "subtract".to_string(), "round_half".to_string()];
+        resolver.register_items("math", &math_file, &items).unwrap();
+        resolver.register_public_items("math", &items[..2]);
+
+        let app = resolver.register_module(PathBuf::from("/project/app.plat"), "app").unwrap();
+        let imports = |paths: &[&str]| ModuleDependencies {
+            id: app.clone(),
+            imports: paths.iter().map(|path| path.to_string()).collect(),
+        };
+
+        assert!(resolver.validate_imports(&imports(&["math", "math::add", "math::subtract"])).is_ok());
+
+        match resolver.validate_imports(&imports(&["math::divide"])) {
+            Err(ModuleError::ItemNotFound { module_path, item_name }) => {
+                assert_eq!(module_path, "math");
+                assert_eq!(item_name, "divide");
+            }
+            other => panic!("Expected missing item, got {:?}", other),
+        }
+
+        match resolver.validate_imports(&imports(&["math::round_half"])) {
+            Err(ModuleError::PrivateItem { module_path, item_name }) => {
+                assert_eq!(module_path, "math");
+                assert_eq!(item_name, "round_half");
+            }
+            other => panic!("Expected private item, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_editing_leaf_invalidates_importers_only() {
+        let project = tempfile::TempDir::new().unwrap();
+        let root = project.path().to_path_buf();
+        let cache = ModuleCache::new(root.join("cache"));
+        cache.init().unwrap();
+
+        // app imports util, which imports leaf; sibling imports nothing
+        let sources = [
+            ("leaf", "mod leaf;\nfn one() -> Int32 { return 1; }\n"),
+            ("util", "mod util;\nuse leaf;\n"),
+            ("app", "mod app;\nuse util;\n"),
+            ("sibling", "mod sibling;\n"),
+        ];
+        let mut resolver = ModuleResolver::new(root.clone(), PathBuf::from("/stdlib"));
+        for (name, source) in &sources {
+            let file = root.join(format!("{}.plat", name));
+            fs::write(&file, source).unwrap();
+            resolver.register_module(file, name).unwrap();
+        }
+        resolver.add_dependencies("leaf", vec![]);
+        resolver.add_dependencies("util", vec!["leaf".to_string()]);
+        resolver.add_dependencies("app", vec!["util".to_string()]);
+        resolver.add_dependencies("sibling", vec![]);
+
+        // Build everything once
+        let object = root.join("module.o");
+        fs::write(&object, b"object").unwrap();
+        for (name, _) in &sources {
+            assert!(resolver.cached_object(name, &cache).unwrap().is_none());
+            cache.put(name, &object, resolver.content_hash(name).unwrap()).unwrap();
+        }
+        for (name, _) in &sources {
+            assert!(resolver.cached_object(name, &cache).unwrap().is_some(), "{} should be cached", name);
+        }
+
+        fs::write(root.join("leaf.plat"), "mod leaf;\nfn one() -> Int32 { return 2; }\n").unwrap();
+
+        for name in ["leaf", "util", "app"] {
+            assert!(resolver.cached_object(name, &cache).unwrap().is_none(), "{} should be recompiled", name);
+        }
+        assert!(resolver.cached_object("sibling", &cache).unwrap().is_some());
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// FNV-1a, used instead of `DefaultHasher` because cached hashes must stay
+/// stable across compiler builds
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
 }
 
 /// Cache for compiled stdlib modules
@@ -523,3 +783,67 @@ impl StdlibCache {
         Ok(())
     }
 }
+
+/// Cache for compiled user modules
+/// Stores each object file next to the content hash it was built from, so a
+/// module is reused only while neither it nor anything it imports has changed
+pub struct ModuleCache {
+    cache_dir: PathBuf,
+    /// Mixed into every stored hash, e.g. to invalidate entries built by another compiler
+    salt: u64,
+}
+
+impl ModuleCache {
+    /// Create a new cache instance
+    pub fn new(cache_dir: PathBuf) -> Self {
+        Self { cache_dir, salt: 0 }
+    }
+
+    /// Only reuse entries stored with the same salt
+    pub fn with_salt(mut self, salt: u64) -> Self {
+        self.salt = salt;
+        self
+    }
+
+    fn stored_hash(&self, hash: u64) -> String {
+        format!("{:016x}", fnv1a(hash, &self.salt.to_le_bytes()))
+    }
+
+    /// Initialize the cache directory structure
+    pub fn init(&self) -> std::io::Result<()> {
+        fs::create_dir_all(&self.cache_dir)?;
+        Ok(())
+    }
+
+    /// Get the cache file stem for a module
+    fn cache_stem(&self, module_path: &str) -> PathBuf {
+        // The entry module has no declared path
+        let safe_name = if module_path.is_empty() {
+            "main".to_string()
+        } else {
+            module_path.replace("::", "-")
+        };
+        self.cache_dir.join(safe_name)
+    }
+
+    /// Get the path to a cached object file if it was built from `hash`
+    pub fn get(&self, module_path: &str, hash: u64) -> Option<PathBuf> {
+        let stem = self.cache_stem(module_path);
+        let object_path = stem.with_extension("o");
+        let stored = fs::read_to_string(stem.with_extension("hash")).ok()?;
+
+        if stored.trim() == self.stored_hash(hash) && object_path.exists() {
+            Some(object_path)
+        } else {
+            None
+        }
+    }
+
+    /// Store a compiled object file along with the content hash it was built from
+    pub fn put(&self, module_path: &str, object_file: &Path, hash: u64) -> std::io::Result<()> {
+        let stem = self.cache_stem(module_path);
+        fs::copy(object_file, stem.with_extension("o"))?;
+        fs::write(stem.with_extension("hash"), self.stored_hash(hash))?;
+        Ok(())
+    }
+}
@@ -61,6 +61,8 @@ impl Parser {
             newtypes.push(self.parse_newtype(is_public)?);
         }
 
+        let mut constants = Vec::new();
+        let mut globals = Vec::new();
         let mut test_blocks = Vec::new();
         let mut bench_blocks = Vec::new();
         let mut functions = Vec::new();
@@ -79,6 +81,21 @@ impl Parser {
                 type_aliases.push(self.parse_type_alias(is_public)?);
             } else if self.check(&Token::Newtype) {
                 newtypes.push(self.parse_newtype(is_public)?);
+            } else if self.check(&Token::Const) {
+                constants.push(self.parse_const(is_public)?);
+            } else if self.check(&Token::Var) {
+                if is_public {
+                    return Err(DiagnosticError::Rich(
+                        Diagnostic::syntax_error(
+                            &self.filename,
+                            self.previous_span(),
+                            "Module-level variables cannot be marked as public"
+                        )
+                        .with_label("'pub' keyword not allowed here")
+                        .with_help("Remove the 'pub' keyword - module-level variables are private to their module")
+                    ));
+                }
+                globals.push(self.parse_global_var()?);
             } else if self.check(&Token::Test) {
                 if is_public {
                     return Err(DiagnosticError::Rich(
@@ -110,7 +127,7 @@ impl Parser {
             }
         }
 
-        Ok(Program { module_decl, use_decls, type_aliases, newtypes, test_blocks, bench_blocks, functions, enums, classes })
+        Ok(Program { module_decl, use_decls, type_aliases, newtypes, constants, globals, test_blocks, bench_blocks, functions, enums, classes })
     }
 
     fn parse_module_decl(&mut self) -> Result<ModuleDecl, DiagnosticError> {
@@ -141,8 +158,20 @@ impl Parser {
         let mut path = Vec::new();
         path.push(self.consume_module_name("Expected module name")?);
 
-        // Parse nested module path (database::connection or std::test)
+        // Parse nested module path (database::connection or std::test),
+        // ending in an optional item list (math::{add, subtract})
+        let mut items = Vec::new();
         while self.match_token(&Token::DoubleColon) {
+            if self.match_token(&Token::LeftBrace) {
+                loop {
+                    items.push(self.consume_identifier("Expected item name in import list")?);
+                    if !self.match_token(&Token::Comma) || self.check(&Token::RightBrace) {
+                        break;
+                    }
+                }
+                self.consume(Token::RightBrace, "Expected '}' after import list")?;
+                break;
+            }
             path.push(self.consume_module_name("Expected module name after '::'")?);
         }
 
@@ -151,6 +180,7 @@ impl Parser {
 
         Ok(UseDecl {
             path,
+            items,
             span: Span::new(start, end),
         })
     }
@@ -186,17 +216,75 @@ impl Parser {
 
         let underlying_type = self.parse_type()?;
 
-        self.consume(Token::Semicolon, "Expected ';' after newtype declaration")?;
+        // Either `;` or a body of methods: `newtype UserId = Int64 { fn ... }`
+        let mut methods = Vec::new();
+        if self.match_token(&Token::LeftBrace) {
+            while !self.check(&Token::RightBrace) && !self.is_at_end() {
+                let method_is_public = self.match_token(&Token::Pub);
+                methods.push(self.parse_function(method_is_public)?);
+            }
+            self.consume(Token::RightBrace, "Expected '}' after newtype methods")?;
+        } else {
+            self.consume(Token::Semicolon, "Expected ';' after newtype declaration")?;
+        }
         let end = self.previous_span().end;
 
         Ok(NewtypeDecl {
             name,
             underlying_type,
+            methods,
+            is_public,
+            span: Span::new(start, end),
+        })
+    }
+
+    fn parse_const(&mut self, is_public: bool) -> Result<ConstDecl, DiagnosticError> {
+        let start = self.current_span().start;
+        self.consume(Token::Const, "Expected 'const'")?;
+
+        let name = self.consume_identifier("Expected constant name")?;
+
+        self.consume(Token::Colon, "Expected ':' after constant name")?;
+        let ty = self.parse_type()?;
+
+        self.consume(Token::Assign, "Expected '=' after constant type")?;
+        let value = self.parse_expression()?;
+
+        self.consume(Token::Semicolon, "Expected ';' after constant declaration")?;
+        let end = self.previous_span().end;
+
+        Ok(ConstDecl {
+            name,
+            ty,
+            value,
             is_public,
             span: Span::new(start, end),
         })
     }
 
+    fn parse_global_var(&mut self) -> Result<GlobalVar, DiagnosticError> {
+        let start = self.current_span().start;
+        self.consume(Token::Var, "Expected 'var'")?;
+
+        let name = self.consume_identifier("Expected variable name")?;
+
+        self.consume(Token::Colon, "Expected ':' after variable name")?;
+        let ty = self.parse_type()?;
+
+        self.consume(Token::Assign, "Expected '=' after variable type")?;
+        let value = self.parse_expression()?;
+
+        self.consume(Token::Semicolon, "Expected ';' after variable declaration")?;
+        let end = self.previous_span().end;
+
+        Ok(GlobalVar {
+            name,
+            ty,
+            value,
+            span: Span::new(start, end),
+        })
+    }
+
     fn parse_test_block(&mut self) -> Result<TestBlock, DiagnosticError> {
         let start = self.current_span().start;
         self.consume(Token::Test, "Expected 'test'")?;
@@ -400,6 +488,21 @@ impl Parser {
             return Ok(Type::Dict(Box::new(key_type), Box::new(value_type)));
         }
 
+        if self.match_token(&Token::LeftParen) {
+            // Tuple type: (T1, T2, ...)
+            let mut element_types = vec![self.parse_type()?];
+            while self.match_token(&Token::Comma) {
+                element_types.push(self.parse_type()?);
+            }
+            self.consume(Token::RightParen, "Expected ')' after tuple element types")?;
+            if element_types.len() < 2 {
+                return Err(DiagnosticError::Syntax(
+                    "Tuple types must have at least two elements".to_string()
+                ));
+            }
+            return Ok(Type::Tuple(element_types));
+        }
+
         if self.match_token(&Token::Set) {
             // Accept both Set[T] and Set<T> syntax
             let use_angle_brackets = self.match_token(&Token::Less);
@@ -493,6 +596,8 @@ impl Parser {
             self.parse_print_statement()
         } else if self.match_token(&Token::Concurrent) {
             self.parse_concurrent_statement()
+        } else if self.match_token(&Token::Select) {
+            self.parse_select_statement()
         } else {
             let expr = self.parse_expression()?;
             self.consume(Token::Semicolon, "Expected ';' after expression")?;
@@ -502,6 +607,15 @@ impl Parser {
 
     fn parse_let_statement(&mut self) -> Result<Statement, DiagnosticError> {
         let start = self.previous_span().start;
+
+        // `let (a: T, b: U) = ...` and `let Enum::Variant(x: T) = ...` destructure the value,
+        // and `let _ = ...` discards it
+        let is_discard = matches!(&self.peek().token, Token::Ident(name) if name == "_")
+            && matches!(self.peek_next(), Some(Token::Assign));
+        if is_discard || self.check(&Token::LeftParen) || matches!(self.peek_next(), Some(Token::DoubleColon) | Some(Token::LeftParen)) {
+            return self.parse_let_pattern_statement(start);
+        }
+
         let name = self.consume_identifier("Expected variable name")?;
 
         self.consume(Token::Colon, "Expected ':' after variable name (type annotation required)")?;
@@ -520,6 +634,31 @@ impl Parser {
         })
     }
 
+    fn parse_let_pattern_statement(&mut self, start: usize) -> Result<Statement, DiagnosticError> {
+        let pattern = if self.match_token(&Token::LeftParen) {
+            let pattern_start = self.previous_span().start;
+            let bindings = self.parse_pattern_bindings()?;
+            self.consume(Token::RightParen, "Expected ')' after tuple bindings")?;
+            Pattern::Tuple {
+                bindings,
+                span: Span::new(pattern_start, self.previous_span().end),
+            }
+        } else {
+            self.parse_pattern()?
+        };
+
+        self.consume(Token::Assign, "Expected '=' in let statement")?;
+        let value = self.parse_expression()?;
+        self.consume(Token::Semicolon, "Expected ';' after let statement")?;
+        let end = self.previous_span().end;
+
+        Ok(Statement::LetPattern {
+            pattern,
+            value,
+            span: Span::new(start, end),
+        })
+    }
+
     fn parse_var_statement(&mut self) -> Result<Statement, DiagnosticError> {
         let start = self.previous_span().start;
         let name = self.consume_identifier("Expected variable name")?;
@@ -627,6 +766,14 @@ impl Parser {
         let variable = self.consume_identifier("Expected variable name in for loop")?;
         self.consume(Token::Colon, "Expected ':' after for loop variable (type annotation required)")?;
         let variable_type = self.parse_type()?;
+        let value_binding = if self.match_token(&Token::Comma) {
+            let value_name = self.consume_identifier("Expected value variable name after ',' in for loop")?;
+            self.consume(Token::Colon, "Expected ':' after for loop value variable (type annotation required)")?;
+            let value_type = self.parse_type()?;
+            Some((value_name, value_type))
+        } else {
+            None
+        };
         self.consume(Token::In, "Expected 'in' after for loop variable type")?;
         let iterable = self.parse_expression()?;
         self.consume(Token::RightParen, "Expected ')' after for loop expression")?;
@@ -637,6 +784,7 @@ impl Parser {
         Ok(Statement::For {
             variable,
             variable_type,
+            value_binding,
             iterable,
             body,
             span: Span::new(start, end),
@@ -685,6 +833,77 @@ impl Parser {
         })
     }
 
+    /// `select { recv x from ch -> { ... } default -> { ... } }`; `recv`, `from` and
+    /// `default` are contextual so they stay usable as identifiers elsewhere
+    fn parse_select_statement(&mut self) -> Result<Statement, DiagnosticError> {
+        let start = self.previous_span().start;
+        self.consume(Token::LeftBrace, "Expected '{' after 'select'")?;
+
+        let mut arms = Vec::new();
+        let mut default = None;
+
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            let arm_start = self.current_span().start;
+            let keyword = self.consume_identifier("Expected 'recv' or 'default' in select arm")?;
+            match keyword.as_str() {
+                "recv" => {
+                    let binding = self.consume_identifier("Expected binding name after 'recv'")?;
+                    if !matches!(&self.peek().token, Token::Ident(name) if name == "from") {
+                        return Err(DiagnosticError::Syntax(format!(
+                            "Expected 'from' after select binding at position {}",
+                            self.current_span().start
+                        )));
+                    }
+                    self.advance();
+                    let channel = self.parse_expression()?;
+                    self.consume(Token::Arrow, "Expected '->' after select channel")?;
+                    let body = self.parse_block()?;
+                    arms.push(SelectArm {
+                        binding,
+                        channel,
+                        span: Span::new(arm_start, body.span.end),
+                        body,
+                    });
+                }
+                "default" => {
+                    if default.is_some() {
+                        return Err(DiagnosticError::Syntax(format!(
+                            "Select has more than one 'default' arm at position {}",
+                            arm_start
+                        )));
+                    }
+                    self.consume(Token::Arrow, "Expected '->' after 'default'")?;
+                    default = Some(self.parse_block()?);
+                }
+                _ => {
+                    return Err(DiagnosticError::Syntax(format!(
+                        "Expected 'recv' or 'default' in select arm, found '{}' at position {}",
+                        keyword, arm_start
+                    )));
+                }
+            }
+
+            // Consume optional comma
+            self.match_token(&Token::Comma);
+        }
+
+        self.consume(Token::RightBrace, "Expected '}' after select arms")?;
+        let end = self.previous_span().end;
+
+        if arms.is_empty() {
+            return Err(DiagnosticError::Syntax(format!(
+                "Select needs at least one 'recv' arm at position {}",
+                start
+            )));
+        }
+
+        Ok(Statement::Select {
+            arms,
+            default,
+            span: Span::new(start, end),
+        })
+    }
+
     fn parse_expression(&mut self) -> Result<Expression, DiagnosticError> {
         self.parse_assignment()
     }
@@ -753,12 +972,15 @@ impl Parser {
                     Expression::Try { span, .. } => span.start,
                     Expression::Self_ { span, .. } => span.start,
                     Expression::MemberAccess { span, .. } => span.start,
+                    Expression::OptionalMemberAccess { span, .. } => span.start,
                     Expression::ConstructorCall { span, .. } => span.start,
                     Expression::SuperCall { span, .. } => span.start,
                     Expression::Range { span, .. } => span.start,
                     Expression::If { span, .. } => span.start,
                     Expression::Cast { span, .. } => span.start,
                     Expression::Spawn { span, .. } => span.start,
+                    Expression::Tuple { span, .. } => span.start,
+                    Expression::TupleAccess { span, .. } => span.start,
                 },
                 self.previous_span().end,
             );
@@ -846,12 +1068,21 @@ impl Parser {
         if let Some(token) = self.match_tokens(&[Token::DotDot, Token::DotDotEq]) {
             let inclusive = token == Token::DotDotEq;
             let end_expr = self.parse_term()?;
+
+            // `step` is contextual so it stays usable as an identifier elsewhere
+            let step_expr = if matches!(&self.peek().token, Token::Ident(name) if name == "step") {
+                self.advance();
+                Some(Box::new(self.parse_term()?))
+            } else {
+                None
+            };
             let span = self.get_expression_span(&start_expr, self.previous_span().end);
 
             return Ok(Expression::Range {
                 start: Box::new(start_expr),
                 end: Box::new(end_expr),
                 inclusive,
+                step: step_expr,
                 span,
             });
         }
@@ -964,6 +1195,19 @@ impl Parser {
                     span: Span::new(start, end),
                 };
             } else if self.match_token(&Token::Dot) {
+                // Tuple element access: pair.0
+                if let Token::IntLiteral(index, _) = self.peek().token.clone() {
+                    self.advance();
+                    let end = self.previous_span().end;
+                    let start = self.get_expression_span(&expr, end).start;
+                    expr = Expression::TupleAccess {
+                        tuple: Box::new(expr),
+                        index: index as usize,
+                        span: Span::new(start, end),
+                    };
+                    continue;
+                }
+
                 // Handle init keyword specially since it's a reserved token
                 let member = if self.check(&Token::Init) {
                     self.advance();
@@ -1013,6 +1257,15 @@ impl Parser {
                         span: Span::new(start, end),
                     };
                 }
+            } else if self.match_token(&Token::QuestionDot) {
+                let member = self.consume_identifier("Expected member name after '?.'")?;
+                let end = self.previous_span().end;
+                let start = self.get_expression_span(&expr, end).start;
+                expr = Expression::OptionalMemberAccess {
+                    object: Box::new(expr),
+                    member,
+                    span: Span::new(start, end),
+                };
             } else if self.match_token(&Token::Question) {
                 let end = self.previous_span().end;
                 let start = self.get_expression_span(&expr, end).start;
@@ -1085,7 +1338,7 @@ impl Parser {
 
         if let Some(Token::InterpolatedString(parts)) = self.match_if(|t| matches!(t, Token::InterpolatedString(_))) {
             let span = self.previous_span();
-            let interpolation_parts = self.parse_interpolated_string(parts)?;
+            let interpolation_parts = self.parse_interpolated_string(parts, span)?;
             return Ok(Expression::Literal(Literal::InterpolatedString(interpolation_parts, span)));
         }
 
@@ -1171,7 +1424,21 @@ impl Parser {
         }
 
         if self.match_token(&Token::LeftParen) {
+            let start = self.previous_span().start;
             let expr = self.parse_expression()?;
+            if self.check(&Token::Comma) {
+                // Tuple literal: (a, b, ...)
+                let mut elements = vec![expr];
+                while self.match_token(&Token::Comma) {
+                    elements.push(self.parse_expression()?);
+                }
+                self.consume(Token::RightParen, "Expected ')' after tuple elements")?;
+                let end = self.previous_span().end;
+                return Ok(Expression::Tuple {
+                    elements,
+                    span: Span::new(start, end),
+                });
+            }
             self.consume(Token::RightParen, "Expected ')' after expression")?;
             return Ok(expr);
         }
@@ -1195,7 +1462,8 @@ impl Parser {
             if !self.check(&Token::RightBracket) {
                 loop {
                     elements.push(self.parse_expression()?);
-                    if !self.match_token(&Token::Comma) {
+                    // A trailing comma before the closing bracket is allowed
+                    if !self.match_token(&Token::Comma) || self.check(&Token::RightBracket) {
                         break;
                     }
                 }
@@ -1210,18 +1478,27 @@ impl Parser {
         Err(DiagnosticError::Syntax("Expected expression".to_string()))
     }
 
-    fn parse_interpolated_string(&mut self, parts: Vec<StringPart>) -> Result<Vec<InterpolationPart>, DiagnosticError> {
+    fn parse_interpolated_string(&mut self, parts: Vec<StringPart>, span: Span) -> Result<Vec<InterpolationPart>, DiagnosticError> {
         let mut result = Vec::new();
+        // Track where each part sits inside the literal (after the opening quote) so
+        // interpolated expressions get file positions; escapes make this a lower bound
+        let mut offset = span.start + 1;
 
         for part in parts {
             match part {
                 StringPart::Text(text) => {
+                    offset += text.len();
                     result.push(InterpolationPart::Text(text));
                 }
                 StringPart::Interpolation(expr_str) => {
-                    // Parse the interpolation expression
-                    let mut parser = Parser::new(&expr_str)?;
+                    // Parse the interpolation expression, skipping past `${`
+                    let base = offset + 2;
+                    let mut parser = Parser::with_filename(&expr_str, self.filename.clone())?;
+                    for token in &mut parser.tokens {
+                        token.span = Span::new(token.span.start + base, token.span.end + base);
+                    }
                     let expr = parser.parse_expression()?;
+                    offset = base + expr_str.len() + 1;
                     result.push(InterpolationPart::Expression(Box::new(expr)));
                 }
             }
@@ -1250,12 +1527,15 @@ impl Parser {
             Expression::Try { span, .. } => span.start,
             Expression::Self_ { span, .. } => span.start,
             Expression::MemberAccess { span, .. } => span.start,
+            Expression::OptionalMemberAccess { span, .. } => span.start,
             Expression::ConstructorCall { span, .. } => span.start,
             Expression::SuperCall { span, .. } => span.start,
             Expression::Range { span, .. } => span.start,
             Expression::If { span, .. } => span.start,
             Expression::Cast { span, .. } => span.start,
             Expression::Spawn { span, .. } => span.start,
+            Expression::Tuple { span, .. } => span.start,
+            Expression::TupleAccess { span, .. } => span.start,
         };
         Span::new(start, end)
     }
@@ -1341,6 +1621,15 @@ impl Parser {
 
         while !self.check(&Token::RightBrace) && !self.is_at_end() {
             let arm_start = self.current_span().start;
+
+            // `name @ pattern` also binds the whole matched value
+            let binding = if matches!(&self.peek().token, Token::Ident(_)) && matches!(self.peek_next(), Some(Token::At)) {
+                let name = self.consume_identifier("Expected binding name before '@'")?;
+                self.consume(Token::At, "Expected '@' after binding name")?;
+                Some(name)
+            } else {
+                None
+            };
             let pattern = self.parse_pattern()?;
 
             self.consume(Token::Arrow, "Expected '=>' after pattern")?;
@@ -1350,6 +1639,7 @@ impl Parser {
             let arm_end = self.previous_span().end;
             arms.push(MatchArm {
                 pattern,
+                binding,
                 body,
                 span: Span::new(arm_start, arm_end),
             });
@@ -1546,15 +1836,7 @@ impl Parser {
 
                 if self.match_token(&Token::LeftParen) {
                     if !self.check(&Token::RightParen) {
-                        loop {
-                            let binding_name = self.consume_identifier("Expected binding name")?;
-                            self.consume(Token::Colon, "Expected ':' after binding name (type annotation required)")?;
-                            let binding_type = self.parse_type()?;
-                            bindings.push((binding_name, binding_type));
-                            if !self.match_token(&Token::Comma) {
-                                break;
-                            }
-                        }
+                        bindings = self.parse_pattern_bindings()?;
                     }
                     self.consume(Token::RightParen, "Expected ')' after pattern bindings")?;
                 }
@@ -1574,15 +1856,7 @@ impl Parser {
                 let mut bindings = Vec::new();
 
                 if !self.check(&Token::RightParen) {
-                    loop {
-                        let binding_name = self.consume_identifier("Expected binding name")?;
-                        self.consume(Token::Colon, "Expected ':' after binding name (type annotation required)")?;
-                        let binding_type = self.parse_type()?;
-                        bindings.push((binding_name, binding_type));
-                        if !self.match_token(&Token::Comma) {
-                            break;
-                        }
-                    }
+                    bindings = self.parse_pattern_bindings()?;
                 }
                 self.consume(Token::RightParen, "Expected ')' after pattern bindings")?;
 
@@ -1606,6 +1880,21 @@ impl Parser {
         Err(DiagnosticError::Syntax("Expected pattern".to_string()))
     }
 
+    /// Parse `name: Type, name: Type` up to (not including) the closing ')'
+    fn parse_pattern_bindings(&mut self) -> Result<Vec<(String, Type)>, DiagnosticError> {
+        let mut bindings = Vec::new();
+        loop {
+            let binding_name = self.consume_identifier("Expected binding name")?;
+            self.consume(Token::Colon, "Expected ':' after binding name (type annotation required)")?;
+            let binding_type = self.parse_type()?;
+            bindings.push((binding_name, binding_type));
+            if !self.match_token(&Token::Comma) {
+                break;
+            }
+        }
+        Ok(bindings)
+    }
+
     fn peek_next(&self) -> Option<&Token> {
         if self.current + 1 < self.tokens.len() {
             Some(&self.tokens[self.current + 1].token)
@@ -1686,37 +1975,7 @@ impl Parser {
 
     /// Extract span from any expression
     fn expr_span(&self, expr: &Expression) -> Span {
-        match expr {
-            Expression::Literal(lit) => match lit {
-                Literal::Bool(_, span) => *span,
-                Literal::Integer(_, _, span) => *span,
-                Literal::Float(_, _, span) => *span,
-                Literal::String(_, span) => *span,
-                Literal::InterpolatedString(_, span) => *span,
-                Literal::Array(_, span) => *span,
-                Literal::Dict(_, span) => *span,
-                Literal::Set(_, span) => *span,
-            },
-            Expression::Identifier { span, .. } => *span,
-            Expression::Binary { span, .. } => *span,
-            Expression::Unary { span, .. } => *span,
-            Expression::Call { span, .. } => *span,
-            Expression::Assignment { span, .. } => *span,
-            Expression::Index { span, .. } => *span,
-            Expression::MethodCall { span, .. } => *span,
-            Expression::Block(block) => block.span,
-            Expression::EnumConstructor { span, .. } => *span,
-            Expression::Match { span, .. } => *span,
-            Expression::Try { span, .. } => *span,
-            Expression::MemberAccess { span, .. } => *span,
-            Expression::If { span, .. } => *span,
-            Expression::Cast { span, .. } => *span,
-            Expression::Self_ { span, .. } => *span,
-            Expression::ConstructorCall { span, .. } => *span,
-            Expression::SuperCall { span, .. } => *span,
-            Expression::Range { span, .. } => *span,
-            Expression::Spawn { span, .. } => *span,
-        }
+        expr.span()
     }
 
     fn consume(&mut self, token: Token, message: &str) -> Result<(), DiagnosticError> {
@@ -1854,7 +2113,7 @@ impl Parser {
 
                 pairs.push((key, value));
 
-                if !self.match_token(&Token::Comma) {
+                if !self.match_token(&Token::Comma) || self.check(&Token::RightBrace) {
                     break;
                 }
             }
@@ -1875,7 +2134,7 @@ impl Parser {
         if !self.check(&Token::RightBrace) {
             loop {
                 elements.push(self.parse_expression()?);
-                if !self.match_token(&Token::Comma) {
+                if !self.match_token(&Token::Comma) || self.check(&Token::RightBrace) {
                     break;
                 }
             }
@@ -1915,6 +2174,7 @@ impl Parser {
         self.consume(Token::LeftBrace, "Expected '{' after class declaration")?;
 
         let mut fields = Vec::new();
+        let mut constants = Vec::new();
         let mut methods = Vec::new();
 
         while !self.check(&Token::RightBrace) && !self.is_at_end() {
@@ -1926,6 +2186,8 @@ impl Parser {
                 || self.check(&Token::Virtual) || self.check(&Token::Override)
                 || (self.check(&Token::Mut) && self.peek_next() == Some(&Token::Fn)) {
                 methods.push(self.parse_function(member_is_public)?);
+            } else if self.check(&Token::Const) {
+                constants.push(self.parse_const(member_is_public)?);
             } else if self.check(&Token::Let) || self.check(&Token::Var) {
                 // It's a field declaration
                 let field_start = self.current_span().start;
@@ -1949,7 +2211,7 @@ impl Parser {
                 });
             } else {
                 return Err(DiagnosticError::Syntax(
-                    "Expected field declaration ('let'/'var') or method declaration ('fn') in class body".to_string()
+                    "Expected field declaration ('let'/'var'), constant ('const') or method declaration ('fn') in class body".to_string()
                 ));
             }
         }
@@ -1962,6 +2224,7 @@ impl Parser {
             type_params,
             parent_class,
             fields,
+            constants,
             methods,
             is_public,
             span: Span::new(start, end),
@@ -1985,7 +2248,7 @@ impl Parser {
                     span: Span::new(start, end),
                 });
 
-                if !self.match_token(&Token::Comma) {
+                if !self.match_token(&Token::Comma) || self.check(&Token::RightParen) {
                     break;
                 }
             }
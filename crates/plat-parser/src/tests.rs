@@ -163,6 +163,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_for_loop_with_key_and_value() {
+        let input = r#"
+            fn main() {
+                for (name: String, score: Int32 in scores) {
+                    print(value = name);
+                }
+            }
+        "#;
+
+        let parser = Parser::new(input).unwrap();
+        let program = parser.parse().unwrap();
+
+        match &program.functions[0].body.statements[0] {
+            Statement::For { variable, variable_type, value_binding, .. } => {
+                assert_eq!(variable, "name");
+                assert_eq!(variable_type, &Type::String);
+                assert_eq!(value_binding, &Some(("score".to_string(), Type::Int32)));
+            }
+            _ => panic!("Expected for statement"),
+        }
+    }
+
     #[test]
     fn test_parse_nested_control_flow() {
         let input = r#"
@@ -582,4 +605,324 @@ mod tests {
         assert_eq!(program.functions.len(), 1);
         assert_eq!(program.functions[0].name, "main");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_for_range_with_step() {
+        let input = r#"
+            fn main() -> Int32 {
+                let step: Int32 = 2;
+                for (i: Int32 in 0..100 step step) {
+                    let x: Int32 = i;
+                }
+                return 0;
+            }
+        "#;
+
+        let parser = Parser::new(input).unwrap();
+        let program = parser.parse().unwrap();
+
+        match &program.functions[0].body.statements[1] {
+            Statement::For { iterable: Expression::Range { step: Some(step), .. }, .. } => {
+                assert!(matches!(step.as_ref(), Expression::Identifier { name, .. } if name == "step"));
+            }
+            other => panic!("Expected a stepped range loop, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_interpolated_expressions_have_source_spans() {
+        let input = r#"fn main() -> Int32 { let s: String = "a ${first} b ${second}"; return 0; }"#;
+
+        let parser = Parser::new(input).unwrap();
+        let program = parser.parse().unwrap();
+
+        let Statement::Let { value: Expression::Literal(Literal::InterpolatedString(parts, _)), .. } = &program.functions[0].body.statements[0] else {
+            panic!("Expected an interpolated string, got {:?}", program.functions[0].body.statements[0]);
+        };
+        let spans: Vec<(String, plat_lexer::Span)> = parts.iter()
+            .filter_map(|part| match part {
+                InterpolationPart::Expression(expr) => match expr.as_ref() {
+                    Expression::Identifier { name, span } => Some((name.clone(), *span)),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(spans.len(), 2);
+        for (name, span) in spans {
+            assert_eq!(&input[span.start..span.end], name);
+        }
+    }
+
+    #[test]
+    fn test_parse_const_declaration() {
+        let input = r#"
+            const MAX: Int32 = 100;
+            pub const DOUBLE_MAX: Int32 = MAX * 2;
+
+            fn main() -> Int32 {
+                return MAX;
+            }
+        "#;
+
+        let parser = Parser::new(input).unwrap();
+        let program = parser.parse().unwrap();
+
+        assert_eq!(program.constants.len(), 2);
+        assert_eq!(program.constants[0].name, "MAX");
+        assert_eq!(program.constants[0].ty, Type::Int32);
+        assert!(!program.constants[0].is_public);
+        assert_eq!(program.constants[1].name, "DOUBLE_MAX");
+        assert!(program.constants[1].is_public);
+        assert!(matches!(program.constants[1].value, Expression::Binary { op: BinaryOp::Multiply, .. }));
+        assert_eq!(program.functions.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_global_var() {
+        let input = r#"
+            var counter: Int32 = 0;
+
+            fn main() -> Int32 {
+                counter = counter + 1;
+                return counter;
+            }
+        "#;
+
+        let parser = Parser::new(input).unwrap();
+        let program = parser.parse().unwrap();
+
+        assert_eq!(program.globals.len(), 1);
+        assert_eq!(program.globals[0].name, "counter");
+        assert_eq!(program.globals[0].ty, Type::Int32);
+        assert_eq!(program.functions.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_public_global_var_rejected() {
+        let input = r#"
+            pub var counter: Int32 = 0;
+
+            fn main() -> Int32 {
+                return 0;
+            }
+        "#;
+
+        let parser = Parser::new(input).unwrap();
+        let result = parser.parse();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("cannot be marked as public"));
+    }
+
+    #[test]
+    fn test_parse_tuple_type_literal_and_access() {
+        let input = r#"
+            fn main() -> Int32 {
+                let pair: (Int32, String) = (1, "one");
+                let nested: ((Int32, Int32), Bool) = ((1, 2), true);
+                let grouped: Int32 = (1 + 2);
+                return nested.0.1;
+            }
+        "#;
+
+        let parser = Parser::new(input).unwrap();
+        let program = parser.parse().unwrap();
+        let statements = &program.functions[0].body.statements;
+
+        match &statements[0] {
+            Statement::Let { ty, value, .. } => {
+                assert_eq!(ty, &Type::Tuple(vec![Type::Int32, Type::String]));
+                assert!(matches!(value, Expression::Tuple { elements, .. } if elements.len() == 2));
+            }
+            _ => panic!("Expected let statement"),
+        }
+
+        // Parenthesized single expressions stay plain expressions
+        match &statements[2] {
+            Statement::Let { value, .. } => assert!(matches!(value, Expression::Binary { .. })),
+            _ => panic!("Expected let statement"),
+        }
+
+        match &statements[3] {
+            Statement::Return { value: Some(Expression::TupleAccess { tuple, index: 1, .. }), .. } => {
+                assert!(matches!(tuple.as_ref(), Expression::TupleAccess { index: 0, .. }));
+            }
+            _ => panic!("Expected return of nested tuple access"),
+        }
+    }
+
+    #[test]
+    fn test_parse_optional_member_access() {
+        let input = r#"
+            fn main() -> Int32 {
+                let city: Option<String> = user.address?.city;
+                return 0;
+            }
+        "#;
+
+        let parser = Parser::new(input).unwrap();
+        let program = parser.parse().unwrap();
+
+        match &program.functions[0].body.statements[0] {
+            Statement::Let { value: Expression::OptionalMemberAccess { object, member, .. }, .. } => {
+                assert_eq!(member, "city");
+                assert!(matches!(object.as_ref(), Expression::MemberAccess { member, .. } if member == "address"));
+            }
+            _ => panic!("Expected optional member access"),
+        }
+    }
+
+    #[test]
+    fn test_parse_select_statement() {
+        let input = r#"
+            fn main() -> Int32 {
+                select {
+                    recv x from first -> {
+                        print(value = "first");
+                    }
+                    recv y from second -> {
+                        print(value = "second");
+                    }
+                    default -> {
+                        print(value = "idle");
+                    }
+                }
+                return 0;
+            }
+        "#;
+
+        let parser = Parser::new(input).unwrap();
+        let program = parser.parse().unwrap();
+
+        match &program.functions[0].body.statements[0] {
+            Statement::Select { arms, default, .. } => {
+                assert_eq!(arms.len(), 2);
+                assert_eq!(arms[0].binding, "x");
+                assert!(matches!(&arms[1].channel, Expression::Identifier { name, .. } if name == "second"));
+                assert_eq!(default.as_ref().map(|block| block.statements.len()), Some(1));
+            }
+            _ => panic!("Expected select statement"),
+        }
+
+        let input = r#"
+            fn main() -> Int32 {
+                select {
+                    default -> {
+                        return 0;
+                    }
+                }
+                return 0;
+            }
+        "#;
+        let parser = Parser::new(input).unwrap();
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn test_trailing_commas_parse_like_comma_less_forms() {
+        // Each comma-less form replaces the trailing comma with a space, so spans line up too
+        let with_commas = r#"
+            fn f(a: Int32, b: Int32) -> Int32 {
+                return a + b;
+            }
+
+            fn main() -> Int32 {
+                let list: List[Int32] = [1, 2, 3,];
+                let dict: Dict[String, Int32] = {"a": 1, "b": 2,};
+                let set: Set[Int32] = Set{1, 2,};
+                return f(a = 1, b = 2,);
+            }
+        "#;
+        let without_commas = r#"
+            fn f(a: Int32, b: Int32) -> Int32 {
+                return a + b;
+            }
+
+            fn main() -> Int32 {
+                let list: List[Int32] = [1, 2, 3 ];
+                let dict: Dict[String, Int32] = {"a": 1, "b": 2 };
+                let set: Set[Int32] = Set{1, 2 };
+                return f(a = 1, b = 2 );
+            }
+        "#;
+
+        let trailing = Parser::new(with_commas).unwrap().parse().unwrap();
+        let plain = Parser::new(without_commas).unwrap().parse().unwrap();
+        assert_eq!(trailing, plain);
+
+        // A lone comma is still not an element
+        assert!(Parser::new("fn main() { let list: List[Int32] = [,]; }").unwrap().parse().is_err());
+    }
+
+    #[test]
+    fn test_let_destructuring_patterns() {
+        let input = r#"
+            fn main() -> Int32 {
+                let (number: Int32, name: String) = pair;
+                let Meters::Value(meters: Int32) = distance;
+                return number;
+            }
+        "#;
+        let program = Parser::new(input).unwrap().parse().unwrap();
+        let statements = &program.functions[0].body.statements;
+
+        match &statements[0] {
+            Statement::LetPattern { pattern: Pattern::Tuple { bindings, .. }, .. } => {
+                assert_eq!(bindings, &vec![("number".to_string(), Type::Int32), ("name".to_string(), Type::String)]);
+            }
+            other => panic!("Expected tuple destructuring, got {:?}", other),
+        }
+        match &statements[1] {
+            Statement::LetPattern { pattern: Pattern::EnumVariant { enum_name, variant, bindings, .. }, .. } => {
+                assert_eq!(enum_name.as_deref(), Some("Meters"));
+                assert_eq!(variant, "Value");
+                assert_eq!(bindings.len(), 1);
+            }
+            other => panic!("Expected enum destructuring, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_match_arm_whole_value_binding() {
+        let input = r#"
+            fn main() -> Int32 {
+                let value: Option<Int32> = Option::None;
+                let kept: Option<Int32> = match value {
+                    full @ Option::Some(x: Int32) -> full,
+                    Option::None -> Option::None
+                };
+                return 0;
+            }
+        "#;
+        let program = Parser::new(input).unwrap().parse().unwrap();
+
+        match &program.functions[0].body.statements[1] {
+            Statement::Let { value: Expression::Match { arms, .. }, .. } => {
+                assert_eq!(arms[0].binding.as_deref(), Some("full"));
+                assert!(matches!(&arms[0].pattern, Pattern::EnumVariant { variant, .. } if variant == "Some"));
+                assert_eq!(arms[1].binding, None);
+            }
+            other => panic!("Expected let with a match value, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_selective_use_declaration() {
+        let input = r#"
+            use math::{add, subtract};
+            use std::json;
+
+            fn main() -> Int32 {
+                return add(a = 1, b = 2);
+            }
+        "#;
+        let program = Parser::new(input).unwrap().parse().unwrap();
+
+        assert_eq!(program.use_decls[0].path, vec!["math"]);
+        assert_eq!(program.use_decls[0].items, vec!["add", "subtract"]);
+        assert_eq!(program.use_decls[0].imported_paths(), vec!["math::add", "math::subtract"]);
+        assert!(program.use_decls[1].items.is_empty());
+        assert_eq!(program.use_decls[1].imported_paths(), vec!["std::json"]);
+    }
+}
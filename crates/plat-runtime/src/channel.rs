@@ -1,4 +1,4 @@
-use crossbeam_channel::{bounded, unbounded, Sender, Receiver};
+use crossbeam_channel::{bounded, unbounded, Sender, Receiver, Select, SelectedOperation};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use lazy_static::lazy_static;
@@ -106,3 +106,55 @@ pub fn get_channel<T: Clone + Send + 'static>(id: ChannelId) -> Option<Channel<T
 
     Some(Channel { id, sender, receiver })
 }
+
+/// Receiver side of a channel of any element type the FFI supports
+pub enum AnyReceiver {
+    I32(Receiver<i32>),
+    I64(Receiver<i64>),
+    Bool(Receiver<bool>),
+    F32(Receiver<f32>),
+    F64(Receiver<f64>),
+}
+
+impl AnyReceiver {
+    /// Add this receiver to a crossbeam `Select`, returning its operation index
+    pub fn register<'a>(&'a self, select: &mut Select<'a>) -> usize {
+        match self {
+            AnyReceiver::I32(receiver) => select.recv(receiver),
+            AnyReceiver::I64(receiver) => select.recv(receiver),
+            AnyReceiver::Bool(receiver) => select.recv(receiver),
+            AnyReceiver::F32(receiver) => select.recv(receiver),
+            AnyReceiver::F64(receiver) => select.recv(receiver),
+        }
+    }
+
+    /// Finish a selected receive; integers are widened to i64 and floats are returned
+    /// as their IEEE bits. Returns None if the channel was disconnected.
+    pub fn complete(&self, oper: SelectedOperation<'_>) -> Option<i64> {
+        match self {
+            AnyReceiver::I32(receiver) => oper.recv(receiver).ok().map(|value| value as i64),
+            AnyReceiver::I64(receiver) => oper.recv(receiver).ok(),
+            AnyReceiver::Bool(receiver) => oper.recv(receiver).ok().map(|value| value as i64),
+            AnyReceiver::F32(receiver) => oper.recv(receiver).ok().map(|value| value.to_bits() as i64),
+            AnyReceiver::F64(receiver) => oper.recv(receiver).ok().map(|value| value.to_bits() as i64),
+        }
+    }
+}
+
+/// Get a channel's receiver from the registry without knowing its element type
+pub fn get_any_receiver(id: ChannelId) -> Option<AnyReceiver> {
+    let registry = CHANNEL_REGISTRY.lock().unwrap();
+    let receiver = &registry.get(&id)?.receiver;
+
+    if let Some(receiver) = receiver.downcast_ref::<Receiver<i32>>() {
+        Some(AnyReceiver::I32(receiver.clone()))
+    } else if let Some(receiver) = receiver.downcast_ref::<Receiver<i64>>() {
+        Some(AnyReceiver::I64(receiver.clone()))
+    } else if let Some(receiver) = receiver.downcast_ref::<Receiver<bool>>() {
+        Some(AnyReceiver::Bool(receiver.clone()))
+    } else if let Some(receiver) = receiver.downcast_ref::<Receiver<f32>>() {
+        Some(AnyReceiver::F32(receiver.clone()))
+    } else {
+        receiver.downcast_ref::<Receiver<f64>>().map(|receiver| AnyReceiver::F64(receiver.clone()))
+    }
+}
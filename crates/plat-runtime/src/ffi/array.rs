@@ -1,6 +1,6 @@
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
-use super::core::plat_gc_alloc;
+use super::core::{plat_gc_alloc, plat_gc_free, plat_panic};
 
 // Array element type constants
 pub const ARRAY_TYPE_I32: u8 = 0;
@@ -19,6 +19,7 @@ pub struct RuntimeArray {
     pub(crate) capacity: usize,
     pub(crate) element_size: usize, // Size of each element in bytes
     pub(crate) element_type: u8, // Type discriminant: 0=i32, 1=i64, 2=bool, 3=string
+    pub(crate) owns_data: bool, // False for views, whose data points into another array's buffer
 }
 
 /// Create a new i8 array on the GC heap
@@ -58,6 +59,19 @@ pub extern "C" fn plat_array_create_class(elements: *const *const u8, count: usi
 }
 
 /// Generic array creation helper
+/// Copy an array into new GC storage; elements are copied bit-for-bit (strings are shared)
+#[no_mangle]
+pub extern "C" fn plat_array_clone(array_ptr: *const RuntimeArray) -> *mut RuntimeArray {
+    if array_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    unsafe {
+        let array = &*array_ptr;
+        create_typed_array(array.data, array.length, array.element_size, array.element_type)
+    }
+}
+
 fn create_typed_array(elements: *const u8, count: usize, element_size: usize, element_type: u8) -> *mut RuntimeArray {
     if elements.is_null() && count > 0 {
         return std::ptr::null_mut();
@@ -98,6 +112,7 @@ fn create_typed_array(elements: *const u8, count: usize, element_size: usize, el
             capacity: count,
             element_size,
             element_type,
+            owns_data: true,
         };
     }
 
@@ -256,11 +271,11 @@ pub extern "C" fn plat_array_length(array_ptr: *const RuntimeArray) -> i32 {
     plat_array_len(array_ptr) as i32
 }
 
-/// Safely get an element from array, returns Option<T> encoded as (found: bool, value: i64)
+/// Safely get an element from array, returns Option<T> encoded as (found: i32, value: i64)
 #[no_mangle]
-pub extern "C" fn plat_array_get_safe(array_ptr: *const RuntimeArray, index: i32) -> (bool, i64) {
+pub extern "C" fn plat_array_get_safe(array_ptr: *const RuntimeArray, index: i32) -> (i32, i64) {
     if array_ptr.is_null() || index < 0 {
-        return (false, 0);
+        return (0, 0);
     }
 
     unsafe {
@@ -268,7 +283,7 @@ pub extern "C" fn plat_array_get_safe(array_ptr: *const RuntimeArray, index: i32
         let index = index as usize;
 
         if index >= array.length || array.data.is_null() {
-            return (false, 0);
+            return (0, 0);
         }
 
         let value = match array.element_type {
@@ -292,10 +307,10 @@ pub extern "C" fn plat_array_get_safe(array_ptr: *const RuntimeArray, index: i32
                 let data_ptr = array.data as *const *const u8;
                 *data_ptr.add(index) as i64
             },
-            _ => return (false, 0),
+            _ => return (0, 0),
         };
 
-        (true, value)
+        (1, value)
     }
 }
 
@@ -370,6 +385,7 @@ pub extern "C" fn plat_array_append(array_ptr: *mut RuntimeArray, value: i64) ->
 
             array.data = new_data_ptr;
             array.capacity = new_capacity;
+            array.owns_data = true;
         }
 
         // Add the new element
@@ -494,6 +510,7 @@ pub extern "C" fn plat_array_insert_at(array_ptr: *mut RuntimeArray, index: i32,
 
             array.data = new_data_ptr;
             array.capacity = new_capacity;
+            array.owns_data = true;
         }
 
         // Shift elements right from insertion point
@@ -554,11 +571,11 @@ pub extern "C" fn plat_array_insert_at(array_ptr: *mut RuntimeArray, index: i32,
     }
 }
 
-/// Remove element at specific index, returns Option<T> encoded as (found: bool, value: i64)
+/// Remove element at specific index, returns Option<T> encoded as (found: i32, value: i64)
 #[no_mangle]
-pub extern "C" fn plat_array_remove_at(array_ptr: *mut RuntimeArray, index: i32) -> (bool, i64) {
+pub extern "C" fn plat_array_remove_at(array_ptr: *mut RuntimeArray, index: i32) -> (i32, i64) {
     if array_ptr.is_null() || index < 0 {
-        return (false, 0);
+        return (0, 0);
     }
 
     unsafe {
@@ -566,7 +583,7 @@ pub extern "C" fn plat_array_remove_at(array_ptr: *mut RuntimeArray, index: i32)
         let index = index as usize;
 
         if index >= array.length || array.data.is_null() {
-            return (false, 0);
+            return (0, 0);
         }
 
         // Get the value being removed
@@ -591,7 +608,7 @@ pub extern "C" fn plat_array_remove_at(array_ptr: *mut RuntimeArray, index: i32)
                 let data_ptr = array.data as *const *const u8;
                 *data_ptr.add(index) as i64
             },
-            _ => return (false, 0),
+            _ => return (0, 0),
         };
 
         // Shift elements left to fill the gap
@@ -618,12 +635,12 @@ pub extern "C" fn plat_array_remove_at(array_ptr: *mut RuntimeArray, index: i32)
                     let data_ptr = array.data as *mut *const u8;
                     std::ptr::copy(data_ptr.add(index + 1), data_ptr.add(index), elements_to_move);
                 },
-                _ => return (false, 0),
+                _ => return (0, 0),
             }
         }
 
         array.length -= 1;
-        (true, removed_value)
+        (1, removed_value)
     }
 }
 
@@ -641,9 +658,11 @@ pub extern "C" fn plat_array_clear(array_ptr: *mut RuntimeArray) -> bool {
     }
 }
 
-/// Check if array contains a specific value
+/// Check if array contains a specific value.
+/// `element_type` is the compiler's view of the elements, as for `plat_array_unique`:
+/// strings compare by content, everything else by value.
 #[no_mangle]
-pub extern "C" fn plat_array_contains(array_ptr: *const RuntimeArray, value: i64) -> bool {
+pub extern "C" fn plat_array_contains(array_ptr: *const RuntimeArray, value: i64, element_type: i32) -> bool {
     if array_ptr.is_null() {
         return false;
     }
@@ -654,32 +673,28 @@ pub extern "C" fn plat_array_contains(array_ptr: *const RuntimeArray, value: i64
             return false;
         }
 
+        let compare_by_content = element_type == ARRAY_TYPE_STRING as i32 && value != 0;
+        let needle = if compare_by_content {
+            Some(CStr::from_ptr(value as *const c_char))
+        } else {
+            None
+        };
+
         for i in 0..array.length {
             let element_value = match array.element_type {
-                ARRAY_TYPE_I32 => {
-                    let data_ptr = array.data as *const i32;
-                    *data_ptr.add(i) as i64
-                },
-                ARRAY_TYPE_I64 => {
-                    let data_ptr = array.data as *const i64;
-                    *data_ptr.add(i)
-                },
-                ARRAY_TYPE_BOOL => {
-                    let data_ptr = array.data as *const bool;
-                    if *data_ptr.add(i) { 1 } else { 0 }
-                },
-                ARRAY_TYPE_STRING => {
-                    let data_ptr = array.data as *const *const c_char;
-                    *data_ptr.add(i) as i64
-                },
-            ARRAY_TYPE_CLASS => {
-                let data_ptr = array.data as *const *const u8;
-                *data_ptr.add(i) as i64
-            },
-            _ => continue,
+                ARRAY_TYPE_I8 => *(array.data as *const i8).add(i) as i64,
+                ARRAY_TYPE_I32 => *(array.data as *const i32).add(i) as i64,
+                ARRAY_TYPE_I64 => *(array.data as *const i64).add(i),
+                ARRAY_TYPE_BOOL => *(array.data as *const bool).add(i) as i64,
+                ARRAY_TYPE_STRING | ARRAY_TYPE_CLASS => *(array.data as *const *const u8).add(i) as i64,
+                _ => continue,
             };
 
-            if element_value == value {
+            let is_match = match needle {
+                Some(needle) if element_value != 0 => CStr::from_ptr(element_value as *const c_char) == needle,
+                _ => element_value == value,
+            };
+            if is_match {
                 return true;
             }
         }
@@ -688,17 +703,17 @@ pub extern "C" fn plat_array_contains(array_ptr: *const RuntimeArray, value: i64
     }
 }
 
-/// Find index of first occurrence of value, returns Option<i32> encoded as (found: bool, index: i32)
+/// Find index of first occurrence of value, returns Option<i32> encoded as (found: i32, index: i64)
 #[no_mangle]
-pub extern "C" fn plat_array_index_of(array_ptr: *const RuntimeArray, value: i64) -> (bool, i32) {
+pub extern "C" fn plat_array_index_of(array_ptr: *const RuntimeArray, value: i64) -> (i32, i64) {
     if array_ptr.is_null() {
-        return (false, -1);
+        return (0, -1);
     }
 
     unsafe {
         let array = &*array_ptr;
         if array.data.is_null() {
-            return (false, -1);
+            return (0, -1);
         }
 
         for i in 0..array.length {
@@ -727,11 +742,11 @@ pub extern "C" fn plat_array_index_of(array_ptr: *const RuntimeArray, value: i64
             };
 
             if element_value == value {
-                return (true, i as i32);
+                return (1, i as i64);
             }
         }
 
-        (false, -1)
+        (0, -1)
     }
 }
 
@@ -828,6 +843,243 @@ pub extern "C" fn plat_array_slice(array_ptr: *const RuntimeArray, start: i32, e
     }
 }
 
+/// Create a view of elements start..end (exclusive) that shares the source array's storage.
+/// Only the array header is allocated: the view's data points into the source buffer, so
+/// indexing, `len`, iteration and `set` work unchanged and writes are visible through both.
+/// The view's capacity equals its length, so pushing onto it moves it to its own storage
+/// instead of overwriting the elements that follow it in the source.
+/// A range outside the array panics, naming the range and the array's length.
+#[no_mangle]
+pub extern "C" fn plat_array_view(array_ptr: *const RuntimeArray, start: i32, end: i32) -> *mut RuntimeArray {
+    if array_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    unsafe {
+        let array = &*array_ptr;
+        if start < 0 || end < start || end as usize > array.length {
+            let message = CString::new(format!(
+                "view range {}..{} out of bounds for list of length {}", start, end, array.length
+            )).unwrap();
+            plat_panic(message.as_ptr());
+        }
+        let start = start as usize;
+        let end = end as usize;
+
+        let view_ptr = plat_gc_alloc(std::mem::size_of::<RuntimeArray>()) as *mut RuntimeArray;
+        let length = end - start;
+        (*view_ptr) = RuntimeArray {
+            data: if length == 0 { std::ptr::null_mut() } else { array.data.add(start * array.element_size) },
+            length,
+            capacity: length,
+            element_size: array.element_size,
+            element_type: array.element_type,
+            owns_data: false,
+        };
+        view_ptr
+    }
+}
+
+/// Free an array for `drop`: the header always, the data buffer only when the array owns it.
+/// A view's data belongs to its source array, so dropping the view leaves the source intact.
+#[no_mangle]
+pub extern "C" fn plat_array_free(array_ptr: *mut RuntimeArray) {
+    if array_ptr.is_null() {
+        return;
+    }
+
+    unsafe {
+        let array = &*array_ptr;
+        if array.owns_data && !array.data.is_null() {
+            plat_gc_free(array.data);
+        }
+        plat_gc_free(array_ptr as *mut u8);
+    }
+}
+
+/// Create a new array with duplicates removed, keeping the first occurrence of each value.
+/// `element_type` is the compiler's view of the elements and decides how values compare:
+/// strings by content, everything else by value.
+#[no_mangle]
+pub extern "C" fn plat_array_unique(array_ptr: *const RuntimeArray, element_type: i32) -> *mut RuntimeArray {
+    if array_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    unsafe {
+        let array = &*array_ptr;
+        let element_size = array.element_size;
+        if array.data.is_null() || array.length == 0 {
+            return create_typed_array(std::ptr::null(), 0, element_size, array.element_type);
+        }
+
+        let compare_by_content = element_type == ARRAY_TYPE_STRING as i32;
+        let mut seen_values = std::collections::HashSet::new();
+        let mut seen_strings = std::collections::HashSet::new();
+        let mut kept: Vec<u8> = Vec::with_capacity(array.length * element_size);
+        let mut count = 0;
+
+        for i in 0..array.length {
+            let element_value = match array.element_type {
+                ARRAY_TYPE_I8 => *(array.data as *const i8).add(i) as i64,
+                ARRAY_TYPE_I32 => *(array.data as *const i32).add(i) as i64,
+                ARRAY_TYPE_I64 => *(array.data as *const i64).add(i),
+                ARRAY_TYPE_BOOL => *(array.data as *const bool).add(i) as i64,
+                ARRAY_TYPE_STRING | ARRAY_TYPE_CLASS => *(array.data as *const *const u8).add(i) as i64,
+                _ => continue,
+            };
+
+            let is_new = if compare_by_content && element_value != 0 {
+                let bytes = CStr::from_ptr(element_value as *const c_char).to_bytes().to_vec();
+                seen_strings.insert(bytes)
+            } else {
+                seen_values.insert(element_value)
+            };
+
+            if is_new {
+                let element_ptr = array.data.add(i * element_size);
+                kept.extend_from_slice(std::slice::from_raw_parts(element_ptr, element_size));
+                count += 1;
+            }
+        }
+
+        create_typed_array(kept.as_ptr(), count, element_size, array.element_type)
+    }
+}
+
+/// Split an array into consecutive chunks of `size` elements; the last chunk may be shorter.
+/// The result is an array of array pointers, each chunk keeping the source element type.
+#[no_mangle]
+pub extern "C" fn plat_array_chunks(array_ptr: *const RuntimeArray, size: i32) -> *mut RuntimeArray {
+    if array_ptr.is_null() || size <= 0 {
+        return plat_array_create_class(std::ptr::null(), 0);
+    }
+
+    unsafe {
+        let array = &*array_ptr;
+        if array.data.is_null() || array.length == 0 {
+            return plat_array_create_class(std::ptr::null(), 0);
+        }
+
+        let size = size as usize;
+        let mut chunks: Vec<*const u8> = Vec::with_capacity(array.length.div_ceil(size));
+
+        for start in (0..array.length).step_by(size) {
+            let chunk_length = size.min(array.length - start);
+            let chunk_data = array.data.add(start * array.element_size);
+            let chunk = create_typed_array(chunk_data, chunk_length, array.element_size, array.element_type);
+            chunks.push(chunk as *const u8);
+        }
+
+        plat_array_create_class(chunks.as_ptr(), chunks.len())
+    }
+}
+
+/// Concatenate an array of arrays into a single array of the inner element type.
+/// The element layout comes from the inner arrays; an empty outer array yields an empty array.
+#[no_mangle]
+pub extern "C" fn plat_array_flatten(array_ptr: *const RuntimeArray) -> *mut RuntimeArray {
+    if array_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    unsafe {
+        let array = &*array_ptr;
+        if array.data.is_null() || array.length == 0 {
+            return create_typed_array(std::ptr::null(), 0, std::mem::size_of::<i32>(), ARRAY_TYPE_I32);
+        }
+
+        let inner_ptrs = array.data as *const *const RuntimeArray;
+        let inners: Vec<&RuntimeArray> = (0..array.length)
+            .filter_map(|i| (*inner_ptrs.add(i)).as_ref())
+            .collect();
+
+        let Some(first) = inners.first() else {
+            return create_typed_array(std::ptr::null(), 0, std::mem::size_of::<i32>(), ARRAY_TYPE_I32);
+        };
+        let element_size = first.element_size;
+        let element_type = first.element_type;
+
+        let total_length: usize = inners.iter().map(|inner| inner.length).sum();
+        let mut flattened: Vec<u8> = Vec::with_capacity(total_length * element_size);
+        for inner in &inners {
+            if !inner.data.is_null() && inner.length > 0 {
+                flattened.extend_from_slice(std::slice::from_raw_parts(inner.data, inner.length * element_size));
+            }
+        }
+
+        create_typed_array(flattened.as_ptr(), total_length, element_size, element_type)
+    }
+}
+
+/// Size of an element once stored in a tuple; booleans widen to the compiler's 4-byte Bool
+fn tuple_slot_size(array: &RuntimeArray) -> usize {
+    if array.element_type == ARRAY_TYPE_BOOL { std::mem::size_of::<i32>() } else { array.element_size }
+}
+
+/// Build an array of `length` two-element tuple pointers using the compiler's tuple layout:
+/// the second element is aligned to its own size and the struct is padded to 8 bytes.
+/// `fill` writes tuple `i` given its (zeroed) memory and the offset of the second element.
+unsafe fn create_pair_array(length: usize, first_size: usize, second_size: usize, fill: impl Fn(*mut u8, usize, usize)) -> *mut RuntimeArray {
+    if length == 0 {
+        return plat_array_create_class(std::ptr::null(), 0);
+    }
+
+    let second_offset = first_size.div_ceil(second_size) * second_size;
+    let tuple_size = ((second_offset + second_size).div_ceil(8) * 8).max(8);
+
+    // Allocate the result up front so the tuples stay reachable while later ones are allocated
+    let placeholders: Vec<*const u8> = vec![std::ptr::null(); length];
+    let result = plat_array_create_class(placeholders.as_ptr(), length);
+    let slots = (*result).data as *mut *mut u8;
+
+    for i in 0..length {
+        let tuple = plat_gc_alloc(tuple_size);
+        std::ptr::write_bytes(tuple, 0, tuple_size);
+        fill(tuple, second_offset, i);
+        *slots.add(i) = tuple;
+    }
+
+    result
+}
+
+/// Pair up the elements of two arrays into an array of `(first, second)` tuples,
+/// stopping at the shorter array
+#[no_mangle]
+pub extern "C" fn plat_array_zip(first_ptr: *const RuntimeArray, second_ptr: *const RuntimeArray) -> *mut RuntimeArray {
+    if first_ptr.is_null() || second_ptr.is_null() {
+        return plat_array_create_class(std::ptr::null(), 0);
+    }
+
+    unsafe {
+        let first = &*first_ptr;
+        let second = &*second_ptr;
+        let length = first.length.min(second.length);
+
+        create_pair_array(length, tuple_slot_size(first), tuple_slot_size(second), |tuple, second_offset, i| {
+            std::ptr::copy_nonoverlapping(first.data.add(i * first.element_size), tuple, first.element_size);
+            std::ptr::copy_nonoverlapping(second.data.add(i * second.element_size), tuple.add(second_offset), second.element_size);
+        })
+    }
+}
+
+/// Pair each element with its position into an array of `(index: i32, element)` tuples
+#[no_mangle]
+pub extern "C" fn plat_array_enumerate(array_ptr: *const RuntimeArray) -> *mut RuntimeArray {
+    if array_ptr.is_null() {
+        return plat_array_create_class(std::ptr::null(), 0);
+    }
+
+    unsafe {
+        let array = &*array_ptr;
+
+        create_pair_array(array.length, std::mem::size_of::<i32>(), tuple_slot_size(array), |tuple, element_offset, i| {
+            *(tuple as *mut i32) = i as i32;
+            std::ptr::copy_nonoverlapping(array.data.add(i * array.element_size), tuple.add(element_offset), array.element_size);
+        })
+    }
+}
+
 /// Concatenate two arrays of the same type
 #[no_mangle]
 pub extern "C" fn plat_array_concat(array1_ptr: *const RuntimeArray, array2_ptr: *const RuntimeArray) -> *mut RuntimeArray {
@@ -882,6 +1134,7 @@ pub extern "C" fn plat_array_concat(array1_ptr: *const RuntimeArray, array2_ptr:
             capacity: total_length,
             element_size: array1.element_size,
             element_type: array1.element_type,
+            owns_data: true,
         };
 
         new_array_ptr
@@ -985,3 +1238,4 @@ pub extern "C" fn plat_array_any_truthy(array_ptr: *const RuntimeArray) -> bool
         false
     }
 }
+
@@ -132,6 +132,141 @@ pub extern "C" fn plat_f64_to_string(value: f64) -> *const c_char {
     gc_ptr as *const c_char
 }
 
+/// Format an f64 with exactly `digits` fractional digits, rounding to nearest
+/// (negative `digits` are treated as 0)
+///
+/// # Safety
+/// This function returns a raw pointer to GC memory
+#[no_mangle]
+pub extern "C" fn plat_float_to_string_precision(value: f64, digits: i32) -> *const c_char {
+    let string_repr = format!("{:.*}", digits.max(0) as usize, value);
+    let mut bytes = string_repr.into_bytes();
+    bytes.push(0); // null terminator
+
+    let size = bytes.len();
+    let gc_ptr = plat_gc_alloc_atomic(size);
+
+    if gc_ptr.is_null() {
+        return std::ptr::null();
+    }
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), gc_ptr, size);
+    }
+
+    gc_ptr as *const c_char
+}
+
+/// Describe a class instance that has no `to_string` as `TypeName@0x<address>`
+///
+/// # Safety
+/// `type_name` must be a valid null-terminated string; returns a raw pointer to GC memory
+#[no_mangle]
+pub extern "C" fn plat_object_describe(type_name: *const c_char, object: *const u8) -> *const c_char {
+    let name = if type_name.is_null() {
+        "<object>".into()
+    } else {
+        unsafe { CStr::from_ptr(type_name).to_string_lossy() }
+    };
+    let string_repr = format!("{}@{:p}", name, object);
+    let mut bytes = string_repr.into_bytes();
+    bytes.push(0); // null terminator
+
+    let size = bytes.len();
+    let gc_ptr = plat_gc_alloc_atomic(size);
+
+    if gc_ptr.is_null() {
+        return std::ptr::null();
+    }
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), gc_ptr, size);
+    }
+
+    gc_ptr as *const c_char
+}
+
+/// Widen an IEEE 754 half-precision value (passed as its raw bits) to f32
+///
+/// `f16` values live in memory as 16-bit patterns; arithmetic on them is done
+/// in f32, so loads go through this conversion. The conversion is exact.
+#[no_mangle]
+pub extern "C" fn plat_f16_to_f32(bits: u32) -> f32 {
+    let bits = bits as u16;
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exponent = ((bits >> 10) & 0x1f) as u32;
+    let mantissa = (bits & 0x03ff) as u32;
+
+    let result = match (exponent, mantissa) {
+        (0, 0) => sign,
+        (0, _) => {
+            // Subnormal half: normalize into an f32 exponent
+            let mut e: u32 = 127 - 15 + 1;
+            let mut m = mantissa;
+            while m & 0x0400 == 0 {
+                m <<= 1;
+                e -= 1;
+            }
+            sign | (e << 23) | ((m & 0x03ff) << 13)
+        }
+        (0x1f, 0) => sign | 0x7f80_0000,
+        (0x1f, _) => sign | 0x7fc0_0000 | (mantissa << 13),
+        _ => sign | ((exponent + 127 - 15) << 23) | (mantissa << 13),
+    };
+
+    f32::from_bits(result)
+}
+
+/// Narrow an f32 to IEEE 754 half precision, returning the raw 16-bit pattern
+///
+/// Rounds to nearest, ties to even. Values too large for `f16` become
+/// infinity and values too small flush through subnormals to zero.
+#[no_mangle]
+pub extern "C" fn plat_f32_to_f16(value: f32) -> u32 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x007f_ffff;
+
+    if exponent == 0xff {
+        // Infinity or NaN (keep NaNs quiet)
+        let payload = if mantissa != 0 { 0x0200 | (mantissa >> 13) as u16 } else { 0 };
+        return (sign | 0x7c00 | payload) as u32;
+    }
+
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1f {
+        return (sign | 0x7c00) as u32;
+    }
+
+    if half_exponent <= 0 {
+        if half_exponent < -10 {
+            return sign as u32;
+        }
+        // Subnormal half: shift in the implicit leading bit and round
+        let full = mantissa | 0x0080_0000;
+        let shift = (14 - half_exponent) as u32;
+        let half_mantissa = full >> shift;
+        let remainder = full & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        let rounded = if remainder > halfway || (remainder == halfway && half_mantissa & 1 == 1) {
+            half_mantissa + 1
+        } else {
+            half_mantissa
+        };
+        return (sign | rounded as u16) as u32;
+    }
+
+    let half_mantissa = mantissa >> 13;
+    let remainder = mantissa & 0x1fff;
+    let mut result = ((half_exponent as u32) << 10) | half_mantissa;
+    if remainder > 0x1000 || (remainder == 0x1000 && half_mantissa & 1 == 1) {
+        // Carry may roll into the exponent, which correctly rounds up to infinity
+        result += 1;
+    }
+    (sign as u32) | result
+}
+
 /// Perform string interpolation by replacing ${N} placeholders with values
 ///
 /// # Safety
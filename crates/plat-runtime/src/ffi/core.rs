@@ -1,11 +1,14 @@
 use std::ffi::CStr;
 use std::os::raw::c_char;
 use std::sync::Once;
-use std::sync::atomic::{AtomicBool, Ordering};
-use super::gc_bindings::{gc_alloc, init_gc, gc_collect, gc_stats};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use super::gc_bindings::{gc_alloc, gc_free, init_gc, gc_collect, gc_stats};
 
 static GC_INIT: Once = Once::new();
 
+// Total bytes released through `plat_gc_free`
+static GC_FREED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
 // Global flag to track if the current test has failed
 static TEST_FAILED: AtomicBool = AtomicBool::new(false);
 
@@ -32,6 +35,29 @@ pub extern "C" fn plat_print(str_ptr: *const c_char) {
     }
 }
 
+/// C-compatible print to stderr, used for errors returned from `main`
+///
+/// # Safety
+/// This function is unsafe because it deals with raw pointers from generated code
+#[no_mangle]
+pub extern "C" fn plat_eprint(str_ptr: *const c_char) {
+    if str_ptr.is_null() {
+        eprintln!("<null>");
+        return;
+    }
+
+    unsafe {
+        match CStr::from_ptr(str_ptr).to_str() {
+            Ok(s) => {
+                eprintln!("{}", s);
+            }
+            Err(_) => {
+                eprintln!("<invalid UTF-8>");
+            }
+        }
+    }
+}
+
 /// C-compatible assert function for testing
 ///
 /// # Arguments
@@ -72,6 +98,21 @@ pub extern "C" fn plat_assert(condition: bool, message_ptr: *const c_char) {
 /// This function is unsafe because it dereferences raw pointers
 #[no_mangle]
 pub extern "C" fn plat_assert_test(condition: bool, message_ptr: *const c_char) -> bool {
+    plat_assert_test_loc(condition, message_ptr, 0, 0)
+}
+
+/// Test-mode assert that also reports the source location of a failing assertion
+///
+/// # Arguments
+/// * `condition` - Boolean condition to check
+/// * `message_ptr` - Pointer to optional error message (can be null)
+/// * `line` - 1-based source line of the assert call, or 0 if unknown
+/// * `column` - 1-based source column of the assert call, or 0 if unknown
+///
+/// # Returns
+/// * `true` if the assertion passed, `false` if it failed
+#[no_mangle]
+pub extern "C" fn plat_assert_test_loc(condition: bool, message_ptr: *const c_char, line: i32, column: i32) -> bool {
     if !condition {
         let message = if message_ptr.is_null() {
             "Assertion failed".to_string()
@@ -84,7 +125,11 @@ pub extern "C" fn plat_assert_test(condition: bool, message_ptr: *const c_char)
             }
         };
 
-        eprintln!("  ✗ {}", message);
+        if line > 0 {
+            eprintln!("  ✗ {} (line {}, column {})", message, line, column);
+        } else {
+            eprintln!("  ✗ {}", message);
+        }
         TEST_FAILED.store(true, Ordering::Relaxed);
         false
     } else {
@@ -92,6 +137,22 @@ pub extern "C" fn plat_assert_test(condition: bool, message_ptr: *const c_char)
     }
 }
 
+/// Print a panic message to stderr and abort the process
+///
+/// # Arguments
+/// * `message_ptr` - Pointer to the panic message (can be null)
+#[no_mangle]
+pub extern "C" fn plat_panic(message_ptr: *const c_char) -> ! {
+    let message = if message_ptr.is_null() {
+        "explicit panic".to_string()
+    } else {
+        unsafe { CStr::from_ptr(message_ptr) }.to_string_lossy().into_owned()
+    };
+
+    eprintln!("panic: {}", message);
+    std::process::abort();
+}
+
 /// Reset the test failure flag before running a new test
 #[no_mangle]
 pub extern "C" fn plat_test_reset() {
@@ -168,6 +229,22 @@ pub extern "C" fn plat_gc_alloc_atomic(size: usize) -> *mut u8 {
     ptr
 }
 
+/// Explicitly free a GC allocation; backs the `drop(value = ...)` builtin
+///
+/// # Safety
+/// Misuse is undefined behavior: `ptr` must be null or the start of a GC allocation, and
+/// nothing may read it afterwards (including other references to the same value)
+#[no_mangle]
+pub extern "C" fn plat_gc_free(ptr: *mut u8) {
+    let freed = unsafe { gc_free(ptr) };
+    GC_FREED_BYTES.fetch_add(freed, Ordering::Relaxed);
+}
+
+/// Total bytes released through `plat_gc_free` so far
+pub(crate) fn gc_freed_bytes() -> usize {
+    GC_FREED_BYTES.load(Ordering::Relaxed)
+}
+
 /// C-compatible GC collection function that can be called from generated code
 #[no_mangle]
 pub extern "C" fn plat_gc_collect() {
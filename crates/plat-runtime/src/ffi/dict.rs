@@ -91,6 +91,19 @@ pub extern "C" fn plat_dict_create(
     dict_ptr
 }
 
+/// Copy a dict into new GC storage; keys and values are shared, the tables are not
+#[no_mangle]
+pub extern "C" fn plat_dict_clone(dict_ptr: *const RuntimeDict) -> *mut RuntimeDict {
+    if dict_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    unsafe {
+        let dict = &*dict_ptr;
+        plat_dict_create(dict.keys, dict.values, dict.value_types, dict.length)
+    }
+}
+
 /// Get a value from the dict by key
 #[no_mangle]
 pub extern "C" fn plat_dict_get(dict_ptr: *const RuntimeDict, key: *const c_char) -> i64 {
@@ -125,6 +138,39 @@ pub extern "C" fn plat_dict_get(dict_ptr: *const RuntimeDict, key: *const c_char
     }
 }
 
+/// Safely get a value from dict by key, returns Option<V> encoded as (found: i32, value: i64)
+#[no_mangle]
+pub extern "C" fn plat_dict_get_safe(dict_ptr: *const RuntimeDict, key: *const c_char) -> (i32, i64) {
+    if dict_ptr.is_null() || key.is_null() {
+        return (0, 0);
+    }
+
+    unsafe {
+        let dict = &*dict_ptr;
+        if dict.keys.is_null() || dict.values.is_null() {
+            return (0, 0);
+        }
+
+        let key_str = match CStr::from_ptr(key).to_str() {
+            Ok(s) => s,
+            Err(_) => return (0, 0),
+        };
+
+        for i in 0..dict.length {
+            let dict_key_ptr = *dict.keys.add(i);
+            if !dict_key_ptr.is_null() {
+                if let Ok(dict_key_str) = CStr::from_ptr(dict_key_ptr).to_str() {
+                    if dict_key_str == key_str {
+                        return (1, *dict.values.add(i));
+                    }
+                }
+            }
+        }
+
+        (0, 0) // Not found
+    }
+}
+
 /// Get the length of a dict
 #[no_mangle]
 pub extern "C" fn plat_dict_len(dict_ptr: *const RuntimeDict) -> i32 {
@@ -392,6 +438,7 @@ pub extern "C" fn plat_dict_keys(dict_ptr: *const RuntimeDict) -> *mut RuntimeAr
             capacity: dict.length,
             element_size: std::mem::size_of::<*const c_char>(),
             element_type: ARRAY_TYPE_STRING,
+            owns_data: true,
         };
 
         array_ptr
@@ -438,6 +485,7 @@ pub extern "C" fn plat_dict_values(dict_ptr: *const RuntimeDict) -> *mut Runtime
             capacity: dict.length,
             element_size: std::mem::size_of::<i64>(),
             element_type: ARRAY_TYPE_I32, // Will contain mixed types
+            owns_data: true,
         };
 
         array_ptr
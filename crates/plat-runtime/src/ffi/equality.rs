@@ -0,0 +1,135 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use super::array::{RuntimeArray, ARRAY_TYPE_STRING};
+use super::dict::{RuntimeDict, DICT_VALUE_TYPE_I64, DICT_VALUE_TYPE_STRING};
+use super::set::{RuntimeSet, SET_VALUE_TYPE_I64, SET_VALUE_TYPE_STRING};
+
+/// Type tags passed by codegen to `plat_deep_eq`
+pub const EQ_TYPE_STRING: i32 = 0;
+pub const EQ_TYPE_ARRAY: i32 = 1;
+pub const EQ_TYPE_DICT: i32 = 2;
+pub const EQ_TYPE_SET: i32 = 3;
+
+/// Compare two heap values structurally
+/// Strings compare by content; arrays, dicts and sets compare element-wise,
+/// with string elements compared by content and other elements by value.
+/// Returns 1 if equal, 0 otherwise
+#[no_mangle]
+pub extern "C" fn plat_deep_eq(a: i64, b: i64, type_tag: i32) -> i32 {
+    if a == b {
+        return 1;
+    }
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    let equal = unsafe {
+        match type_tag {
+            EQ_TYPE_STRING => c_strings_equal(a as *const c_char, b as *const c_char),
+            EQ_TYPE_ARRAY => arrays_equal(&*(a as *const RuntimeArray), &*(b as *const RuntimeArray)),
+            EQ_TYPE_DICT => dicts_equal(&*(a as *const RuntimeDict), &*(b as *const RuntimeDict)),
+            EQ_TYPE_SET => sets_equal(&*(a as *const RuntimeSet), &*(b as *const RuntimeSet)),
+            _ => false,
+        }
+    };
+    equal as i32
+}
+
+/// Compare two arrays of class instances element-wise with the class's generated equality function
+/// Returns 1 if equal, 0 otherwise
+#[no_mangle]
+pub extern "C" fn plat_array_eq_with(
+    a: *const RuntimeArray,
+    b: *const RuntimeArray,
+    element_eq: extern "C" fn(i64, i64) -> i32,
+) -> i32 {
+    if a == b {
+        return 1;
+    }
+    if a.is_null() || b.is_null() {
+        return 0;
+    }
+
+    let equal = unsafe {
+        let (a, b) = (&*a, &*b);
+        a.length == b.length && {
+            let a_items = std::slice::from_raw_parts(a.data as *const i64, a.length);
+            let b_items = std::slice::from_raw_parts(b.data as *const i64, b.length);
+            a_items.iter().zip(b_items).all(|(&x, &y)| element_eq(x, y) != 0)
+        }
+    };
+    equal as i32
+}
+
+unsafe fn c_strings_equal(a: *const c_char, b: *const c_char) -> bool {
+    if a.is_null() || b.is_null() {
+        return a == b;
+    }
+    CStr::from_ptr(a).to_bytes() == CStr::from_ptr(b).to_bytes()
+}
+
+unsafe fn arrays_equal(a: &RuntimeArray, b: &RuntimeArray) -> bool {
+    if a.length != b.length || a.element_type != b.element_type || a.element_size != b.element_size {
+        return false;
+    }
+
+    if a.element_type == ARRAY_TYPE_STRING {
+        let a_items = std::slice::from_raw_parts(a.data as *const *const c_char, a.length);
+        let b_items = std::slice::from_raw_parts(b.data as *const *const c_char, b.length);
+        return a_items.iter().zip(b_items).all(|(&x, &y)| c_strings_equal(x, y));
+    }
+
+    let byte_len = a.length * a.element_size;
+    std::slice::from_raw_parts(a.data, byte_len) == std::slice::from_raw_parts(b.data, byte_len)
+}
+
+/// Compare two tagged values as stored in dicts and sets
+/// Only the low 32 bits of Int32 and Bool slots are meaningful
+unsafe fn tagged_values_equal(a: i64, a_type: u8, b: i64, b_type: u8, i64_type: u8, string_type: u8) -> bool {
+    if a_type != b_type {
+        return false;
+    }
+    if a_type == string_type {
+        return c_strings_equal(a as *const c_char, b as *const c_char);
+    }
+    if a_type == i64_type {
+        return a == b;
+    }
+    a as i32 == b as i32
+}
+
+unsafe fn dicts_equal(a: &RuntimeDict, b: &RuntimeDict) -> bool {
+    if a.length != b.length {
+        return false;
+    }
+
+    // Order-insensitive: every key of `a` must map to an equal value in `b`
+    (0..a.length).all(|i| {
+        let key = *a.keys.add(i);
+        (0..b.length).any(|j| {
+            c_strings_equal(key, *b.keys.add(j))
+                && tagged_values_equal(
+                    *a.values.add(i), *a.value_types.add(i),
+                    *b.values.add(j), *b.value_types.add(j),
+                    DICT_VALUE_TYPE_I64, DICT_VALUE_TYPE_STRING,
+                )
+        })
+    })
+}
+
+unsafe fn sets_equal(a: &RuntimeSet, b: &RuntimeSet) -> bool {
+    if a.length != b.length {
+        return false;
+    }
+
+    // Sets hold unique values, so equal sizes plus containment means equality
+    (0..a.length).all(|i| {
+        (0..b.length).any(|j| {
+            tagged_values_equal(
+                *a.values.add(i), *a.value_types.add(i),
+                *b.values.add(j), *b.value_types.add(j),
+                SET_VALUE_TYPE_I64, SET_VALUE_TYPE_STRING,
+            )
+        })
+    })
+}
@@ -293,6 +293,84 @@ pub extern "C" fn plat_file_close(fd: i32) -> i64 {
     }
 }
 
+/// Read a whole file by path
+/// Returns Result<String, String>
+#[no_mangle]
+pub extern "C" fn plat_file_read_all(path_ptr: *const c_char) -> i64 {
+    unsafe {
+        if path_ptr.is_null() {
+            let err_msg = alloc_c_string("file_read: path is null");
+            return create_result_enum_err_string(err_msg);
+        }
+
+        let path = match CStr::from_ptr(path_ptr).to_str() {
+            Ok(s) => s,
+            Err(_) => {
+                let err_msg = alloc_c_string("file_read: invalid path string");
+                return create_result_enum_err_string(err_msg);
+            }
+        };
+
+        match std::fs::read_to_string(path) {
+            Ok(contents) => {
+                let c_str = alloc_c_string(&contents);
+                create_result_enum_ok_string(c_str)
+            }
+            Err(e) => {
+                let err_msg = alloc_c_string(&format!("file_read failed for '{}': {}", path, e));
+                create_result_enum_err_string(err_msg)
+            }
+        }
+    }
+}
+
+/// Write (create/truncate) a whole file by path
+/// Returns Result<Int32, String> where Int32 is the number of bytes written
+#[no_mangle]
+pub extern "C" fn plat_file_write_all(path_ptr: *const c_char, contents_ptr: *const c_char) -> i64 {
+    unsafe { write_path(path_ptr, contents_ptr, false) }
+}
+
+/// Append to a file by path, creating it if it doesn't exist
+/// Returns Result<Int32, String> where Int32 is the number of bytes written
+#[no_mangle]
+pub extern "C" fn plat_file_append(path_ptr: *const c_char, contents_ptr: *const c_char) -> i64 {
+    unsafe { write_path(path_ptr, contents_ptr, true) }
+}
+
+/// Shared body of `plat_file_write_all` and `plat_file_append`
+unsafe fn write_path(path_ptr: *const c_char, contents_ptr: *const c_char, append: bool) -> i64 {
+    let name = if append { "file_append" } else { "file_write" };
+
+    if path_ptr.is_null() || contents_ptr.is_null() {
+        let err_msg = alloc_c_string(&format!("{}: path or contents is null", name));
+        return create_result_enum_err_string(err_msg);
+    }
+
+    let (path, contents) = match (CStr::from_ptr(path_ptr).to_str(), CStr::from_ptr(contents_ptr).to_str()) {
+        (Ok(path), Ok(contents)) => (path, contents),
+        _ => {
+            let err_msg = alloc_c_string(&format!("{}: invalid path or contents string", name));
+            return create_result_enum_err_string(err_msg);
+        }
+    };
+
+    let opened = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .append(append)
+        .truncate(!append)
+        .open(path);
+
+    match opened.and_then(|mut file| file.write_all(contents.as_bytes())) {
+        Ok(()) => create_result_enum_ok_i32(contents.len() as i32),
+        Err(e) => {
+            let err_msg = alloc_c_string(&format!("{} failed for '{}': {}", name, path, e));
+            create_result_enum_err_string(err_msg)
+        }
+    }
+}
+
 /// Check if file exists
 /// Returns Bool (1 = true, 0 = false)
 #[no_mangle]
@@ -11,6 +11,12 @@ extern "C" {
     /// Allocate atomic (pointer-free) memory - faster, no scanning
     pub fn GC_malloc_atomic(size: usize) -> *mut c_void;
 
+    /// Explicitly deallocate an object (must have come from GC_malloc/GC_malloc_atomic)
+    pub fn GC_free(ptr: *mut c_void);
+
+    /// Size in bytes of the object containing `ptr`
+    pub fn GC_size(ptr: *const c_void) -> usize;
+
     /// Explicitly trigger garbage collection
     pub fn GC_gcollect();
 
@@ -54,6 +60,19 @@ pub fn gc_alloc(size: usize, atomic: bool) -> *mut u8 {
     }
 }
 
+/// Safety wrapper for explicit deallocation; returns the number of bytes released
+///
+/// # Safety
+/// `ptr` must be null or the start of a live GC allocation that nothing uses anymore
+pub unsafe fn gc_free(ptr: *mut u8) -> usize {
+    if ptr.is_null() {
+        return 0;
+    }
+    let size = GC_size(ptr as *const c_void);
+    GC_free(ptr as *mut c_void);
+    size
+}
+
 /// Trigger explicit garbage collection
 pub fn gc_collect() {
     unsafe {
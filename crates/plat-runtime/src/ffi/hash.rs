@@ -0,0 +1,33 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Hash a string's bytes with 64-bit FNV-1a
+/// Deterministic across runs; a null string hashes like the empty string
+#[no_mangle]
+pub extern "C" fn plat_string_hash(str_ptr: *const c_char) -> i64 {
+    let bytes: &[u8] = if str_ptr.is_null() {
+        &[]
+    } else {
+        unsafe { CStr::from_ptr(str_ptr).to_bytes() }
+    };
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash as i64
+}
+
+/// Hash an integer with the SplitMix64 finalizer
+/// Narrower integers are sign-extended by codegen, so equal values hash equally at any width
+#[no_mangle]
+pub extern "C" fn plat_int_hash(value: i64) -> i64 {
+    let mut z = (value as u64).wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    (z ^ (z >> 31)) as i64
+}
@@ -14,6 +14,9 @@ pub mod time;
 pub mod env;
 pub mod random;
 pub mod process;
+pub mod sync;
+pub mod hash;
+pub mod equality;
 
 // Re-export commonly used items
 pub use array::{RuntimeArray, ARRAY_TYPE_I32, ARRAY_TYPE_I64, ARRAY_TYPE_BOOL, ARRAY_TYPE_STRING, ARRAY_TYPE_CLASS};
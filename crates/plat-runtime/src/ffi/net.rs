@@ -1,6 +1,6 @@
 use std::ffi::CStr;
 use std::os::raw::c_char;
-use std::net::{TcpListener, TcpStream};
+use std::net::{TcpListener, TcpStream, UdpSocket};
 use std::io::{Read, Write};
 use std::sync::Mutex;
 use std::collections::HashMap;
@@ -11,6 +11,7 @@ use super::core::{plat_gc_alloc, plat_gc_alloc_atomic};
 lazy_static::lazy_static! {
     static ref LISTENERS: Mutex<HashMap<i32, TcpListener>> = Mutex::new(HashMap::new());
     static ref STREAMS: Mutex<HashMap<i32, TcpStream>> = Mutex::new(HashMap::new());
+    static ref UDP_SOCKETS: Mutex<HashMap<i32, UdpSocket>> = Mutex::new(HashMap::new());
     static ref NEXT_FD: Mutex<i32> = Mutex::new(1000); // Start at 1000 to avoid conflicts
 }
 
@@ -285,3 +286,118 @@ pub extern "C" fn plat_tcp_close(socket_fd: i32) -> i64 {
         create_result_enum_err_string(err_msg)
     }
 }
+
+/// Bind a UDP socket to host:port
+/// Returns Result<Int32, String> where Int32 is the socket file descriptor
+#[no_mangle]
+pub extern "C" fn plat_udp_bind(host_ptr: *const c_char, port: i32) -> i64 {
+    unsafe {
+        if host_ptr.is_null() {
+            let err_msg = alloc_c_string("udp_bind: host is null");
+            return create_result_enum_err_string(err_msg);
+        }
+
+        let host = match CStr::from_ptr(host_ptr).to_str() {
+            Ok(s) => s,
+            Err(_) => {
+                let err_msg = alloc_c_string("udp_bind: invalid host string");
+                return create_result_enum_err_string(err_msg);
+            }
+        };
+
+        let addr = format!("{}:{}", host, port);
+        match UdpSocket::bind(&addr) {
+            Ok(socket) => {
+                let fd = next_fd();
+                UDP_SOCKETS.lock().unwrap().insert(fd, socket);
+                create_result_enum_ok_i32(fd)
+            }
+            Err(e) => {
+                let err_msg = alloc_c_string(&format!("udp_bind failed: {}", e));
+                create_result_enum_err_string(err_msg)
+            }
+        }
+    }
+}
+
+/// Send a datagram to host:port
+/// Returns Result<Int32, String> where Int32 is the number of bytes sent
+#[no_mangle]
+pub extern "C" fn plat_udp_send_to(socket_fd: i32, data_ptr: *const c_char, host_ptr: *const c_char, port: i32) -> i64 {
+    unsafe {
+        if data_ptr.is_null() || host_ptr.is_null() {
+            let err_msg = alloc_c_string("udp_send_to: data or host is null");
+            return create_result_enum_err_string(err_msg);
+        }
+
+        let (data, host) = match (CStr::from_ptr(data_ptr).to_str(), CStr::from_ptr(host_ptr).to_str()) {
+            (Ok(data), Ok(host)) => (data, host),
+            _ => {
+                let err_msg = alloc_c_string("udp_send_to: invalid data or host string");
+                return create_result_enum_err_string(err_msg);
+            }
+        };
+
+        let sockets = UDP_SOCKETS.lock().unwrap();
+
+        if let Some(socket) = sockets.get(&socket_fd) {
+            let addr = format!("{}:{}", host, port);
+            match socket.send_to(data.as_bytes(), &addr) {
+                Ok(bytes_sent) => create_result_enum_ok_i32(bytes_sent as i32),
+                Err(e) => {
+                    let err_msg = alloc_c_string(&format!("udp_send_to failed: {}", e));
+                    create_result_enum_err_string(err_msg)
+                }
+            }
+        } else {
+            let err_msg = alloc_c_string("udp_send_to: invalid socket file descriptor");
+            create_result_enum_err_string(err_msg)
+        }
+    }
+}
+
+/// Receive one datagram of up to max_bytes (blocking)
+/// Returns Result<String, String>
+#[no_mangle]
+pub extern "C" fn plat_udp_recv_from(socket_fd: i32, max_bytes: i32) -> i64 {
+    unsafe {
+        let sockets = UDP_SOCKETS.lock().unwrap();
+
+        if let Some(socket) = sockets.get(&socket_fd) {
+            // Clone so the lock is released before blocking (UdpSocket can be cloned)
+            let socket = match socket.try_clone() {
+                Ok(socket) => socket,
+                Err(e) => {
+                    let err_msg = alloc_c_string(&format!("udp_recv_from: failed to clone socket: {}", e));
+                    return create_result_enum_err_string(err_msg);
+                }
+            };
+            drop(sockets);
+
+            let mut buffer = vec![0u8; max_bytes.max(0) as usize];
+            match socket.recv_from(&mut buffer) {
+                Ok((bytes_read, _sender)) => {
+                    buffer.truncate(bytes_read);
+
+                    match String::from_utf8(buffer) {
+                        Ok(s) => {
+                            let c_str = alloc_c_string(&s);
+                            create_result_enum_ok_string(c_str)
+                        }
+                        Err(_) => {
+                            let err_msg = alloc_c_string("udp_recv_from: received invalid UTF-8 data");
+                            create_result_enum_err_string(err_msg)
+                        }
+                    }
+                }
+                Err(e) => {
+                    let err_msg = alloc_c_string(&format!("udp_recv_from failed: {}", e));
+                    create_result_enum_err_string(err_msg)
+                }
+            }
+        } else {
+            let err_msg = alloc_c_string("udp_recv_from: invalid socket file descriptor");
+            create_result_enum_err_string(err_msg)
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use std::env;
 use std::ffi::CString;
 use std::os::raw::c_char;
+use super::array::{plat_array_create_string, RuntimeArray};
+use super::core::plat_gc_alloc_atomic;
 
 /// Exit the process with the given exit code
 #[no_mangle]
@@ -20,3 +22,29 @@ pub extern "C" fn plat_process_args() -> *mut c_char {
         Err(_) => std::ptr::null_mut(),
     }
 }
+
+/// Get command-line arguments (including the program path) as a List[String]
+/// Returns null pointer on allocation failure
+#[no_mangle]
+pub extern "C" fn plat_args() -> *mut RuntimeArray {
+    let mut c_strings: Vec<*const c_char> = Vec::new();
+
+    for arg in env::args() {
+        let mut arg_bytes = arg.into_bytes();
+        arg_bytes.push(0); // null terminator
+
+        let size = arg_bytes.len();
+        let gc_ptr = plat_gc_alloc_atomic(size);
+
+        if gc_ptr.is_null() {
+            return std::ptr::null_mut();
+        }
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(arg_bytes.as_ptr(), gc_ptr, size);
+        }
+        c_strings.push(gc_ptr as *const c_char);
+    }
+
+    plat_array_create_string(c_strings.as_ptr(), c_strings.len())
+}
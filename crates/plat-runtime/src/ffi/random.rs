@@ -1,19 +1,38 @@
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::Mutex;
 
-/// Generate a random integer in the range [min, max]
+/// Process-wide generator installed by `seed_random`; unseeded programs use the thread RNG
+static SEEDED_RNG: Mutex<Option<StdRng>> = Mutex::new(None);
+
+/// Run `f` with the seeded generator if one is installed, otherwise with the thread RNG
+fn with_rng<T>(f: impl FnOnce(&mut dyn rand::RngCore) -> T) -> T {
+    let mut seeded = SEEDED_RNG.lock().unwrap();
+    match seeded.as_mut() {
+        Some(rng) => f(rng),
+        None => f(&mut rand::thread_rng()),
+    }
+}
+
+/// Seed the generator so later random_int/random_float calls are reproducible
+#[no_mangle]
+pub extern "C" fn plat_seed_random(seed: i64) {
+    *SEEDED_RNG.lock().unwrap() = Some(StdRng::seed_from_u64(seed as u64));
+}
+
+/// Generate a random integer in the half-open range [min, max)
+/// Returns min when the range is empty
 #[no_mangle]
 pub extern "C" fn plat_random_int(min: i64, max: i64) -> i64 {
-    if min > max {
+    if min >= max {
         return min;
     }
 
-    let mut rng = rand::thread_rng();
-    rng.gen_range(min..=max)
+    with_rng(|rng| rng.gen_range(min..max))
 }
 
 /// Generate a random float in the range [0.0, 1.0)
 #[no_mangle]
 pub extern "C" fn plat_random_float() -> f64 {
-    let mut rng = rand::thread_rng();
-    rng.gen()
+    with_rng(|rng| rng.gen())
 }
@@ -1,6 +1,7 @@
 use std::ffi::CStr;
 use std::os::raw::c_char;
 use super::core::plat_gc_alloc;
+use super::array::{plat_array_create_bool, plat_array_create_i32, plat_array_create_i64, plat_array_create_string, RuntimeArray};
 
 // Set type constants
 pub const SET_VALUE_TYPE_I32: u8 = 0;
@@ -106,6 +107,19 @@ pub extern "C" fn plat_set_create(
     set_ptr
 }
 
+/// Copy a set into new GC storage; values are shared, the tables are not
+#[no_mangle]
+pub extern "C" fn plat_set_clone(set_ptr: *const RuntimeSet) -> *mut RuntimeSet {
+    if set_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    unsafe {
+        let set = &*set_ptr;
+        plat_set_create(set.values, set.value_types, set.length)
+    }
+}
+
 /// Check if a value is in the set
 #[no_mangle]
 pub extern "C" fn plat_set_contains(set_ptr: *const RuntimeSet, value: i64, value_type: u8) -> bool {
@@ -319,6 +333,39 @@ pub extern "C" fn plat_set_length(set_ptr: *const RuntimeSet) -> i32 {
     plat_set_len(set_ptr) as i32
 }
 
+/// Convert a set to a list for iteration
+/// The list's element type comes from the set's stored value-type tag
+#[no_mangle]
+pub extern "C" fn plat_set_to_list(set_ptr: *const RuntimeSet) -> *mut RuntimeArray {
+    if set_ptr.is_null() {
+        return plat_array_create_i32(std::ptr::null(), 0);
+    }
+
+    unsafe {
+        let set = &*set_ptr;
+        if set.length == 0 || set.values.is_null() || set.value_types.is_null() {
+            return plat_array_create_i32(std::ptr::null(), 0);
+        }
+
+        let values = std::slice::from_raw_parts(set.values, set.length);
+        match *set.value_types {
+            SET_VALUE_TYPE_I64 => plat_array_create_i64(values.as_ptr(), values.len()),
+            SET_VALUE_TYPE_BOOL => {
+                let bools: Vec<bool> = values.iter().map(|&v| v != 0).collect();
+                plat_array_create_bool(bools.as_ptr(), bools.len())
+            }
+            SET_VALUE_TYPE_STRING => {
+                let strings: Vec<*const c_char> = values.iter().map(|&v| v as *const c_char).collect();
+                plat_array_create_string(strings.as_ptr(), strings.len())
+            }
+            _ => {
+                let ints: Vec<i32> = values.iter().map(|&v| v as i32).collect();
+                plat_array_create_i32(ints.as_ptr(), ints.len())
+            }
+        }
+    }
+}
+
 /// Create a union of two sets (returns new set)
 #[no_mangle]
 pub extern "C" fn plat_set_union(set1_ptr: *const RuntimeSet, set2_ptr: *const RuntimeSet) -> *mut RuntimeSet {
@@ -1,7 +1,7 @@
 use std::ffi::CStr;
 use std::os::raw::c_char;
 use super::core::{plat_gc_alloc, plat_gc_alloc_atomic};
-use super::array::plat_array_create_string;
+use super::array::{plat_array_create_string, RuntimeArray, ARRAY_TYPE_STRING};
 
 /// Get the character length of a string (not byte length)
 #[no_mangle]
@@ -52,29 +52,15 @@ pub extern "C" fn plat_string_concat(str1_ptr: *const c_char, str2_ptr: *const c
     }
 }
 
-/// Check if two strings are equal
+/// Check if two strings have the same contents
+/// Returns 1 if equal, 0 otherwise (an i32 so codegen never sees stale upper bits of a Rust bool)
 #[no_mangle]
-pub extern "C" fn plat_string_equals(str1_ptr: *const c_char, str2_ptr: *const c_char) -> bool {
-    if str1_ptr.is_null() && str2_ptr.is_null() {
-        return true;
-    }
+pub extern "C" fn plat_string_eq(str1_ptr: *const c_char, str2_ptr: *const c_char) -> i32 {
     if str1_ptr.is_null() || str2_ptr.is_null() {
-        return false;
+        return (str1_ptr == str2_ptr) as i32;
     }
 
-    unsafe {
-        let str1 = match CStr::from_ptr(str1_ptr).to_str() {
-            Ok(s) => s,
-            Err(_) => return false,
-        };
-
-        let str2 = match CStr::from_ptr(str2_ptr).to_str() {
-            Ok(s) => s,
-            Err(_) => return false,
-        };
-
-        str1 == str2
-    }
+    unsafe { (CStr::from_ptr(str1_ptr).to_bytes() == CStr::from_ptr(str2_ptr).to_bytes()) as i32 }
 }
 
 /// Check if string contains a substring
@@ -143,6 +129,32 @@ pub extern "C" fn plat_string_ends_with(str_ptr: *const c_char, suffix_ptr: *con
     }
 }
 
+/// Find the first occurrence of a substring, returns Option<i32> encoded as (found: i32, index: i32)
+/// The index is a character index (not byte index) to match `length()`
+#[no_mangle]
+pub extern "C" fn plat_string_index_of(str_ptr: *const c_char, needle_ptr: *const c_char) -> (i32, i32) {
+    if str_ptr.is_null() || needle_ptr.is_null() {
+        return (0, -1);
+    }
+
+    unsafe {
+        let str_val = match CStr::from_ptr(str_ptr).to_str() {
+            Ok(s) => s,
+            Err(_) => return (0, -1),
+        };
+
+        let needle = match CStr::from_ptr(needle_ptr).to_str() {
+            Ok(s) => s,
+            Err(_) => return (0, -1),
+        };
+
+        match str_val.find(needle) {
+            Some(byte_index) => (1, str_val[..byte_index].chars().count() as i32),
+            None => (0, -1),
+        }
+    }
+}
+
 /// Trim whitespace from both ends of string
 #[no_mangle]
 pub extern "C" fn plat_string_trim(str_ptr: *const c_char) -> *const c_char {
@@ -308,9 +320,40 @@ pub extern "C" fn plat_string_replace_all(str_ptr: *const c_char, from_ptr: *con
     }
 }
 
+/// Copy string slices onto the GC heap as a string array
+fn parts_to_string_array<'a>(parts: impl Iterator<Item = &'a str>) -> *mut crate::ffi::RuntimeArray {
+    let mut c_strings: Vec<*const c_char> = Vec::new();
+
+    for part in parts {
+        let mut part_bytes = part.as_bytes().to_vec();
+        part_bytes.push(0); // null terminator
+
+        let size = part_bytes.len();
+        let gc_ptr = plat_gc_alloc_atomic(size);
+
+        if gc_ptr.is_null() {
+            return std::ptr::null_mut();
+        }
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(part_bytes.as_ptr(), gc_ptr, size);
+        }
+        c_strings.push(gc_ptr as *const c_char);
+    }
+
+    plat_array_create_string(c_strings.as_ptr(), c_strings.len())
+}
+
 /// Split string by delimiter and return as string array
 #[no_mangle]
 pub extern "C" fn plat_string_split(str_ptr: *const c_char, delimiter_ptr: *const c_char) -> *mut crate::ffi::RuntimeArray {
+    plat_string_splitn(str_ptr, delimiter_ptr, 0)
+}
+
+/// Split string by delimiter into at most `limit` parts; the last part keeps any remaining
+/// delimiters. A limit of 0 (or below) means unlimited
+#[no_mangle]
+pub extern "C" fn plat_string_splitn(str_ptr: *const c_char, delimiter_ptr: *const c_char, limit: i32) -> *mut crate::ffi::RuntimeArray {
     if str_ptr.is_null() || delimiter_ptr.is_null() {
         return std::ptr::null_mut();
     }
@@ -326,28 +369,42 @@ pub extern "C" fn plat_string_split(str_ptr: *const c_char, delimiter_ptr: *cons
             Err(_) => return std::ptr::null_mut(),
         };
 
-        let parts: Vec<&str> = str_val.split(delimiter).collect();
-
-        // Convert parts to C strings on GC heap
-        let mut c_strings: Vec<*const c_char> = Vec::new();
+        if limit > 0 {
+            parts_to_string_array(str_val.splitn(limit as usize, delimiter))
+        } else {
+            parts_to_string_array(str_val.split(delimiter))
+        }
+    }
+}
 
-        for part in parts {
-            let mut part_bytes = part.as_bytes().to_vec();
-            part_bytes.push(0); // null terminator
+/// Split string on `\n` or `\r\n` line endings. A trailing line ending does not produce a
+/// final empty line, so "a\nb\n" has two lines
+#[no_mangle]
+pub extern "C" fn plat_string_lines(str_ptr: *const c_char) -> *mut crate::ffi::RuntimeArray {
+    if str_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
 
-            let size = part_bytes.len();
-            let gc_ptr = plat_gc_alloc_atomic(size);
+    unsafe {
+        match CStr::from_ptr(str_ptr).to_str() {
+            Ok(s) => parts_to_string_array(s.lines()),
+            Err(_) => std::ptr::null_mut(),
+        }
+    }
+}
 
-            if gc_ptr.is_null() {
-                return std::ptr::null_mut();
-            }
+/// Split string into one-character strings, one per Unicode code point
+#[no_mangle]
+pub extern "C" fn plat_string_chars(str_ptr: *const c_char) -> *mut crate::ffi::RuntimeArray {
+    if str_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
 
-            std::ptr::copy_nonoverlapping(part_bytes.as_ptr(), gc_ptr, size);
-            c_strings.push(gc_ptr as *const c_char);
+    unsafe {
+        match CStr::from_ptr(str_ptr).to_str() {
+            Ok(s) => parts_to_string_array(s.char_indices().map(|(i, c)| &s[i..i + c.len_utf8()])),
+            Err(_) => std::ptr::null_mut(),
         }
-
-        // Create string array
-        plat_array_create_string(c_strings.as_ptr(), c_strings.len())
     }
 }
 
@@ -711,3 +768,76 @@ pub extern "C" fn plat_string_char_at(str_ptr: *const c_char, index: i32) -> *co
         gc_ptr as *const c_char
     }
 }
+
+/// Get character at index as Option<String>, encoded as (found: i32, string pointer: i64)
+#[no_mangle]
+pub extern "C" fn plat_string_char_at_safe(str_ptr: *const c_char, index: i32) -> (i32, i64) {
+    if str_ptr.is_null() || index < 0 {
+        return (0, 0);
+    }
+
+    unsafe {
+        let char_count = match CStr::from_ptr(str_ptr).to_str() {
+            Ok(s) => s.chars().count(),
+            Err(_) => return (0, 0),
+        };
+
+        if index as usize >= char_count {
+            return (0, 0);
+        }
+    }
+
+    (1, plat_string_char_at(str_ptr, index) as i64)
+}
+
+/// Substitute `{0}`, `{1}`, ... in a template with the strings of a List[String].
+/// Placeholders without a matching argument are left as written.
+#[no_mangle]
+pub extern "C" fn plat_format(template_ptr: *const c_char, args_ptr: *const RuntimeArray) -> *const c_char {
+    if template_ptr.is_null() {
+        return std::ptr::null();
+    }
+
+    unsafe {
+        let template = match CStr::from_ptr(template_ptr).to_str() {
+            Ok(s) => s,
+            Err(_) => return std::ptr::null(),
+        };
+
+        let args: Vec<&str> = if args_ptr.is_null() || (*args_ptr).element_type != ARRAY_TYPE_STRING {
+            Vec::new()
+        } else {
+            let array = &*args_ptr;
+            let elements = array.data as *const *const c_char;
+            (0..array.length)
+                .map(|i| {
+                    let element = *elements.add(i);
+                    if element.is_null() { "" } else { CStr::from_ptr(element).to_str().unwrap_or("") }
+                })
+                .collect()
+        };
+
+        let mut result = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(open) = rest.find('{') {
+            result.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            let argument = after.find('}')
+                .and_then(|close| after[..close].parse::<usize>().ok().map(|index| (index, close)))
+                .and_then(|(index, close)| args.get(index).map(|arg| (arg, close)));
+            match argument {
+                Some((arg, close)) => {
+                    result.push_str(arg);
+                    rest = &after[close + 1..];
+                }
+                None => {
+                    result.push('{');
+                    rest = after;
+                }
+            }
+        }
+        result.push_str(rest);
+
+        create_error_message(&result)
+    }
+}
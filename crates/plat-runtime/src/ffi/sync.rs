@@ -0,0 +1,63 @@
+use parking_lot::lock_api::RawMutex as _;
+use parking_lot::RawMutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::core::plat_panic;
+
+/// A mutex that remembers which task holds it
+///
+/// Tasks run to completion on one worker thread, and a task blocked in `lock` keeps its
+/// thread, so the holding thread identifies the holding task.
+struct PlatMutex {
+    raw: RawMutex,
+    /// Token of the thread holding the lock, or 0 when unlocked
+    owner: AtomicU64,
+}
+
+static NEXT_THREAD_TOKEN: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static THREAD_TOKEN: u64 = NEXT_THREAD_TOKEN.fetch_add(1, Ordering::Relaxed);
+}
+
+fn current_token() -> u64 {
+    THREAD_TOKEN.with(|token| *token)
+}
+
+/// Create a new unlocked mutex and return its handle
+///
+/// Mutexes live for the rest of the program, like channels; the handle is never freed.
+#[no_mangle]
+pub extern "C" fn plat_mutex_create() -> i64 {
+    Box::into_raw(Box::new(PlatMutex { raw: RawMutex::INIT, owner: AtomicU64::new(0) })) as i64
+}
+
+/// Block until the mutex is acquired
+#[no_mangle]
+pub extern "C" fn plat_mutex_lock(mutex: i64) {
+    if mutex == 0 {
+        return;
+    }
+    let mutex = unsafe { &*(mutex as *const PlatMutex) };
+    mutex.raw.lock();
+    mutex.owner.store(current_token(), Ordering::Relaxed);
+}
+
+/// Release a mutex acquired with `plat_mutex_lock`
+///
+/// Unlocking a mutex the current task does not hold would let two tasks into the
+/// critical section, so it panics instead.
+#[no_mangle]
+pub extern "C" fn plat_mutex_unlock(mutex: i64) {
+    if mutex == 0 {
+        return;
+    }
+    let mutex = unsafe { &*(mutex as *const PlatMutex) };
+    let held = mutex.raw.is_locked() && mutex.owner.load(Ordering::Relaxed) == current_token();
+    if !held {
+        plat_panic(c"unlock of a mutex not held by the current task".as_ptr());
+    }
+    mutex.owner.store(0, Ordering::Relaxed);
+    unsafe { mutex.raw.unlock() };
+}
+
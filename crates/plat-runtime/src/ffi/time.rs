@@ -1,6 +1,16 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::thread;
 
+/// Per-benchmark (call count, total wall-clock time), keyed by bench function name
+static BENCH_STATS: Mutex<Option<HashMap<String, (u64, Duration)>>> = Mutex::new(None);
+
+/// Reference point for the monotonic clock, fixed on first use
+static MONOTONIC_EPOCH: OnceLock<Instant> = OnceLock::new();
+
 /// Get the current Unix timestamp in milliseconds
 #[no_mangle]
 pub extern "C" fn plat_time_now() -> i64 {
@@ -10,6 +20,18 @@ pub extern "C" fn plat_time_now() -> i64 {
     }
 }
 
+/// Monotonic clock reading in milliseconds (only meaningful as a difference)
+#[no_mangle]
+pub extern "C" fn plat_now_millis() -> i64 {
+    MONOTONIC_EPOCH.get_or_init(Instant::now).elapsed().as_millis() as i64
+}
+
+/// Monotonic clock reading in nanoseconds (only meaningful as a difference)
+#[no_mangle]
+pub extern "C" fn plat_now_nanos() -> i64 {
+    MONOTONIC_EPOCH.get_or_init(Instant::now).elapsed().as_nanos() as i64
+}
+
 /// Sleep for the specified number of milliseconds
 #[no_mangle]
 pub extern "C" fn plat_time_sleep(millis: i64) {
@@ -17,3 +39,67 @@ pub extern "C" fn plat_time_sleep(millis: i64) {
         thread::sleep(std::time::Duration::from_millis(millis as u64));
     }
 }
+
+/// Mark the start of one bench function call (emitted by codegen in bench mode)
+///
+/// Returns the start timestamp for the matching `plat_bench_end`; nothing is locked or
+/// allocated, so the timed region holds only the bench body.
+#[no_mangle]
+pub extern "C" fn plat_bench_start() -> i64 {
+    plat_now_nanos()
+}
+
+/// Record one bench function call that began at `start`, a `plat_bench_start` timestamp
+#[no_mangle]
+pub extern "C" fn plat_bench_end(name_ptr: *const c_char, start: i64) {
+    // Read the clock before any bookkeeping
+    let elapsed = Duration::from_nanos(plat_now_nanos().saturating_sub(start).max(0) as u64);
+    if name_ptr.is_null() {
+        return;
+    }
+
+    let name = unsafe { CStr::from_ptr(name_ptr) }.to_string_lossy();
+    let mut stats = BENCH_STATS.lock().unwrap();
+    let stats = stats.get_or_insert_with(HashMap::new);
+    // Only a bench's first call allocates its key
+    match stats.get_mut(name.as_ref()) {
+        Some(entry) => {
+            entry.0 += 1;
+            entry.1 += elapsed;
+        }
+        None => {
+            stats.insert(name.into_owned(), (1, elapsed));
+        }
+    }
+}
+
+/// Discard all recorded bench timings (used to drop warmup iterations)
+#[no_mangle]
+pub extern "C" fn plat_bench_reset() {
+    *BENCH_STATS.lock().unwrap() = None;
+}
+
+/// Recorded (call count, total nanoseconds) for a bench function
+pub fn bench_stats(name: &str) -> (u64, u128) {
+    let stats = BENCH_STATS.lock().unwrap();
+    stats.as_ref()
+        .and_then(|stats| stats.get(name))
+        .map(|(count, total)| (*count, total.as_nanos()))
+        .unwrap_or((0, 0))
+}
+
+/// Print the iteration count and timings recorded for a bench function
+#[no_mangle]
+pub extern "C" fn plat_bench_report(name_ptr: *const c_char) {
+    if name_ptr.is_null() {
+        return;
+    }
+    let name = unsafe { CStr::from_ptr(name_ptr) }.to_string_lossy();
+    let (count, total_nanos) = bench_stats(&name);
+
+    println!("  Iterations: {}", count);
+    println!("  Total: {:.3} ms", total_nanos as f64 / 1_000_000.0);
+    if count > 0 {
+        println!("  Per iteration: {:.1} ns", total_nanos as f64 / count as f64);
+    }
+}
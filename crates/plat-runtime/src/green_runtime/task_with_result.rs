@@ -78,6 +78,7 @@ impl<T: Send + 'static> TaskWithResult<T> {
     pub fn handle(&self) -> TaskHandle<T> {
         TaskHandle {
             id: self.id,
+            state: self.state.clone(),
             result: self.result.clone(),
             completed: self.completed.clone(),
             condvar: self.condvar.clone(),
@@ -87,17 +88,32 @@ impl<T: Send + 'static> TaskWithResult<T> {
 
     /// Execute the task
     pub fn execute(mut self) {
-        // Update state to Running
-        self.set_state(TaskState::Running);
+        // Update state to Running, unless the task was cancelled before it started
+        let cancelled = {
+            let mut state = self.state.lock();
+            if *state == TaskState::Cancelled {
+                true
+            } else {
+                *state = TaskState::Running;
+                false
+            }
+        };
 
         // Take the closure and execute it
-        if let Some(closure) = self.closure.take() {
-            let result = closure();
-            *self.result.lock() = Some(result);
+        if !cancelled {
+            if let Some(closure) = self.closure.take() {
+                let result = closure();
+                *self.result.lock() = Some(result);
+            }
         }
 
-        // Mark as completed
-        self.set_state(TaskState::Completed);
+        // Mark as completed; a task cancelled while running stays Cancelled
+        {
+            let mut state = self.state.lock();
+            if *state != TaskState::Cancelled {
+                *state = TaskState::Completed;
+            }
+        }
         self.completed.store(true, Ordering::SeqCst);
 
         // Wake up all threads waiting on this task
@@ -114,6 +130,7 @@ impl<T: Send + 'static> TaskWithResult<T> {
 #[derive(Clone)]
 pub struct TaskHandle<T> {
     id: TaskId,
+    state: Arc<Mutex<TaskState>>,
     result: Arc<Mutex<Option<T>>>,
     completed: Arc<AtomicBool>,
     condvar: Arc<Condvar>,
@@ -131,6 +148,28 @@ impl<T: Send + 'static> TaskHandle<T> {
         self.completed.load(Ordering::SeqCst)
     }
 
+    /// Check if the task was cancelled before it completed
+    pub fn is_cancelled(&self) -> bool {
+        *self.state.lock() == TaskState::Cancelled
+    }
+
+    /// Request cancellation. Cancellation is cooperative: a task that has not started
+    /// yet is skipped, a running task runs to completion but its result is discarded,
+    /// and awaiting a cancelled task returns None right away. No-op once completed.
+    pub fn cancel(&self) {
+        {
+            let mut state = self.state.lock();
+            if *state == TaskState::Completed {
+                return;
+            }
+            *state = TaskState::Cancelled;
+        }
+
+        // Wake awaiters so they observe the cancellation
+        let _result_guard = self.result.lock();
+        self.condvar.notify_all();
+    }
+
     /// Wait for the task to complete without retrieving the result
     pub fn wait(&self) {
         if self.is_completed() {
@@ -144,16 +183,21 @@ impl<T: Send + 'static> TaskHandle<T> {
     }
 
     /// Wait for the task to complete and get the result
+    /// Returns None if the task was cancelled
     pub fn await_result(&self) -> Option<T>
     where
         T: Clone,
     {
-        // Wait for completion using condition variable
+        // Wait for completion (or cancellation) using condition variable
         let mut result_guard = self.result.lock();
-        while !self.is_completed() {
+        while !self.is_completed() && !self.is_cancelled() {
             self.condvar.wait(&mut result_guard);
         }
 
+        if self.is_cancelled() {
+            return None;
+        }
+
         // Extract the result
         result_guard.clone()
     }
@@ -202,4 +246,30 @@ mod tests {
         let result = handle.await_result();
         assert_eq!(result, Some("Hello from task!".to_string()));
     }
+
+    #[test]
+    fn test_cancelled_task_is_skipped() {
+        let task = TaskWithResult::new(|| {
+            panic!("cancelled task must not run");
+        });
+
+        let handle: TaskHandle<i32> = task.handle();
+        handle.cancel();
+        task.execute();
+
+        assert!(handle.is_cancelled());
+        assert!(handle.is_completed());
+        assert_eq!(handle.await_result(), None);
+    }
+
+    #[test]
+    fn test_cancel_after_completion_is_ignored() {
+        let task = TaskWithResult::new(|| 7i32);
+        let handle = task.handle();
+        task.execute();
+
+        handle.cancel();
+        assert!(!handle.is_cancelled());
+        assert_eq!(handle.await_result(), Some(7));
+    }
 }
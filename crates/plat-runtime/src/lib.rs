@@ -111,7 +111,7 @@ pub extern "C" fn plat_task_await_i64(handle_id: u64) -> i64 {
     if let Some(handle_any) = handles.get(&handle_id) {
         if let Some(handle) = handle_any.downcast_ref::<TaskHandle<i64>>() {
             // Wait for result
-            return handle.await_result().unwrap_or(0);
+            return handle.await_result().unwrap_or_else(|| awaited_cancelled_task());
         }
     }
 
@@ -150,7 +150,7 @@ pub extern "C" fn plat_task_await_i32(handle_id: u64) -> i32 {
     let handles = TASK_HANDLES.lock().unwrap();
     if let Some(handle_any) = handles.get(&handle_id) {
         if let Some(handle) = handle_any.downcast_ref::<TaskHandle<i32>>() {
-            return handle.await_result().unwrap_or(0);
+            return handle.await_result().unwrap_or_else(|| awaited_cancelled_task());
         }
     }
     0
@@ -187,7 +187,7 @@ pub extern "C" fn plat_task_await_bool(handle_id: u64) -> bool {
     let handles = TASK_HANDLES.lock().unwrap();
     if let Some(handle_any) = handles.get(&handle_id) {
         if let Some(handle) = handle_any.downcast_ref::<TaskHandle<bool>>() {
-            return handle.await_result().unwrap_or(false);
+            return handle.await_result().unwrap_or_else(|| awaited_cancelled_task());
         }
     }
     false
@@ -224,7 +224,7 @@ pub extern "C" fn plat_task_await_f32(handle_id: u64) -> f32 {
     let handles = TASK_HANDLES.lock().unwrap();
     if let Some(handle_any) = handles.get(&handle_id) {
         if let Some(handle) = handle_any.downcast_ref::<TaskHandle<f32>>() {
-            return handle.await_result().unwrap_or(0.0);
+            return handle.await_result().unwrap_or_else(|| awaited_cancelled_task());
         }
     }
     0.0
@@ -261,7 +261,7 @@ pub extern "C" fn plat_task_await_f64(handle_id: u64) -> f64 {
     let handles = TASK_HANDLES.lock().unwrap();
     if let Some(handle_any) = handles.get(&handle_id) {
         if let Some(handle) = handle_any.downcast_ref::<TaskHandle<f64>>() {
-            return handle.await_result().unwrap_or(0.0);
+            return handle.await_result().unwrap_or_else(|| awaited_cancelled_task());
         }
     }
     0.0
@@ -299,7 +299,7 @@ pub extern "C" fn plat_task_await_string(handle_id: u64) -> *const i8 {
     let handles = TASK_HANDLES.lock().unwrap();
     if let Some(handle_any) = handles.get(&handle_id) {
         if let Some(handle) = handle_any.downcast_ref::<TaskHandle<usize>>() {
-            let ptr_value = handle.await_result().unwrap_or(0);
+            let ptr_value = handle.await_result().unwrap_or_else(|| awaited_cancelled_task());
             return ptr_value as *const i8;
         }
     }
@@ -338,13 +338,71 @@ pub extern "C" fn plat_task_await_ptr(handle_id: u64) -> *const u8 {
     let handles = TASK_HANDLES.lock().unwrap();
     if let Some(handle_any) = handles.get(&handle_id) {
         if let Some(handle) = handle_any.downcast_ref::<TaskHandle<usize>>() {
-            let ptr_value = handle.await_result().unwrap_or(0);
+            let ptr_value = handle.await_result().unwrap_or_else(|| awaited_cancelled_task());
             return ptr_value as *const u8;
         }
     }
     std::ptr::null()
 }
 
+/// `.await()` on a cancelled task has no result to return, so it panics rather than
+/// making one up
+fn awaited_cancelled_task() -> ! {
+    ffi::core::plat_panic(c"awaited a cancelled task".as_ptr())
+}
+
+/// Run `action` on a task handle of any result type stored in the registry
+fn with_task_handle<R>(handle_id: u64, action: impl Fn(&dyn CancellableTask) -> R) -> Option<R> {
+    use green_runtime::task_with_result::TaskHandle;
+
+    let handles = TASK_HANDLES.lock().unwrap();
+    let handle_any = handles.get(&handle_id)?;
+    if let Some(handle) = handle_any.downcast_ref::<TaskHandle<i32>>() {
+        Some(action(handle))
+    } else if let Some(handle) = handle_any.downcast_ref::<TaskHandle<i64>>() {
+        Some(action(handle))
+    } else if let Some(handle) = handle_any.downcast_ref::<TaskHandle<bool>>() {
+        Some(action(handle))
+    } else if let Some(handle) = handle_any.downcast_ref::<TaskHandle<f32>>() {
+        Some(action(handle))
+    } else if let Some(handle) = handle_any.downcast_ref::<TaskHandle<f64>>() {
+        Some(action(handle))
+    } else {
+        handle_any.downcast_ref::<TaskHandle<usize>>().map(|handle| action(handle))
+    }
+}
+
+/// Type-erased view of a task handle for cancellation
+trait CancellableTask {
+    fn cancel(&self);
+    /// Block until the task completes or is cancelled; true if it was cancelled
+    fn wait_cancelled(&self) -> bool;
+}
+
+impl<T: Send + Clone + 'static> CancellableTask for green_runtime::task_with_result::TaskHandle<T> {
+    fn cancel(&self) {
+        green_runtime::task_with_result::TaskHandle::cancel(self)
+    }
+
+    fn wait_cancelled(&self) -> bool {
+        self.await_result().is_none()
+    }
+}
+
+/// Request cooperative cancellation of a task (`task.cancel()`)
+/// Awaiting the task afterwards panics; `try_await` returns None
+#[no_mangle]
+pub extern "C" fn plat_task_cancel(handle_id: u64) {
+    with_task_handle(handle_id, |handle| handle.cancel());
+}
+
+/// Block until a task completes or is cancelled (`task.try_await()`)
+/// Returns 1 if cancelled, 0 if its result is ready to await
+#[no_mangle]
+pub extern "C" fn plat_task_await_cancelled(handle_id: u64) -> i32 {
+    with_task_handle(handle_id, |handle| handle.wait_cancelled() as i32).unwrap_or(0)
+}
+
 // ============================================================================
 // Context-aware Spawn Functions (for variable capture)
 // ============================================================================
@@ -853,3 +911,53 @@ pub extern "C" fn plat_channel_close(channel_id: u64) {
         ch.close();
     }
 }
+
+/// Wait for a value on any of `count` channels (the `select` statement)
+/// Returns the index of the channel that delivered a value and writes the value to
+/// `out_value` (integers widened to i64, floats as their IEEE bits).
+/// Returns -1 if `blocking` is 0 and no channel is ready, or if every channel is closed.
+#[no_mangle]
+pub extern "C" fn plat_select(channel_ids: *const u64, count: i32, blocking: i32, out_value: *mut i64) -> i32 {
+    use channel::get_any_receiver;
+    use crossbeam_channel::Select;
+
+    if channel_ids.is_null() || out_value.is_null() || count <= 0 {
+        return -1;
+    }
+
+    let ids = unsafe { std::slice::from_raw_parts(channel_ids, count as usize) };
+
+    // Closed channels are gone from the registry, so remember each open channel's position
+    let receivers: Vec<(usize, channel::AnyReceiver)> = ids.iter()
+        .enumerate()
+        .filter_map(|(index, &id)| get_any_receiver(id).map(|receiver| (index, receiver)))
+        .collect();
+    if receivers.is_empty() {
+        return -1;
+    }
+
+    let mut select = Select::new();
+    for (_, receiver) in &receivers {
+        receiver.register(&mut select);
+    }
+
+    let oper = if blocking != 0 {
+        select.select()
+    } else {
+        match select.try_select() {
+            Ok(oper) => oper,
+            Err(_) => return -1,
+        }
+    };
+
+    let (index, receiver) = &receivers[oper.index()];
+    match receiver.complete(oper) {
+        Some(bits) => {
+            unsafe {
+                *out_value = bits;
+            }
+            *index as i32
+        }
+        None => -1,
+    }
+}
@@ -279,4 +279,508 @@ mod tests {
         assert_ne!(PlatValue::I32(5), PlatValue::I64(5));
         assert_ne!(PlatValue::Bool(true), PlatValue::I32(1));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_f16_conversions() {
+        use crate::ffi::conversions::{plat_f16_to_f32, plat_f32_to_f16};
+
+        assert_eq!(plat_f32_to_f16(1.0), 0x3c00);
+        assert_eq!(plat_f32_to_f16(-2.5), 0xc100);
+        assert_eq!(plat_f32_to_f16(65504.0), 0x7bff);
+        assert_eq!(plat_f32_to_f16(1.0e6), 0x7c00);
+        assert_eq!(plat_f32_to_f16(5.960_464_5e-8), 0x0001);
+        assert_eq!(plat_f16_to_f32(0x0001), 5.960_464_5e-8);
+        assert!(plat_f16_to_f32(plat_f32_to_f16(f32::NAN)).is_nan());
+
+        // Round-trips are exact for representable values and round otherwise
+        for value in [0.0f32, 0.5, 1.5, 3.140625, -1024.0, 6.1035156e-5] {
+            assert_eq!(plat_f16_to_f32(plat_f32_to_f16(value)), value);
+        }
+        assert_eq!(plat_f16_to_f32(plat_f32_to_f16(0.1)), 0.099975586);
+    }
+
+    #[test]
+    fn test_array_unique() {
+        use crate::ffi::array::{
+            plat_array_create_i32, plat_array_create_string, plat_array_get, plat_array_len,
+            plat_array_unique, ARRAY_TYPE_I32, ARRAY_TYPE_STRING,
+        };
+        use std::ffi::{CStr, CString};
+        use std::os::raw::c_char;
+
+        let elements = [1i32, 2, 2, 3, 1];
+        let array = plat_array_create_i32(elements.as_ptr(), elements.len());
+        let unique = plat_array_unique(array, ARRAY_TYPE_I32 as i32);
+        let values: Vec<i64> = (0..plat_array_len(unique)).map(|i| plat_array_get(unique, i)).collect();
+        assert_eq!(values, vec![1, 2, 3]);
+        assert_eq!(plat_array_len(array), 5);
+
+        // Distinct allocations with equal content count as duplicates
+        let owned: Vec<CString> = ["a", "b", "a"].iter().map(|s| CString::new(*s).unwrap()).collect();
+        let ptrs: Vec<*const c_char> = owned.iter().map(|s| s.as_ptr()).collect();
+        let array = plat_array_create_string(ptrs.as_ptr(), ptrs.len());
+        let unique = plat_array_unique(array, ARRAY_TYPE_STRING as i32);
+        let values: Vec<String> = (0..plat_array_len(unique))
+            .map(|i| unsafe { CStr::from_ptr(plat_array_get(unique, i) as *const c_char) }.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(values, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_array_contains() {
+        use crate::ffi::array::{
+            plat_array_contains, plat_array_create_i32, plat_array_create_string, ARRAY_TYPE_I32,
+            ARRAY_TYPE_STRING,
+        };
+        use std::ffi::CString;
+        use std::os::raw::c_char;
+
+        let elements = [1i32, -2, 3];
+        let array = plat_array_create_i32(elements.as_ptr(), elements.len());
+        assert!(plat_array_contains(array, 3, ARRAY_TYPE_I32 as i32));
+        assert!(plat_array_contains(array, -2, ARRAY_TYPE_I32 as i32));
+        assert!(!plat_array_contains(array, 9, ARRAY_TYPE_I32 as i32));
+
+        // A separately allocated string with equal content is found
+        let owned: Vec<CString> = ["ann", "bob"].iter().map(|s| CString::new(*s).unwrap()).collect();
+        let ptrs: Vec<*const c_char> = owned.iter().map(|s| s.as_ptr()).collect();
+        let array = plat_array_create_string(ptrs.as_ptr(), ptrs.len());
+        let needle = CString::new("bob").unwrap();
+        let missing = CString::new("cal").unwrap();
+        assert!(plat_array_contains(array, needle.as_ptr() as i64, ARRAY_TYPE_STRING as i32));
+        assert!(!plat_array_contains(array, missing.as_ptr() as i64, ARRAY_TYPE_STRING as i32));
+    }
+
+    #[test]
+    fn test_array_chunks() {
+        use crate::ffi::array::{plat_array_chunks, plat_array_create_i32, plat_array_get, plat_array_len, RuntimeArray};
+
+        let elements = [1i32, 2, 3, 4, 5];
+        let array = plat_array_create_i32(elements.as_ptr(), elements.len());
+        let chunks = plat_array_chunks(array, 2);
+
+        let values: Vec<Vec<i64>> = (0..plat_array_len(chunks))
+            .map(|i| {
+                let chunk = plat_array_get(chunks, i) as *const RuntimeArray;
+                (0..plat_array_len(chunk)).map(|j| plat_array_get(chunk, j)).collect()
+            })
+            .collect();
+        assert_eq!(values, vec![vec![1, 2], vec![3, 4], vec![5]]);
+
+        assert_eq!(plat_array_len(plat_array_chunks(array, 0)), 0);
+    }
+
+    #[test]
+    fn test_array_flatten() {
+        use crate::ffi::array::{
+            plat_array_create_class, plat_array_create_i32, plat_array_flatten, plat_array_get, plat_array_len,
+        };
+
+        let rows: [&[i32]; 3] = [&[1, 2], &[3], &[4, 5]];
+        let inner: Vec<*const u8> = rows
+            .iter()
+            .map(|row| plat_array_create_i32(row.as_ptr(), row.len()) as *const u8)
+            .collect();
+        let nested = plat_array_create_class(inner.as_ptr(), inner.len());
+
+        let flat = plat_array_flatten(nested);
+        let values: Vec<i64> = (0..plat_array_len(flat)).map(|i| plat_array_get(flat, i)).collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+
+        let empty = plat_array_create_class(std::ptr::null(), 0);
+        assert_eq!(plat_array_len(plat_array_flatten(empty)), 0);
+    }
+
+    #[test]
+    fn test_array_zip() {
+        use crate::ffi::array::{
+            plat_array_create_i32, plat_array_create_string, plat_array_get, plat_array_len, plat_array_zip,
+        };
+        use std::ffi::{CStr, CString};
+        use std::os::raw::c_char;
+
+        let owned: Vec<CString> = ["a", "b", "c"].iter().map(|s| CString::new(*s).unwrap()).collect();
+        let ptrs: Vec<*const c_char> = owned.iter().map(|s| s.as_ptr()).collect();
+        let names = plat_array_create_string(ptrs.as_ptr(), ptrs.len());
+        let numbers = [1i32, 2];
+        let ages = plat_array_create_i32(numbers.as_ptr(), numbers.len());
+
+        // Stops at the shorter array; each tuple is (pointer at offset 0, i32 at offset 8)
+        let pairs = plat_array_zip(names, ages);
+        let values: Vec<(String, i32)> = (0..plat_array_len(pairs))
+            .map(|i| unsafe {
+                let tuple = plat_array_get(pairs, i) as *const u8;
+                let name = CStr::from_ptr(*(tuple as *const *const c_char)).to_string_lossy().into_owned();
+                (name, *(tuple.add(8) as *const i32))
+            })
+            .collect();
+        assert_eq!(values, vec![("a".to_string(), 1), ("b".to_string(), 2)]);
+
+        let empty = plat_array_create_i32(std::ptr::null(), 0);
+        assert_eq!(plat_array_len(plat_array_zip(names, empty)), 0);
+    }
+
+    #[test]
+    fn test_array_enumerate() {
+        use crate::ffi::array::{plat_array_create_i64, plat_array_enumerate, plat_array_get, plat_array_len};
+
+        // Each tuple is (i32 index at offset 0, i64 element at offset 8)
+        let elements = [10i64, 20, 30];
+        let array = plat_array_create_i64(elements.as_ptr(), elements.len());
+        let pairs = plat_array_enumerate(array);
+        let values: Vec<(i32, i64)> = (0..plat_array_len(pairs))
+            .map(|i| unsafe {
+                let tuple = plat_array_get(pairs, i) as *const u8;
+                (*(tuple as *const i32), *(tuple.add(8) as *const i64))
+            })
+            .collect();
+        assert_eq!(values, vec![(0, 10), (1, 20), (2, 30)]);
+    }
+
+    #[test]
+    fn test_array_view_shares_storage() {
+        use crate::ffi::array::{
+            plat_array_append, plat_array_create_i32, plat_array_get, plat_array_len, plat_array_set, plat_array_view,
+        };
+
+        let elements = [1i32, 2, 3, 4, 5];
+        let array = plat_array_create_i32(elements.as_ptr(), elements.len());
+        let view = plat_array_view(array, 1, 4);
+        let read = |array| (0..plat_array_len(array)).map(|i| plat_array_get(array, i)).collect::<Vec<i64>>();
+        assert_eq!(read(view), vec![2, 3, 4]);
+
+        // Writes go through to the shared storage in both directions
+        plat_array_set(array, 2, 30);
+        plat_array_set(view, 0, 20);
+        assert_eq!(read(view), vec![20, 30, 4]);
+        assert_eq!(read(array), vec![1, 20, 30, 4, 5]);
+
+        // Growing the view moves it to its own storage rather than clobbering the source
+        assert!(plat_array_append(view, 40));
+        assert_eq!(read(view), vec![20, 30, 4, 40]);
+        assert_eq!(read(array), vec![1, 20, 30, 4, 5]);
+
+        assert_eq!(plat_array_len(plat_array_view(array, 5, 5)), 0);
+    }
+
+    #[test]
+    fn test_float_to_string_precision() {
+        use crate::ffi::conversions::plat_float_to_string_precision;
+        use std::ffi::CStr;
+
+        let format = |value: f64, digits: i32| {
+            unsafe { CStr::from_ptr(plat_float_to_string_precision(value, digits)) }.to_str().unwrap().to_string()
+        };
+
+        assert_eq!(format(3.14159, 2), "3.14");
+        assert_eq!(format(3.14159, 0), "3");
+        assert_eq!(format(2.5, 3), "2.500");
+        assert_eq!(format(-1.236, 2), "-1.24");
+        assert_eq!(format(7.0, -1), "7");
+    }
+
+    #[test]
+    fn test_string_char_at_safe() {
+        use crate::ffi::string::plat_string_char_at_safe;
+        use std::ffi::{CStr, CString};
+        use std::os::raw::c_char;
+
+        let text = CString::new("héllo").unwrap();
+        let (found, ptr) = plat_string_char_at_safe(text.as_ptr(), 1);
+        assert_eq!(found, 1);
+        assert_eq!(unsafe { CStr::from_ptr(ptr as *const c_char) }.to_str().unwrap(), "é");
+
+        assert_eq!(plat_string_char_at_safe(text.as_ptr(), 5), (0, 0));
+        assert_eq!(plat_string_char_at_safe(text.as_ptr(), -1), (0, 0));
+    }
+
+    #[test]
+    fn test_string_splitn_keeps_remaining_delimiters() {
+        use crate::ffi::array::{plat_array_get, plat_array_len, RuntimeArray};
+        use crate::ffi::string::plat_string_splitn;
+        use std::ffi::{CStr, CString};
+        use std::os::raw::c_char;
+
+        let parts = |array: *mut RuntimeArray| -> Vec<String> {
+            (0..plat_array_len(array))
+                .map(|i| unsafe { CStr::from_ptr(plat_array_get(array, i) as *const c_char) }.to_string_lossy().into_owned())
+                .collect()
+        };
+
+        let text = CString::new("key=value=extra").unwrap();
+        let delimiter = CString::new("=").unwrap();
+        assert_eq!(parts(plat_string_splitn(text.as_ptr(), delimiter.as_ptr(), 2)), vec!["key", "value=extra"]);
+        assert_eq!(parts(plat_string_splitn(text.as_ptr(), delimiter.as_ptr(), 0)), vec!["key", "value", "extra"]);
+        assert_eq!(parts(plat_string_splitn(text.as_ptr(), delimiter.as_ptr(), 10)), vec!["key", "value", "extra"]);
+    }
+
+    #[test]
+    fn test_string_lines_drops_final_empty_line() {
+        use crate::ffi::array::{plat_array_get, plat_array_len};
+        use crate::ffi::string::plat_string_lines;
+        use std::ffi::{CStr, CString};
+        use std::os::raw::c_char;
+
+        let lines = |text: &str| -> Vec<String> {
+            let text = CString::new(text).unwrap();
+            let array = plat_string_lines(text.as_ptr());
+            (0..plat_array_len(array))
+                .map(|i| unsafe { CStr::from_ptr(plat_array_get(array, i) as *const c_char) }.to_string_lossy().into_owned())
+                .collect()
+        };
+
+        assert_eq!(lines("a\r\nb\nc\n"), vec!["a", "b", "c"]);
+        assert_eq!(lines("a\n\nb"), vec!["a", "", "b"]);
+        assert!(lines("").is_empty());
+    }
+
+    #[test]
+    fn test_string_chars_splits_by_code_point() {
+        use crate::ffi::array::{plat_array_get, plat_array_len};
+        use crate::ffi::string::plat_string_chars;
+        use std::ffi::{CStr, CString};
+        use std::os::raw::c_char;
+
+        let text = CString::new("a\u{e9}\u{1F600}").unwrap();
+        let array = plat_string_chars(text.as_ptr());
+        let chars: Vec<String> = (0..plat_array_len(array))
+            .map(|i| unsafe { CStr::from_ptr(plat_array_get(array, i) as *const c_char) }.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(chars, vec!["a", "\u{e9}", "\u{1F600}"]);
+    }
+
+    #[test]
+    fn test_format_substitutes_placeholders() {
+        use crate::ffi::string::plat_format;
+        use crate::ffi::array::plat_array_create_string;
+        use std::ffi::{CStr, CString};
+
+        let parts: Vec<CString> = ["1", "2", "3"].iter().map(|s| CString::new(*s).unwrap()).collect();
+        let pointers: Vec<*const std::os::raw::c_char> = parts.iter().map(|s| s.as_ptr()).collect();
+        let args = plat_array_create_string(pointers.as_ptr(), pointers.len());
+
+        let template = CString::new("{0} + {1} = {2}").unwrap();
+        let result = plat_format(template.as_ptr(), args);
+        assert_eq!(unsafe { CStr::from_ptr(result) }.to_str().unwrap(), "1 + 2 = 3");
+
+        let template = CString::new("{3} {x} {1").unwrap();
+        let result = plat_format(template.as_ptr(), args);
+        assert_eq!(unsafe { CStr::from_ptr(result) }.to_str().unwrap(), "{3} {x} {1");
+    }
+
+    #[test]
+    fn test_bench_hooks_record_calls() {
+        use crate::ffi::time::{bench_stats, plat_bench_end, plat_bench_start};
+        use std::ffi::CString;
+
+        let name = CString::new("bench_runtime_hooks").unwrap();
+        for _ in 0..3 {
+            let start = plat_bench_start();
+            plat_bench_end(name.as_ptr(), start);
+        }
+        // The elapsed time is measured from the timestamp passed back in
+        plat_bench_end(name.as_ptr(), plat_bench_start() - 1_000_000);
+
+        let (count, total_nanos) = bench_stats("bench_runtime_hooks");
+        assert_eq!(count, 4);
+        assert!(total_nanos >= 1_000_000);
+        assert_eq!(bench_stats("bench_never_called"), (0, 0));
+    }
+
+    #[test]
+    fn test_gc_free_releases_large_array() {
+        use crate::ffi::array::{plat_array_create_i32, plat_array_free};
+        use crate::ffi::core::gc_freed_bytes;
+
+        let elements = vec![7i32; 100_000];
+        let array = plat_array_create_i32(elements.as_ptr(), elements.len());
+        let before = gc_freed_bytes();
+
+        plat_array_free(array);
+        plat_array_free(std::ptr::null_mut());
+
+        assert!(gc_freed_bytes() - before >= elements.len() * std::mem::size_of::<i32>());
+    }
+
+    #[test]
+    fn test_array_free_of_view_keeps_source() {
+        use crate::ffi::array::{plat_array_create_i32, plat_array_free, plat_array_get, plat_array_len, plat_array_view};
+
+        let elements = [1i32, 2, 3, 4, 5];
+        let array = plat_array_create_i32(elements.as_ptr(), elements.len());
+        let view = plat_array_view(array, 1, 4);
+
+        plat_array_free(view);
+
+        assert_eq!(plat_array_len(array), 5);
+        for (i, expected) in elements.iter().enumerate() {
+            assert_eq!(plat_array_get(array, i), *expected as i64);
+        }
+    }
+
+    #[test]
+    fn test_mutex_serializes_threads() {
+        use crate::ffi::sync::{plat_mutex_create, plat_mutex_lock, plat_mutex_unlock};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let mutex = plat_mutex_create();
+        let holders = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..4).map(|_| {
+            let holders = Arc::clone(&holders);
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    plat_mutex_lock(mutex);
+                    assert_eq!(holders.fetch_add(1, Ordering::SeqCst), 0);
+                    holders.fetch_sub(1, Ordering::SeqCst);
+                    plat_mutex_unlock(mutex);
+                }
+            })
+        }).collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // The last holder released it, so this thread can take it again
+        plat_mutex_lock(mutex);
+        plat_mutex_unlock(mutex);
+    }
+
+    #[test]
+    fn test_monotonic_clock_advances() {
+        use crate::ffi::time::{plat_now_millis, plat_now_nanos};
+
+        let start_nanos = plat_now_nanos();
+        let start_millis = plat_now_millis();
+        std::thread::sleep(std::time::Duration::from_millis(3));
+
+        assert!(plat_now_nanos() - start_nanos >= 3_000_000);
+        assert!(plat_now_millis() - start_millis >= 3);
+    }
+
+    #[test]
+    fn test_seeded_random_is_deterministic() {
+        use crate::ffi::random::{plat_random_float, plat_random_int, plat_seed_random};
+
+        plat_seed_random(7);
+        let first: Vec<i64> = (0..20).map(|_| plat_random_int(0, 10)).collect();
+        let first_float = plat_random_float();
+
+        plat_seed_random(7);
+        let second: Vec<i64> = (0..20).map(|_| plat_random_int(0, 10)).collect();
+        let second_float = plat_random_float();
+
+        assert_eq!(first, second);
+        assert_eq!(first_float, second_float);
+        assert!(first.iter().all(|&n| (0..10).contains(&n)));
+
+        // The upper bound is exclusive, and an empty range yields min
+        assert!((0..50).all(|_| plat_random_int(3, 4) == 3));
+        assert_eq!(plat_random_int(5, 5), 5);
+    }
+
+    #[test]
+    fn test_string_and_int_hash_are_stable() {
+        use crate::ffi::hash::{plat_int_hash, plat_string_hash};
+        use std::ffi::CString;
+
+        let apple = CString::new("apple").unwrap();
+        let apple_again = CString::new("apple").unwrap();
+        let pear = CString::new("pear").unwrap();
+
+        assert_eq!(plat_string_hash(apple.as_ptr()), plat_string_hash(apple_again.as_ptr()));
+        assert_ne!(plat_string_hash(apple.as_ptr()), plat_string_hash(pear.as_ptr()));
+        assert_eq!(plat_string_hash(std::ptr::null()), plat_string_hash(CString::new("").unwrap().as_ptr()));
+
+        assert_eq!(plat_int_hash(42), plat_int_hash(42));
+        assert_ne!(plat_int_hash(42), plat_int_hash(43));
+    }
+
+    #[test]
+    fn test_deep_eq_compares_contents() {
+        use crate::ffi::array::{plat_array_create_i32, plat_array_create_string};
+        use crate::ffi::dict::plat_dict_create;
+        use crate::ffi::equality::{plat_deep_eq, EQ_TYPE_ARRAY, EQ_TYPE_DICT, EQ_TYPE_STRING};
+        use std::ffi::CString;
+
+        let first = plat_array_create_i32([1, 2, 3].as_ptr(), 3) as i64;
+        let second = plat_array_create_i32([1, 2, 3].as_ptr(), 3) as i64;
+        let shorter = plat_array_create_i32([1, 2].as_ptr(), 2) as i64;
+        assert_eq!(plat_deep_eq(first, second, EQ_TYPE_ARRAY), 1);
+        assert_eq!(plat_deep_eq(first, shorter, EQ_TYPE_ARRAY), 0);
+
+        let x = CString::new("x").unwrap();
+        let x_again = CString::new("x").unwrap();
+        assert_eq!(plat_deep_eq(x.as_ptr() as i64, x_again.as_ptr() as i64, EQ_TYPE_STRING), 1);
+        let names = plat_array_create_string([x.as_ptr()].as_ptr(), 1) as i64;
+        let names_again = plat_array_create_string([x_again.as_ptr()].as_ptr(), 1) as i64;
+        assert_eq!(plat_deep_eq(names, names_again, EQ_TYPE_ARRAY), 1);
+
+        // Dict equality ignores insertion order and the unused upper half of Int32 slots
+        let a = CString::new("a").unwrap();
+        let b = CString::new("b").unwrap();
+        let forward = plat_dict_create([a.as_ptr(), b.as_ptr()].as_ptr(), [1, 2].as_ptr(), [0, 0].as_ptr(), 2) as i64;
+        let backward = plat_dict_create([b.as_ptr(), a.as_ptr()].as_ptr(), [2 | (7 << 32), 1].as_ptr(), [0, 0].as_ptr(), 2) as i64;
+        let changed = plat_dict_create([a.as_ptr(), b.as_ptr()].as_ptr(), [1, 3].as_ptr(), [0, 0].as_ptr(), 2) as i64;
+        assert_eq!(plat_deep_eq(forward, backward, EQ_TYPE_DICT), 1);
+        assert_eq!(plat_deep_eq(forward, changed, EQ_TYPE_DICT), 0);
+    }
+
+    #[test]
+    fn test_array_eq_with_compares_elements_through_callback() {
+        use crate::ffi::array::plat_array_create_class;
+        use crate::ffi::equality::plat_array_eq_with;
+
+        // Stand-in for a generated class equality function: compare the pointed-to values
+        extern "C" fn values_equal(a: i64, b: i64) -> i32 {
+            unsafe { (*(a as *const i32) == *(b as *const i32)) as i32 }
+        }
+
+        let (one, one_again, two) = (1i32, 1i32, 2i32);
+        let ptr = |value: &i32| value as *const i32 as *const u8;
+        let first = plat_array_create_class([ptr(&one)].as_ptr(), 1);
+        let second = plat_array_create_class([ptr(&one_again)].as_ptr(), 1);
+        let different = plat_array_create_class([ptr(&two)].as_ptr(), 1);
+        let longer = plat_array_create_class([ptr(&one), ptr(&two)].as_ptr(), 2);
+
+        assert_eq!(plat_array_eq_with(first, second, values_equal), 1);
+        assert_eq!(plat_array_eq_with(first, different, values_equal), 0);
+        assert_eq!(plat_array_eq_with(first, longer, values_equal), 0);
+    }
+
+    #[test]
+    fn test_string_eq_compares_contents() {
+        use crate::ffi::string::plat_string_eq;
+        use std::ffi::CString;
+
+        let built = CString::new(format!("{}{}", "ab", "c")).unwrap();
+        let literal = CString::new("abc").unwrap();
+        let other = CString::new("abd").unwrap();
+
+        assert_eq!(plat_string_eq(built.as_ptr(), literal.as_ptr()), 1);
+        assert_eq!(plat_string_eq(built.as_ptr(), other.as_ptr()), 0);
+        assert_eq!(plat_string_eq(std::ptr::null(), std::ptr::null()), 1);
+        assert_eq!(plat_string_eq(literal.as_ptr(), std::ptr::null()), 0);
+    }
+
+    #[test]
+    fn test_clone_collections_are_independent() {
+        use crate::ffi::array::{plat_array_append, plat_array_clone, plat_array_create_i32, plat_array_len};
+        use crate::ffi::set::{plat_set_clone, plat_set_create};
+
+        let original = plat_array_create_i32([1, 2, 3].as_ptr(), 3);
+        let copy = plat_array_clone(original);
+        assert_ne!(original, copy);
+        assert!(plat_array_append(copy, 4));
+        assert_eq!(plat_array_len(original), 3);
+        assert_eq!(plat_array_len(copy), 4);
+
+        let set = plat_set_create([1i64, 2].as_ptr(), [0u8, 0].as_ptr(), 2);
+        let set_copy = plat_set_clone(set);
+        unsafe {
+            assert_ne!((*set).values, (*set_copy).values);
+            assert_eq!((*set_copy).length, 2);
+        }
+    }
+}